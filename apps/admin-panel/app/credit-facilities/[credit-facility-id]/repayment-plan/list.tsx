@@ -35,6 +35,8 @@ export const CreditFacilityRepaymentPlan: React.FC<CreditFacilityRepaymentPlanPr
         return t("repaymentTypes.principal")
       case "INTEREST":
         return t("repaymentTypes.interest")
+      case "FEE":
+        return t("repaymentTypes.fee")
       default:
         return type
     }
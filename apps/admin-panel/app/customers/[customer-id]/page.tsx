@@ -79,6 +79,14 @@ gql`
                   createdAt
                 }
               }
+              ... on InterestAccrualEntry {
+                recordedAt
+                amount
+              }
+              ... on InterestCapitalizationEntry {
+                recordedAt
+                amount
+              }
             }
           }
         }
@@ -42,6 +42,8 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
         "CancelledWithdrawalEntry",
         "DisbursalEntry",
         "PaymentEntry",
+        "InterestAccrualEntry",
+        "InterestCapitalizationEntry",
       ].includes(entry.__typename),
   )
 
@@ -68,6 +70,10 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
             return t("table.types.disbursal")
           case "PaymentEntry":
             return t("table.types.payment")
+          case "InterestAccrualEntry":
+            return t("table.types.interestAccrual")
+          case "InterestCapitalizationEntry":
+            return t("table.types.interestCapitalization")
           default:
             return "-"
         }
@@ -87,6 +93,9 @@ export const CustomerTransactionsTable: React.FC<CustomerTransactionsTableProps>
             return <Balance amount={entry.disbursal.amount} currency="usd" />
           case "PaymentEntry":
             return <Balance amount={entry.payment.amount} currency="usd" />
+          case "InterestAccrualEntry":
+          case "InterestCapitalizationEntry":
+            return <Balance amount={entry.amount} currency="usd" />
           default:
             return "-"
         }
@@ -41,11 +41,19 @@ const initialFormData: CreditModuleConfigureInput = {
   chartOfAccountFacilityOmnibusParentCode: "",
   chartOfAccountCollateralOmnibusParentCode: "",
   chartOfAccountInLiquidationOmnibusParentCode: "",
+  chartOfAccountLiquidatorOmnibusParentCode: "",
+  chartOfAccountLiquidationProceedsOmnibusParentCode: "",
+  chartOfAccountCreditLossExpenseOmnibusParentCode: "",
   chartOfAccountFacilityParentCode: "",
   chartOfAccountCollateralParentCode: "",
   chartOfAccountInLiquidationParentCode: "",
   chartOfAccountInterestIncomeParentCode: "",
   chartOfAccountFeeIncomeParentCode: "",
+  chartOfAccountUnappliedFundsParentCode: "",
+  chartOfAccountPenaltyIncomeParentCode: "",
+  chartOfAccountCommitmentFeeIncomeParentCode: "",
+  chartOfAccountMaintenanceFeeIncomeParentCode: "",
+  chartOfAccountDisbursalFeeIncomeParentCode: "",
   chartOfAccountShortTermIndividualDisbursedReceivableParentCode: "",
   chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode: "",
   chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode: "",
@@ -87,11 +95,19 @@ const creditModuleCodes = {
   chartOfAccountFacilityOmnibusParentCode: "9110.02.0201",
   chartOfAccountCollateralOmnibusParentCode: "9220.08.0201",
   chartOfAccountInLiquidationOmnibusParentCode: "9170.00.0001",
+  chartOfAccountLiquidatorOmnibusParentCode: "9170.00.0001",
+  chartOfAccountLiquidationProceedsOmnibusParentCode: "9170.00.0001",
+  chartOfAccountCreditLossExpenseOmnibusParentCode: "9170.00.0001",
   chartOfAccountFacilityParentCode: "9110.02.0201",
   chartOfAccountCollateralParentCode: "9220.08.0201",
   chartOfAccountInLiquidationParentCode: "9170.00.0001",
   chartOfAccountInterestIncomeParentCode: "6110.01.0100",
   chartOfAccountFeeIncomeParentCode: "6110.01.0300",
+  chartOfAccountUnappliedFundsParentCode: "2110.01.0104",
+  chartOfAccountPenaltyIncomeParentCode: "6110.01.0400",
+  chartOfAccountCommitmentFeeIncomeParentCode: "6110.01.00500",
+  chartOfAccountMaintenanceFeeIncomeParentCode: "6110.01.00600",
+  chartOfAccountDisbursalFeeIncomeParentCode: "6110.01.00700",
   chartOfAccountShortTermIndividualInterestReceivableParentCode: "1141.04.9901",
   chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode: "1141.02.9901",
   chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode: "1141.03.9901",
@@ -27,6 +27,7 @@ gql`
       depositConfig {
         chartOfAccountsId
         chartOfAccountsOmnibusParentCode
+        chartOfAccountsInterestExpenseParentCode
         chartOfAccountsIndividualDepositAccountsParentCode
         chartOfAccountsGovernmentEntityDepositAccountsParentCode
         chartOfAccountPrivateCompanyDepositAccountsParentCode
@@ -46,6 +47,7 @@ type DepositConfigUpdateDialogProps = {
 
 const initialFormData: DepositModuleConfigureInput = {
   chartOfAccountsOmnibusParentCode: "",
+  chartOfAccountsInterestExpenseParentCode: "",
   chartOfAccountsIndividualDepositAccountsParentCode: "",
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: "",
   chartOfAccountPrivateCompanyDepositAccountsParentCode: "",
@@ -56,6 +58,7 @@ const initialFormData: DepositModuleConfigureInput = {
 
 const depositModuleCodes = {
   chartOfAccountsOmnibusParentCode: "1110.01.0101",
+  chartOfAccountsInterestExpenseParentCode: "7110.01.0100",
   chartOfAccountsIndividualDepositAccountsParentCode: "2110.01.0401",
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: "2110.01.0201",
   chartOfAccountPrivateCompanyDepositAccountsParentCode: "2110.01.0301",
@@ -88,6 +91,7 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
     if (
       depositModuleConfig &&
       depositModuleConfig.chartOfAccountsOmnibusParentCode &&
+      depositModuleConfig.chartOfAccountsInterestExpenseParentCode &&
       depositModuleConfig.chartOfAccountsIndividualDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountsGovernmentEntityDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountPrivateCompanyDepositAccountsParentCode &&
@@ -98,6 +102,8 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
       setFormData({
         chartOfAccountsOmnibusParentCode:
           depositModuleConfig.chartOfAccountsOmnibusParentCode,
+        chartOfAccountsInterestExpenseParentCode:
+          depositModuleConfig.chartOfAccountsInterestExpenseParentCode,
         chartOfAccountsIndividualDepositAccountsParentCode:
           depositModuleConfig.chartOfAccountsIndividualDepositAccountsParentCode,
         chartOfAccountsGovernmentEntityDepositAccountsParentCode:
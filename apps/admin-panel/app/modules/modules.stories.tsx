@@ -123,6 +123,11 @@ const DataStory = () => {
             chartOfAccountInterestReceivableParentCode: "51.01",
             chartOfAccountInterestIncomeParentCode: "41.01.0101",
             chartOfAccountFeeIncomeParentCode: "51.01",
+            chartOfAccountUnappliedFundsParentCode: "21.01.0104",
+            chartOfAccountPenaltyIncomeParentCode: "41.01.0104",
+            chartOfAccountCommitmentFeeIncomeParentCode: "41.01.0105",
+            chartOfAccountMaintenanceFeeIncomeParentCode: "41.01.0106",
+            chartOfAccountDisbursalFeeIncomeParentCode: "41.01.0107",
           },
         },
       },
@@ -35,6 +35,7 @@ gql`
   query depositConfig {
     depositConfig {
       chartOfAccountsOmnibusParentCode
+      chartOfAccountsInterestExpenseParentCode
       chartOfAccountsIndividualDepositAccountsParentCode
       chartOfAccountsGovernmentEntityDepositAccountsParentCode
       chartOfAccountPrivateCompanyDepositAccountsParentCode
@@ -49,11 +50,19 @@ gql`
       chartOfAccountFacilityOmnibusParentCode
       chartOfAccountCollateralOmnibusParentCode
       chartOfAccountInLiquidationOmnibusParentCode
+      chartOfAccountLiquidatorOmnibusParentCode
+      chartOfAccountLiquidationProceedsOmnibusParentCode
+      chartOfAccountCreditLossExpenseOmnibusParentCode
       chartOfAccountFacilityParentCode
       chartOfAccountCollateralParentCode
       chartOfAccountInLiquidationParentCode
       chartOfAccountInterestIncomeParentCode
       chartOfAccountFeeIncomeParentCode
+      chartOfAccountUnappliedFundsParentCode
+      chartOfAccountPenaltyIncomeParentCode
+      chartOfAccountCommitmentFeeIncomeParentCode
+      chartOfAccountMaintenanceFeeIncomeParentCode
+      chartOfAccountDisbursalFeeIncomeParentCode
       chartOfAccountShortTermIndividualDisbursedReceivableParentCode
       chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode
       chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode
@@ -37,6 +37,11 @@ export type Scalars = {
   UsdCents: { input: UsdCents; output: UsdCents; }
 };
 
+export type AbaBankAccountInput = {
+  accountNumber: Scalars['String']['input'];
+  routingNumber: Scalars['String']['input'];
+};
+
 export enum AccountStatus {
   Active = 'ACTIVE',
   Inactive = 'INACTIVE'
@@ -147,7 +152,9 @@ export enum ApprovalProcessStatus {
 export type ApprovalProcessTarget = CreditFacility | CreditFacilityDisbursal | Withdrawal;
 
 export enum ApprovalProcessType {
+  CollateralWithdrawalApproval = 'COLLATERAL_WITHDRAWAL_APPROVAL',
   CreditFacilityApproval = 'CREDIT_FACILITY_APPROVAL',
+  CreditFacilityRestructuringApproval = 'CREDIT_FACILITY_RESTRUCTURING_APPROVAL',
   DisbursalApproval = 'DISBURSAL_APPROVAL',
   WithdrawalApproval = 'WITHDRAWAL_APPROVAL'
 }
@@ -228,6 +235,24 @@ export type BalanceSheetModuleConfigurePayload = {
   balanceSheetConfig: BalanceSheetModuleConfig;
 };
 
+export type BankAccountDetailsInput =
+  { aba: AbaBankAccountInput; iban?: never; }
+  |  { aba?: never; iban: IbanBankAccountInput; };
+
+export enum BankAccountType {
+  Aba = 'ABA',
+  Iban = 'IBAN'
+}
+
+export type BeneficiaryAccount = {
+  __typename?: 'BeneficiaryAccount';
+  accountType: BankAccountType;
+  bankName: Scalars['String']['output'];
+  beneficiaryAccountId: Scalars['UUID']['output'];
+  holderName: Scalars['String']['output'];
+  maskedAccountNumber: Scalars['String']['output'];
+};
+
 export type BtcAmount = {
   __typename?: 'BtcAmount';
   btc: Scalars['Satoshis']['output'];
@@ -512,10 +537,12 @@ export type CreditFacilityDisbursal = {
   __typename?: 'CreditFacilityDisbursal';
   amount: Scalars['UsdCents']['output'];
   approvalProcess: ApprovalProcess;
+  beneficiaryAccount?: Maybe<BeneficiaryAccount>;
   createdAt: Scalars['Timestamp']['output'];
   creditFacility: CreditFacility;
   disbursalId: Scalars['UUID']['output'];
   id: Scalars['ID']['output'];
+  paymentExternalId?: Maybe<Scalars['String']['output']>;
   status: DisbursalStatus;
 };
 
@@ -548,6 +575,7 @@ export type CreditFacilityDisbursalExecuted = {
 
 export type CreditFacilityDisbursalInitiateInput = {
   amount: Scalars['UsdCents']['input'];
+  beneficiaryAccountId?: InputMaybe<Scalars['UUID']['input']>;
   creditFacilityId: Scalars['UUID']['input'];
 };
 
@@ -556,6 +584,25 @@ export type CreditFacilityDisbursalInitiatePayload = {
   disbursal: CreditFacilityDisbursal;
 };
 
+export type CreditFacilityDisbursalPaymentReturnInput = {
+  disbursalId: Scalars['UUID']['input'];
+  reason: Scalars['String']['input'];
+};
+
+export type CreditFacilityDisbursalPaymentReturnPayload = {
+  __typename?: 'CreditFacilityDisbursalPaymentReturnPayload';
+  disbursal: CreditFacilityDisbursal;
+};
+
+export type CreditFacilityDisbursalPaymentSettleInput = {
+  disbursalId: Scalars['UUID']['input'];
+};
+
+export type CreditFacilityDisbursalPaymentSettlePayload = {
+  __typename?: 'CreditFacilityDisbursalPaymentSettlePayload';
+  disbursal: CreditFacilityDisbursal;
+};
+
 /** An edge in a connection. */
 export type CreditFacilityEdge = {
   __typename?: 'CreditFacilityEdge';
@@ -633,6 +680,7 @@ export enum CreditFacilityRepaymentStatus {
 
 export enum CreditFacilityRepaymentType {
   Disbursal = 'DISBURSAL',
+  Fee = 'FEE',
   Interest = 'INTEREST'
 }
 
@@ -651,7 +699,15 @@ export type CreditModuleConfig = {
   chartOfAccountFacilityOmnibusParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountFacilityParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountUnappliedFundsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountPenaltyIncomeParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountCommitmentFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountMaintenanceFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountDisbursalFeeIncomeParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountInLiquidationOmnibusParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountLiquidatorOmnibusParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountLiquidationProceedsOmnibusParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountCreditLossExpenseOmnibusParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountInLiquidationParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountInterestIncomeParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountLongTermBankDisbursedReceivableParentCode?: Maybe<Scalars['String']['output']>;
@@ -698,7 +754,15 @@ export type CreditModuleConfigureInput = {
   chartOfAccountFacilityOmnibusParentCode: Scalars['String']['input'];
   chartOfAccountFacilityParentCode: Scalars['String']['input'];
   chartOfAccountFeeIncomeParentCode: Scalars['String']['input'];
+  chartOfAccountUnappliedFundsParentCode: Scalars['String']['input'];
+  chartOfAccountPenaltyIncomeParentCode: Scalars['String']['input'];
+  chartOfAccountCommitmentFeeIncomeParentCode: Scalars['String']['input'];
+  chartOfAccountMaintenanceFeeIncomeParentCode: Scalars['String']['input'];
+  chartOfAccountDisbursalFeeIncomeParentCode: Scalars['String']['input'];
   chartOfAccountInLiquidationOmnibusParentCode: Scalars['String']['input'];
+  chartOfAccountLiquidatorOmnibusParentCode: Scalars['String']['input'];
+  chartOfAccountLiquidationProceedsOmnibusParentCode: Scalars['String']['input'];
+  chartOfAccountCreditLossExpenseOmnibusParentCode: Scalars['String']['input'];
   chartOfAccountInLiquidationParentCode: Scalars['String']['input'];
   chartOfAccountInterestIncomeParentCode: Scalars['String']['input'];
   chartOfAccountLongTermBankDisbursedReceivableParentCode: Scalars['String']['input'];
@@ -794,6 +858,8 @@ export type CustodianEdge = {
 export type Customer = {
   __typename?: 'Customer';
   applicantId?: Maybe<Scalars['String']['output']>;
+  beneficiaryAccounts: Array<BeneficiaryAccount>;
+  btcWithdrawalAddress?: Maybe<Scalars['String']['output']>;
   createdAt: Scalars['Timestamp']['output'];
   creditFacilities: Array<CreditFacility>;
   customerId: Scalars['UUID']['output'];
@@ -809,6 +875,38 @@ export type Customer = {
   transactions: Array<Transaction>;
 };
 
+export type CustomerBeneficiaryAccountAddInput = {
+  bankName: Scalars['String']['input'];
+  customerId: Scalars['UUID']['input'];
+  details: BankAccountDetailsInput;
+  holderName: Scalars['String']['input'];
+};
+
+export type CustomerBeneficiaryAccountAddPayload = {
+  __typename?: 'CustomerBeneficiaryAccountAddPayload';
+  customer: Customer;
+};
+
+export type CustomerBeneficiaryAccountRemoveInput = {
+  beneficiaryAccountId: Scalars['UUID']['input'];
+  customerId: Scalars['UUID']['input'];
+};
+
+export type CustomerBeneficiaryAccountRemovePayload = {
+  __typename?: 'CustomerBeneficiaryAccountRemovePayload';
+  customer: Customer;
+};
+
+export type CustomerBtcWithdrawalAddressUpdateInput = {
+  address: Scalars['String']['input'];
+  customerId: Scalars['UUID']['input'];
+};
+
+export type CustomerBtcWithdrawalAddressUpdatePayload = {
+  __typename?: 'CustomerBtcWithdrawalAddressUpdatePayload';
+  customer: Customer;
+};
+
 export type CustomerConnection = {
   __typename?: 'CustomerConnection';
   /** A list of edges. */
@@ -985,7 +1083,7 @@ export type DepositAccountBalance = {
   settled: Scalars['UsdCents']['output'];
 };
 
-export type DepositAccountHistoryEntry = CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | PaymentEntry | UnknownEntry | WithdrawalEntry;
+export type DepositAccountHistoryEntry = CancelledWithdrawalEntry | DepositEntry | DisbursalEntry | InterestAccrualEntry | InterestCapitalizationEntry | PaymentEntry | UnknownEntry | WithdrawalEntry;
 
 export type DepositAccountHistoryEntryConnection = {
   __typename?: 'DepositAccountHistoryEntryConnection';
@@ -1040,6 +1138,7 @@ export type DepositModuleConfig = {
   chartOfAccountsGovernmentEntityDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsId?: Maybe<Scalars['UUID']['output']>;
   chartOfAccountsIndividualDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountsInterestExpenseParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsOmnibusParentCode?: Maybe<Scalars['String']['output']>;
 };
 
@@ -1050,6 +1149,7 @@ export type DepositModuleConfigureInput = {
   chartOfAccountPrivateCompanyDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsIndividualDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountsInterestExpenseParentCode: Scalars['String']['input'];
   chartOfAccountsOmnibusParentCode: Scalars['String']['input'];
 };
 
@@ -1079,7 +1179,9 @@ export enum DisbursalStatus {
   Approved = 'APPROVED',
   Confirmed = 'CONFIRMED',
   Denied = 'DENIED',
-  New = 'NEW'
+  New = 'NEW',
+  Returned = 'RETURNED',
+  Sent = 'SENT'
 }
 
 export type Disbursed = {
@@ -1118,6 +1220,11 @@ export type GovernanceNavigationItems = {
   policy: Scalars['Boolean']['output'];
 };
 
+export type IbanBankAccountInput = {
+  bic: Scalars['String']['input'];
+  iban: Scalars['String']['input'];
+};
+
 export type Interest = {
   __typename?: 'Interest';
   outstanding: Outstanding;
@@ -1125,6 +1232,20 @@ export type Interest = {
   total: Total;
 };
 
+export type InterestAccrualEntry = {
+  __typename?: 'InterestAccrualEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
+export type InterestCapitalizationEntry = {
+  __typename?: 'InterestCapitalizationEntry';
+  amount: Scalars['UsdCents']['output'];
+  recordedAt: Scalars['Timestamp']['output'];
+  txId: Scalars['UUID']['output'];
+};
+
 export enum InterestInterval {
   EndOfDay = 'END_OF_DAY',
   EndOfMonth = 'END_OF_MONTH'
@@ -1309,10 +1430,15 @@ export type Mutation = {
   creditFacilityComplete: CreditFacilityCompletePayload;
   creditFacilityCreate: CreditFacilityCreatePayload;
   creditFacilityDisbursalInitiate: CreditFacilityDisbursalInitiatePayload;
+  creditFacilityDisbursalPaymentReturn: CreditFacilityDisbursalPaymentReturnPayload;
+  creditFacilityDisbursalPaymentSettle: CreditFacilityDisbursalPaymentSettlePayload;
   creditFacilityPartialPayment: CreditFacilityPartialPaymentPayload;
   creditModuleConfigure: CreditModuleConfigurePayload;
   custodianConfigUpdate: CustodianConfigUpdatePayload;
   custodianCreate: CustodianCreatePayload;
+  customerBeneficiaryAccountAdd: CustomerBeneficiaryAccountAddPayload;
+  customerBeneficiaryAccountRemove: CustomerBeneficiaryAccountRemovePayload;
+  customerBtcWithdrawalAddressUpdate: CustomerBtcWithdrawalAddressUpdatePayload;
   customerCreate: CustomerCreatePayload;
   customerDocumentArchive: CustomerDocumentArchivePayload;
   customerDocumentAttach: CustomerDocumentCreatePayload;
@@ -1409,6 +1535,16 @@ export type MutationCreditFacilityDisbursalInitiateArgs = {
 };
 
 
+export type MutationCreditFacilityDisbursalPaymentReturnArgs = {
+  input: CreditFacilityDisbursalPaymentReturnInput;
+};
+
+
+export type MutationCreditFacilityDisbursalPaymentSettleArgs = {
+  input: CreditFacilityDisbursalPaymentSettleInput;
+};
+
+
 export type MutationCreditFacilityPartialPaymentArgs = {
   input: CreditFacilityPartialPaymentInput;
 };
@@ -1429,6 +1565,21 @@ export type MutationCustodianCreateArgs = {
 };
 
 
+export type MutationCustomerBeneficiaryAccountAddArgs = {
+  input: CustomerBeneficiaryAccountAddInput;
+};
+
+
+export type MutationCustomerBeneficiaryAccountRemoveArgs = {
+  input: CustomerBeneficiaryAccountRemoveInput;
+};
+
+
+export type MutationCustomerBtcWithdrawalAddressUpdateArgs = {
+  input: CustomerBtcWithdrawalAddressUpdateInput;
+};
+
+
 export type MutationCustomerCreateArgs = {
   input: CustomerCreateInput;
 };
@@ -2604,7 +2755,7 @@ export type GetCustomerTransactionHistoryQueryVariables = Exact<{
 }>;
 
 
-export type GetCustomerTransactionHistoryQuery = { __typename?: 'Query', customer?: { __typename?: 'Customer', id: string, customerId: string, customerType: CustomerType, depositAccount?: { __typename?: 'DepositAccount', depositAccountId: string, history: { __typename?: 'DepositAccountHistoryEntryConnection', pageInfo: { __typename?: 'PageInfo', hasNextPage: boolean, endCursor?: string | null, hasPreviousPage: boolean, startCursor?: string | null }, edges: Array<{ __typename?: 'DepositAccountHistoryEntryEdge', cursor: string, node: { __typename?: 'CancelledWithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } } | { __typename?: 'DepositEntry', recordedAt: any, deposit: { __typename?: 'Deposit', id: string, depositId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string } } | { __typename?: 'DisbursalEntry', recordedAt: any, disbursal: { __typename?: 'CreditFacilityDisbursal', id: string, disbursalId: string, amount: UsdCents, createdAt: any, status: DisbursalStatus } } | { __typename?: 'PaymentEntry', recordedAt: any, payment: { __typename?: 'CreditFacilityPaymentAllocation', id: string, paymentAllocationId: string, amount: UsdCents, createdAt: any } } | { __typename?: 'UnknownEntry' } | { __typename?: 'WithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } } }> } } | null } | null };
+export type GetCustomerTransactionHistoryQuery = { __typename?: 'Query', customer?: { __typename?: 'Customer', id: string, customerId: string, customerType: CustomerType, depositAccount?: { __typename?: 'DepositAccount', depositAccountId: string, history: { __typename?: 'DepositAccountHistoryEntryConnection', pageInfo: { __typename?: 'PageInfo', hasNextPage: boolean, endCursor?: string | null, hasPreviousPage: boolean, startCursor?: string | null }, edges: Array<{ __typename?: 'DepositAccountHistoryEntryEdge', cursor: string, node: { __typename?: 'CancelledWithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } } | { __typename?: 'DepositEntry', recordedAt: any, deposit: { __typename?: 'Deposit', id: string, depositId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string } } | { __typename?: 'DisbursalEntry', recordedAt: any, disbursal: { __typename?: 'CreditFacilityDisbursal', id: string, disbursalId: string, amount: UsdCents, createdAt: any, status: DisbursalStatus } } | { __typename?: 'InterestAccrualEntry', recordedAt: any, amount: UsdCents } | { __typename?: 'InterestCapitalizationEntry', recordedAt: any, amount: UsdCents } | { __typename?: 'PaymentEntry', recordedAt: any, payment: { __typename?: 'CreditFacilityPaymentAllocation', id: string, paymentAllocationId: string, amount: UsdCents, createdAt: any } } | { __typename?: 'UnknownEntry' } | { __typename?: 'WithdrawalEntry', recordedAt: any, withdrawal: { __typename?: 'Withdrawal', id: string, withdrawalId: string, accountId: string, amount: UsdCents, createdAt: any, reference: string, status: WithdrawalStatus } } }> } } | null } | null };
 
 export type CustomerEmailUpdateMutationVariables = Exact<{
   input: CustomerEmailUpdateInput;
@@ -2772,17 +2923,17 @@ export type DepositModuleConfigureMutationVariables = Exact<{
 }>;
 
 
-export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } } };
+export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsInterestExpenseParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } } };
 
 export type DepositConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } | null };
+export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsInterestExpenseParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } | null };
 
 export type CreditConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type CreditConfigQuery = { __typename?: 'Query', creditConfig?: { __typename?: 'CreditModuleConfig', chartOfAccountFacilityOmnibusParentCode?: string | null, chartOfAccountCollateralOmnibusParentCode?: string | null, chartOfAccountInLiquidationOmnibusParentCode?: string | null, chartOfAccountFacilityParentCode?: string | null, chartOfAccountCollateralParentCode?: string | null, chartOfAccountInLiquidationParentCode?: string | null, chartOfAccountInterestIncomeParentCode?: string | null, chartOfAccountFeeIncomeParentCode?: string | null, chartOfAccountShortTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountShortTermBankInterestReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermBankInterestReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountOverdueIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountOverduePrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueBankDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueNonDomiciledCompanyDisbursedReceivableParentCode?: string | null } | null };
+export type CreditConfigQuery = { __typename?: 'Query', creditConfig?: { __typename?: 'CreditModuleConfig', chartOfAccountFacilityOmnibusParentCode?: string | null, chartOfAccountCollateralOmnibusParentCode?: string | null, chartOfAccountInLiquidationOmnibusParentCode?: string | null, chartOfAccountLiquidatorOmnibusParentCode?: string | null, chartOfAccountLiquidationProceedsOmnibusParentCode?: string | null, chartOfAccountCreditLossExpenseOmnibusParentCode?: string | null, chartOfAccountFacilityParentCode?: string | null, chartOfAccountCollateralParentCode?: string | null, chartOfAccountInLiquidationParentCode?: string | null, chartOfAccountInterestIncomeParentCode?: string | null, chartOfAccountFeeIncomeParentCode?: string | null, chartOfAccountUnappliedFundsParentCode?: string | null, chartOfAccountPenaltyIncomeParentCode?: string | null, chartOfAccountCommitmentFeeIncomeParentCode?: string | null, chartOfAccountMaintenanceFeeIncomeParentCode?: string | null, chartOfAccountDisbursalFeeIncomeParentCode?: string | null, chartOfAccountShortTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermBankDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountShortTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountShortTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountShortTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountShortTermBankInterestReceivableParentCode?: string | null, chartOfAccountShortTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountShortTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountShortTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermIndividualInterestReceivableParentCode?: string | null, chartOfAccountLongTermGovernmentEntityInterestReceivableParentCode?: string | null, chartOfAccountLongTermPrivateCompanyInterestReceivableParentCode?: string | null, chartOfAccountLongTermBankInterestReceivableParentCode?: string | null, chartOfAccountLongTermFinancialInstitutionInterestReceivableParentCode?: string | null, chartOfAccountLongTermForeignAgencyOrSubsidiaryInterestReceivableParentCode?: string | null, chartOfAccountLongTermNonDomiciledCompanyInterestReceivableParentCode?: string | null, chartOfAccountOverdueIndividualDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueGovernmentEntityDisbursedReceivableParentCode?: string | null, chartOfAccountOverduePrivateCompanyDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueBankDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueFinancialInstitutionDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueForeignAgencyOrSubsidiaryDisbursedReceivableParentCode?: string | null, chartOfAccountOverdueNonDomiciledCompanyDisbursedReceivableParentCode?: string | null } | null };
 
 export type BalanceSheetConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
@@ -4857,6 +5008,14 @@ export const GetCustomerTransactionHistoryDocument = gql`
                 createdAt
               }
             }
+            ... on InterestAccrualEntry {
+              recordedAt
+              amount
+            }
+            ... on InterestCapitalizationEntry {
+              recordedAt
+              amount
+            }
           }
         }
       }
@@ -5925,6 +6084,7 @@ export const DepositModuleConfigureDocument = gql`
     depositConfig {
       chartOfAccountsId
       chartOfAccountsOmnibusParentCode
+      chartOfAccountsInterestExpenseParentCode
       chartOfAccountsIndividualDepositAccountsParentCode
       chartOfAccountsGovernmentEntityDepositAccountsParentCode
       chartOfAccountPrivateCompanyDepositAccountsParentCode
@@ -5965,6 +6125,7 @@ export const DepositConfigDocument = gql`
     query depositConfig {
   depositConfig {
     chartOfAccountsOmnibusParentCode
+    chartOfAccountsInterestExpenseParentCode
     chartOfAccountsIndividualDepositAccountsParentCode
     chartOfAccountsGovernmentEntityDepositAccountsParentCode
     chartOfAccountPrivateCompanyDepositAccountsParentCode
@@ -6012,11 +6173,19 @@ export const CreditConfigDocument = gql`
     chartOfAccountFacilityOmnibusParentCode
     chartOfAccountCollateralOmnibusParentCode
     chartOfAccountInLiquidationOmnibusParentCode
+    chartOfAccountLiquidatorOmnibusParentCode
+    chartOfAccountLiquidationProceedsOmnibusParentCode
+    chartOfAccountCreditLossExpenseOmnibusParentCode
     chartOfAccountFacilityParentCode
     chartOfAccountCollateralParentCode
     chartOfAccountInLiquidationParentCode
     chartOfAccountInterestIncomeParentCode
     chartOfAccountFeeIncomeParentCode
+    chartOfAccountUnappliedFundsParentCode
+    chartOfAccountPenaltyIncomeParentCode
+    chartOfAccountCommitmentFeeIncomeParentCode
+    chartOfAccountMaintenanceFeeIncomeParentCode
+    chartOfAccountDisbursalFeeIncomeParentCode
     chartOfAccountShortTermIndividualDisbursedReceivableParentCode
     chartOfAccountShortTermGovernmentEntityDisbursedReceivableParentCode
     chartOfAccountShortTermPrivateCompanyDisbursedReceivableParentCode
@@ -856,7 +856,15 @@ export const mockCreditModuleConfig = (overrides?: Partial<CreditModuleConfig>,
         chartOfAccountFacilityOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFacilityOmnibusParentCode') ? overrides.chartOfAccountFacilityOmnibusParentCode! : faker.lorem.word(),
         chartOfAccountFacilityParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFacilityParentCode') ? overrides.chartOfAccountFacilityParentCode! : faker.lorem.word(),
         chartOfAccountFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFeeIncomeParentCode') ? overrides.chartOfAccountFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountUnappliedFundsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountUnappliedFundsParentCode') ? overrides.chartOfAccountUnappliedFundsParentCode! : faker.lorem.word(),
+        chartOfAccountPenaltyIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountPenaltyIncomeParentCode') ? overrides.chartOfAccountPenaltyIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountCommitmentFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountCommitmentFeeIncomeParentCode') ? overrides.chartOfAccountCommitmentFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountMaintenanceFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountMaintenanceFeeIncomeParentCode') ? overrides.chartOfAccountMaintenanceFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountDisbursalFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountDisbursalFeeIncomeParentCode') ? overrides.chartOfAccountDisbursalFeeIncomeParentCode! : faker.lorem.word(),
         chartOfAccountInLiquidationOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInLiquidationOmnibusParentCode') ? overrides.chartOfAccountInLiquidationOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountLiquidatorOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLiquidatorOmnibusParentCode') ? overrides.chartOfAccountLiquidatorOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountLiquidationProceedsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLiquidationProceedsOmnibusParentCode') ? overrides.chartOfAccountLiquidationProceedsOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountCreditLossExpenseOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountCreditLossExpenseOmnibusParentCode') ? overrides.chartOfAccountCreditLossExpenseOmnibusParentCode! : faker.lorem.word(),
         chartOfAccountInLiquidationParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInLiquidationParentCode') ? overrides.chartOfAccountInLiquidationParentCode! : faker.lorem.word(),
         chartOfAccountInterestIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestIncomeParentCode') ? overrides.chartOfAccountInterestIncomeParentCode! : faker.lorem.word(),
         chartOfAccountLongTermBankDisbursedReceivableParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLongTermBankDisbursedReceivableParentCode') ? overrides.chartOfAccountLongTermBankDisbursedReceivableParentCode! : faker.lorem.word(),
@@ -907,7 +915,15 @@ export const mockCreditModuleConfigureInput = (overrides?: Partial<CreditModuleC
         chartOfAccountFacilityOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFacilityOmnibusParentCode') ? overrides.chartOfAccountFacilityOmnibusParentCode! : faker.lorem.word(),
         chartOfAccountFacilityParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFacilityParentCode') ? overrides.chartOfAccountFacilityParentCode! : faker.lorem.word(),
         chartOfAccountFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountFeeIncomeParentCode') ? overrides.chartOfAccountFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountUnappliedFundsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountUnappliedFundsParentCode') ? overrides.chartOfAccountUnappliedFundsParentCode! : faker.lorem.word(),
+        chartOfAccountPenaltyIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountPenaltyIncomeParentCode') ? overrides.chartOfAccountPenaltyIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountCommitmentFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountCommitmentFeeIncomeParentCode') ? overrides.chartOfAccountCommitmentFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountMaintenanceFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountMaintenanceFeeIncomeParentCode') ? overrides.chartOfAccountMaintenanceFeeIncomeParentCode! : faker.lorem.word(),
+        chartOfAccountDisbursalFeeIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountDisbursalFeeIncomeParentCode') ? overrides.chartOfAccountDisbursalFeeIncomeParentCode! : faker.lorem.word(),
         chartOfAccountInLiquidationOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInLiquidationOmnibusParentCode') ? overrides.chartOfAccountInLiquidationOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountLiquidatorOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLiquidatorOmnibusParentCode') ? overrides.chartOfAccountLiquidatorOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountLiquidationProceedsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLiquidationProceedsOmnibusParentCode') ? overrides.chartOfAccountLiquidationProceedsOmnibusParentCode! : faker.lorem.word(),
+        chartOfAccountCreditLossExpenseOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountCreditLossExpenseOmnibusParentCode') ? overrides.chartOfAccountCreditLossExpenseOmnibusParentCode! : faker.lorem.word(),
         chartOfAccountInLiquidationParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInLiquidationParentCode') ? overrides.chartOfAccountInLiquidationParentCode! : faker.lorem.word(),
         chartOfAccountInterestIncomeParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestIncomeParentCode') ? overrides.chartOfAccountInterestIncomeParentCode! : faker.lorem.word(),
         chartOfAccountLongTermBankDisbursedReceivableParentCode: overrides && overrides.hasOwnProperty('chartOfAccountLongTermBankDisbursedReceivableParentCode') ? overrides.chartOfAccountLongTermBankDisbursedReceivableParentCode! : faker.lorem.word(),
@@ -1350,6 +1366,7 @@ export const mockDepositModuleConfig = (overrides?: Partial<DepositModuleConfig>
         chartOfAccountsGovernmentEntityDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsGovernmentEntityDepositAccountsParentCode') ? overrides.chartOfAccountsGovernmentEntityDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsId: overrides && overrides.hasOwnProperty('chartOfAccountsId') ? overrides.chartOfAccountsId! : generateMockValue.uuid(),
         chartOfAccountsIndividualDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsIndividualDepositAccountsParentCode') ? overrides.chartOfAccountsIndividualDepositAccountsParentCode! : faker.lorem.word(),
+        chartOfAccountsInterestExpenseParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsInterestExpenseParentCode') ? overrides.chartOfAccountsInterestExpenseParentCode! : faker.lorem.word(),
         chartOfAccountsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsOmnibusParentCode') ? overrides.chartOfAccountsOmnibusParentCode! : faker.lorem.word(),
     };
 };
@@ -1364,6 +1381,7 @@ export const mockDepositModuleConfigureInput = (overrides?: Partial<DepositModul
         chartOfAccountPrivateCompanyDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountPrivateCompanyDepositAccountsParentCode') ? overrides.chartOfAccountPrivateCompanyDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsGovernmentEntityDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsGovernmentEntityDepositAccountsParentCode') ? overrides.chartOfAccountsGovernmentEntityDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsIndividualDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsIndividualDepositAccountsParentCode') ? overrides.chartOfAccountsIndividualDepositAccountsParentCode! : faker.lorem.word(),
+        chartOfAccountsInterestExpenseParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsInterestExpenseParentCode') ? overrides.chartOfAccountsInterestExpenseParentCode! : faker.lorem.word(),
         chartOfAccountsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsOmnibusParentCode') ? overrides.chartOfAccountsOmnibusParentCode! : faker.lorem.word(),
     };
 };
@@ -105,6 +105,10 @@ export const formatProcessType = (processType: ApprovalProcessType) => {
   switch (processType) {
     case ApprovalProcessType.CreditFacilityApproval:
       return "Credit Facility"
+    case ApprovalProcessType.CreditFacilityRestructuringApproval:
+      return "Credit Facility Restructuring"
+    case ApprovalProcessType.CollateralWithdrawalApproval:
+      return "Collateral Withdrawal"
     case ApprovalProcessType.WithdrawalApproval:
       return "Withdrawal"
     case ApprovalProcessType.DisbursalApproval:
@@ -105,6 +105,8 @@ const getRepaymentTypeDisplay = (type: RepaymentPlan["repaymentType"]) => {
       return "Principal"
     case "INTEREST":
       return "Interest"
+    case "FEE":
+      return "Fee"
     default:
       return type
   }
@@ -77,6 +77,14 @@ gql`
                     createdAt
                   }
                 }
+                ... on InterestAccrualEntry {
+                  recordedAt
+                  amount
+                }
+                ... on InterestCapitalizationEntry {
+                  recordedAt
+                  amount
+                }
               }
             }
           }
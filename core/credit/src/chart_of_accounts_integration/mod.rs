@@ -18,11 +18,19 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_facility_omnibus_parent_code: AccountCode,
     pub chart_of_account_collateral_omnibus_parent_code: AccountCode,
     pub chart_of_account_in_liquidation_omnibus_parent_code: AccountCode,
+    pub chart_of_account_liquidator_omnibus_parent_code: AccountCode,
+    pub chart_of_account_liquidation_proceeds_omnibus_parent_code: AccountCode,
+    pub chart_of_account_credit_loss_expense_omnibus_parent_code: AccountCode,
     pub chart_of_account_facility_parent_code: AccountCode,
     pub chart_of_account_collateral_parent_code: AccountCode,
     pub chart_of_account_in_liquidation_parent_code: AccountCode,
     pub chart_of_account_interest_income_parent_code: AccountCode,
     pub chart_of_account_fee_income_parent_code: AccountCode,
+    pub chart_of_account_unapplied_funds_parent_code: AccountCode,
+    pub chart_of_account_penalty_income_parent_code: AccountCode,
+    pub chart_of_account_commitment_fee_income_parent_code: AccountCode,
+    pub chart_of_account_maintenance_fee_income_parent_code: AccountCode,
+    pub chart_of_account_disbursal_fee_income_parent_code: AccountCode,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: AccountCode,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: AccountCode,
@@ -145,6 +153,14 @@ where
         let in_liquidation_omnibus_parent_account_set_id = chart.account_set_id_from_code(
             &config.chart_of_account_in_liquidation_omnibus_parent_code,
         )?;
+        let liquidator_omnibus_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_liquidator_omnibus_parent_code)?;
+        let liquidation_proceeds_omnibus_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_account_liquidation_proceeds_omnibus_parent_code,
+        )?;
+        let credit_loss_expense_omnibus_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_account_credit_loss_expense_omnibus_parent_code,
+        )?;
         let facility_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_facility_parent_code)?;
         let collateral_parent_account_set_id =
@@ -155,6 +171,17 @@ where
             chart.account_set_id_from_code(&config.chart_of_account_interest_income_parent_code)?;
         let fee_income_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_fee_income_parent_code)?;
+        let unapplied_funds_parent_account_set_id =
+            chart.account_set_id_from_code(&config.chart_of_account_unapplied_funds_parent_code)?;
+        let penalty_income_parent_account_set_id =
+            chart.account_set_id_from_code(&config.chart_of_account_penalty_income_parent_code)?;
+        let commitment_fee_income_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_commitment_fee_income_parent_code)?;
+        let maintenance_fee_income_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_account_maintenance_fee_income_parent_code,
+        )?;
+        let disbursal_fee_income_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_disbursal_fee_income_parent_code)?;
 
         let short_term_individual_disbursed_receivable_parent_account_set_id = chart
             .account_set_id_from_code(
@@ -335,11 +362,19 @@ where
             facility_omnibus_parent_account_set_id,
             collateral_omnibus_parent_account_set_id,
             in_liquidation_omnibus_parent_account_set_id,
+            liquidator_omnibus_parent_account_set_id,
+            liquidation_proceeds_omnibus_parent_account_set_id,
+            credit_loss_expense_omnibus_parent_account_set_id,
             facility_parent_account_set_id,
             collateral_parent_account_set_id,
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            unapplied_funds_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            commitment_fee_income_parent_account_set_id,
+            maintenance_fee_income_parent_account_set_id,
+            disbursal_fee_income_parent_account_set_id,
 
             short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta {
                 short_term_individual_disbursed_receivable_parent_account_set_id,
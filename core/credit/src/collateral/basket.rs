@@ -0,0 +1,142 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use core_price::{AssetPrice, PriceOfOneBTC};
+
+use crate::{
+    primitives::{CollateralAsset, Satoshis},
+    terms::CollateralHaircuts,
+};
+
+use super::{entity::Collateral, error::CollateralError};
+
+/// All collateral pledged to a single facility, one position per asset.
+#[derive(Debug, Clone, Default)]
+pub struct CollateralBasket {
+    positions: Vec<(CollateralAsset, u64)>,
+}
+
+impl CollateralBasket {
+    pub fn new(positions: impl IntoIterator<Item = (CollateralAsset, u64)>) -> Self {
+        Self {
+            positions: positions.into_iter().collect(),
+        }
+    }
+
+    pub fn assets(&self) -> impl Iterator<Item = CollateralAsset> + '_ {
+        self.positions.iter().map(|(asset, _)| *asset)
+    }
+
+    /// Whether the basket can be valued at the BTC price alone without any
+    /// adjustment, which is the case for every facility holding only BTC.
+    pub fn is_plain_btc(&self, haircuts: &CollateralHaircuts) -> bool {
+        haircuts.for_asset(CollateralAsset::Btc).retained_fraction() == Decimal::ONE
+            && self
+                .positions
+                .iter()
+                .all(|(asset, units)| *asset == CollateralAsset::Btc || *units == 0)
+    }
+
+    /// Amount of BTC worth as much as the haircut-adjusted basket at
+    /// `btc_price`. Lets the basket stand in for BTC collateral in the
+    /// existing CVL calculations. `prices` must cover every non-BTC asset held.
+    pub fn btc_equivalent(
+        &self,
+        btc_price: PriceOfOneBTC,
+        prices: &[AssetPrice],
+        haircuts: &CollateralHaircuts,
+    ) -> Result<Satoshis, CollateralError> {
+        let btc_usd = btc_price.into_inner().to_usd();
+        if btc_usd.is_zero() {
+            return Ok(Satoshis::ZERO);
+        }
+
+        let mut btc = Decimal::ZERO;
+        for (asset, units) in self.positions.iter().filter(|(_, units)| *units > 0) {
+            let retained = haircuts.for_asset(*asset).retained_fraction();
+            let amount = asset.units_to_decimal(*units);
+            btc += if *asset == CollateralAsset::Btc {
+                amount * retained
+            } else {
+                let price = prices
+                    .iter()
+                    .find(|price| price.asset == *asset)
+                    .ok_or(CollateralError::MissingAssetPrice(*asset))?;
+                amount * price.into_inner().to_usd() * retained / btc_usd
+            };
+        }
+
+        Ok(Satoshis::try_from_btc(
+            btc.round_dp_with_strategy(8, RoundingStrategy::ToZero),
+        )?)
+    }
+}
+
+impl From<&[Collateral]> for CollateralBasket {
+    fn from(collaterals: &[Collateral]) -> Self {
+        Self::new(
+            collaterals
+                .iter()
+                .map(|collateral| (collateral.asset, collateral.amount.units())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::{primitives::UsdCents, terms::HaircutPct};
+
+    use super::*;
+
+    fn btc_price() -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(100_000_00))
+    }
+
+    #[test]
+    fn btc_only_basket_is_unchanged() {
+        let basket = CollateralBasket::new([(CollateralAsset::Btc, 12_345_678)]);
+        let haircuts = CollateralHaircuts::default();
+
+        assert!(basket.is_plain_btc(&haircuts));
+        assert_eq!(
+            basket.btc_equivalent(btc_price(), &[], &haircuts).unwrap(),
+            Satoshis::from(12_345_678)
+        );
+    }
+
+    #[test]
+    fn values_other_assets_after_haircut() {
+        // 0.5 BTC plus 10 ETH at $3,000 with a 20% ETH haircut
+        let basket = CollateralBasket::new([
+            (CollateralAsset::Btc, 50_000_000),
+            (CollateralAsset::Eth, 10_000_000_000),
+        ]);
+        let haircuts = CollateralHaircuts {
+            eth: HaircutPct::from(dec!(20)),
+            ..Default::default()
+        };
+        let prices = [AssetPrice::new(
+            CollateralAsset::Eth,
+            UsdCents::from(3_000_00),
+        )];
+
+        assert!(!basket.is_plain_btc(&haircuts));
+        // $24,000 of ETH is worth 0.24 BTC
+        assert_eq!(
+            basket
+                .btc_equivalent(btc_price(), &prices, &haircuts)
+                .unwrap(),
+            Satoshis::from(74_000_000)
+        );
+    }
+
+    #[test]
+    fn missing_price_is_an_error() {
+        let basket = CollateralBasket::new([(CollateralAsset::Usdt, 1_000_000)]);
+        assert!(matches!(
+            basket.btc_equivalent(btc_price(), &[], &CollateralHaircuts::default()),
+            Err(CollateralError::MissingAssetPrice(CollateralAsset::Usdt))
+        ));
+    }
+}
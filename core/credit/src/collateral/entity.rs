@@ -11,9 +11,12 @@ use es_entity::*;
 use cala_ledger::AccountId as CalaAccountId;
 use core_custody::WalletId;
 
-use crate::primitives::{CollateralAction, CollateralId, CreditFacilityId, LedgerTxId, Satoshis};
+use crate::primitives::{
+    ApprovalProcessId, CollateralAction, CollateralAmount, CollateralAsset, CollateralId,
+    CreditFacilityId, LedgerTxId, Satoshis,
+};
 
-use super::CollateralUpdate;
+use super::{CollateralUpdate, error::CollateralError};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -25,14 +28,40 @@ pub enum CollateralEvent {
         account_id: CalaAccountId,
         credit_facility_id: CreditFacilityId,
         wallet_id: Option<WalletId>,
+        #[serde(default)]
+        asset: CollateralAsset,
     },
+    /// Amounts are in the smallest unit of the collateral's asset.
     Updated {
         ledger_tx_id: LedgerTxId,
-        collateral_amount: Satoshis,
-        abs_diff: Satoshis,
+        collateral_amount: u64,
+        abs_diff: u64,
         action: CollateralAction,
         audit_info: AuditInfo,
     },
+    WithdrawalRequested {
+        approval_process_id: ApprovalProcessId,
+        amount: Satoshis,
+        address: String,
+        audit_info: AuditInfo,
+    },
+    WithdrawalConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    WithdrawalFailed {
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingCollateralWithdrawal {
+    pub approval_process_id: ApprovalProcessId,
+    pub amount: Satoshis,
+    pub address: String,
 }
 
 #[derive(EsEntity, Builder)]
@@ -41,7 +70,9 @@ pub struct Collateral {
     pub id: CollateralId,
     pub credit_facility_id: CreditFacilityId,
     pub wallet_id: Option<WalletId>,
-    pub amount: Satoshis,
+    pub account_id: CalaAccountId,
+    pub asset: CollateralAsset,
+    pub amount: CollateralAmount,
 
     events: EntityEvents<CollateralEvent>,
 }
@@ -55,15 +86,32 @@ impl Collateral {
 
     pub fn record_collateral_update(
         &mut self,
-        new_amount: Satoshis,
+        new_amount: CollateralAmount,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<CollateralUpdate>, CollateralError> {
+        if new_amount.asset() != self.asset {
+            return Err(CollateralError::AssetMismatch(
+                new_amount.asset(),
+                self.asset,
+            ));
+        }
+
+        Ok(self.update_amount(new_amount, effective, audit_info))
+    }
+
+    fn update_amount(
+        &mut self,
+        new_amount: CollateralAmount,
         effective: chrono::NaiveDate,
         audit_info: &AuditInfo,
     ) -> Idempotent<CollateralUpdate> {
-        let current = self.amount;
+        let current = self.amount.units();
+        let new_units = new_amount.units();
 
-        let (abs_diff, action) = match new_amount.cmp(&current) {
-            Ordering::Less => (current - new_amount, CollateralAction::Remove),
-            Ordering::Greater => (new_amount - current, CollateralAction::Add),
+        let (abs_diff, action) = match new_units.cmp(&current) {
+            Ordering::Less => (current - new_units, CollateralAction::Remove),
+            Ordering::Greater => (new_units - current, CollateralAction::Add),
             Ordering::Equal => return Idempotent::Ignored,
         };
 
@@ -72,7 +120,7 @@ impl Collateral {
         self.events.push(CollateralEvent::Updated {
             ledger_tx_id: tx_id,
             abs_diff,
-            collateral_amount: new_amount,
+            collateral_amount: new_units,
             action,
             audit_info: audit_info.clone(),
         });
@@ -81,11 +129,168 @@ impl Collateral {
 
         Idempotent::Executed(CollateralUpdate {
             tx_id,
-            abs_diff,
+            collateral_account_id: self.account_id,
+            abs_diff: CollateralAmount::new(self.asset, abs_diff),
             action,
             effective,
         })
     }
+
+    pub fn pending_withdrawal(&self) -> Option<PendingCollateralWithdrawal> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CollateralEvent::WithdrawalRequested {
+                approval_process_id,
+                amount,
+                address,
+                ..
+            } => Some(Some(PendingCollateralWithdrawal {
+                approval_process_id: *approval_process_id,
+                amount: *amount,
+                address: address.clone(),
+            })),
+            CollateralEvent::WithdrawalConcluded { .. } => Some(None),
+            _ => None,
+        })?
+    }
+
+    pub fn withdrawal_request(
+        &self,
+        approval_process_id: ApprovalProcessId,
+    ) -> Option<PendingCollateralWithdrawal> {
+        self.events.iter_all().find_map(|event| match event {
+            CollateralEvent::WithdrawalRequested {
+                approval_process_id: id,
+                amount,
+                address,
+                ..
+            } if *id == approval_process_id => Some(PendingCollateralWithdrawal {
+                approval_process_id: *id,
+                amount: *amount,
+                address: address.clone(),
+            }),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn request_withdrawal(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        amount: Satoshis,
+        address: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CollateralError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::WithdrawalRequested { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        if self.wallet_id.is_none() || self.asset != CollateralAsset::Btc {
+            return Err(CollateralError::WithdrawalWithoutCustody);
+        }
+        if self.pending_withdrawal().is_some() {
+            return Err(CollateralError::WithdrawalInProgress);
+        }
+        let held = Satoshis::try_from(self.amount)?;
+        if amount == Satoshis::ZERO || amount > held {
+            return Err(CollateralError::InvalidWithdrawalAmount(amount, held));
+        }
+
+        self.events.push(CollateralEvent::WithdrawalRequested {
+            approval_process_id,
+            amount,
+            address,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// On approval the withdrawn amount is removed through a regular collateral
+    /// update so the ledger and collateralization follow the usual path.
+    pub(crate) fn conclude_withdrawal(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<Option<CollateralUpdate>> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::WithdrawalConcluded { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        let Some(pending) = self.pending_withdrawal() else {
+            return Idempotent::Ignored;
+        };
+        if pending.approval_process_id != approval_process_id {
+            return Idempotent::Ignored;
+        }
+
+        let remaining = self
+            .amount
+            .checked_sub(pending.amount.into())
+            .filter(|_| approved);
+        self.events.push(CollateralEvent::WithdrawalConcluded {
+            approval_process_id,
+            approved: remaining.is_some(),
+            audit_info: audit_info.clone(),
+        });
+        let Some(remaining) = remaining else {
+            return Idempotent::Executed(None);
+        };
+
+        match self.update_amount(remaining, effective, audit_info) {
+            Idempotent::Executed(update) => Idempotent::Executed(Some(update)),
+            Idempotent::Ignored => Idempotent::Executed(None),
+        }
+    }
+
+    /// Restores the collateral removed on approval when the custodian did not
+    /// accept the withdrawal, so the funds still held are accounted for again.
+    pub(crate) fn fail_withdrawal(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<Option<CollateralUpdate>> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::WithdrawalFailed { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        let approved = self.events.iter_all().any(|event| {
+            matches!(
+                event,
+                CollateralEvent::WithdrawalConcluded {
+                    approval_process_id: id,
+                    approved: true,
+                    ..
+                } if *id == approval_process_id
+            )
+        });
+        let Some(request) = self
+            .withdrawal_request(approval_process_id)
+            .filter(|_| approved)
+        else {
+            return Idempotent::Ignored;
+        };
+
+        self.events.push(CollateralEvent::WithdrawalFailed {
+            approval_process_id,
+            reason,
+            audit_info: audit_info.clone(),
+        });
+
+        let restored = CollateralAmount::new(
+            self.asset,
+            self.amount.units() + request.amount.into_inner(),
+        );
+        match self.update_amount(restored, effective, audit_info) {
+            Idempotent::Executed(update) => Idempotent::Executed(Some(update)),
+            Idempotent::Ignored => Idempotent::Executed(None),
+        }
+    }
 }
 
 #[derive(Debug, Builder)]
@@ -98,6 +303,8 @@ pub struct NewCollateral {
     pub(super) credit_facility_id: CreditFacilityId,
     #[builder(default)]
     pub(super) wallet_id: Option<WalletId>,
+    #[builder(default)]
+    pub(super) asset: CollateralAsset,
 }
 
 impl NewCollateral {
@@ -109,26 +316,34 @@ impl NewCollateral {
 impl TryFromEvents<CollateralEvent> for Collateral {
     fn try_from_events(events: EntityEvents<CollateralEvent>) -> Result<Self, EsEntityError> {
         let mut builder = CollateralBuilder::default();
+        let mut collateral_asset = CollateralAsset::default();
         for event in events.iter_all() {
             match event {
                 CollateralEvent::Initialized {
                     id,
+                    account_id,
                     credit_facility_id,
                     wallet_id,
-                    ..
+                    asset,
                 } => {
+                    collateral_asset = *asset;
                     builder = builder
                         .id(*id)
-                        .amount(Satoshis::ZERO)
+                        .amount(CollateralAmount::zero(*asset))
                         .wallet_id(*wallet_id)
+                        .account_id(*account_id)
+                        .asset(*asset)
                         .credit_facility_id(*credit_facility_id)
                 }
                 CollateralEvent::Updated {
                     collateral_amount: new_value,
                     ..
                 } => {
-                    builder = builder.amount(*new_value);
+                    builder = builder.amount(CollateralAmount::new(collateral_asset, *new_value));
                 }
+                CollateralEvent::WithdrawalRequested { .. } => (),
+                CollateralEvent::WithdrawalConcluded { .. } => (),
+                CollateralEvent::WithdrawalFailed { .. } => (),
             }
         }
         builder.events(events).build()
@@ -144,7 +359,156 @@ impl IntoEvents<CollateralEvent> for NewCollateral {
                 account_id: self.account_id,
                 credit_facility_id: self.credit_facility_id,
                 wallet_id: self.wallet_id,
+                asset: self.asset,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn custodial_collateral(amount: Satoshis) -> Collateral {
+        let id = CollateralId::new();
+        let events = vec![
+            CollateralEvent::Initialized {
+                id,
+                account_id: CalaAccountId::new(),
+                credit_facility_id: CreditFacilityId::new(),
+                wallet_id: Some(WalletId::new()),
+                asset: CollateralAsset::Btc,
+            },
+            CollateralEvent::Updated {
+                ledger_tx_id: LedgerTxId::new(),
+                collateral_amount: amount.into_inner(),
+                abs_diff: amount.into_inner(),
+                action: CollateralAction::Add,
+                audit_info: dummy_audit_info(),
+            },
+        ];
+        Collateral::try_from_events(EntityEvents::init(id, events)).unwrap()
+    }
+
+    #[test]
+    fn approved_withdrawal_removes_collateral() {
+        let mut collateral = custodial_collateral(Satoshis::from(100_000));
+        let approval_process_id = ApprovalProcessId::new();
+        collateral
+            .request_withdrawal(
+                approval_process_id,
+                Satoshis::from(30_000),
+                "bc1qaddress".to_string(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(matches!(
+            collateral.request_withdrawal(
+                ApprovalProcessId::new(),
+                Satoshis::from(10_000),
+                "bc1qaddress".to_string(),
+                dummy_audit_info(),
+            ),
+            Err(CollateralError::WithdrawalInProgress)
+        ));
+
+        let update = collateral.conclude_withdrawal(
+            approval_process_id,
+            true,
+            chrono::Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        let Idempotent::Executed(Some(update)) = update else {
+            panic!("expected collateral update");
+        };
+        assert_eq!(update.action, CollateralAction::Remove);
+        assert_eq!(update.abs_diff, Satoshis::from(30_000).into());
+        assert_eq!(collateral.amount, Satoshis::from(70_000).into());
+        assert!(collateral.pending_withdrawal().is_none());
+    }
+
+    #[test]
+    fn denied_withdrawal_keeps_collateral() {
+        let mut collateral = custodial_collateral(Satoshis::from(100_000));
+        let approval_process_id = ApprovalProcessId::new();
+        collateral
+            .request_withdrawal(
+                approval_process_id,
+                Satoshis::from(30_000),
+                "bc1qaddress".to_string(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+
+        let res = collateral.conclude_withdrawal(
+            approval_process_id,
+            false,
+            chrono::Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(res, Idempotent::Executed(None)));
+        assert_eq!(collateral.amount, Satoshis::from(100_000).into());
+        assert!(
+            collateral
+                .conclude_withdrawal(
+                    approval_process_id,
+                    true,
+                    chrono::Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn failed_withdrawal_restores_collateral() {
+        let mut collateral = custodial_collateral(Satoshis::from(100_000));
+        let approval_process_id = ApprovalProcessId::new();
+        collateral
+            .request_withdrawal(
+                approval_process_id,
+                Satoshis::from(30_000),
+                "bc1qaddress".to_string(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        let _ = collateral.conclude_withdrawal(
+            approval_process_id,
+            true,
+            chrono::Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+
+        let res = collateral.fail_withdrawal(
+            approval_process_id,
+            "rejected".to_string(),
+            chrono::Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        let Idempotent::Executed(Some(update)) = res else {
+            panic!("expected collateral update");
+        };
+        assert_eq!(update.action, CollateralAction::Add);
+        assert_eq!(update.abs_diff, Satoshis::from(30_000).into());
+        assert_eq!(collateral.amount, Satoshis::from(100_000).into());
+        assert!(
+            collateral
+                .fail_withdrawal(
+                    approval_process_id,
+                    "rejected".to_string(),
+                    chrono::Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .was_ignored()
+        );
+    }
+}
@@ -6,8 +6,34 @@ pub enum CollateralError {
     EsEntityError(es_entity::EsEntityError),
     #[error("CollateralError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("CollateralError - ConversionError: {0}")]
+    ConversionError(#[from] crate::primitives::ConversionError),
     #[error("CollateralError - ManualUpdateError: Cannot update collateral with a custodian")]
     ManualUpdateError,
+    #[error("CollateralError - WithdrawalWithoutCustody: Collateral is not held with a custodian")]
+    WithdrawalWithoutCustody,
+    #[error("CollateralError - WithdrawalInProgress")]
+    WithdrawalInProgress,
+    #[error("CollateralError - InvalidWithdrawalAmount: {0} of {1}")]
+    InvalidWithdrawalAmount(crate::primitives::Satoshis, crate::primitives::Satoshis),
+    #[error("CollateralError - InvalidLiquidationSaleAmount: {0} of {1}")]
+    InvalidLiquidationSaleAmount(
+        crate::primitives::CollateralAmount,
+        crate::primitives::CollateralAmount,
+    ),
+    #[error("CollateralError - AssetMismatch: {0} for {1} collateral")]
+    AssetMismatch(
+        crate::primitives::CollateralAsset,
+        crate::primitives::CollateralAsset,
+    ),
+    #[error("CollateralError - AssetAlreadyPledged: {0}")]
+    AssetAlreadyPledged(crate::primitives::CollateralAsset),
+    #[error("CollateralError - AssetNotPledged: {0}")]
+    AssetNotPledged(crate::primitives::CollateralAsset),
+    #[error("CollateralError - MissingAssetPrice: {0}")]
+    MissingAssetPrice(crate::primitives::CollateralAsset),
+    #[error("CollateralError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
 }
 
 es_entity::from_es_entity_error!(CollateralError);
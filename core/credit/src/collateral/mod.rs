@@ -1,15 +1,19 @@
+mod basket;
 mod entity;
 pub mod error;
 mod repo;
 
 use std::collections::HashMap;
 
+use audit::AuditSvc;
 use authz::PermissionCheck;
 use core_custody::WalletId;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use outbox::OutboxEventMarker;
 
 use crate::{CreditFacilityPublisher, event::CoreCreditEvent, primitives::*};
 
+pub use basket::CollateralBasket;
 pub use entity::Collateral;
 pub(super) use entity::*;
 
@@ -21,21 +25,23 @@ use repo::CollateralRepo;
 pub struct Collaterals<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     authz: Perms,
     repo: CollateralRepo<E>,
+    governance: Governance<Perms, E>,
 }
 
 impl<Perms, E> Clone for Collaterals<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
             authz: self.authz.clone(),
             repo: self.repo.clone(),
+            governance: self.governance.clone(),
         }
     }
 }
@@ -43,15 +49,33 @@ where
 impl<Perms, E> Collaterals<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(pool: &sqlx::PgPool, authz: &Perms, publisher: &CreditFacilityPublisher<E>) -> Self {
+    pub async fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        publisher: &CreditFacilityPublisher<E>,
+        governance: &Governance<Perms, E>,
+    ) -> Self {
+        let _ = governance
+            .init_policy(crate::APPROVE_COLLATERAL_WITHDRAWAL_PROCESS)
+            .await;
+
         Self {
             authz: authz.clone(),
             repo: CollateralRepo::new(pool, publisher),
+            governance: governance.clone(),
         }
     }
 
+    pub async fn begin_op(&self) -> Result<es_entity::DbOp<'_>, CollateralError> {
+        Ok(self.repo.begin_op().await?)
+    }
+
     pub async fn find_all<T: From<Collateral>>(
         &self,
         ids: &[CollateralId],
@@ -66,23 +90,223 @@ where
         credit_facility_id: CreditFacilityId,
         wallet_id: Option<WalletId>,
         account_id: CalaAccountId,
+        asset: CollateralAsset,
     ) -> Result<Collateral, CollateralError> {
         let new_collateral = NewCollateral::builder()
             .id(collateral_id)
             .credit_facility_id(credit_facility_id)
             .account_id(account_id)
             .wallet_id(wallet_id)
+            .asset(asset)
             .build()
             .expect("all fields for new collateral provided");
 
         self.repo.create_in_op(db, new_collateral).await
     }
 
+    pub(super) async fn list_for_credit_facility_id_without_audit(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<Collateral>, CollateralError> {
+        let mut collaterals = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at(
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            collaterals.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(collaterals)
+    }
+
+    pub(super) async fn find_for_credit_facility_and_asset_without_audit(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        asset: CollateralAsset,
+    ) -> Result<Option<Collateral>, CollateralError> {
+        Ok(self
+            .list_for_credit_facility_id_without_audit(credit_facility_id)
+            .await?
+            .into_iter()
+            .find(|collateral| collateral.asset == asset))
+    }
+
+    pub(super) async fn basket_for_credit_facility_without_audit(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<CollateralBasket, CollateralError> {
+        let collaterals = self
+            .list_for_credit_facility_id_without_audit(credit_facility_id)
+            .await?;
+        Ok(CollateralBasket::from(collaterals.as_slice()))
+    }
+
+    pub(super) async fn find_by_id_without_audit(
+        &self,
+        id: CollateralId,
+    ) -> Result<Collateral, CollateralError> {
+        self.repo.find_by_id(id).await
+    }
+
+    /// Removes collateral sold by the liquidator regardless of custody, as
+    /// the funds have already left the borrower's wallet.
+    pub(super) async fn record_liquidation_sale_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        sold: core_money::Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<CollateralUpdate, CollateralError> {
+        let mut collateral = self.repo.find_by_id_in_tx(db.tx(), collateral_id).await?;
+
+        let sold = CollateralAmount::from(sold);
+        let remaining = match collateral.amount.checked_sub(sold) {
+            Some(remaining) if !sold.is_zero() => remaining,
+            _ => {
+                return Err(CollateralError::InvalidLiquidationSaleAmount(
+                    sold,
+                    collateral.amount,
+                ));
+            }
+        };
+
+        match collateral.record_collateral_update(remaining, effective, audit_info)? {
+            es_entity::Idempotent::Executed(data) => {
+                self.repo.update_in_op(db, &mut collateral).await?;
+                Ok(data)
+            }
+            es_entity::Idempotent::Ignored => Err(CollateralError::InvalidLiquidationSaleAmount(
+                sold,
+                collateral.amount,
+            )),
+        }
+    }
+
+    pub(super) async fn request_withdrawal_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        amount: core_money::Satoshis,
+        address: String,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Collateral, CollateralError> {
+        let mut collateral = self.repo.find_by_id_in_tx(db.tx(), collateral_id).await?;
+
+        let approval_process_id = ApprovalProcessId::new();
+        if collateral
+            .request_withdrawal(approval_process_id, amount, address, audit_info)?
+            .was_ignored()
+        {
+            return Ok(collateral);
+        }
+
+        self.governance
+            .start_process(
+                db,
+                approval_process_id,
+                collateral.credit_facility_id.to_string(),
+                crate::APPROVE_COLLATERAL_WITHDRAWAL_PROCESS,
+            )
+            .await?;
+        self.repo.update_in_op(db, &mut collateral).await?;
+
+        Ok(collateral)
+    }
+
+    pub(super) async fn conclude_withdrawal_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CollateralUpdate>, CollateralError> {
+        let mut collateral = self.repo.find_by_id_in_tx(db.tx(), collateral_id).await?;
+
+        let res = if let es_entity::Idempotent::Executed(update) =
+            collateral.conclude_withdrawal(approval_process_id, approved, effective, audit_info)
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+            update
+        } else {
+            None
+        };
+
+        Ok(res)
+    }
+
+    pub(super) async fn fail_withdrawal_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CollateralUpdate>, CollateralError> {
+        let mut collateral = self.repo.find_by_id_in_tx(db.tx(), collateral_id).await?;
+
+        let res = if let es_entity::Idempotent::Executed(update) =
+            collateral.fail_withdrawal(approval_process_id, reason, effective, audit_info)
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+            update
+        } else {
+            None
+        };
+
+        Ok(res)
+    }
+
+    /// Zeroes every non-BTC asset pledged to the facility. The BTC collateral
+    /// is released by the facility completion itself.
+    pub(super) async fn release_assets_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Vec<CollateralUpdate>, CollateralError> {
+        let mut res = Vec::new();
+        for mut collateral in self
+            .list_for_credit_facility_id_without_audit(credit_facility_id)
+            .await?
+            .into_iter()
+            .filter(|collateral| collateral.asset != CollateralAsset::Btc)
+        {
+            if let es_entity::Idempotent::Executed(update) = collateral.record_collateral_update(
+                CollateralAmount::zero(collateral.asset),
+                effective,
+                audit_info,
+            )? {
+                self.repo.update_in_op(db, &mut collateral).await?;
+                res.push(update);
+            }
+        }
+
+        Ok(res)
+    }
+
     pub(super) async fn record_manual_collateral_update_in_op(
         &self,
         db: &mut es_entity::DbOp<'_>,
         collateral_id: CollateralId,
-        updated_collateral: core_money::Satoshis,
+        updated_collateral: CollateralAmount,
         effective: chrono::NaiveDate,
         audit_info: &audit::AuditInfo,
     ) -> Result<Option<CollateralUpdate>, CollateralError> {
@@ -93,7 +317,7 @@ where
         }
 
         let res = if let es_entity::Idempotent::Executed(data) =
-            collateral.record_collateral_update(updated_collateral, effective, audit_info)
+            collateral.record_collateral_update(updated_collateral, effective, audit_info)?
         {
             self.repo.update_in_op(db, &mut collateral).await?;
             Some(data)
@@ -3,7 +3,11 @@ use sqlx::PgPool;
 use es_entity::*;
 use outbox::OutboxEventMarker;
 
-use crate::{CreditFacilityPublisher, event::CoreCreditEvent, primitives::CollateralId};
+use crate::{
+    CreditFacilityPublisher,
+    event::CoreCreditEvent,
+    primitives::{CollateralId, CreditFacilityId},
+};
 
 use super::{entity::*, error::*};
 
@@ -11,6 +15,7 @@ use super::{entity::*, error::*};
 #[es_repo(
     entity = "Collateral",
     err = "CollateralError",
+    columns(credit_facility_id(ty = "CreditFacilityId", list_for, update(persist = false))),
     tbl_prefix = "core",
     post_persist_hook = "publish"
 )]
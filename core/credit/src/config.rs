@@ -2,6 +2,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use std::path::PathBuf;
+
 use crate::primitives::CVLPct;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -11,6 +13,56 @@ pub struct CreditConfig {
     pub upgrade_buffer_cvl_pct: CVLPct,
     #[serde(default = "default_customer_active_check_enabled")]
     pub customer_active_check_enabled: bool,
+    #[serde(default = "default_margin_call_cure_period_days")]
+    pub margin_call_cure_period_days: u32,
+    /// Payouts to beneficiary accounts are refused when no rail is configured.
+    #[serde(default)]
+    pub payment_rail: Option<PaymentRailConfig>,
+}
+
+/// Rail used to pay out disbursals to a customer's external bank account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentRailConfig {
+    /// Accepts and settles every payment immediately. For local development.
+    Mock,
+    /// Writes a payment file per disbursal for upload to the bank. Settlement
+    /// and returns are recorded by an operator once the bank reports them.
+    FileBatch {
+        format: PaymentFileFormat,
+        output_dir: PathBuf,
+        originator: PaymentOriginator,
+    },
+    /// Posts each payment to an HTTP endpoint and waits for the outcome to
+    /// arrive on the payment rail webhook.
+    Webhook {
+        url: String,
+        #[serde(default)]
+        api_key: Option<String>,
+        webhook_secret: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentFileFormat {
+    Nacha,
+    Pain001,
+}
+
+/// The bank's own account that disbursals are paid from.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PaymentOriginator {
+    pub name: String,
+    /// NACHA company identification / ISO 20022 initiating party id.
+    pub id: String,
+    pub routing_number: String,
+    pub account_number: String,
+    #[serde(default)]
+    pub bic: Option<String>,
 }
 
 impl Default for CreditConfig {
@@ -18,6 +70,8 @@ impl Default for CreditConfig {
         CreditConfig {
             upgrade_buffer_cvl_pct: default_upgrade_buffer_cvl_pct(),
             customer_active_check_enabled: default_customer_active_check_enabled(),
+            margin_call_cure_period_days: default_margin_call_cure_period_days(),
+            payment_rail: None,
         }
     }
 }
@@ -29,3 +83,7 @@ fn default_upgrade_buffer_cvl_pct() -> CVLPct {
 fn default_customer_active_check_enabled() -> bool {
     true
 }
+
+fn default_margin_call_cure_period_days() -> u32 {
+    3
+}
@@ -11,8 +11,9 @@ use es_entity::*;
 use crate::{
     interest_accrual_cycle::*,
     ledger::*,
-    obligation::{NewObligation, ObligationsAmounts},
+    obligation::{NewObligation, ObligationAccounts, ObligationsAmounts},
     primitives::*,
+    reference_rate::{AppliedRateFixing, ReferenceRate},
     terms::{InterestPeriod, TermValues},
 };
 
@@ -58,6 +59,15 @@ pub enum CreditFacilityEvent {
         obligation_id: ObligationId,
         audit_info: AuditInfo,
     },
+    FeeCharged {
+        fee_type: FacilityFeeType,
+        ledger_tx_id: LedgerTxId,
+        obligation_id: ObligationId,
+        amount: UsdCents,
+        tx_ref: String,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     CollateralizationStateChanged {
         collateralization_state: CollateralizationState,
         collateral: Satoshis,
@@ -69,6 +79,54 @@ pub enum CreditFacilityEvent {
         collateralization_ratio: Option<Decimal>,
         audit_info: AuditInfo,
     },
+    UnappliedFundsRecorded {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsApplied {
+        ledger_tx_id: LedgerTxId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsRefunded {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsReversed {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    TermsAmendmentRequested {
+        approval_process_id: ApprovalProcessId,
+        terms: TermValues,
+        audit_info: AuditInfo,
+    },
+    TermsAmended {
+        approval_process_id: ApprovalProcessId,
+        previous_terms: TermValues,
+        terms: TermValues,
+        matures_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    TermsAmendmentDenied {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    Prepaid {
+        ledger_tx_id: LedgerTxId,
+        outstanding: UsdCents,
+        prepayment_fee: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     Completed {
         audit_info: AuditInfo,
     },
@@ -109,6 +167,29 @@ impl CreditFacilityReceivable {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PayoffQuote {
+    pub as_of: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+    pub principal: UsdCents,
+    pub posted_interest: UsdCents,
+    pub fees: UsdCents,
+    pub penalties: UsdCents,
+    pub projected_interest: UsdCents,
+    pub prepayment_fee: UsdCents,
+}
+
+impl PayoffQuote {
+    pub fn total(&self) -> UsdCents {
+        self.principal
+            + self.posted_interest
+            + self.fees
+            + self.penalties
+            + self.projected_interest
+            + self.prepayment_fee
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct NewAccrualPeriods {
     pub(crate) accrual: InterestPeriod,
@@ -263,6 +344,7 @@ impl CreditFacility {
         activated_at: DateTime<Utc>,
         price: PriceOfOneBTC,
         balances: CreditFacilityBalanceSummary,
+        reference_rate: Option<&ReferenceRate>,
         audit_info: AuditInfo,
     ) -> Result<Idempotent<(CreditFacilityActivation, InterestPeriod)>, CreditFacilityError> {
         if self.is_activated() {
@@ -281,6 +363,8 @@ impl CreditFacility {
             return Err(CreditFacilityError::BelowMarginLimit);
         }
 
+        self.applied_rate_fixing(reference_rate, activated_at)?;
+
         self.activated_at = Some(activated_at);
         self.matures_at = Some(self.terms.duration.maturity_date(activated_at));
         let tx_id = LedgerTxId::new();
@@ -291,7 +375,7 @@ impl CreditFacility {
         });
 
         let periods = self
-            .start_interest_accrual_cycle(audit_info)
+            .start_interest_accrual_cycle(reference_rate, audit_info)
             .expect("first accrual")
             .expect("first accrual");
         let activation = CreditFacilityActivation {
@@ -313,17 +397,17 @@ impl CreditFacility {
     fn next_interest_accrual_cycle_period(
         &self,
     ) -> Result<Option<InterestPeriod>, CreditFacilityError> {
-        let last_accrual_start_date = self.events.iter_all().rev().find_map(|event| match event {
+        let last_accrual_period = self.events.iter_all().rev().find_map(|event| match event {
             CreditFacilityEvent::InterestAccrualCycleStarted {
                 interest_period: period,
                 ..
-            } => Some(period.start),
+            } => Some(*period),
             _ => None,
         });
 
         let interval = self.terms.accrual_cycle_interval;
-        let full_period = match last_accrual_start_date {
-            Some(last_accrual_start_date) => interval.period_from(last_accrual_start_date).next(),
+        let full_period = match last_accrual_period {
+            Some(last_accrual_period) => last_accrual_period.next(),
             None => interval.period_from(
                 self.activated_at
                     .ok_or(CreditFacilityError::NotActivatedYet)?,
@@ -333,8 +417,42 @@ impl CreditFacility {
         Ok(full_period.truncate(self.matures_at.expect("Facility is already active")))
     }
 
+    fn applied_rate_fixing(
+        &self,
+        reference_rate: Option<&ReferenceRate>,
+        at: DateTime<Utc>,
+    ) -> Result<Option<AppliedRateFixing>, CreditFacilityError> {
+        let Some(floating_rate) = self.terms.floating_rate else {
+            return Ok(None);
+        };
+
+        reference_rate
+            .and_then(|rate| rate.applied_fixing(floating_rate, at.date_naive()))
+            .map(Some)
+            .ok_or(CreditFacilityError::ReferenceRateFixingNotFound(
+                floating_rate.reference_rate_id,
+                at.date_naive(),
+            ))
+    }
+
+    /// Terms an accrual cycle starting at `cycle_start` accrues on, with the
+    /// rate of a floating rate facility fixed as of the cycle start.
+    fn accrual_cycle_terms(
+        &self,
+        reference_rate: Option<&ReferenceRate>,
+        cycle_start: DateTime<Utc>,
+    ) -> Result<(TermValues, Option<AppliedRateFixing>), CreditFacilityError> {
+        let rate_fixing = self.applied_rate_fixing(reference_rate, cycle_start)?;
+        let terms = match rate_fixing {
+            Some(fixing) => self.terms.with_annual_rate(fixing.annual_rate()),
+            None => self.terms,
+        };
+        Ok((terms, rate_fixing))
+    }
+
     pub(crate) fn start_interest_accrual_cycle(
         &mut self,
+        reference_rate: Option<&ReferenceRate>,
         audit_info: AuditInfo,
     ) -> Result<Option<NewAccrualPeriods>, CreditFacilityError> {
         let accrual_cycle_period = match self.next_interest_accrual_cycle_period()? {
@@ -346,6 +464,9 @@ impl CreditFacility {
             return Err(CreditFacilityError::InterestAccrualCycleWithInvalidFutureStartDate);
         }
 
+        let (terms, rate_fixing) =
+            self.accrual_cycle_terms(reference_rate, accrual_cycle_period.start)?;
+
         let idx = self
             .events
             .iter_all()
@@ -374,7 +495,8 @@ impl CreditFacility {
             .idx(idx)
             .period(accrual_cycle_period)
             .facility_matures_at(self.matures_at.expect("Facility is already approved"))
-            .terms(self.terms)
+            .terms(terms)
+            .rate_fixing(rate_fixing)
             .audit_info(audit_info)
             .build()
             .expect("could not build new interest accrual");
@@ -391,9 +513,10 @@ impl CreditFacility {
         &mut self,
         audit_info: AuditInfo,
     ) -> Result<Idempotent<NewObligation>, CreditFacilityError> {
-        let accrual_cycle_data = self
-            .interest_accrual_cycle_in_progress()
-            .expect("accrual not found")
+        let Some(accrual) = self.interest_accrual_cycle_in_progress() else {
+            return Ok(Idempotent::Ignored);
+        };
+        let accrual_cycle_data = accrual
             .accrual_cycle_data()
             .ok_or(CreditFacilityError::InterestAccrualNotCompletedYet)?;
 
@@ -423,6 +546,34 @@ impl CreditFacility {
         Ok(Idempotent::Executed(new_obligation))
     }
 
+    /// Starts a new accrual cycle when a restructuring extended the maturity of a
+    /// facility whose cycles had already run out.
+    /// Puts amended terms into effect for interest accrual. The cycle in
+    /// progress accrues the rest of its period on them, and accrual that had
+    /// stopped at the previous maturity starts again.
+    pub(crate) fn accrue_on_amended_terms(
+        &mut self,
+        reference_rate: Option<&ReferenceRate>,
+        audit_info: AuditInfo,
+    ) -> Result<Option<InterestPeriod>, CreditFacilityError> {
+        let Some(cycle_start) = self
+            .interest_accrual_cycle_in_progress()
+            .map(|cycle| cycle.period.start)
+        else {
+            return Ok(self
+                .start_interest_accrual_cycle(reference_rate, audit_info)?
+                .map(|periods| periods.accrual));
+        };
+
+        let (terms, rate_fixing) = self.accrual_cycle_terms(reference_rate, cycle_start)?;
+        let matures_at = self.matures_at.expect("Facility is already active");
+        self.interest_accrual_cycle_in_progress_mut()
+            .expect("accrual cycle in progress")
+            .amend_terms(terms, rate_fixing, matures_at, audit_info);
+
+        Ok(None)
+    }
+
     pub fn interest_accrual_cycle_in_progress(&self) -> Option<&InterestAccrualCycle> {
         if let Some(id) = self
             .events
@@ -501,6 +652,79 @@ impl CreditFacility {
         })
     }
 
+    /// BTC price below which the facility drops into a worse collateralization
+    /// state, given its last recorded collateralization ratio. Facilities that
+    /// are not active yet are measured on the facility amount against their
+    /// initial CVL, see [`TermValues::pending_collateralization`].
+    pub fn collateralization_price_floor(&self) -> Option<Decimal> {
+        let ratio = self.last_collateralization_ratio()?;
+        let state = self.last_collateralization_state();
+        let cvl = match self.status() {
+            CreditFacilityStatus::PendingCollateralization
+            | CreditFacilityStatus::PendingApproval => match state {
+                CollateralizationState::FullyCollateralized => self.terms.initial_cvl,
+                CollateralizationState::UnderMarginCallThreshold => self.terms.liquidation_cvl,
+                CollateralizationState::UnderLiquidationThreshold
+                | CollateralizationState::NoCollateral => return None,
+            },
+            CreditFacilityStatus::Active | CreditFacilityStatus::Matured => match state {
+                CollateralizationState::FullyCollateralized => self.terms.margin_call_cvl,
+                CollateralizationState::UnderMarginCallThreshold => self.terms.liquidation_cvl,
+                CollateralizationState::UnderLiquidationThreshold
+                | CollateralizationState::NoCollateral => return None,
+            },
+            CreditFacilityStatus::Closed => return None,
+        };
+        cvl.btc_price_at_ratio(ratio)
+    }
+
+    /// BTC price above which the facility may recover into a better
+    /// collateralization state. The upgrade buffer is not applied here, so
+    /// this is a lower bound on the price that actually triggers an upgrade.
+    pub fn collateralization_price_ceiling(&self) -> Option<Decimal> {
+        let ratio = self.last_collateralization_ratio()?;
+        let state = self.last_collateralization_state();
+        let cvl = match self.status() {
+            CreditFacilityStatus::PendingCollateralization
+            | CreditFacilityStatus::PendingApproval => match state {
+                CollateralizationState::UnderMarginCallThreshold => self.terms.initial_cvl,
+                CollateralizationState::UnderLiquidationThreshold => self.terms.liquidation_cvl,
+                CollateralizationState::FullyCollateralized
+                | CollateralizationState::NoCollateral => return None,
+            },
+            CreditFacilityStatus::Active | CreditFacilityStatus::Matured => match state {
+                CollateralizationState::UnderMarginCallThreshold => self.terms.margin_call_cvl,
+                CollateralizationState::UnderLiquidationThreshold => self.terms.liquidation_cvl,
+                CollateralizationState::FullyCollateralized
+                | CollateralizationState::NoCollateral => return None,
+            },
+            CreditFacilityStatus::Closed => return None,
+        };
+        cvl.btc_price_at_ratio(ratio)
+    }
+
+    /// Collateral in excess of what the facility needs to stay at its initial
+    /// CVL at the given price. Mirrors `current_cvl` in using the facility
+    /// amount until the first disbursal and the outstanding amount after.
+    pub fn releasable_collateral(
+        &self,
+        balances: CreditFacilityBalanceSummary,
+        price: PriceOfOneBTC,
+    ) -> Satoshis {
+        let basis = if balances.any_disbursed() {
+            balances.total_outstanding()
+        } else {
+            balances.facility()
+        };
+        let required = self.terms.required_collateral(basis, price);
+        let collateral = balances.collateral();
+        if collateral > required {
+            collateral - required
+        } else {
+            Satoshis::ZERO
+        }
+    }
+
     fn is_fully_collateralized(&self) -> bool {
         self.last_collateralization_state() == CollateralizationState::FullyCollateralized
     }
@@ -520,11 +744,9 @@ impl CreditFacility {
 
         let collateralization_update = match self.status() {
             CreditFacilityStatus::PendingCollateralization
-            | CreditFacilityStatus::PendingApproval => self.terms.collateralization_update(
+            | CreditFacilityStatus::PendingApproval => self.terms.pending_collateralization_update(
                 balances.facility_amount_cvl(price),
                 last_collateralization_state,
-                None,
-                true,
             ),
             CreditFacilityStatus::Active | CreditFacilityStatus::Matured => {
                 self.terms.collateralization_update(
@@ -555,6 +777,169 @@ impl CreditFacility {
         }
     }
 
+    /// Next activation anniversary on which the maintenance fee has not been
+    /// charged yet.
+    pub fn next_maintenance_fee_date(&self) -> Option<DateTime<Utc>> {
+        let charged = self
+            .events
+            .iter_all()
+            .filter(|event| {
+                matches!(
+                    event,
+                    CreditFacilityEvent::FeeCharged {
+                        fee_type: FacilityFeeType::Maintenance,
+                        ..
+                    }
+                )
+            })
+            .count();
+
+        self.terms
+            .maintenance_fee_dates(self.activated_at?, self.matures_at?)
+            .into_iter()
+            .nth(charged)
+    }
+
+    pub(crate) fn charge_commitment_fee(
+        &mut self,
+        period: InterestPeriod,
+        undrawn: UsdCents,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<(CreditFacilityFee, NewObligation)> {
+        let amount = self.terms.commitment_fee_for_period(undrawn, &period);
+        self.charge_fee(
+            FacilityFeeType::Commitment,
+            format!("{}-commitment-fee-{}", self.id, period.end.date_naive()),
+            amount,
+            period.end,
+            audit_info,
+        )
+    }
+
+    pub(crate) fn charge_maintenance_fee(
+        &mut self,
+        due_at: DateTime<Utc>,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<(CreditFacilityFee, NewObligation)> {
+        let amount = self
+            .terms
+            .fee_schedule
+            .annual_maintenance_fee
+            .unwrap_or(UsdCents::ZERO);
+        self.charge_fee(
+            FacilityFeeType::Maintenance,
+            format!("{}-maintenance-fee-{}", self.id, due_at.date_naive()),
+            amount,
+            due_at,
+            audit_info,
+        )
+    }
+
+    pub(crate) fn charge_disbursal_fee(
+        &mut self,
+        disbursal_tx_id: LedgerTxId,
+        disbursed: UsdCents,
+        recorded_at: DateTime<Utc>,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<(CreditFacilityFee, NewObligation)> {
+        let amount = self.terms.disbursal_fee(disbursed);
+        self.charge_fee(
+            FacilityFeeType::Disbursal,
+            format!("{}-disbursal-fee-{}", self.id, disbursal_tx_id),
+            amount,
+            recorded_at,
+            audit_info,
+        )
+    }
+
+    fn charge_fee(
+        &mut self,
+        fee_type: FacilityFeeType,
+        tx_ref: String,
+        amount: UsdCents,
+        charged_at: DateTime<Utc>,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<(CreditFacilityFee, NewObligation)> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::FeeCharged { tx_ref: charged, .. } if charged == &tx_ref
+        );
+        if amount.is_zero() || self.activated_at.is_none() || self.is_completed() {
+            return Idempotent::Ignored;
+        }
+
+        let fee_income_account_id = match fee_type {
+            FacilityFeeType::Commitment => self.account_ids.commitment_fee_income_account_id,
+            FacilityFeeType::Maintenance => self.account_ids.maintenance_fee_income_account_id,
+            FacilityFeeType::Disbursal => self.account_ids.disbursal_fee_income_account_id,
+        };
+        let obligation_id = ObligationId::new();
+        let tx_id = LedgerTxId::new();
+        let effective = charged_at.date_naive();
+
+        self.events.push(CreditFacilityEvent::FeeCharged {
+            fee_type,
+            ledger_tx_id: tx_id,
+            obligation_id,
+            amount,
+            tx_ref: tx_ref.clone(),
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        let due_date = self
+            .terms
+            .interest_due_duration_from_accrual
+            .end_date(charged_at);
+        let new_obligation = NewObligation::builder()
+            .id(obligation_id)
+            .credit_facility_id(self.id)
+            .obligation_type(ObligationType::Fee)
+            .reference(tx_ref.clone())
+            .amount(amount)
+            .tx_id(tx_id)
+            .not_yet_due_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.interest_receivable_not_yet_due_account_id,
+                account_to_be_credited_id: fee_income_account_id,
+            })
+            .due_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.interest_receivable_due_account_id,
+                account_to_be_credited_id: fee_income_account_id,
+            })
+            .overdue_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.interest_receivable_overdue_account_id,
+                account_to_be_credited_id: fee_income_account_id,
+            })
+            .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
+            .defaulted_account_id(self.account_ids.interest_defaulted_account_id)
+            .due_date(due_date)
+            .overdue_date(
+                self.terms
+                    .obligation_overdue_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .liquidation_date(
+                self.terms
+                    .obligation_liquidation_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .effective(effective)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new fee obligation");
+
+        let fee = CreditFacilityFee {
+            tx_id,
+            tx_ref,
+            amount,
+            receivable_account_id: self.account_ids.interest_receivable_not_yet_due_account_id,
+            fee_income_account_id,
+            effective,
+        };
+
+        Idempotent::Executed((fee, new_obligation))
+    }
+
     pub(crate) fn is_completed(&self) -> bool {
         self.events
             .iter_all()
@@ -577,10 +962,13 @@ impl CreditFacility {
             return Err(CreditFacilityError::OutstandingAmount);
         }
 
+        let unapplied_funds_refund =
+            self.refund_unapplied_funds(crate::time::now().date_naive(), &audit_info);
         let res = CreditFacilityCompletion {
             tx_id: LedgerTxId::new(),
             collateral: balances.collateral(),
             credit_facility_account_ids: self.account_ids,
+            unapplied_funds_refund,
         };
 
         self.events
@@ -589,76 +977,500 @@ impl CreditFacility {
         Ok(Idempotent::Executed(res))
     }
 
-    fn update_collateralization_ratio(
+    pub fn pending_terms_amendment(&self) -> Option<(ApprovalProcessId, TermValues)> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::TermsAmendmentRequested {
+                approval_process_id,
+                terms,
+                ..
+            } => Some(Some((*approval_process_id, *terms))),
+            CreditFacilityEvent::TermsAmended { .. }
+            | CreditFacilityEvent::TermsAmendmentDenied { .. } => Some(None),
+            _ => None,
+        })?
+    }
+
+    pub(crate) fn request_terms_amendment(
         &mut self,
-        balance: &CreditFacilityBalanceSummary,
+        approval_process_id: ApprovalProcessId,
+        terms: TermValues,
         audit_info: AuditInfo,
-    ) -> Idempotent<()> {
-        let ratio = balance.current_collateralization_ratio();
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::TermsAmendmentRequested { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        let activated_at = self
+            .activated_at
+            .ok_or(CreditFacilityError::NotActivatedYet)?;
+        if self.pending_terms_amendment().is_some() {
+            return Err(CreditFacilityError::RestructuringInProgress);
+        }
+        if terms.duration.maturity_date(activated_at) <= crate::time::now() {
+            return Err(CreditFacilityError::RestructuredMaturityInPast);
+        }
 
-        if self.last_collateralization_ratio() != ratio {
-            self.events
-                .push(CreditFacilityEvent::CollateralizationRatioChanged {
-                    collateralization_ratio: ratio,
-                    audit_info,
-                });
-        } else {
+        self.events
+            .push(CreditFacilityEvent::TermsAmendmentRequested {
+                approval_process_id,
+                terms,
+                audit_info,
+            });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn conclude_terms_amendment(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<DateTime<Utc>>> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::TermsAmended { approval_process_id: id, .. }
+                | CreditFacilityEvent::TermsAmendmentDenied { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        let Some((pending_id, terms)) = self.pending_terms_amendment() else {
+            return Idempotent::Ignored;
+        };
+        if pending_id != approval_process_id {
             return Idempotent::Ignored;
         }
 
-        Idempotent::Executed(())
+        if !approved || self.is_completed() {
+            self.events.push(CreditFacilityEvent::TermsAmendmentDenied {
+                approval_process_id,
+                audit_info,
+            });
+            return Idempotent::Executed(None);
+        }
+
+        let matures_at = terms
+            .duration
+            .maturity_date(self.activated_at.expect("Facility is already active"));
+        let previous_terms = self.terms;
+        self.terms = terms;
+        self.matures_at = Some(matures_at);
+        self.events.push(CreditFacilityEvent::TermsAmended {
+            approval_process_id,
+            previous_terms,
+            terms,
+            matures_at,
+            audit_info,
+        });
+
+        Idempotent::Executed(Some(matures_at))
     }
-}
 
-impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
-    fn try_from_events(events: EntityEvents<CreditFacilityEvent>) -> Result<Self, EsEntityError> {
-        let mut builder = CreditFacilityBuilder::default();
-        let mut terms = None;
-        for event in events.iter_all() {
-            match event {
-                CreditFacilityEvent::Initialized {
-                    id,
-                    amount,
-                    customer_id,
-                    collateral_id,
-                    account_ids,
-                    disbursal_credit_account_id,
-                    terms: t,
-                    approval_process_id,
-                    ..
-                } => {
-                    terms = Some(*t);
-                    builder = builder
-                        .id(*id)
-                        .amount(*amount)
-                        .customer_id(*customer_id)
-                        .collateral_id(*collateral_id)
-                        .terms(*t)
-                        .account_ids(*account_ids)
-                        .disbursal_credit_account_id(*disbursal_credit_account_id)
-                        .approval_process_id(*approval_process_id)
-                }
-                CreditFacilityEvent::Activated { activated_at, .. } => {
-                    let matures_at = terms
-                        .expect("terms should be set")
-                        .duration
-                        .maturity_date(*activated_at);
-                    builder = builder.activated_at(*activated_at).matures_at(matures_at)
-                }
-                CreditFacilityEvent::ApprovalProcessConcluded { .. } => (),
-                CreditFacilityEvent::InterestAccrualCycleStarted { .. } => (),
-                CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
-                CreditFacilityEvent::CollateralizationStateChanged { .. } => (),
-                CreditFacilityEvent::CollateralizationRatioChanged { .. } => (),
-                CreditFacilityEvent::Completed { .. } => (),
+    pub(crate) fn prepay(
+        &mut self,
+        prepaid_at: DateTime<Utc>,
+        balances: CreditFacilityBalanceSummary,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<(CreditFacilityPrepayment, Option<NewObligation>)>, CreditFacilityError>
+    {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CreditFacilityEvent::Completed { .. }
+        );
+        if !self.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet);
+        }
+
+        let account_ids = self.account_ids;
+        let mut interest_accrual = None;
+        let mut posted_interest = None;
+        let mut new_obligation = None;
+        if let Some(accrual) = self.interest_accrual_cycle_in_progress_mut() {
+            interest_accrual = accrual
+                .record_payoff_accrual(
+                    balances.disbursed_outstanding(),
+                    prepaid_at,
+                    audit_info.clone(),
+                )
+                .map(|data| CreditFacilityInterestAccrual::from((data, account_ids)));
+
+            let idx = accrual.idx;
+            let accrual_cycle_data = accrual.payoff_accrual_cycle_data(prepaid_at);
+            if let Idempotent::Executed(obligation) =
+                accrual.record_accrual_cycle(accrual_cycle_data.clone(), audit_info.clone())
+            {
+                self.events
+                    .push(CreditFacilityEvent::InterestAccrualCycleConcluded {
+                        interest_accrual_cycle_idx: idx,
+                        obligation_id: obligation.id,
+                        ledger_tx_id: accrual_cycle_data.tx_id,
+                        audit_info: audit_info.clone(),
+                    });
+                posted_interest = Some(CreditFacilityPostedInterest {
+                    tx_id: accrual_cycle_data.tx_id,
+                    tx_ref: accrual_cycle_data.tx_ref,
+                    interest: accrual_cycle_data.interest,
+                });
+                new_obligation = Some(obligation);
             }
         }
-        builder.events(events).build()
+
+        let prepayment_fee = if self
+            .matures_at
+            .is_some_and(|matures_at| prepaid_at < matures_at)
+        {
+            self.terms
+                .prepayment_fee_rate
+                .apply(balances.disbursed_outstanding())
+        } else {
+            UsdCents::ZERO
+        };
+        let outstanding = balances.total_outstanding()
+            + posted_interest
+                .as_ref()
+                .map(|posted| posted.interest)
+                .unwrap_or(UsdCents::ZERO);
+
+        let unapplied_funds_refund =
+            self.refund_unapplied_funds(prepaid_at.date_naive(), &audit_info);
+        let prepayment = CreditFacilityPrepayment {
+            tx_id: LedgerTxId::new(),
+            tx_ref: format!("{}-prepayment", self.id),
+            interest_accrual,
+            posted_interest,
+            outstanding,
+            prepayment_fee,
+            debit_account_id: self.disbursal_credit_account_id,
+            effective: prepaid_at.date_naive(),
+            completion: CreditFacilityCompletion {
+                tx_id: LedgerTxId::new(),
+                collateral: balances.collateral(),
+                credit_facility_account_ids: self.account_ids,
+                unapplied_funds_refund,
+            },
+        };
+
+        self.events.push(CreditFacilityEvent::Prepaid {
+            ledger_tx_id: prepayment.tx_id,
+            outstanding,
+            prepayment_fee,
+            effective: prepayment.effective,
+            audit_info: audit_info.clone(),
+        });
+        self.events
+            .push(CreditFacilityEvent::Completed { audit_info });
+
+        Ok(Idempotent::Executed((prepayment, new_obligation)))
     }
-}
 
-#[derive(Debug, Builder)]
-pub struct NewCreditFacility {
+    pub fn unapplied_funds(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                CreditFacilityEvent::UnappliedFundsRecorded { amount, .. } => total + *amount,
+                CreditFacilityEvent::UnappliedFundsApplied { amount, .. }
+                | CreditFacilityEvent::UnappliedFundsRefunded { amount, .. }
+                | CreditFacilityEvent::UnappliedFundsReversed { amount, .. } => total - *amount,
+                _ => total,
+            })
+    }
+
+    fn unapplied_funds_movement(
+        &self,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> CreditFacilityUnappliedFunds {
+        let idx = self
+            .events
+            .iter_all()
+            .filter(|event| {
+                matches!(
+                    event,
+                    CreditFacilityEvent::UnappliedFundsRecorded { .. }
+                        | CreditFacilityEvent::UnappliedFundsApplied { .. }
+                        | CreditFacilityEvent::UnappliedFundsRefunded { .. }
+                        | CreditFacilityEvent::UnappliedFundsReversed { .. }
+                )
+            })
+            .count();
+        CreditFacilityUnappliedFunds {
+            tx_id: LedgerTxId::new(),
+            tx_ref: format!("{}-unapplied-funds-{}", self.id, idx + 1),
+            amount,
+            deposit_account_id: self.disbursal_credit_account_id,
+            unapplied_funds_account_id: self.account_ids.unapplied_funds_account_id,
+            effective,
+        }
+    }
+
+    pub(crate) fn record_unapplied_funds(
+        &mut self,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<CreditFacilityUnappliedFunds, CreditFacilityError> {
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+
+        let unapplied_funds = self.unapplied_funds_movement(amount, effective);
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsRecorded {
+                ledger_tx_id: unapplied_funds.tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Ok(unapplied_funds)
+    }
+
+    /// Takes `allocated` out of unapplied funds. It must be exactly what was
+    /// allocated to obligations by `payment_id`, as the whole amount is
+    /// released from the unapplied funds account.
+    pub(crate) fn apply_unapplied_funds(
+        &mut self,
+        payment_id: PaymentId,
+        allocated: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<CreditFacilityUnappliedFunds>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::UnappliedFundsApplied { payment_id: id, .. } if *id == payment_id
+        );
+        if allocated.is_zero() {
+            return Ok(Idempotent::Ignored);
+        }
+        if allocated > self.unapplied_funds() {
+            return Err(CreditFacilityError::InsufficientUnappliedFunds(
+                allocated,
+                self.unapplied_funds(),
+            ));
+        }
+
+        let unapplied_funds = self.unapplied_funds_movement(allocated, effective);
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsApplied {
+                ledger_tx_id: unapplied_funds.tx_id,
+                payment_id,
+                amount: allocated,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Ok(Idempotent::Executed(unapplied_funds))
+    }
+
+    /// Payments created by applying unapplied funds, most recent first.
+    pub(crate) fn unapplied_funds_payment_ids(&self) -> Vec<PaymentId> {
+        self.events
+            .iter_all()
+            .rev()
+            .filter_map(|event| match event {
+                CreditFacilityEvent::UnappliedFundsApplied { payment_id, .. } => Some(*payment_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Reverses `amount` out of unapplied funds. `returned` is what was taken back
+    /// from obligations by unwinding payments made out of unapplied funds; it is
+    /// put back into the pool first so it can cover the reversal.
+    pub(crate) fn reverse_unapplied_funds(
+        &mut self,
+        amount: UsdCents,
+        returned: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<UnappliedFundsReversal, CreditFacilityError> {
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if self.unapplied_funds() + returned < amount {
+            return Err(CreditFacilityError::InsufficientUnappliedFunds(
+                amount,
+                self.unapplied_funds() + returned,
+            ));
+        }
+
+        let returned = if returned.is_zero() {
+            None
+        } else {
+            Some(self.record_unapplied_funds(returned, effective, audit_info)?)
+        };
+
+        let reversal = self.unapplied_funds_movement(amount, effective);
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsReversed {
+                ledger_tx_id: reversal.tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Ok(UnappliedFundsReversal { returned, reversal })
+    }
+
+    fn refund_unapplied_funds(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Option<CreditFacilityUnappliedFunds> {
+        let amount = self.unapplied_funds();
+        if amount.is_zero() {
+            return None;
+        }
+
+        let refund = self.unapplied_funds_movement(amount, effective);
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsRefunded {
+                ledger_tx_id: refund.tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Some(refund)
+    }
+
+    /// `fees` and `penalties` are the outstanding fee and penalty obligations,
+    /// which are booked together with interest on the receivable accounts.
+    pub fn payoff_quote(
+        &self,
+        as_of: DateTime<Utc>,
+        balances: CreditFacilityBalanceSummary,
+        fees: UsdCents,
+        penalties: UsdCents,
+        reference_rate: Option<&ReferenceRate>,
+    ) -> Result<PayoffQuote, CreditFacilityError> {
+        if !self.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet);
+        }
+        let matures_at = self.matures_at.expect("Facility is already active");
+        let projected_until = as_of.min(matures_at);
+        let principal = balances.disbursed_outstanding();
+
+        let accrual = self.interest_accrual_cycle_in_progress();
+        let mut projected_interest = accrual
+            .map(|accrual| accrual.interest_accrued_to(principal, projected_until))
+            .unwrap_or(UsdCents::ZERO);
+
+        // Cycles that have not started are projected at the latest fixing
+        // known for their start, i.e. the current one for future cycles.
+        let mut next_cycle_period = self.next_interest_accrual_cycle_period()?;
+        while let Some(cycle_period) = next_cycle_period {
+            let Some(period) = cycle_period.truncate(projected_until) else {
+                break;
+            };
+            let (terms, _) = self.accrual_cycle_terms(reference_rate, cycle_period.start)?;
+            projected_interest += terms.interest_for_period(principal, &period);
+            next_cycle_period = cycle_period.next().truncate(matures_at);
+        }
+
+        let prepayment_fee = if as_of < matures_at {
+            self.terms.prepayment_fee_rate.apply(principal)
+        } else {
+            UsdCents::ZERO
+        };
+
+        Ok(PayoffQuote {
+            as_of,
+            valid_until: self.terms.accrual_interval.period_from(as_of).end,
+            principal,
+            posted_interest: balances.interest_outstanding() - fees - penalties,
+            fees,
+            penalties,
+            projected_interest,
+            prepayment_fee,
+        })
+    }
+
+    fn update_collateralization_ratio(
+        &mut self,
+        balance: &CreditFacilityBalanceSummary,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        let ratio = balance.current_collateralization_ratio();
+
+        if self.last_collateralization_ratio() != ratio {
+            self.events
+                .push(CreditFacilityEvent::CollateralizationRatioChanged {
+                    collateralization_ratio: ratio,
+                    audit_info,
+                });
+        } else {
+            return Idempotent::Ignored;
+        }
+
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
+    fn try_from_events(events: EntityEvents<CreditFacilityEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = CreditFacilityBuilder::default();
+        let mut terms = None;
+        for event in events.iter_all() {
+            match event {
+                CreditFacilityEvent::Initialized {
+                    id,
+                    amount,
+                    customer_id,
+                    collateral_id,
+                    account_ids,
+                    disbursal_credit_account_id,
+                    terms: t,
+                    approval_process_id,
+                    ..
+                } => {
+                    terms = Some(*t);
+                    builder = builder
+                        .id(*id)
+                        .amount(*amount)
+                        .customer_id(*customer_id)
+                        .collateral_id(*collateral_id)
+                        .terms(*t)
+                        .account_ids(*account_ids)
+                        .disbursal_credit_account_id(*disbursal_credit_account_id)
+                        .approval_process_id(*approval_process_id)
+                }
+                CreditFacilityEvent::Activated { activated_at, .. } => {
+                    let matures_at = terms
+                        .expect("terms should be set")
+                        .duration
+                        .maturity_date(*activated_at);
+                    builder = builder.activated_at(*activated_at).matures_at(matures_at)
+                }
+                CreditFacilityEvent::TermsAmended {
+                    terms: t,
+                    matures_at,
+                    ..
+                } => builder = builder.terms(*t).matures_at(*matures_at),
+                CreditFacilityEvent::ApprovalProcessConcluded { .. } => (),
+                CreditFacilityEvent::TermsAmendmentRequested { .. } => (),
+                CreditFacilityEvent::TermsAmendmentDenied { .. } => (),
+                CreditFacilityEvent::InterestAccrualCycleStarted { .. } => (),
+                CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
+                CreditFacilityEvent::FeeCharged { .. } => (),
+                CreditFacilityEvent::CollateralizationStateChanged { .. } => (),
+                CreditFacilityEvent::CollateralizationRatioChanged { .. } => (),
+                CreditFacilityEvent::UnappliedFundsRecorded { .. } => (),
+                CreditFacilityEvent::UnappliedFundsApplied { .. } => (),
+                CreditFacilityEvent::UnappliedFundsRefunded { .. } => (),
+                CreditFacilityEvent::UnappliedFundsReversed { .. } => (),
+                CreditFacilityEvent::Prepaid { .. } => (),
+                CreditFacilityEvent::Completed { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewCreditFacility {
     #[builder(setter(into))]
     pub(super) id: CreditFacilityId,
     #[builder(setter(into))]
@@ -832,7 +1644,7 @@ mod test {
         );
 
         credit_facility
-            .start_interest_accrual_cycle(dummy_audit_info())
+            .start_interest_accrual_cycle(None, dummy_audit_info())
             .unwrap()
             .unwrap();
 
@@ -854,7 +1666,7 @@ mod test {
         let mut credit_facility = facility_from(events);
 
         credit_facility
-            .start_interest_accrual_cycle(dummy_audit_info())
+            .start_interest_accrual_cycle(None, dummy_audit_info())
             .unwrap()
             .unwrap();
         hydrate_accruals_in_facility(&mut credit_facility);
@@ -932,7 +1744,13 @@ mod test {
 
         assert!(
             credit_facility
-                .activate(approval_time, default_price(), balances, dummy_audit_info())
+                .activate(
+                    approval_time,
+                    default_price(),
+                    balances,
+                    None,
+                    dummy_audit_info()
+                )
                 .unwrap()
                 .did_execute()
         );
@@ -968,7 +1786,13 @@ mod test {
         balances.collateral = default_full_collateral();
         assert!(
             credit_facility
-                .activate(Utc::now(), default_price(), balances, dummy_audit_info())
+                .activate(
+                    Utc::now(),
+                    default_price(),
+                    balances,
+                    None,
+                    dummy_audit_info()
+                )
                 .unwrap()
                 .did_execute()
         );
@@ -993,6 +1817,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::ApprovalInProgress)
@@ -1014,6 +1839,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::Denied)
@@ -1035,6 +1861,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::BelowMarginLimit)
@@ -1056,6 +1883,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Err(CreditFacilityError::BelowMarginLimit)
@@ -1084,6 +1912,7 @@ mod test {
                     Utc::now(),
                     default_price(),
                     default_balances(credit_facility.amount),
+                    None,
                     dummy_audit_info()
                 ),
                 Ok(Idempotent::Ignored)
@@ -1105,10 +1934,97 @@ mod test {
 
             assert!(
                 credit_facility
-                    .activate(Utc::now(), default_price(), balances, dummy_audit_info())
+                    .activate(
+                        Utc::now(),
+                        default_price(),
+                        balances,
+                        None,
+                        dummy_audit_info()
+                    )
                     .is_ok()
             );
         }
+
+        fn floating_rate_facility(reference_rate_id: ReferenceRateId) -> CreditFacility {
+            let mut events = initial_events();
+            if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+                terms.floating_rate = Some(FloatingRate {
+                    reference_rate_id,
+                    spread: dec!(3).into(),
+                });
+            }
+            events.extend([CreditFacilityEvent::ApprovalProcessConcluded {
+                approval_process_id: ApprovalProcessId::new(),
+                approved: true,
+                audit_info: dummy_audit_info(),
+            }]);
+            facility_from(events)
+        }
+
+        pub(super) fn reference_rate_with_fixing(id: ReferenceRateId) -> ReferenceRate {
+            ReferenceRate::try_from_events(EntityEvents::init(
+                id,
+                [
+                    ReferenceRateEvent::Initialized {
+                        id,
+                        name: "SOFR".to_string(),
+                        audit_info: dummy_audit_info(),
+                    },
+                    ReferenceRateEvent::FixingPublished {
+                        effective_date: (Utc::now() - chrono::Duration::days(1)).date_naive(),
+                        rate: dec!(4.25).into(),
+                        audit_info: dummy_audit_info(),
+                    },
+                ],
+            ))
+            .unwrap()
+        }
+
+        #[test]
+        fn floating_rate_errors_without_fixing() {
+            let mut credit_facility = floating_rate_facility(ReferenceRateId::new());
+            let mut balances = default_balances(credit_facility.amount);
+            balances.collateral = default_full_collateral();
+
+            assert!(matches!(
+                credit_facility.activate(
+                    Utc::now(),
+                    default_price(),
+                    balances,
+                    None,
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::ReferenceRateFixingNotFound(..))
+            ));
+            assert!(!credit_facility.is_activated());
+        }
+
+        #[test]
+        fn floating_rate_cycle_snapshots_fixing() {
+            let reference_rate_id = ReferenceRateId::new();
+            let reference_rate = reference_rate_with_fixing(reference_rate_id);
+            let mut credit_facility = floating_rate_facility(reference_rate_id);
+            let mut balances = default_balances(credit_facility.amount);
+            balances.collateral = default_full_collateral();
+
+            credit_facility
+                .activate(
+                    Utc::now(),
+                    default_price(),
+                    balances,
+                    Some(&reference_rate),
+                    dummy_audit_info(),
+                )
+                .unwrap();
+            hydrate_accruals_in_facility(&mut credit_facility);
+
+            let cycle = credit_facility
+                .interest_accrual_cycle_in_progress()
+                .unwrap();
+            let rate_fixing = cycle.rate_fixing.unwrap();
+            assert_eq!(rate_fixing.reference_rate_id, reference_rate_id);
+            assert_eq!(cycle.terms.annual_rate, AnnualRatePct::from(dec!(7.25)));
+        }
     }
 
     mod completion {
@@ -1390,4 +2306,819 @@ mod test {
             ));
         }
     }
+
+    mod prepayment {
+        use super::*;
+
+        fn facility_with_prepayment_fee(activated_at: DateTime<Utc>) -> CreditFacility {
+            let mut events = initial_events();
+            if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+                terms.prepayment_fee_rate = OneTimeFeeRatePct::new(2);
+            }
+            events.push(CreditFacilityEvent::Activated {
+                ledger_tx_id: LedgerTxId::new(),
+                audit_info: dummy_audit_info(),
+                activated_at,
+            });
+            facility_from(events)
+        }
+
+        fn balances_with_disbursed(disbursed: UsdCents) -> CreditFacilityBalanceSummary {
+            CreditFacilityBalanceSummary {
+                disbursed,
+                not_yet_due_disbursed_outstanding: disbursed,
+                ..default_balances(default_facility())
+            }
+        }
+
+        #[test]
+        fn errors_if_not_activated() {
+            let mut credit_facility = facility_from(initial_events());
+
+            let res = credit_facility.prepay(
+                Utc::now(),
+                balances_with_disbursed(UsdCents::from(10_00)),
+                dummy_audit_info(),
+            );
+            assert!(matches!(res, Err(CreditFacilityError::NotActivatedYet)));
+        }
+
+        #[test]
+        fn settles_interest_to_date_and_charges_fee() {
+            let activated_at = Utc::now();
+            let mut credit_facility = facility_with_prepayment_fee(activated_at);
+            credit_facility
+                .start_interest_accrual_cycle(None, dummy_audit_info())
+                .unwrap()
+                .unwrap();
+            hydrate_accruals_in_facility(&mut credit_facility);
+
+            let disbursed = UsdCents::from(10_00);
+            let Idempotent::Executed((prepayment, new_obligation)) = credit_facility
+                .prepay(
+                    activated_at + chrono::Duration::hours(1),
+                    balances_with_disbursed(disbursed),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+            else {
+                panic!("prepayment should execute");
+            };
+
+            let posted_interest = prepayment.posted_interest.expect("interest not posted");
+            assert!(new_obligation.is_some());
+            assert_eq!(prepayment.outstanding, disbursed + posted_interest.interest);
+            assert_eq!(prepayment.prepayment_fee, UsdCents::from(20));
+            assert!(
+                credit_facility
+                    .interest_accrual_cycle_in_progress()
+                    .is_none()
+            );
+            assert!(credit_facility.is_completed());
+        }
+
+        #[test]
+        fn no_fee_after_maturity() {
+            let activated_at = Utc::now() - chrono::Duration::days(120);
+            let mut credit_facility = facility_with_prepayment_fee(activated_at);
+
+            let disbursed = UsdCents::from(10_00);
+            let Idempotent::Executed((prepayment, new_obligation)) = credit_facility
+                .prepay(
+                    Utc::now(),
+                    balances_with_disbursed(disbursed),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+            else {
+                panic!("prepayment should execute");
+            };
+
+            assert!(new_obligation.is_none());
+            assert_eq!(prepayment.outstanding, disbursed);
+            assert_eq!(prepayment.prepayment_fee, UsdCents::ZERO);
+        }
+
+        #[test]
+        fn payoff_quote_projects_interest_across_cycles() {
+            let activated_at = Utc::now();
+            let mut credit_facility = facility_with_prepayment_fee(activated_at);
+            credit_facility
+                .start_interest_accrual_cycle(None, dummy_audit_info())
+                .unwrap()
+                .unwrap();
+            hydrate_accruals_in_facility(&mut credit_facility);
+
+            let disbursed = UsdCents::from(1_000_000_00);
+            let balances = balances_with_disbursed(disbursed);
+            let next_week = credit_facility
+                .payoff_quote(
+                    activated_at + chrono::Duration::days(7),
+                    balances,
+                    UsdCents::ZERO,
+                    UsdCents::ZERO,
+                    None,
+                )
+                .unwrap();
+            let next_month = credit_facility
+                .payoff_quote(
+                    activated_at + chrono::Duration::days(40),
+                    balances,
+                    UsdCents::ZERO,
+                    UsdCents::ZERO,
+                    None,
+                )
+                .unwrap();
+
+            assert_eq!(next_month.principal, disbursed);
+            assert_eq!(next_month.prepayment_fee, UsdCents::from(20_000_00));
+            assert!(next_month.projected_interest > next_week.projected_interest);
+            assert_eq!(
+                next_month.total(),
+                disbursed + next_month.projected_interest + next_month.prepayment_fee
+            );
+        }
+
+        #[test]
+        fn payoff_quote_itemizes_fees_and_penalties() {
+            let activated_at = Utc::now();
+            let credit_facility = facility_with_prepayment_fee(activated_at);
+
+            let disbursed = UsdCents::from(1_000_00);
+            let balances = CreditFacilityBalanceSummary {
+                due_interest_outstanding: UsdCents::from(30_00),
+                ..balances_with_disbursed(disbursed)
+            };
+            let quote = credit_facility
+                .payoff_quote(
+                    activated_at,
+                    balances,
+                    UsdCents::from(10_00),
+                    UsdCents::from(5_00),
+                    None,
+                )
+                .unwrap();
+
+            assert_eq!(quote.posted_interest, UsdCents::from(15_00));
+            assert_eq!(quote.fees, UsdCents::from(10_00));
+            assert_eq!(quote.penalties, UsdCents::from(5_00));
+            assert_eq!(
+                quote.total(),
+                disbursed + UsdCents::from(30_00) + quote.projected_interest + quote.prepayment_fee
+            );
+        }
+
+        #[test]
+        fn payoff_quote_projects_floating_rate_at_current_fixing() {
+            let activated_at = Utc::now();
+            let facility = |floating_rate: Option<FloatingRate>, annual_rate: AnnualRatePct| {
+                let mut events = initial_events();
+                if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+                    terms.floating_rate = floating_rate;
+                    terms.annual_rate = annual_rate;
+                }
+                events.push(CreditFacilityEvent::Activated {
+                    ledger_tx_id: LedgerTxId::new(),
+                    audit_info: dummy_audit_info(),
+                    activated_at,
+                });
+                facility_from(events)
+            };
+            let reference_rate_id = ReferenceRateId::new();
+            let reference_rate = super::activate::reference_rate_with_fixing(reference_rate_id);
+            let floating = facility(
+                Some(FloatingRate {
+                    reference_rate_id,
+                    spread: dec!(3).into(),
+                }),
+                dec!(12).into(),
+            );
+            let fixed = facility(None, dec!(7.25).into());
+
+            let as_of = activated_at + chrono::Duration::days(40);
+            let balances = balances_with_disbursed(UsdCents::from(1_000_000_00));
+            let floating_quote = floating
+                .payoff_quote(
+                    as_of,
+                    balances,
+                    UsdCents::ZERO,
+                    UsdCents::ZERO,
+                    Some(&reference_rate),
+                )
+                .unwrap();
+            let fixed_quote = fixed
+                .payoff_quote(as_of, balances, UsdCents::ZERO, UsdCents::ZERO, None)
+                .unwrap();
+
+            assert!(!floating_quote.projected_interest.is_zero());
+            assert_eq!(
+                floating_quote.projected_interest,
+                fixed_quote.projected_interest
+            );
+            assert!(matches!(
+                floating.payoff_quote(as_of, balances, UsdCents::ZERO, UsdCents::ZERO, None),
+                Err(CreditFacilityError::ReferenceRateFixingNotFound(..))
+            ));
+        }
+
+        #[test]
+        fn payoff_quote_stops_projecting_at_maturity() {
+            let activated_at = Utc::now();
+            let credit_facility = facility_with_prepayment_fee(activated_at);
+            let matures_at = credit_facility.matures_at.unwrap();
+
+            let balances = balances_with_disbursed(UsdCents::from(1_000_000_00));
+            let at_maturity = credit_facility
+                .payoff_quote(matures_at, balances, UsdCents::ZERO, UsdCents::ZERO, None)
+                .unwrap();
+            let after_maturity = credit_facility
+                .payoff_quote(
+                    matures_at + chrono::Duration::days(10),
+                    balances,
+                    UsdCents::ZERO,
+                    UsdCents::ZERO,
+                    None,
+                )
+                .unwrap();
+
+            assert_eq!(
+                at_maturity.projected_interest,
+                after_maturity.projected_interest
+            );
+            assert_eq!(after_maturity.prepayment_fee, UsdCents::ZERO);
+        }
+
+        #[test]
+        fn ignored_once_completed() {
+            let mut credit_facility = facility_with_prepayment_fee(Utc::now());
+            let balances = balances_with_disbursed(UsdCents::from(10_00));
+
+            assert!(
+                credit_facility
+                    .prepay(Utc::now(), balances, dummy_audit_info())
+                    .unwrap()
+                    .did_execute()
+            );
+            assert!(
+                credit_facility
+                    .prepay(Utc::now(), balances, dummy_audit_info())
+                    .unwrap()
+                    .was_ignored()
+            );
+        }
+    }
+
+    mod unapplied_funds {
+        use super::*;
+
+        #[test]
+        fn applies_allocated_amount() {
+            let mut credit_facility = facility_from(initial_events());
+            let today = Utc::now().date_naive();
+
+            credit_facility
+                .record_unapplied_funds(UsdCents::from(10_00), today, &dummy_audit_info())
+                .unwrap();
+            assert_eq!(credit_facility.unapplied_funds(), UsdCents::from(10_00));
+
+            let Idempotent::Executed(applied) = credit_facility
+                .apply_unapplied_funds(
+                    PaymentId::new(),
+                    UsdCents::from(4_00),
+                    today,
+                    &dummy_audit_info(),
+                )
+                .unwrap()
+            else {
+                panic!("unapplied funds should be applied");
+            };
+            assert_eq!(applied.amount, UsdCents::from(4_00));
+            assert_eq!(credit_facility.unapplied_funds(), UsdCents::from(6_00));
+        }
+
+        #[test]
+        fn apply_ignored_when_nothing_allocated() {
+            let mut credit_facility = facility_from(initial_events());
+            let today = Utc::now().date_naive();
+            credit_facility
+                .record_unapplied_funds(UsdCents::from(10_00), today, &dummy_audit_info())
+                .unwrap();
+
+            assert!(
+                credit_facility
+                    .apply_unapplied_funds(
+                        PaymentId::new(),
+                        UsdCents::ZERO,
+                        today,
+                        &dummy_audit_info()
+                    )
+                    .unwrap()
+                    .was_ignored()
+            );
+        }
+
+        #[test]
+        fn apply_rejects_more_than_available() {
+            let mut credit_facility = facility_from(initial_events());
+
+            assert!(matches!(
+                credit_facility.apply_unapplied_funds(
+                    PaymentId::new(),
+                    UsdCents::from(4_00),
+                    Utc::now().date_naive(),
+                    &dummy_audit_info()
+                ),
+                Err(CreditFacilityError::InsufficientUnappliedFunds(_, _))
+            ));
+        }
+
+        #[test]
+        fn reversal_uses_funds_returned_from_applied_payments() {
+            let mut credit_facility = facility_from(initial_events());
+            let today = Utc::now().date_naive();
+            let payment_id = PaymentId::new();
+
+            credit_facility
+                .record_unapplied_funds(UsdCents::from(10_00), today, &dummy_audit_info())
+                .unwrap();
+            let _ = credit_facility.apply_unapplied_funds(
+                payment_id,
+                UsdCents::from(10_00),
+                today,
+                &dummy_audit_info(),
+            );
+            assert_eq!(
+                credit_facility.unapplied_funds_payment_ids(),
+                vec![payment_id]
+            );
+
+            assert!(matches!(
+                credit_facility.reverse_unapplied_funds(
+                    UsdCents::from(10_00),
+                    UsdCents::ZERO,
+                    today,
+                    &dummy_audit_info()
+                ),
+                Err(CreditFacilityError::InsufficientUnappliedFunds(_, _))
+            ));
+
+            let reversal = credit_facility
+                .reverse_unapplied_funds(
+                    UsdCents::from(10_00),
+                    UsdCents::from(10_00),
+                    today,
+                    &dummy_audit_info(),
+                )
+                .unwrap();
+            assert_eq!(
+                reversal.returned.map(|returned| returned.amount),
+                Some(UsdCents::from(10_00))
+            );
+            assert_eq!(reversal.reversal.amount, UsdCents::from(10_00));
+            assert_eq!(credit_facility.unapplied_funds(), UsdCents::ZERO);
+        }
+
+        #[test]
+        fn refunded_on_completion() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .record_unapplied_funds(
+                    UsdCents::from(10_00),
+                    Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .unwrap();
+
+            let Idempotent::Executed(completion) = credit_facility
+                .complete(
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(default_facility()),
+                )
+                .unwrap()
+            else {
+                panic!("completion should execute");
+            };
+
+            let refund = completion
+                .unapplied_funds_refund
+                .expect("unapplied funds not refunded");
+            assert_eq!(refund.amount, UsdCents::from(10_00));
+            assert_eq!(credit_facility.unapplied_funds(), UsdCents::ZERO);
+        }
+
+        #[test]
+        fn errors_if_recorded_after_completion() {
+            let mut credit_facility = facility_from(initial_events());
+            let _ = credit_facility
+                .complete(
+                    dummy_audit_info(),
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(default_facility()),
+                )
+                .unwrap();
+
+            let res = credit_facility.record_unapplied_funds(
+                UsdCents::from(10_00),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            );
+            assert!(matches!(res, Err(CreditFacilityError::AlreadyCompleted)));
+        }
+    }
+
+    mod price_thresholds {
+        use super::*;
+
+        #[test]
+        fn no_thresholds_without_collateral() {
+            let credit_facility = facility_from(initial_events());
+            assert_eq!(credit_facility.collateralization_price_floor(), None);
+            assert_eq!(credit_facility.collateralization_price_ceiling(), None);
+        }
+
+        #[test]
+        fn fully_collateralized_only_has_floor() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .update_collateralization(
+                    default_price(),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount)
+                        .with_collateral(default_full_collateral()),
+                    &dummy_audit_info(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                credit_facility.last_collateralization_state(),
+                CollateralizationState::FullyCollateralized
+            );
+            assert_eq!(
+                credit_facility.collateralization_price_floor(),
+                Some(dec!(1_400_000))
+            );
+            assert_eq!(credit_facility.collateralization_price_ceiling(), None);
+        }
+
+        #[test]
+        fn pending_below_initial_cvl_is_not_fully_collateralized() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .update_collateralization(
+                    PriceOfOneBTC::new(UsdCents::from(1_300_000)),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount)
+                        .with_collateral(default_full_collateral()),
+                    &dummy_audit_info(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                credit_facility.status(),
+                CreditFacilityStatus::PendingCollateralization
+            );
+            assert_eq!(
+                credit_facility.collateralization_price_ceiling(),
+                Some(dec!(1_400_000))
+            );
+        }
+
+        #[test]
+        fn under_margin_call_has_floor_and_ceiling() {
+            let mut credit_facility = facility_from(initial_events());
+            credit_facility
+                .update_collateralization(
+                    PriceOfOneBTC::new(UsdCents::from(1_100_000)),
+                    default_upgrade_buffer_cvl_pct(),
+                    default_balances(credit_facility.amount)
+                        .with_collateral(default_full_collateral()),
+                    &dummy_audit_info(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                credit_facility.last_collateralization_state(),
+                CollateralizationState::UnderMarginCallThreshold
+            );
+            assert_eq!(
+                credit_facility.collateralization_price_floor(),
+                Some(dec!(1_050_000))
+            );
+            assert_eq!(
+                credit_facility.collateralization_price_ceiling(),
+                Some(dec!(1_400_000))
+            );
+        }
+    }
+
+    mod restructuring {
+        use super::*;
+
+        fn activated_facility() -> CreditFacility {
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::Activated {
+                ledger_tx_id: LedgerTxId::new(),
+                audit_info: dummy_audit_info(),
+                activated_at: Utc::now(),
+            });
+            facility_from(events)
+        }
+
+        fn extended_terms() -> TermValues {
+            let mut terms = default_terms();
+            terms.duration = FacilityDuration::Months(6);
+            terms
+        }
+
+        #[test]
+        fn errors_if_not_activated() {
+            let mut credit_facility = facility_from(initial_events());
+
+            let res = credit_facility.request_terms_amendment(
+                ApprovalProcessId::new(),
+                extended_terms(),
+                dummy_audit_info(),
+            );
+            assert!(matches!(res, Err(CreditFacilityError::NotActivatedYet)));
+        }
+
+        #[test]
+        fn errors_if_amendment_already_pending() {
+            let mut credit_facility = activated_facility();
+            credit_facility
+                .request_terms_amendment(
+                    ApprovalProcessId::new(),
+                    extended_terms(),
+                    dummy_audit_info(),
+                )
+                .unwrap();
+
+            let res = credit_facility.request_terms_amendment(
+                ApprovalProcessId::new(),
+                extended_terms(),
+                dummy_audit_info(),
+            );
+            assert!(matches!(
+                res,
+                Err(CreditFacilityError::RestructuringInProgress)
+            ));
+        }
+
+        #[test]
+        fn allows_restructuring_after_maturity() {
+            let mut events = initial_events();
+            events.push(CreditFacilityEvent::Activated {
+                ledger_tx_id: LedgerTxId::new(),
+                audit_info: dummy_audit_info(),
+                activated_at: Utc::now() - chrono::Duration::days(120),
+            });
+            let mut credit_facility = facility_from(events);
+            assert!(credit_facility.is_after_maturity_date());
+
+            let res = credit_facility.request_terms_amendment(
+                ApprovalProcessId::new(),
+                extended_terms(),
+                dummy_audit_info(),
+            );
+            assert!(res.unwrap().did_execute());
+        }
+
+        #[test]
+        fn approval_extends_maturity() {
+            let mut credit_facility = activated_facility();
+            let original_maturity = credit_facility.matures_at.unwrap();
+            let approval_process_id = ApprovalProcessId::new();
+            credit_facility
+                .request_terms_amendment(approval_process_id, extended_terms(), dummy_audit_info())
+                .unwrap();
+
+            assert!(
+                credit_facility
+                    .conclude_terms_amendment(approval_process_id, true, dummy_audit_info())
+                    .did_execute()
+            );
+            assert!(matches!(
+                credit_facility.terms.duration,
+                FacilityDuration::Months(6)
+            ));
+            assert!(credit_facility.matures_at.unwrap() > original_maturity);
+            assert!(credit_facility.pending_terms_amendment().is_none());
+
+            let rebuilt = facility_from(credit_facility.events.iter_all().cloned().collect());
+            assert!(matches!(
+                rebuilt.terms.duration,
+                FacilityDuration::Months(6)
+            ));
+            assert_eq!(rebuilt.matures_at, credit_facility.matures_at);
+        }
+
+        #[test]
+        fn denial_keeps_existing_terms() {
+            let mut credit_facility = activated_facility();
+            let original_maturity = credit_facility.matures_at;
+            let approval_process_id = ApprovalProcessId::new();
+            credit_facility
+                .request_terms_amendment(approval_process_id, extended_terms(), dummy_audit_info())
+                .unwrap();
+
+            assert!(
+                credit_facility
+                    .conclude_terms_amendment(approval_process_id, false, dummy_audit_info())
+                    .did_execute()
+            );
+            assert!(matches!(
+                credit_facility.terms.duration,
+                FacilityDuration::Months(3)
+            ));
+            assert_eq!(credit_facility.matures_at, original_maturity);
+            assert!(credit_facility.pending_terms_amendment().is_none());
+        }
+
+        #[test]
+        fn approval_applies_to_cycle_in_progress() {
+            let mut credit_facility = activated_facility();
+            credit_facility
+                .start_interest_accrual_cycle(None, dummy_audit_info())
+                .unwrap()
+                .unwrap();
+            hydrate_accruals_in_facility(&mut credit_facility);
+
+            let approval_process_id = ApprovalProcessId::new();
+            let terms = extended_terms().with_annual_rate(AnnualRatePct::from(dec!(5)));
+            credit_facility
+                .request_terms_amendment(approval_process_id, terms, dummy_audit_info())
+                .unwrap();
+            let _ = credit_facility.conclude_terms_amendment(
+                approval_process_id,
+                true,
+                dummy_audit_info(),
+            );
+
+            assert!(
+                credit_facility
+                    .accrue_on_amended_terms(None, dummy_audit_info())
+                    .unwrap()
+                    .is_none()
+            );
+            let cycle = credit_facility
+                .interest_accrual_cycle_in_progress()
+                .unwrap();
+            assert_eq!(cycle.terms.annual_rate, terms.annual_rate);
+            assert_eq!(Some(cycle.facility_matures_at), credit_facility.matures_at);
+        }
+    }
+
+    mod releasable_collateral {
+        use super::*;
+
+        #[test]
+        fn uses_facility_amount_before_disbursal() {
+            let credit_facility = facility_from(initial_events());
+            let balances = CreditFacilityBalanceSummary {
+                collateral: default_full_collateral(),
+                ..default_balances(default_facility())
+            };
+
+            assert_eq!(
+                credit_facility.releasable_collateral(balances, default_price()),
+                Satoshis::from(72_000)
+            );
+        }
+
+        #[test]
+        fn uses_outstanding_after_disbursal() {
+            let credit_facility = facility_from(initial_events());
+            let balances = CreditFacilityBalanceSummary {
+                collateral: default_full_collateral(),
+                disbursed: UsdCents::from(500),
+                not_yet_due_disbursed_outstanding: UsdCents::from(500),
+                ..default_balances(default_facility())
+            };
+
+            assert_eq!(
+                credit_facility.releasable_collateral(balances, default_price()),
+                Satoshis::from(86_000)
+            );
+        }
+
+        #[test]
+        fn nothing_releasable_below_initial_cvl() {
+            let credit_facility = facility_from(initial_events());
+            let balances = CreditFacilityBalanceSummary {
+                collateral: Satoshis::from(20_000),
+                ..default_balances(default_facility())
+            };
+
+            assert_eq!(
+                credit_facility.releasable_collateral(balances, default_price()),
+                Satoshis::ZERO
+            );
+        }
+    }
+
+    mod fees {
+        use super::*;
+
+        use crate::terms::FeeSchedule;
+
+        fn facility_with_fees(activated: bool) -> CreditFacility {
+            let mut events = initial_events();
+            if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+                terms.duration = FacilityDuration::Months(24);
+                terms.fee_schedule = FeeSchedule {
+                    commitment_fee_rate: None,
+                    annual_maintenance_fee: Some(UsdCents::from(50_00)),
+                    disbursal_fee_rate: Some(OneTimeFeeRatePct::new(1)),
+                };
+            }
+            if activated {
+                events.push(CreditFacilityEvent::Activated {
+                    ledger_tx_id: LedgerTxId::new(),
+                    audit_info: dummy_audit_info(),
+                    activated_at: Utc::now(),
+                });
+            }
+            facility_from(events)
+        }
+
+        #[test]
+        fn ignored_if_not_activated() {
+            let mut credit_facility = facility_with_fees(false);
+
+            assert!(credit_facility.next_maintenance_fee_date().is_none());
+            assert!(
+                credit_facility
+                    .charge_disbursal_fee(
+                        LedgerTxId::new(),
+                        UsdCents::from(10_00),
+                        Utc::now(),
+                        &dummy_audit_info(),
+                    )
+                    .was_ignored()
+            );
+        }
+
+        #[test]
+        fn maintenance_fee_advances_to_next_anniversary() {
+            let mut credit_facility = facility_with_fees(true);
+            let activated_at = credit_facility.activated_at.unwrap();
+            assert_eq!(
+                credit_facility.next_maintenance_fee_date(),
+                Some(activated_at)
+            );
+
+            let Idempotent::Executed((fee, _)) =
+                credit_facility.charge_maintenance_fee(activated_at, &dummy_audit_info())
+            else {
+                panic!("maintenance fee should be charged");
+            };
+            assert_eq!(fee.amount, UsdCents::from(50_00));
+            assert_eq!(
+                fee.fee_income_account_id,
+                credit_facility
+                    .account_ids
+                    .maintenance_fee_income_account_id
+            );
+
+            let next = credit_facility.next_maintenance_fee_date().unwrap();
+            assert!(next > activated_at);
+            assert!(
+                credit_facility
+                    .charge_maintenance_fee(activated_at, &dummy_audit_info())
+                    .was_ignored()
+            );
+
+            let _ = credit_facility.charge_maintenance_fee(next, &dummy_audit_info());
+            assert!(credit_facility.next_maintenance_fee_date().is_none());
+        }
+
+        #[test]
+        fn disbursal_fee_charged_once_per_disbursal() {
+            let mut credit_facility = facility_with_fees(true);
+            let tx_id = LedgerTxId::new();
+
+            let Idempotent::Executed((fee, _)) = credit_facility.charge_disbursal_fee(
+                tx_id,
+                UsdCents::from(10_00),
+                Utc::now(),
+                &dummy_audit_info(),
+            ) else {
+                panic!("disbursal fee should be charged");
+            };
+            assert_eq!(fee.amount, UsdCents::from(10));
+            assert!(
+                credit_facility
+                    .charge_disbursal_fee(
+                        tx_id,
+                        UsdCents::from(10_00),
+                        Utc::now(),
+                        &dummy_audit_info(),
+                    )
+                    .was_ignored()
+            );
+        }
+    }
 }
@@ -40,8 +40,20 @@ pub enum CreditFacilityError {
     FacilityLedgerBalanceMismatch,
     #[error("CreditFacilityError - OutstandingAmount")]
     OutstandingAmount,
+    #[error("CreditFacilityError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("CreditFacilityError - InsufficientUnappliedFunds: {0} requested, {1} available")]
+    InsufficientUnappliedFunds(UsdCents, UsdCents),
+    #[error("CreditFacilityError - PrepaymentNotFullyAllocated: {0} of {1}")]
+    PrepaymentNotFullyAllocated(UsdCents, UsdCents),
+    #[error("CreditFacilityError - RestructuringInProgress")]
+    RestructuringInProgress,
+    #[error("CreditFacilityError - RestructuredMaturityInPast")]
+    RestructuredMaturityInPast,
     #[error("CreditFacilityError - InterestAccrualCycleWithInvalidFutureStartDate")]
     InterestAccrualCycleWithInvalidFutureStartDate,
+    #[error("CreditFacilityError - ReferenceRateFixingNotFound: {0} as of {1}")]
+    ReferenceRateFixingNotFound(crate::primitives::ReferenceRateId, chrono::NaiveDate),
     #[error(
         "CreditFacilityError - DisbursalAmountTooLarge: amount '{0}' is larger than facility balance '{1}'"
     )]
@@ -54,8 +66,12 @@ pub enum CreditFacilityError {
     LedgerError(#[from] crate::ledger::error::CreditLedgerError),
     #[error("CreditFacilityError - PriceError: {0}")]
     PriceError(#[from] core_price::error::PriceError),
+    #[error("CreditFacilityError - ReferenceRateError: {0}")]
+    ReferenceRateError(#[from] crate::reference_rate::error::ReferenceRateError),
     #[error("CreditFacilityError - ObligationError: {0}")]
     ObligationError(#[from] crate::obligation::error::ObligationError),
+    #[error("CreditFacilityError - CollateralError: {0}")]
+    CollateralError(#[from] crate::collateral::error::CollateralError),
     #[error("CreditFacilityError - GovernanceError: {0}")]
     GovernanceError(#[from] governance::error::GovernanceError),
 }
@@ -10,12 +10,14 @@ use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject
 use outbox::OutboxEventMarker;
 
 use crate::{
-    CoreCreditAction, CoreCreditObject, CreditFacilityActivation, CreditLedger, InterestPeriod,
-    Obligation, Obligations, Price, event::CoreCreditEvent, primitives::*,
+    Collaterals, CoreCreditAction, CoreCreditObject, CreditFacilityActivation,
+    CreditFacilityBalanceSummary, CreditLedger, InterestPeriod, Obligation,
+    ObligationRescheduleReallocationData, Obligations, Price, ReferenceRate, ReferenceRates,
+    TermValues, event::CoreCreditEvent, primitives::*,
 };
 
-pub use entity::CreditFacility;
 pub(crate) use entity::*;
+pub use entity::{CreditFacility, PayoffQuote};
 
 #[cfg(feature = "json-schema")]
 pub use entity::CreditFacilityEvent;
@@ -32,6 +34,8 @@ where
 {
     repo: CreditFacilityRepo<E>,
     obligations: Obligations<Perms, E>,
+    collaterals: Collaterals<Perms, E>,
+    reference_rates: ReferenceRates<Perms>,
     authz: Perms,
     ledger: CreditLedger,
     price: Price,
@@ -47,6 +51,8 @@ where
         Self {
             repo: self.repo.clone(),
             obligations: self.obligations.clone(),
+            collaterals: self.collaterals.clone(),
+            reference_rates: self.reference_rates.clone(),
             authz: self.authz.clone(),
             ledger: self.ledger.clone(),
             price: self.price.clone(),
@@ -68,12 +74,53 @@ pub(super) struct ActivationData {
     pub audit_info: audit::AuditInfo,
 }
 
+#[allow(clippy::large_enum_variant)]
+pub(super) enum RestructuringOutcome {
+    Ignored(CreditFacility),
+    Denied(CreditFacility),
+    Amended(RestructuringData),
+}
+
+pub(super) struct RestructuringData {
+    pub credit_facility: CreditFacility,
+    pub rescheduled: Vec<ObligationRescheduleReallocationData>,
+    pub resumed_accrual_period: Option<InterestPeriod>,
+}
+
 #[allow(clippy::large_enum_variant)]
 pub(super) enum CompletionOutcome {
     Ignored(CreditFacility),
     Completed((CreditFacility, crate::CreditFacilityCompletion)),
 }
 
+#[allow(clippy::large_enum_variant)]
+pub(super) enum PrepaymentOutcome {
+    Ignored(CreditFacility),
+    Prepaid(PrepaymentData),
+}
+
+pub(super) struct PrepaymentData {
+    pub credit_facility: CreditFacility,
+    pub prepayment: crate::CreditFacilityPrepayment,
+    pub interest_obligation: Option<Obligation>,
+}
+
+/// A fee from the facility's fee schedule that has fallen due.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum FeeCharge {
+    Commitment {
+        period: InterestPeriod,
+    },
+    Maintenance {
+        due_at: chrono::DateTime<chrono::Utc>,
+    },
+    Disbursal {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        recorded_at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
 #[derive(Clone)]
 pub(super) struct ConfirmedAccrual {
     pub(super) accrual: super::CreditFacilityInterestAccrual,
@@ -91,10 +138,13 @@ where
         From<CoreCreditObject> + From<GovernanceObject>,
     E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         pool: &sqlx::PgPool,
         authz: &Perms,
         obligations: &Obligations<Perms, E>,
+        collaterals: &Collaterals<Perms, E>,
+        reference_rates: &ReferenceRates<Perms>,
         ledger: &CreditLedger,
         price: &Price,
         publisher: &crate::CreditFacilityPublisher<E>,
@@ -104,10 +154,15 @@ where
         let _ = governance
             .init_policy(crate::APPROVE_CREDIT_FACILITY_PROCESS)
             .await;
+        let _ = governance
+            .init_policy(crate::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS)
+            .await;
 
         Self {
             repo,
             obligations: obligations.clone(),
+            collaterals: collaterals.clone(),
+            reference_rates: reference_rates.clone(),
             authz: authz.clone(),
             ledger: ledger.clone(),
             price: price.clone(),
@@ -156,11 +211,21 @@ where
             .ledger
             .get_credit_facility_balance(credit_facility.account_ids)
             .await?;
-
-        let Ok(es_entity::Idempotent::Executed((credit_facility_activation, next_accrual_period))) =
-            credit_facility.activate(now, price, balances, audit_info.clone())
-        else {
-            return Ok(ActivationOutcome::Ignored(credit_facility));
+        let balances = self
+            .with_collateral_basket(&credit_facility, balances, price)
+            .await?;
+        let reference_rate = self.reference_rate_for(&credit_facility).await?;
+
+        let (credit_facility_activation, next_accrual_period) = match credit_facility.activate(
+            now,
+            price,
+            balances,
+            reference_rate.as_ref(),
+            audit_info.clone(),
+        ) {
+            Ok(es_entity::Idempotent::Executed(res)) => res,
+            Err(e @ CreditFacilityError::ReferenceRateFixingNotFound(..)) => return Err(e),
+            _ => return Ok(ActivationOutcome::Ignored(credit_facility)),
         };
 
         self.repo.update_in_op(db, &mut credit_facility).await?;
@@ -210,11 +275,103 @@ where
         Ok(credit_facility)
     }
 
+    #[instrument(
+        name = "core_credit.credit_facility.restructure_in_op",
+        skip(self, db, terms, audit_info),
+        err
+    )]
+    pub(super) async fn restructure_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        terms: TermValues,
+        audit_info: audit::AuditInfo,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id_in_tx(db.tx(), id).await?;
+
+        let approval_process_id = ApprovalProcessId::new();
+        if credit_facility
+            .request_terms_amendment(approval_process_id, terms, audit_info)?
+            .was_ignored()
+        {
+            return Ok(credit_facility);
+        }
+
+        self.governance
+            .start_process(
+                db,
+                approval_process_id,
+                credit_facility.id.to_string(),
+                crate::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS,
+            )
+            .await?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(credit_facility)
+    }
+
+    pub(super) async fn conclude_restructuring_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<RestructuringOutcome, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id_in_tx(db.tx(), id).await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility.id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        let matures_at = match credit_facility.conclude_terms_amendment(
+            approval_process_id,
+            approved,
+            audit_info.clone(),
+        ) {
+            es_entity::Idempotent::Executed(matures_at) => matures_at,
+            es_entity::Idempotent::Ignored => {
+                return Ok(RestructuringOutcome::Ignored(credit_facility));
+            }
+        };
+
+        let Some(matures_at) = matures_at else {
+            self.repo.update_in_op(db, &mut credit_facility).await?;
+            return Ok(RestructuringOutcome::Denied(credit_facility));
+        };
+
+        let reference_rate = self.reference_rate_for(&credit_facility).await?;
+        let resumed_accrual_period =
+            credit_facility.accrue_on_amended_terms(reference_rate.as_ref(), audit_info.clone())?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        let rescheduled = self
+            .obligations
+            .reschedule_for_facility_in_op(
+                db,
+                credit_facility.id,
+                matures_at,
+                &credit_facility.terms,
+                &audit_info,
+            )
+            .await?;
+
+        Ok(RestructuringOutcome::Amended(RestructuringData {
+            credit_facility,
+            rescheduled,
+            resumed_accrual_period,
+        }))
+    }
+
     pub(super) async fn confirm_interest_accrual_in_op(
         &self,
         db: &mut es_entity::DbOp<'_>,
         id: CreditFacilityId,
-    ) -> Result<ConfirmedAccrual, CreditFacilityError> {
+    ) -> Result<Option<ConfirmedAccrual>, CreditFacilityError> {
         let audit_info = self
             .authz
             .audit()
@@ -235,9 +392,9 @@ where
 
             let account_ids = credit_facility.account_ids;
 
-            let accrual = credit_facility
-                .interest_accrual_cycle_in_progress_mut()
-                .expect("Accrual in progress should exist for scheduled job");
+            let Some(accrual) = credit_facility.interest_accrual_cycle_in_progress_mut() else {
+                return Ok(None);
+            };
 
             let interest_accrual =
                 accrual.record_accrual(balances.disbursed_outstanding(), audit_info);
@@ -252,7 +409,60 @@ where
 
         self.repo.update_in_op(db, &mut credit_facility).await?;
 
-        Ok(confirmed_accrual)
+        Ok(Some(confirmed_accrual))
+    }
+
+    /// Returns `None` if the fee was already charged or nothing is owed.
+    pub(super) async fn charge_fee_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        charge: FeeCharge,
+    ) -> Result<Option<crate::CreditFacilityFee>, CreditFacilityError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(id),
+                CoreCreditAction::CREDIT_FACILITY_RECORD_FEE,
+            )
+            .await?;
+
+        let mut credit_facility = self.repo.find_by_id_in_tx(db.tx(), id).await?;
+        let res = match charge {
+            FeeCharge::Commitment { period } => {
+                let balances = self
+                    .ledger
+                    .get_credit_facility_balance(credit_facility.account_ids)
+                    .await?;
+                credit_facility.charge_commitment_fee(
+                    period,
+                    balances.facility_remaining(),
+                    &audit_info,
+                )
+            }
+            FeeCharge::Maintenance { due_at } => {
+                credit_facility.charge_maintenance_fee(due_at, &audit_info)
+            }
+            FeeCharge::Disbursal {
+                ledger_tx_id,
+                amount,
+                recorded_at,
+            } => {
+                credit_facility.charge_disbursal_fee(ledger_tx_id, amount, recorded_at, &audit_info)
+            }
+        };
+        let es_entity::Idempotent::Executed((fee, new_obligation)) = res else {
+            return Ok(None);
+        };
+
+        self.obligations
+            .create_with_jobs_in_op(db, new_obligation)
+            .await?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(Some(fee))
     }
 
     pub(super) async fn complete_in_op(
@@ -284,16 +494,110 @@ where
         Ok(CompletionOutcome::Completed((credit_facility, completion)))
     }
 
+    pub(super) async fn prepay_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<PrepaymentOutcome, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+
+        let (prepayment, new_obligation) = if let es_entity::Idempotent::Executed(res) =
+            credit_facility.prepay(db.now(), balances, audit_info.clone())?
+        {
+            res
+        } else {
+            return Ok(PrepaymentOutcome::Ignored(credit_facility));
+        };
+
+        let interest_obligation = match new_obligation {
+            Some(new_obligation) => Some(
+                self.obligations
+                    .create_with_jobs_in_op(db, new_obligation)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(PrepaymentOutcome::Prepaid(PrepaymentData {
+            credit_facility,
+            prepayment,
+            interest_obligation,
+        }))
+    }
+
+    pub(super) async fn record_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(CreditFacility, crate::CreditFacilityUnappliedFunds), CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let unapplied_funds =
+            credit_facility.record_unapplied_funds(amount, effective, audit_info)?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok((credit_facility, unapplied_funds))
+    }
+
+    pub(super) async fn reverse_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        amount: UsdCents,
+        returned: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(CreditFacility, crate::UnappliedFundsReversal), CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let reversal =
+            credit_facility.reverse_unapplied_funds(amount, returned, effective, audit_info)?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok((credit_facility, reversal))
+    }
+
+    pub(super) async fn apply_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        payment_id: PaymentId,
+        allocated: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<crate::CreditFacilityUnappliedFunds>, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+        let es_entity::Idempotent::Executed(unapplied_funds) =
+            credit_facility.apply_unapplied_funds(payment_id, allocated, effective, audit_info)?
+        else {
+            return Ok(None);
+        };
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(Some(unapplied_funds))
+    }
+
     pub(super) async fn complete_interest_cycle_and_maybe_start_new_cycle(
         &self,
         db: &mut es_entity::DbOp<'_>,
         id: CreditFacilityId,
         audit_info: &audit::AuditInfo,
     ) -> Result<
-        (
+        Option<(
             Obligation,
             Option<(InterestAccrualCycleId, chrono::DateTime<chrono::Utc>)>,
-        ),
+        )>,
         CreditFacilityError,
     > {
         let mut credit_facility = self.repo.find_by_id(id).await?;
@@ -303,7 +607,7 @@ where
         {
             new_obligation
         } else {
-            unreachable!("Should not be possible");
+            return Ok(None);
         };
 
         let obligation = self
@@ -311,7 +615,9 @@ where
             .create_with_jobs_in_op(db, new_obligation)
             .await?;
 
-        let res = credit_facility.start_interest_accrual_cycle(audit_info.clone())?;
+        let reference_rate = self.reference_rate_for(&credit_facility).await?;
+        let res = credit_facility
+            .start_interest_accrual_cycle(reference_rate.as_ref(), audit_info.clone())?;
         self.repo.update_in_op(db, &mut credit_facility).await?;
 
         let new_cycle_data = res.map(|periods| {
@@ -323,7 +629,21 @@ where
             (new_accrual_cycle_id, periods.accrual.end)
         });
 
-        Ok((obligation, new_cycle_data))
+        Ok(Some((obligation, new_cycle_data)))
+    }
+
+    async fn reference_rate_for(
+        &self,
+        credit_facility: &CreditFacility,
+    ) -> Result<Option<ReferenceRate>, CreditFacilityError> {
+        match credit_facility.terms.floating_rate {
+            Some(floating_rate) => Ok(Some(
+                self.reference_rates
+                    .find_by_id_without_audit(floating_rate.reference_rate_id)
+                    .await?,
+            )),
+            None => Ok(None),
+        }
     }
 
     pub async fn find_by_id_without_audit(
@@ -357,72 +677,51 @@ where
 
     pub(super) async fn update_collateralization_from_price(
         &self,
+        price: PriceOfOneBTC,
         upgrade_buffer_cvl_pct: CVLPct,
     ) -> Result<(), CreditFacilityError> {
-        let price = self.price.usd_cents_per_btc().await?;
-        let mut has_next_page = true;
-        let mut after: Option<CreditFacilitiesByCollateralizationRatioCursor> = None;
-        while has_next_page {
-            let mut credit_facilities =
-                self
-                    .list_by_collateralization_ratio_without_audit(
-                        es_entity::PaginatedQueryArgs::<
-                            CreditFacilitiesByCollateralizationRatioCursor,
-                        > {
-                            first: 10,
-                            after,
-                        },
-                        es_entity::ListDirection::Ascending,
-                    )
-                    .await?;
-            (after, has_next_page) = (
-                credit_facilities.end_cursor,
-                credit_facilities.has_next_page,
-            );
-            let mut db = self.repo.begin_op().await?;
-            let audit_info = self
-                .authz
-                .audit()
-                .record_system_entry_in_tx(
-                    db.tx(),
-                    CoreCreditObject::all_credit_facilities(),
-                    CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE,
-                )
+        for id in self.repo.list_ids_crossing_price(price).await? {
+            self.update_collateralization_at_price(id, price, upgrade_buffer_cvl_pct)
                 .await?;
+        }
+        Ok(())
+    }
 
-            let mut at_least_one = false;
-
-            for facility in credit_facilities.entities.iter_mut() {
-                if facility.status() == CreditFacilityStatus::Closed {
-                    continue;
-                }
-                let balances = self
-                    .ledger
-                    .get_credit_facility_balance(facility.account_ids)
-                    .await?;
-                if facility
-                    .update_collateralization(price, upgrade_buffer_cvl_pct, balances, &audit_info)
-                    .did_execute()
-                {
-                    self.repo.update_in_op(&mut db, facility).await?;
-                    at_least_one = true;
-                }
-            }
+    /// Re-evaluates every facility holding `asset` after its price moved.
+    pub(super) async fn update_collateralization_from_asset_price(
+        &self,
+        asset: CollateralAsset,
+        upgrade_buffer_cvl_pct: CVLPct,
+    ) -> Result<(), CreditFacilityError> {
+        let ids = self.repo.list_ids_with_collateral_asset(asset).await?;
+        if ids.is_empty() {
+            return Ok(());
+        }
 
-            if at_least_one {
-                db.commit().await?;
-            } else {
-                break;
-            }
+        let price = self.price.usd_cents_per_btc().await?;
+        for id in ids {
+            self.update_collateralization_at_price(id, price, upgrade_buffer_cvl_pct)
+                .await?;
         }
         Ok(())
     }
 
-    #[es_entity::retry_on_concurrent_modification(any_error = true)]
     pub(super) async fn update_collateralization_from_events(
         &self,
         id: CreditFacilityId,
         upgrade_buffer_cvl_pct: CVLPct,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let price = self.price.usd_cents_per_btc().await?;
+        self.update_collateralization_at_price(id, price, upgrade_buffer_cvl_pct)
+            .await
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    async fn update_collateralization_at_price(
+        &self,
+        id: CreditFacilityId,
+        price: PriceOfOneBTC,
+        upgrade_buffer_cvl_pct: CVLPct,
     ) -> Result<CreditFacility, CreditFacilityError> {
         let mut db = self.repo.begin_op().await?;
         let mut credit_facility = self.repo.find_by_id_in_tx(db.tx(), id).await?;
@@ -441,7 +740,9 @@ where
             .ledger
             .get_credit_facility_balance(credit_facility.account_ids)
             .await?;
-        let price = self.price.usd_cents_per_btc().await?;
+        let balances = self
+            .with_collateral_basket(&credit_facility, balances, price)
+            .await?;
 
         if credit_facility
             .update_collateralization(price, upgrade_buffer_cvl_pct, balances, &audit_info)
@@ -456,6 +757,111 @@ where
         Ok(credit_facility)
     }
 
+    /// Replaces the BTC collateral in `balances` with the BTC equivalent of
+    /// every asset pledged to the facility, after haircuts, so that the CVL
+    /// is computed over the whole basket.
+    async fn with_collateral_basket(
+        &self,
+        credit_facility: &CreditFacility,
+        balances: CreditFacilityBalanceSummary,
+        price: PriceOfOneBTC,
+    ) -> Result<CreditFacilityBalanceSummary, CreditFacilityError> {
+        let haircuts = credit_facility.terms.collateral_haircuts;
+        let basket = self
+            .collaterals
+            .basket_for_credit_facility_without_audit(credit_facility.id)
+            .await?;
+        if basket.is_plain_btc(&haircuts) {
+            return Ok(balances);
+        }
+
+        let mut prices = Vec::new();
+        for asset in basket
+            .assets()
+            .filter(|asset| *asset != CollateralAsset::Btc)
+        {
+            prices.push(self.price.usd_cents_per_unit(asset).await?);
+        }
+        let collateral = basket.btc_equivalent(price, &prices, &haircuts)?;
+        Ok(balances.with_collateral(collateral))
+    }
+
+    /// BTC that can be withdrawn while the whole basket keeps the facility at
+    /// its initial CVL. Withdrawing BTC only reduces the basket by its
+    /// haircut-adjusted value, so the excess is scaled back up accordingly.
+    pub(crate) async fn releasable_collateral_without_audit(
+        &self,
+        credit_facility: &CreditFacility,
+        price: PriceOfOneBTC,
+    ) -> Result<Satoshis, CreditFacilityError> {
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+        let held = balances.collateral();
+        let balances = self
+            .with_collateral_basket(credit_facility, balances, price)
+            .await?;
+        let excess = credit_facility.releasable_collateral(balances, price);
+
+        let retained = credit_facility
+            .terms
+            .collateral_haircuts
+            .for_asset(CollateralAsset::Btc)
+            .retained_fraction();
+        if retained.is_zero() {
+            return Ok(held);
+        }
+        let releasable = Satoshis::try_from_btc(
+            (excess.to_btc() / retained)
+                .round_dp_with_strategy(8, rust_decimal::RoundingStrategy::ToZero),
+        )?;
+
+        Ok(releasable.min(held))
+    }
+
+    /// Every active facility together with its balances, the collateral
+    /// basket valued at `price`. Nothing is persisted.
+    pub(crate) async fn list_active_with_balances_at_price_without_audit(
+        &self,
+        price: PriceOfOneBTC,
+    ) -> Result<Vec<(CreditFacility, CreditFacilityBalanceSummary)>, CreditFacilityError> {
+        let mut facilities = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_status_by_created_at(
+                    CreditFacilityStatus::Active,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            facilities.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        let mut res = Vec::with_capacity(facilities.len());
+        for credit_facility in facilities {
+            let balances = self
+                .ledger
+                .get_credit_facility_balance(credit_facility.account_ids)
+                .await?;
+            let balances = self
+                .with_collateral_basket(&credit_facility, balances, price)
+                .await?;
+            res.push((credit_facility, balances));
+        }
+
+        Ok(res)
+    }
+
     #[instrument(name = "core_credit.credit_facility.list", skip(self), err)]
     pub async fn list(
         &self,
@@ -560,6 +966,37 @@ where
             .await
     }
 
+    #[instrument(name = "core_credit.credit_facility.payoff_quote", skip(self), err)]
+    pub async fn payoff_quote(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<PayoffQuote, CreditFacilityError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        let credit_facility = self.repo.find_by_id(id).await?;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+        let (fees, penalties) = self
+            .obligations
+            .outstanding_fees_and_penalties(credit_facility.id)
+            .await?;
+        let reference_rate = self.reference_rate_for(&credit_facility).await?;
+
+        credit_facility.payoff_quote(as_of, balances, fees, penalties, reference_rate.as_ref())
+    }
+
     #[instrument(name = "core_credit.credit_facility.balance", skip(self), err)]
     pub async fn balance(
         &self,
@@ -32,6 +32,16 @@ use super::{entity::*, error::CreditFacilityError};
             list_for,
             update(accessor = "last_collateralization_state()")
         ),
+        collateralization_price_floor(
+            ty = "Option<Decimal>",
+            create(persist = false),
+            update(accessor = "collateralization_price_floor()")
+        ),
+        collateralization_price_ceiling(
+            ty = "Option<Decimal>",
+            create(persist = false),
+            update(accessor = "collateralization_price_ceiling()")
+        ),
         status(ty = "CreditFacilityStatus", list_for, update(accessor = "status()"))
     ),
     tbl_prefix = "core",
@@ -74,6 +84,64 @@ where
         }
     }
 
+    /// Facilities whose collateralization state may change at `price`, i.e.
+    /// whose price floor is above it or whose price ceiling is below it.
+    /// The band only accounts for BTC, so facilities with a price band that
+    /// also hold other assets are always included.
+    pub async fn list_ids_crossing_price(
+        &self,
+        price: PriceOfOneBTC,
+    ) -> Result<Vec<CreditFacilityId>, CreditFacilityError> {
+        let price = Decimal::from(price.into_inner().into_inner());
+        let rows = sqlx::query!(
+            r#"
+            SELECT f.id AS "id: CreditFacilityId"
+            FROM core_credit_facilities f
+            WHERE f.collateralization_price_floor > $1
+               OR f.collateralization_price_ceiling < $1
+               OR (
+                 (f.collateralization_price_floor IS NOT NULL
+                   OR f.collateralization_price_ceiling IS NOT NULL)
+                 AND EXISTS (
+                   SELECT 1
+                   FROM core_collaterals c
+                   JOIN core_collateral_events e ON e.id = c.id
+                   WHERE c.credit_facility_id = f.id
+                     AND e.event_type = 'initialized'
+                     AND e.event->>'asset' <> 'BTC'
+                 )
+               )
+            "#,
+            price
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|row| row.id).collect())
+    }
+
+    /// Facilities with a price band that have `asset` pledged as collateral.
+    pub async fn list_ids_with_collateral_asset(
+        &self,
+        asset: CollateralAsset,
+    ) -> Result<Vec<CreditFacilityId>, CreditFacilityError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT c.credit_facility_id AS "id: CreditFacilityId"
+            FROM core_collaterals c
+            JOIN core_collateral_events e ON e.id = c.id
+            JOIN core_credit_facilities f ON f.id = c.credit_facility_id
+            WHERE e.event_type = 'initialized'
+              AND e.event->'asset' = $1
+              AND (f.collateralization_price_floor IS NOT NULL
+                OR f.collateralization_price_ceiling IS NOT NULL)
+            "#,
+            serde_json::json!(asset)
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|row| row.id).collect())
+    }
+
     async fn publish(
         &self,
         db: &mut es_entity::DbOp<'_>,
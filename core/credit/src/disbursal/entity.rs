@@ -10,9 +10,13 @@ use es_entity::*;
 use crate::{
     ledger::CreditFacilityAccountIds,
     obligation::{NewObligation, ObligationAccounts},
+    payment_rail::PaymentOutcome,
     primitives::*,
+    terms::TermValues,
 };
 
+use super::error::DisbursalError;
+
 #[allow(clippy::large_enum_variant)]
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -29,6 +33,8 @@ pub enum DisbursalEvent {
         due_date: DateTime<Utc>,
         overdue_date: Option<DateTime<Utc>>,
         liquidation_date: Option<DateTime<Utc>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        beneficiary_account: Option<BeneficiaryAccount>,
         audit_info: AuditInfo,
     },
     ApprovalProcessConcluded {
@@ -39,6 +45,8 @@ pub enum DisbursalEvent {
     Settled {
         ledger_tx_id: LedgerTxId,
         obligation_id: ObligationId,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        installment_obligation_ids: Vec<ObligationId>,
         amount: UsdCents,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
@@ -47,6 +55,22 @@ pub enum DisbursalEvent {
         ledger_tx_id: LedgerTxId,
         audit_info: AuditInfo,
     },
+    SentToPaymentRail {
+        payment_rail: String,
+        external_id: String,
+        audit_info: AuditInfo,
+    },
+    PaymentSettled {
+        ledger_tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    PaymentReturned {
+        ledger_tx_id: LedgerTxId,
+        reason: String,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -61,8 +85,12 @@ pub struct Disbursal {
     pub due_date: DateTime<Utc>,
     pub overdue_date: Option<DateTime<Utc>>,
     pub liquidation_date: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub beneficiary_account: Option<BeneficiaryAccount>,
     #[builder(setter(strip_option), default)]
     pub concluded_tx_id: Option<LedgerTxId>,
+    #[builder(setter(strip_option), default)]
+    pub payment_external_id: Option<String>,
     events: EntityEvents<DisbursalEvent>,
 }
 
@@ -81,6 +109,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                     due_date,
                     overdue_date,
                     liquidation_date,
+                    beneficiary_account,
                     ..
                 } => {
                     builder = builder
@@ -93,6 +122,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                         .due_date(*due_date)
                         .overdue_date(*overdue_date)
                         .liquidation_date(*liquidation_date)
+                        .beneficiary_account(beneficiary_account.clone())
                 }
                 DisbursalEvent::Settled { ledger_tx_id, .. } => {
                     builder = builder.concluded_tx_id(*ledger_tx_id)
@@ -100,7 +130,12 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                 DisbursalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.concluded_tx_id(*ledger_tx_id)
                 }
-                DisbursalEvent::ApprovalProcessConcluded { .. } => (),
+                DisbursalEvent::SentToPaymentRail { external_id, .. } => {
+                    builder = builder.payment_external_id(external_id.clone())
+                }
+                DisbursalEvent::ApprovalProcessConcluded { .. }
+                | DisbursalEvent::PaymentSettled { .. }
+                | DisbursalEvent::PaymentReturned { .. } => (),
             }
         }
         builder.events(events).build()
@@ -120,7 +155,18 @@ impl Disbursal {
             .any(|e| matches!(e, DisbursalEvent::ApprovalProcessConcluded { .. }))
     }
     pub fn status(&self) -> DisbursalStatus {
-        if self.is_confirmed() {
+        if self.is_external() {
+            for event in self.events.iter_all().rev() {
+                match event {
+                    DisbursalEvent::PaymentReturned { .. } => return DisbursalStatus::Returned,
+                    DisbursalEvent::PaymentSettled { .. } => return DisbursalStatus::Confirmed,
+                    DisbursalEvent::SentToPaymentRail { .. } => return DisbursalStatus::Sent,
+                    _ => (),
+                }
+            }
+        }
+
+        if self.is_confirmed() && !self.is_external() {
             DisbursalStatus::Confirmed
         } else {
             match self.is_approved() {
@@ -138,13 +184,32 @@ impl Disbursal {
         })
     }
 
+    pub fn obligation_ids(&self) -> Vec<ObligationId> {
+        self.events
+            .iter_all()
+            .find_map(|event| match event {
+                DisbursalEvent::Settled {
+                    obligation_id,
+                    installment_obligation_ids,
+                    ..
+                } => Some(
+                    std::iter::once(*obligation_id)
+                        .chain(installment_obligation_ids.iter().copied())
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
     pub(crate) fn approval_process_concluded(
         &mut self,
         tx_id: LedgerTxId,
         approved: bool,
+        terms: &TermValues,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
-    ) -> Idempotent<Option<NewObligation>> {
+    ) -> Idempotent<Option<Vec<NewObligation>>> {
         idempotency_guard!(
             self.events.iter_all(),
             DisbursalEvent::ApprovalProcessConcluded { .. }
@@ -155,11 +220,11 @@ impl Disbursal {
             audit_info: audit_info.clone(),
         });
         let tx_ref: &str = &format!("disbursal-{}", self.id);
-        let new_obligation = if approved {
-            if let Idempotent::Executed(new_obligation) =
-                self.settle_disbursal(tx_id, tx_ref, effective, audit_info.clone())
+        let new_obligations = if approved {
+            if let Idempotent::Executed(new_obligations) =
+                self.settle_disbursal(tx_id, tx_ref, terms, effective, audit_info.clone())
             {
-                Some(new_obligation)
+                Some(new_obligations)
             } else {
                 return Idempotent::Ignored;
             }
@@ -172,7 +237,7 @@ impl Disbursal {
         };
         self.concluded_tx_id = Some(tx_id);
 
-        Idempotent::Executed(new_obligation)
+        Idempotent::Executed(new_obligations)
     }
 
     pub(super) fn is_approved(&self) -> Option<bool> {
@@ -188,51 +253,154 @@ impl Disbursal {
         &mut self,
         tx_id: LedgerTxId,
         tx_ref: &str,
+        terms: &TermValues,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
-    ) -> Idempotent<NewObligation> {
+    ) -> Idempotent<Vec<NewObligation>> {
         idempotency_guard!(self.events.iter_all(), DisbursalEvent::Settled { .. });
-        let obligation_id = ObligationId::new();
+
+        let installments = terms.principal_installments(
+            self.amount,
+            effective
+                .and_hms_opt(0, 0, 0)
+                .expect("should return a valid date time")
+                .and_utc(),
+            self.due_date,
+        );
+        let new_obligations: Vec<NewObligation> = installments
+            .into_iter()
+            .enumerate()
+            .map(|(idx, installment)| {
+                let reference = if idx == 0 {
+                    tx_ref.to_string()
+                } else {
+                    format!("{tx_ref}-installment-{}", idx + 1)
+                };
+                let (due_date, overdue_date, liquidation_date) =
+                    if installment.due_at == self.due_date {
+                        (self.due_date, self.overdue_date, self.liquidation_date)
+                    } else {
+                        (
+                            installment.due_at,
+                            terms
+                                .obligation_overdue_duration_from_due
+                                .map(|d| d.end_date(installment.due_at)),
+                            terms
+                                .obligation_liquidation_duration_from_due
+                                .map(|d| d.end_date(installment.due_at)),
+                        )
+                    };
+
+                NewObligation::builder()
+                    .id(ObligationId::new())
+                    .credit_facility_id(self.facility_id)
+                    .obligation_type(ObligationType::Disbursal)
+                    .reference(reference)
+                    .amount(installment.amount)
+                    .tx_id(tx_id)
+                    .not_yet_due_accounts(ObligationAccounts {
+                        receivable_account_id: self
+                            .account_ids
+                            .disbursed_receivable_not_yet_due_account_id,
+                        account_to_be_credited_id: self.disbursal_credit_account_id,
+                    })
+                    .due_accounts(ObligationAccounts {
+                        receivable_account_id: self.account_ids.disbursed_receivable_due_account_id,
+                        account_to_be_credited_id: self.disbursal_credit_account_id,
+                    })
+                    .overdue_accounts(ObligationAccounts {
+                        receivable_account_id: self
+                            .account_ids
+                            .disbursed_receivable_overdue_account_id,
+                        account_to_be_credited_id: self.disbursal_credit_account_id,
+                    })
+                    .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
+                    .defaulted_account_id(self.account_ids.disbursed_defaulted_account_id)
+                    .due_date(due_date)
+                    .overdue_date(overdue_date)
+                    .liquidation_date(liquidation_date)
+                    .effective(effective)
+                    .audit_info(audit_info.clone())
+                    .build()
+                    .expect("could not build new disbursal obligation")
+            })
+            .collect();
+
+        let mut obligation_ids = new_obligations.iter().map(|o| o.id);
         self.events.push(DisbursalEvent::Settled {
             ledger_tx_id: tx_id,
-            obligation_id,
+            obligation_id: obligation_ids
+                .next()
+                .expect("disbursal should have at least one obligation"),
+            installment_obligation_ids: obligation_ids.collect(),
             amount: self.amount,
             effective,
-            audit_info: audit_info.clone(),
+            audit_info,
         });
 
-        Idempotent::Executed(
-            NewObligation::builder()
-                .id(obligation_id)
-                .credit_facility_id(self.facility_id)
-                .obligation_type(ObligationType::Disbursal)
-                .reference(tx_ref.to_string())
-                .amount(self.amount)
-                .tx_id(tx_id)
-                .not_yet_due_accounts(ObligationAccounts {
-                    receivable_account_id: self
-                        .account_ids
-                        .disbursed_receivable_not_yet_due_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .due_accounts(ObligationAccounts {
-                    receivable_account_id: self.account_ids.disbursed_receivable_due_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .overdue_accounts(ObligationAccounts {
-                    receivable_account_id: self.account_ids.disbursed_receivable_overdue_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
-                .defaulted_account_id(self.account_ids.disbursed_defaulted_account_id)
-                .due_date(self.due_date)
-                .overdue_date(self.overdue_date)
-                .liquidation_date(self.liquidation_date)
-                .effective(effective)
-                .audit_info(audit_info)
-                .build()
-                .expect("could not build new disbursal obligation"),
-        )
+        Idempotent::Executed(new_obligations)
+    }
+
+    /// Paid out to a beneficiary bank account over the payment rail rather
+    /// than into the customer's deposit account.
+    pub fn is_external(&self) -> bool {
+        self.beneficiary_account.is_some()
+    }
+
+    pub(crate) fn sent_to_payment_rail(
+        &mut self,
+        payment_rail: String,
+        external_id: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DisbursalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DisbursalEvent::SentToPaymentRail { .. }
+        );
+        if !self.is_external() || !self.is_confirmed() {
+            return Err(DisbursalError::NotAwaitingPayment);
+        }
+
+        self.events.push(DisbursalEvent::SentToPaymentRail {
+            payment_rail,
+            external_id: external_id.clone(),
+            audit_info,
+        });
+        self.payment_external_id = Some(external_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn record_payment_outcome(
+        &mut self,
+        tx_id: LedgerTxId,
+        outcome: PaymentOutcome,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DisbursalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DisbursalEvent::PaymentSettled { .. } | DisbursalEvent::PaymentReturned { .. }
+        );
+        if self.payment_external_id.is_none() {
+            return Err(DisbursalError::NotSentToPaymentRail);
+        }
+
+        self.events.push(match outcome {
+            PaymentOutcome::Settled => DisbursalEvent::PaymentSettled {
+                ledger_tx_id: tx_id,
+                effective,
+                audit_info,
+            },
+            PaymentOutcome::Returned { reason } => DisbursalEvent::PaymentReturned {
+                ledger_tx_id: tx_id,
+                reason,
+                effective,
+                audit_info,
+            },
+        });
+
+        Ok(Idempotent::Executed(()))
     }
 
     pub(super) fn is_confirmed(&self) -> bool {
@@ -261,6 +429,8 @@ pub struct NewDisbursal {
     pub(super) due_date: DateTime<Utc>,
     pub(super) overdue_date: Option<DateTime<Utc>>,
     pub(super) liquidation_date: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub(super) beneficiary_account: Option<BeneficiaryAccount>,
     #[builder(setter(into))]
     pub(super) audit_info: AuditInfo,
 }
@@ -294,6 +464,7 @@ impl IntoEvents<DisbursalEvent> for NewDisbursal {
                 due_date: self.due_date,
                 overdue_date: self.overdue_date,
                 liquidation_date: self.liquidation_date,
+                beneficiary_account: self.beneficiary_account,
                 audit_info: self.audit_info,
             }],
         )
@@ -14,6 +14,10 @@ pub enum DisbursalError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("DisbursalError - GovernanceError: {0}")]
     GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("DisbursalError - NotAwaitingPayment")]
+    NotAwaitingPayment,
+    #[error("DisbursalError - NotSentToPaymentRail")]
+    NotSentToPaymentRail,
     #[error("DisbursalError - ObligationError: {0}")]
     ObligationError(#[from] crate::obligation::error::ObligationError),
 }
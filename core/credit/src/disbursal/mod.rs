@@ -9,7 +9,10 @@ use authz::PermissionCheck;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use outbox::OutboxEventMarker;
 
-use crate::{Obligation, Obligations, event::CoreCreditEvent, primitives::*};
+use crate::{
+    Obligation, Obligations, event::CoreCreditEvent, payment_rail::PaymentOutcome, primitives::*,
+    terms::TermValues,
+};
 
 pub(super) use entity::*;
 use error::DisbursalError;
@@ -49,7 +52,7 @@ where
 
 pub(super) enum ApprovalProcessOutcome {
     Ignored(Disbursal),
-    Approved((Disbursal, Obligation)),
+    Approved((Disbursal, Vec<Obligation>)),
     Denied(Disbursal),
 }
 
@@ -107,23 +110,27 @@ where
         &self,
         db: &mut es_entity::DbOp<'_>,
         new_disbursal: NewDisbursal,
+        terms: &TermValues,
         audit_info: &audit::AuditInfo,
     ) -> Result<Disbursal, DisbursalError> {
         let mut disbursal = self.repo.create_in_op(db, new_disbursal).await?;
 
-        let new_obligation = disbursal
+        let new_obligations = disbursal
             .approval_process_concluded(
                 LedgerTxId::new(),
                 true,
+                terms,
                 db.now().date_naive(),
                 audit_info.clone(),
             )
             .expect("First instance of idempotent action ignored")
             .expect("First disbursal obligation was already created");
 
-        self.obligations
-            .create_with_jobs_in_op(db, new_obligation)
-            .await?;
+        for new_obligation in new_obligations {
+            self.obligations
+                .create_with_jobs_in_op(db, new_obligation)
+                .await?;
+        }
 
         self.repo.update_in_op(db, &mut disbursal).await?;
 
@@ -152,6 +159,13 @@ where
         }
     }
 
+    pub(super) async fn find_by_id_without_audit(
+        &self,
+        id: DisbursalId,
+    ) -> Result<Disbursal, DisbursalError> {
+        self.repo.find_by_id(id).await
+    }
+
     pub(super) async fn find_by_concluded_tx_id_without_audit(
         &self,
         tx_id: impl Into<crate::primitives::LedgerTxId> + std::fmt::Debug,
@@ -183,11 +197,72 @@ where
         Ok(disbursal)
     }
 
+    pub(super) async fn find_by_payment_external_id_without_audit(
+        &self,
+        external_id: impl Into<String> + std::fmt::Debug,
+    ) -> Result<Disbursal, DisbursalError> {
+        self.repo
+            .find_by_payment_external_id(Some(external_id.into()))
+            .await
+    }
+
+    pub(super) async fn record_sent_to_payment_rail_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+        payment_rail: String,
+        external_id: String,
+    ) -> Result<Disbursal, DisbursalError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::disbursal(disbursal_id),
+                CoreCreditAction::DISBURSAL_SETTLE,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let mut disbursal = self.repo.find_by_id_in_tx(db.tx(), disbursal_id).await?;
+        if disbursal
+            .sent_to_payment_rail(payment_rail, external_id, audit_info)?
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut disbursal).await?;
+        }
+
+        Ok(disbursal)
+    }
+
+    /// Returns `None` if an outcome was already recorded for the payment.
+    pub(super) async fn record_payment_outcome_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+        tx_id: LedgerTxId,
+        outcome: PaymentOutcome,
+        effective: chrono::NaiveDate,
+        audit_info: audit::AuditInfo,
+    ) -> Result<Option<Disbursal>, DisbursalError> {
+        let mut disbursal = self.repo.find_by_id_in_tx(db.tx(), disbursal_id).await?;
+        if disbursal
+            .record_payment_outcome(tx_id, outcome, effective, audit_info)?
+            .was_ignored()
+        {
+            return Ok(None);
+        }
+        self.repo.update_in_op(db, &mut disbursal).await?;
+
+        Ok(Some(disbursal))
+    }
+
     pub(super) async fn conclude_approval_process_in_op(
         &self,
         db: &mut es_entity::DbOp<'_>,
         disbursal_id: DisbursalId,
         approved: bool,
+        terms: &TermValues,
         tx_id: LedgerTxId,
     ) -> Result<ApprovalProcessOutcome, DisbursalError> {
         let audit_info = self
@@ -206,17 +281,22 @@ where
         let ret = match disbursal.approval_process_concluded(
             tx_id,
             approved,
+            terms,
             db.now().date_naive(),
             audit_info,
         ) {
             es_entity::Idempotent::Ignored => ApprovalProcessOutcome::Ignored(disbursal),
-            es_entity::Idempotent::Executed(Some(new_obligation)) => {
-                let obligation = self
-                    .obligations
-                    .create_with_jobs_in_op(db, new_obligation)
-                    .await?;
+            es_entity::Idempotent::Executed(Some(new_obligations)) => {
+                let mut obligations = Vec::with_capacity(new_obligations.len());
+                for new_obligation in new_obligations {
+                    obligations.push(
+                        self.obligations
+                            .create_with_jobs_in_op(db, new_obligation)
+                            .await?,
+                    );
+                }
                 self.repo.update_in_op(db, &mut disbursal).await?;
-                ApprovalProcessOutcome::Approved((disbursal, obligation))
+                ApprovalProcessOutcome::Approved((disbursal, obligations))
             }
             es_entity::Idempotent::Executed(None) => {
                 self.repo.update_in_op(db, &mut disbursal).await?;
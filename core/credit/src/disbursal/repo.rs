@@ -21,6 +21,7 @@ use super::{entity::*, error::DisbursalError};
         ),
         approval_process_id(ty = "ApprovalProcessId", list_by, update(persist = "false")),
         concluded_tx_id(ty = "Option<LedgerTxId>", create(persist = false)),
+        payment_external_id(ty = "Option<String>", create(persist = false)),
     ),
     tbl_prefix = "core",
     post_persist_hook = "publish"
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DisbursalPayoutError {
+    #[error("DisbursalPayoutError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("DisbursalPayoutError - DisbursalError: {0}")]
+    DisbursalError(#[from] crate::disbursal::error::DisbursalError),
+    #[error("DisbursalPayoutError - CreditLedgerError: {0}")]
+    CreditLedgerError(#[from] crate::ledger::error::CreditLedgerError),
+    #[error("DisbursalPayoutError - PaymentRailError: {0}")]
+    PaymentRailError(#[from] crate::payment_rail::error::PaymentRailError),
+    #[error("DisbursalPayoutError - PaymentRailNotConfigured")]
+    PaymentRailNotConfigured,
+    #[error("DisbursalPayoutError - SerdeJson: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+    #[error("DisbursalPayoutError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+}
@@ -0,0 +1,248 @@
+pub mod error;
+
+use tracing::instrument;
+
+use std::sync::Arc;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, Disbursal, Disbursals,
+    ledger::CreditLedger,
+    payment_rail::{PaymentInstruction, PaymentOutcome, PaymentRail, PaymentRailNotification},
+    primitives::*,
+};
+
+use error::DisbursalPayoutError;
+
+/// Pays out approved disbursals to beneficiary bank accounts and records what
+/// the payment rail reports back.
+pub struct DisbursalPayouts<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    disbursals: Disbursals<Perms, E>,
+    ledger: CreditLedger,
+    payment_rail: Option<Arc<dyn PaymentRail>>,
+}
+
+impl<Perms, E> Clone for DisbursalPayouts<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            disbursals: self.disbursals.clone(),
+            ledger: self.ledger.clone(),
+            payment_rail: self.payment_rail.clone(),
+        }
+    }
+}
+
+impl<Perms, E> DisbursalPayouts<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        authz: &Perms,
+        disbursals: &Disbursals<Perms, E>,
+        ledger: &CreditLedger,
+        payment_rail: Option<Arc<dyn PaymentRail>>,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            disbursals: disbursals.clone(),
+            ledger: ledger.clone(),
+            payment_rail,
+        }
+    }
+
+    pub(crate) fn is_configured(&self) -> bool {
+        self.payment_rail.is_some()
+    }
+
+    fn payment_rail(&self) -> Result<&Arc<dyn PaymentRail>, DisbursalPayoutError> {
+        self.payment_rail
+            .as_ref()
+            .ok_or(DisbursalPayoutError::PaymentRailNotConfigured)
+    }
+
+    #[instrument(name = "core_credit.disbursal_payouts.send", skip(self), err)]
+    pub(crate) async fn send(
+        &self,
+        disbursal_id: DisbursalId,
+    ) -> Result<Disbursal, DisbursalPayoutError> {
+        let disbursal = self
+            .disbursals
+            .find_by_id_without_audit(disbursal_id)
+            .await?;
+        let Some(beneficiary) = disbursal.beneficiary_account.clone() else {
+            return Ok(disbursal);
+        };
+        if disbursal.payment_external_id.is_some() {
+            return Ok(disbursal);
+        }
+        let payment_rail = self.payment_rail()?;
+
+        let submission = payment_rail
+            .send(&PaymentInstruction {
+                disbursal_id,
+                amount: disbursal.amount,
+                beneficiary,
+                requested_at: crate::time::now(),
+            })
+            .await?;
+
+        let mut db = self.disbursals.begin_op().await?;
+        let disbursal = self
+            .disbursals
+            .record_sent_to_payment_rail_in_op(
+                &mut db,
+                disbursal_id,
+                payment_rail.name().to_string(),
+                submission.external_id,
+            )
+            .await?;
+        if !submission.settled {
+            db.commit().await?;
+            return Ok(disbursal);
+        }
+
+        let audit_info = self.record_system_entry(&mut db, disbursal_id).await?;
+        self.record_outcome_in_op(db, disbursal_id, PaymentOutcome::Settled, audit_info)
+            .await
+    }
+
+    #[instrument(name = "core_credit.disbursal_payouts.record_outcome", skip(self), err)]
+    pub async fn record_outcome(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        disbursal_id: impl Into<DisbursalId> + std::fmt::Debug,
+        outcome: PaymentOutcome,
+    ) -> Result<Disbursal, DisbursalPayoutError> {
+        let disbursal_id = disbursal_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::disbursal(disbursal_id),
+                CoreCreditAction::DISBURSAL_SETTLE,
+            )
+            .await?;
+
+        let db = self.disbursals.begin_op().await?;
+        self.record_outcome_in_op(db, disbursal_id, outcome, audit_info)
+            .await
+    }
+
+    /// Applies a settlement notification pushed by the payment rail.
+    #[instrument(
+        name = "core_credit.disbursal_payouts.handle_webhook",
+        skip(self, secret),
+        err
+    )]
+    pub async fn handle_webhook(
+        &self,
+        secret: Option<&str>,
+        payload: serde_json::Value,
+    ) -> Result<Disbursal, DisbursalPayoutError> {
+        self.payment_rail()?.verify_webhook_secret(secret)?;
+        let notification: PaymentRailNotification = serde_json::from_value(payload)?;
+
+        let disbursal = self
+            .disbursals
+            .find_by_payment_external_id_without_audit(notification.external_id)
+            .await?;
+
+        let mut db = self.disbursals.begin_op().await?;
+        let audit_info = self.record_system_entry(&mut db, disbursal.id).await?;
+        self.record_outcome_in_op(db, disbursal.id, notification.outcome, audit_info)
+            .await
+    }
+
+    async fn record_outcome_in_op(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+        outcome: PaymentOutcome,
+        audit_info: AuditInfo,
+    ) -> Result<Disbursal, DisbursalPayoutError> {
+        let tx_id = LedgerTxId::new();
+        let effective = db.now().date_naive();
+        let Some(disbursal) = self
+            .disbursals
+            .record_payment_outcome_in_op(
+                &mut db,
+                disbursal_id,
+                tx_id,
+                outcome.clone(),
+                effective,
+                audit_info,
+            )
+            .await?
+        else {
+            return Ok(self
+                .disbursals
+                .find_by_id_without_audit(disbursal_id)
+                .await?);
+        };
+
+        let tx_ref = format!("disbursal-{}-payment", disbursal.id);
+        match outcome {
+            PaymentOutcome::Settled => {
+                self.ledger
+                    .record_disbursal_payment_settled(
+                        db,
+                        tx_id,
+                        disbursal.amount,
+                        tx_ref,
+                        effective,
+                    )
+                    .await?
+            }
+            PaymentOutcome::Returned { .. } => {
+                self.ledger
+                    .record_disbursal_payment_returned(
+                        db,
+                        tx_id,
+                        disbursal.amount,
+                        disbursal.disbursal_credit_account_id,
+                        tx_ref,
+                        effective,
+                    )
+                    .await?
+            }
+        }
+
+        Ok(disbursal)
+    }
+
+    async fn record_system_entry(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+    ) -> Result<AuditInfo, DisbursalPayoutError> {
+        Ok(self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::disbursal(disbursal_id),
+                CoreCreditAction::DISBURSAL_SETTLE,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?)
+    }
+}
@@ -1,5 +1,8 @@
 use thiserror::Error;
 
+use core_customer::BeneficiaryAccountId;
+use core_money::Satoshis;
+
 #[derive(Error, Debug)]
 pub enum CoreCreditError {
     #[error("CoreCreditError - Sqlx: {0}")]
@@ -34,10 +37,14 @@ pub enum CoreCreditError {
     DisbursalError(#[from] super::disbursal::error::DisbursalError),
     #[error("CoreCreditError - ObligationError: {0}")]
     ObligationError(#[from] super::obligation::error::ObligationError),
+    #[error("CoreCreditError - MarginCallError: {0}")]
+    MarginCallError(#[from] super::margin_call::error::MarginCallError),
     #[error("CoreCreditError - InterestAccrualCycleError: {0}")]
     InterestAccrualCycleError(
         #[from] super::interest_accrual_cycle::error::InterestAccrualCycleError,
     ),
+    #[error("CoreCreditError - ReferenceRateError: {0}")]
+    ReferenceRateError(#[from] super::reference_rate::error::ReferenceRateError),
     #[error("CoreCreditError - PriceError: {0}")]
     PriceError(#[from] core_price::error::PriceError),
     #[error("CoreCreditError - GovernanceError: {0}")]
@@ -52,6 +59,14 @@ pub enum CoreCreditError {
     CustomerNotActive,
     #[error("CoreCreditError - CustomerNotFound")]
     CustomerNotFound,
+    #[error("CoreCreditError - BeneficiaryAccountNotFound: {0}")]
+    BeneficiaryAccountNotFound(BeneficiaryAccountId),
+    #[error("CoreCreditError - PaymentRailNotConfigured")]
+    PaymentRailNotConfigured,
+    #[error("CoreCreditError - WithdrawalAddressNotRegistered")]
+    WithdrawalAddressNotRegistered,
+    #[error("CoreCreditError - CollateralWithdrawalExceedsReleasable: {0} > {1}")]
+    CollateralWithdrawalExceedsReleasable(Satoshis, Satoshis),
     #[error("CoreCreditError - DisbursalBuilderError: {0}")]
     DisbursalBuilderError(#[from] super::NewDisbursalBuilderError),
 }
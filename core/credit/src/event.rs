@@ -6,7 +6,10 @@ use schemars::JsonSchema;
 
 use core_money::{Satoshis, UsdCents};
 
-use crate::{CollateralizationState, CreditFacilityReceivable, TermValues, terms::InterestPeriod};
+use crate::{
+    AppliedRateFixing, CollateralizationState, CreditFacilityReceivable, TermValues,
+    terms::InterestPeriod,
+};
 
 use super::primitives::*;
 
@@ -29,6 +32,21 @@ pub enum CoreCreditEvent {
         activated_at: DateTime<Utc>,
         amount: UsdCents,
     },
+    FacilityTermsAmended {
+        id: CreditFacilityId,
+        previous_terms: TermValues,
+        terms: TermValues,
+        matures_at: DateTime<Utc>,
+        recorded_at: DateTime<Utc>,
+    },
+    FacilityPrepaid {
+        id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        outstanding: UsdCents,
+        prepayment_fee: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     FacilityCompleted {
         id: CreditFacilityId,
         completed_at: DateTime<Utc>,
@@ -42,6 +60,15 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    FacilityRepaymentReversed {
+        credit_facility_id: CreditFacilityId,
+        obligation_id: ObligationId,
+        obligation_type: ObligationType,
+        payment_id: PaymentAllocationId,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     FacilityCollateralUpdated {
         credit_facility_id: CreditFacilityId,
         ledger_tx_id: LedgerTxId,
@@ -51,6 +78,24 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    FacilityCollateralAssetUpdated {
+        credit_facility_id: CreditFacilityId,
+        asset: CollateralAsset,
+        ledger_tx_id: LedgerTxId,
+        new_units: u64,
+        abs_diff_units: u64,
+        action: CollateralAction,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    FacilityCollateralWithdrawalApproved {
+        credit_facility_id: CreditFacilityId,
+        collateral_id: CollateralId,
+        approval_process_id: ApprovalProcessId,
+        address: String,
+        amount: Satoshis,
+        recorded_at: DateTime<Utc>,
+    },
     FacilityCollateralizationChanged {
         id: CreditFacilityId,
         state: CollateralizationState,
@@ -72,6 +117,8 @@ pub enum CoreCreditEvent {
         ledger_tx_id: LedgerTxId,
         amount: UsdCents,
         period: InterestPeriod,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate_fixing: Option<AppliedRateFixing>,
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
@@ -87,6 +134,14 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    ObligationRescheduled {
+        id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        due_at: DateTime<Utc>,
+        overdue_at: Option<DateTime<Utc>>,
+        recorded_at: DateTime<Utc>,
+    },
     ObligationDue {
         id: ObligationId,
         credit_facility_id: CreditFacilityId,
@@ -116,9 +171,78 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    LiquidationCollateralSent {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: Satoshis,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    LiquidationSaleRecorded {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        sold: Satoshis,
+        proceeds: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    LiquidationProceedsApplied {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    LiquidationSurplusReturned {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    LiquidationShortfallWrittenOff {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     LiquidationProcessConcluded {
         id: LiquidationProcessId,
         obligation_id: ObligationId,
         credit_facility_id: CreditFacilityId,
     },
+    MarginCallOpened {
+        id: MarginCallId,
+        credit_facility_id: CreditFacilityId,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        price: PriceOfOneBTC,
+        cure_deadline: DateTime<Utc>,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    MarginCallCured {
+        id: MarginCallId,
+        credit_facility_id: CreditFacilityId,
+        reason: MarginCallCureReason,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    MarginCallEscalated {
+        id: MarginCallId,
+        credit_facility_id: CreditFacilityId,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
 }
@@ -1,5 +1,7 @@
 use audit::AuditSvc;
 use authz::PermissionCheck;
+use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
+use core_price::Price;
 use es_entity::{PaginatedQueryArgs, PaginatedQueryRet};
 use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
 
@@ -9,7 +11,9 @@ use crate::history::CreditFacilityHistoryEntry;
 pub struct CreditFacilitiesForSubject<'a, Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     customer_id: CustomerId,
     subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -17,19 +21,24 @@ where
     credit_facilities: &'a CreditFacilities<Perms, E>,
     disbursals: &'a Disbursals<Perms, E>,
     payments: &'a Payments<Perms, E>,
+    collaterals: &'a Collaterals<Perms, E>,
+    customers: &'a Customers<Perms, E>,
     histories: &'a HistoryRepo,
     repayment_plans: &'a RepaymentPlanRepo,
     ledger: &'a CreditLedger,
+    price: &'a Price,
 }
 
 impl<'a, Perms, E> CreditFacilitiesForSubject<'a, Perms, E>
 where
     Perms: PermissionCheck,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
-        From<CoreCreditAction> + From<GovernanceAction>,
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustomerAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
-        From<CoreCreditObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+        From<CoreCreditObject> + From<GovernanceObject> + From<CustomerObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustomerEvent>,
 {
     #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
@@ -39,9 +48,12 @@ where
         credit_facilities: &'a CreditFacilities<Perms, E>,
         disbursals: &'a Disbursals<Perms, E>,
         payments: &'a Payments<Perms, E>,
+        collaterals: &'a Collaterals<Perms, E>,
+        customers: &'a Customers<Perms, E>,
         history: &'a HistoryRepo,
         repayment_plans: &'a RepaymentPlanRepo,
         ledger: &'a CreditLedger,
+        price: &'a Price,
     ) -> Self {
         Self {
             customer_id,
@@ -50,9 +62,12 @@ where
             credit_facilities,
             disbursals,
             payments,
+            collaterals,
+            customers,
             histories: history,
             repayment_plans,
             ledger,
+            price,
         }
     }
 
@@ -124,6 +139,104 @@ where
         Ok(balances)
     }
 
+    pub async fn payoff_quote(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<PayoffQuote, CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+
+        self.ensure_credit_facility_access(
+            &credit_facility,
+            CoreCreditObject::credit_facility(id),
+            CoreCreditAction::CREDIT_FACILITY_READ,
+        )
+        .await?;
+
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+
+        Ok(credit_facility.payoff_quote(as_of, balances)?)
+    }
+
+    pub async fn releasable_collateral(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Satoshis, CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+
+        self.ensure_credit_facility_access(
+            &credit_facility,
+            CoreCreditObject::credit_facility(id),
+            CoreCreditAction::CREDIT_FACILITY_READ,
+        )
+        .await?;
+
+        let price = self.price.usd_cents_per_btc().await?;
+
+        Ok(self
+            .credit_facilities
+            .releasable_collateral_without_audit(&credit_facility, price)
+            .await?)
+    }
+
+    /// Requests excess collateral back to the customer's registered BTC
+    /// address. The withdrawal is executed once the approval process concludes.
+    pub async fn request_collateral_withdrawal(
+        &self,
+        id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        amount: Satoshis,
+    ) -> Result<Collateral, CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+
+        let audit_info = self
+            .ensure_credit_facility_access(
+                &credit_facility,
+                CoreCreditObject::credit_facility(id),
+                CoreCreditAction::CREDIT_FACILITY_WITHDRAW_COLLATERAL,
+            )
+            .await?;
+
+        let customer = self
+            .customers
+            .find_by_id_without_audit(self.customer_id)
+            .await?;
+        let address = customer
+            .btc_withdrawal_address
+            .ok_or(CoreCreditError::WithdrawalAddressNotRegistered)?;
+
+        let price = self.price.usd_cents_per_btc().await?;
+        let releasable = self
+            .credit_facilities
+            .releasable_collateral_without_audit(&credit_facility, price)
+            .await?;
+        if amount > releasable {
+            return Err(CoreCreditError::CollateralWithdrawalExceedsReleasable(
+                amount, releasable,
+            ));
+        }
+
+        let mut db = self.collaterals.begin_op().await?;
+        let collateral = self
+            .collaterals
+            .request_withdrawal_in_op(
+                &mut db,
+                credit_facility.collateral_id,
+                amount,
+                address,
+                audit_info,
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(collateral)
+    }
+
     pub async fn find_by_id(
         &self,
         id: impl Into<CreditFacilityId>,
@@ -149,7 +262,7 @@ where
         credit_facility: &CreditFacility,
         object: CoreCreditObject,
         action: CoreCreditAction,
-    ) -> Result<(), CoreCreditError> {
+    ) -> Result<audit::AuditInfo, CoreCreditError> {
         if credit_facility.customer_id != self.customer_id {
             self.authz
                 .audit()
@@ -158,11 +271,11 @@ where
             return Err(CoreCreditError::CustomerMismatchForCreditFacility);
         }
 
-        self.authz
+        Ok(self
+            .authz
             .audit()
             .record_entry(self.subject, object, action, true)
-            .await?;
-        Ok(())
+            .await?)
     }
 
     pub async fn list_disbursals_for_credit_facility(
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use crate::primitives::*;
+use crate::{primitives::*, reference_rate::AppliedRateFixing, terms::TermValues};
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreditFacilityApproved {
@@ -53,6 +53,8 @@ pub struct InterestAccrualsPosted {
     pub effective: chrono::NaiveDate,
     pub days: u32,
     pub tx_id: LedgerTxId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_fixing: Option<AppliedRateFixing>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -63,6 +65,15 @@ pub struct ObligationMovedToLiquidation {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreditFacilityTermsAmended {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub matures_at: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+}
+
 /// Represents an entry in Credit Facility history as it is stored in a database.
 /// The entries contain no running sums; if needed, they have to be calculated
 /// during replaying.
@@ -76,4 +87,5 @@ pub enum CreditFacilityHistoryEntry {
     Disbursal(DisbursalExecuted),
     Interest(InterestAccrualsPosted),
     ReservedForLiquidation(ObligationMovedToLiquidation),
+    TermsAmended(CreditFacilityTermsAmended),
 }
@@ -87,6 +87,15 @@ impl CreditFacilityHistory {
                         payment_id: *payment_id,
                     }));
             }
+            FacilityRepaymentReversed { payment_id, .. } => {
+                self.entries.retain(|entry| {
+                    !matches!(
+                        entry,
+                        CreditFacilityHistoryEntry::Payment(payment)
+                            if payment.payment_id == *payment_id
+                    )
+                });
+            }
             DisbursalSettled {
                 amount,
                 recorded_at,
@@ -105,6 +114,7 @@ impl CreditFacilityHistory {
             AccrualPosted {
                 amount,
                 period,
+                rate_fixing,
                 ledger_tx_id,
                 recorded_at,
                 effective,
@@ -117,6 +127,7 @@ impl CreditFacilityHistory {
                         effective: *effective,
                         tx_id: *ledger_tx_id,
                         days: period.days(),
+                        rate_fixing: *rate_fixing,
                     },
                 ));
             }
@@ -137,12 +148,41 @@ impl CreditFacilityHistory {
                         },
                     ));
             }
+            FacilityTermsAmended {
+                previous_terms,
+                terms,
+                matures_at,
+                recorded_at,
+                ..
+            } => {
+                self.entries.push(CreditFacilityHistoryEntry::TermsAmended(
+                    CreditFacilityTermsAmended {
+                        previous_terms: *previous_terms,
+                        terms: *terms,
+                        matures_at: *matures_at,
+                        recorded_at: *recorded_at,
+                        effective: recorded_at.date_naive(),
+                    },
+                ));
+            }
+            FacilityPrepaid { .. } => {}
             FacilityCompleted { .. } => {}
             ObligationCreated { .. } => {}
+            ObligationRescheduled { .. } => {}
             ObligationDue { .. } => {}
             ObligationOverdue { .. } => {}
             ObligationDefaulted { .. } => {}
+            LiquidationCollateralSent { .. } => {}
+            LiquidationSaleRecorded { .. } => {}
+            LiquidationProceedsApplied { .. } => {}
+            LiquidationSurplusReturned { .. } => {}
+            LiquidationShortfallWrittenOff { .. } => {}
             LiquidationProcessConcluded { .. } => {}
+            MarginCallOpened { .. } => {}
+            MarginCallCured { .. } => {}
+            MarginCallEscalated { .. } => {}
+            FacilityCollateralAssetUpdated { .. } => {}
+            FacilityCollateralWithdrawalApproved { .. } => {}
             ObligationCompleted { .. } => {}
         }
     }
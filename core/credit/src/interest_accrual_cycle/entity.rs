@@ -11,6 +11,7 @@ use crate::{
     ledger::CreditFacilityAccountIds,
     obligation::{NewObligation, ObligationAccounts},
     primitives::*,
+    reference_rate::AppliedRateFixing,
     terms::{InterestPeriod, TermValues},
 };
 
@@ -56,6 +57,8 @@ pub enum InterestAccrualCycleEvent {
         facility_matures_at: DateTime<Utc>,
         account_ids: InterestAccrualCycleAccountIds,
         terms: TermValues,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate_fixing: Option<AppliedRateFixing>,
         audit_info: AuditInfo,
     },
     InterestAccrued {
@@ -73,6 +76,13 @@ pub enum InterestAccrualCycleEvent {
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
+    TermsAmended {
+        terms: TermValues,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate_fixing: Option<AppliedRateFixing>,
+        facility_matures_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -85,6 +95,8 @@ pub struct InterestAccrualCycle {
     pub facility_matures_at: DateTime<Utc>,
     pub terms: TermValues,
     pub period: InterestPeriod,
+    #[builder(default)]
+    pub rate_fixing: Option<AppliedRateFixing>,
     events: EntityEvents<InterestAccrualCycleEvent>,
 }
 
@@ -119,6 +131,7 @@ impl TryFromEvents<InterestAccrualCycleEvent> for InterestAccrualCycle {
                     period,
                     facility_matures_at,
                     terms,
+                    rate_fixing,
                     ..
                 } => {
                     builder = builder
@@ -129,9 +142,21 @@ impl TryFromEvents<InterestAccrualCycleEvent> for InterestAccrualCycle {
                         .period(*period)
                         .facility_matures_at(*facility_matures_at)
                         .terms(*terms)
+                        .rate_fixing(*rate_fixing)
                 }
                 InterestAccrualCycleEvent::InterestAccrued { .. } => (),
                 InterestAccrualCycleEvent::InterestAccrualsPosted { .. } => (),
+                InterestAccrualCycleEvent::TermsAmended {
+                    terms,
+                    rate_fixing,
+                    facility_matures_at,
+                    ..
+                } => {
+                    builder = builder
+                        .terms(*terms)
+                        .rate_fixing(*rate_fixing)
+                        .facility_matures_at(*facility_matures_at)
+                }
             }
         }
         builder.events(events).build()
@@ -200,6 +225,28 @@ impl InterestAccrualCycle {
         untruncated_period.truncate(self.accrual_cycle_ends_at())
     }
 
+    /// Accrues the rest of the cycle on amended terms. Interest already
+    /// accrued in the cycle keeps the terms it was accrued on.
+    pub(crate) fn amend_terms(
+        &mut self,
+        terms: TermValues,
+        rate_fixing: Option<AppliedRateFixing>,
+        facility_matures_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) {
+        self.terms = terms;
+        self.rate_fixing = rate_fixing;
+        if facility_matures_at > self.period.start {
+            self.facility_matures_at = facility_matures_at;
+        }
+        self.events.push(InterestAccrualCycleEvent::TermsAmended {
+            terms,
+            rate_fixing,
+            facility_matures_at: self.facility_matures_at,
+            audit_info,
+        });
+    }
+
     pub(crate) fn record_accrual(
         &mut self,
         amount: UsdCents,
@@ -209,11 +256,47 @@ impl InterestAccrualCycle {
             .next_accrual_period()
             .expect("Accrual period should exist inside this function");
 
-        let days_in_interest_period = accrual_period.days();
-        let interest_for_period = self
-            .terms
-            .annual_rate
-            .interest_for_time_period(amount, days_in_interest_period);
+        self.record_accrual_for_period(amount, accrual_period, audit_info)
+    }
+
+    fn payoff_accrual_period(&self, payoff_at: DateTime<Utc>) -> Option<InterestPeriod> {
+        self.next_accrual_period()?.truncate(payoff_at)
+    }
+
+    pub(crate) fn interest_accrued_to(&self, amount: UsdCents, as_of: DateTime<Utc>) -> UsdCents {
+        let unaccrued = self
+            .next_accrual_period()
+            .and_then(|period| {
+                InterestPeriod {
+                    end: self.accrual_cycle_ends_at(),
+                    ..period
+                }
+                .truncate(as_of)
+            })
+            .map(|period| self.terms.interest_for_period(amount, &period))
+            .unwrap_or(UsdCents::ZERO);
+
+        self.total_accrued() + unaccrued
+    }
+
+    pub(crate) fn record_payoff_accrual(
+        &mut self,
+        amount: UsdCents,
+        payoff_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Option<InterestAccrualData> {
+        let accrual_period = self.payoff_accrual_period(payoff_at)?;
+
+        Some(self.record_accrual_for_period(amount, accrual_period, audit_info))
+    }
+
+    fn record_accrual_for_period(
+        &mut self,
+        amount: UsdCents,
+        accrual_period: InterestPeriod,
+        audit_info: AuditInfo,
+    ) -> InterestAccrualData {
+        let interest_for_period = self.terms.interest_for_period(amount, &accrual_period);
 
         let accrual_tx_ref = format!("{}-interest-accrual-{}", self.id, self.count_accrued() + 1);
         let interest_accrual = InterestAccrualData {
@@ -244,13 +327,9 @@ impl InterestAccrualCycle {
         {
             Some(_) => None,
             None => {
-                let accrual_cycle_tx_ref = format!(
-                    "{}-interest-accrual-cycle-{}",
-                    self.credit_facility_id, self.idx
-                );
                 let interest_accrual_cycle = InterestAccrualCycleData {
                     interest: self.total_accrued(),
-                    tx_ref: accrual_cycle_tx_ref,
+                    tx_ref: self.accrual_cycle_tx_ref(),
                     tx_id: LedgerTxId::new(),
                     effective: last_accrual_period.end.date_naive(),
                 };
@@ -260,6 +339,25 @@ impl InterestAccrualCycle {
         }
     }
 
+    pub(crate) fn payoff_accrual_cycle_data(
+        &self,
+        payoff_at: DateTime<Utc>,
+    ) -> InterestAccrualCycleData {
+        InterestAccrualCycleData {
+            interest: self.total_accrued(),
+            tx_ref: self.accrual_cycle_tx_ref(),
+            tx_id: LedgerTxId::new(),
+            effective: payoff_at.date_naive(),
+        }
+    }
+
+    fn accrual_cycle_tx_ref(&self) -> String {
+        format!(
+            "{}-interest-accrual-cycle-{}",
+            self.credit_facility_id, self.idx
+        )
+    }
+
     pub(crate) fn record_accrual_cycle(
         &mut self,
         InterestAccrualCycleData {
@@ -341,6 +439,8 @@ pub struct NewInterestAccrualCycle {
     pub period: InterestPeriod,
     pub facility_matures_at: DateTime<Utc>,
     terms: TermValues,
+    #[builder(default)]
+    rate_fixing: Option<AppliedRateFixing>,
     #[builder(setter(into))]
     audit_info: AuditInfo,
 }
@@ -367,6 +467,7 @@ impl IntoEvents<InterestAccrualCycleEvent> for NewInterestAccrualCycle {
                 period: self.period,
                 facility_matures_at: self.facility_matures_at,
                 terms: self.terms,
+                rate_fixing: self.rate_fixing,
                 audit_info: self.audit_info,
             }],
         )
@@ -434,6 +535,7 @@ mod test {
             period: default_period(),
             facility_matures_at: terms.duration.maturity_date(started_at),
             terms,
+            rate_fixing: None,
             audit_info: dummy_audit_info(),
         }]
     }
@@ -643,4 +745,49 @@ mod test {
             _ => panic!("Expected accrual to be returned"),
         }
     }
+
+    #[test]
+    fn interest_accrued_to_projects_unaccrued_days() {
+        let disbursed_outstanding_amount = UsdCents::from(1_000_000_00);
+        let accrual = accrual_from(initial_events());
+
+        let as_of = default_started_at() + chrono::Duration::days(4);
+        assert_eq!(
+            accrual.interest_accrued_to(disbursed_outstanding_amount, as_of),
+            default_terms()
+                .annual_rate
+                .interest_for_time_period(disbursed_outstanding_amount, 5)
+        );
+    }
+
+    #[test]
+    fn payoff_accrues_interest_to_date() {
+        let disbursed_outstanding_amount = UsdCents::from(1_000_000_00);
+        let expected_daily_interest = default_terms()
+            .annual_rate
+            .interest_for_time_period(disbursed_outstanding_amount, 1);
+
+        let mut accrual = accrual_from(initial_events());
+        accrual.record_accrual(disbursed_outstanding_amount, dummy_audit_info());
+        accrual.record_accrual(disbursed_outstanding_amount, dummy_audit_info());
+
+        let payoff_at = default_started_at() + chrono::Duration::days(2);
+        assert_eq!(
+            accrual.interest_accrued_to(disbursed_outstanding_amount, payoff_at),
+            expected_daily_interest * 3
+        );
+
+        let InterestAccrualData { period, .. } = accrual
+            .record_payoff_accrual(disbursed_outstanding_amount, payoff_at, dummy_audit_info())
+            .unwrap();
+        assert_eq!(period.end, payoff_at);
+
+        let InterestAccrualCycleData {
+            interest,
+            effective,
+            ..
+        } = accrual.payoff_accrual_cycle_data(payoff_at);
+        assert_eq!(interest, expected_daily_interest * 3);
+        assert_eq!(effective, payoff_at.date_naive());
+    }
 }
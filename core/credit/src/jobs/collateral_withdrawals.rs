@@ -0,0 +1,254 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_custody::{CoreCustody, CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, collateral::Collaterals,
+    ledger::CreditLedger, primitives::*,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct CollateralWithdrawalsJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for CollateralWithdrawalsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    type Initializer = CollateralWithdrawalsInit<Perms, E>;
+}
+
+pub struct CollateralWithdrawalsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    outbox: Outbox<E>,
+    collaterals: Collaterals<Perms, E>,
+    custody: CoreCustody<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CollateralWithdrawalsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        collaterals: &Collaterals<Perms, E>,
+        custody: &CoreCustody<Perms, E>,
+        ledger: &CreditLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            collaterals: collaterals.clone(),
+            custody: custody.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const COLLATERAL_WITHDRAWALS_JOB: JobType = JobType::new("collateral-withdrawals");
+impl<Perms, E> JobInitializer for CollateralWithdrawalsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        COLLATERAL_WITHDRAWALS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(CollateralWithdrawalsJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            collaterals: self.collaterals.clone(),
+            custody: self.custody.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct CollateralWithdrawalsJobData {
+    sequence: EventSequence,
+}
+
+pub struct CollateralWithdrawalsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    outbox: Outbox<E>,
+    collaterals: Collaterals<Perms, E>,
+    custody: CoreCustody<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> CollateralWithdrawalsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    async fn fail_withdrawal(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        collateral_id: CollateralId,
+        approval_process_id: ApprovalProcessId,
+        reason: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut db = self.collaterals.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERAL,
+            )
+            .await?;
+
+        let Some(collateral_update) = self
+            .collaterals
+            .fail_withdrawal_in_op(
+                &mut db,
+                collateral_id,
+                approval_process_id,
+                reason,
+                crate::time::now().date_naive(),
+                &audit_info,
+            )
+            .await?
+        else {
+            db.commit().await?;
+            return Ok(());
+        };
+
+        self.ledger
+            .update_credit_facility_collateral(db, collateral_update)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for CollateralWithdrawalsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<CollateralWithdrawalsJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            if let Some(CoreCreditEvent::FacilityCollateralWithdrawalApproved {
+                credit_facility_id,
+                collateral_id,
+                approval_process_id,
+                address,
+                amount,
+                ..
+            }) = message.as_ref().as_event()
+            {
+                let collateral = self
+                    .collaterals
+                    .find_by_id_without_audit(*collateral_id)
+                    .await?;
+                // A withdrawal rejected by the custodian must not hold up the
+                // rest of the queue, so it is recorded as failed instead.
+                if let Err(e) = self
+                    .custody
+                    .initiate_withdrawal(
+                        collateral
+                            .wallet_id
+                            .expect("withdrawal is only approved for custodial collateral"),
+                        approval_process_id.to_string(),
+                        address.clone(),
+                        *amount,
+                    )
+                    .await
+                {
+                    tracing::error!(
+                        error = %e,
+                        collateral_id = %collateral_id,
+                        approval_process_id = %approval_process_id,
+                        "collateral withdrawal could not be initiated"
+                    );
+                    self.fail_withdrawal(
+                        *credit_facility_id,
+                        *collateral_id,
+                        *approval_process_id,
+                        e.to_string(),
+                    )
+                    .await?;
+                }
+                state.sequence = message.sequence;
+                current_job.update_execution_state(state).await?;
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
@@ -128,6 +128,10 @@ where
                     credit_facility_id: id,
                     ..
                 })
+                | Some(CoreCreditEvent::FacilityCollateralAssetUpdated {
+                    credit_facility_id: id,
+                    ..
+                })
                 | Some(CoreCreditEvent::ObligationCreated {
                     credit_facility_id: id,
                     ..
@@ -135,6 +139,10 @@ where
                 | Some(CoreCreditEvent::FacilityRepaymentRecorded {
                     credit_facility_id: id,
                     ..
+                })
+                | Some(CoreCreditEvent::FacilityRepaymentReversed {
+                    credit_facility_id: id,
+                    ..
                 }) => {
                     self.credit_facilities
                         .update_collateralization_from_events(
@@ -1,24 +1,20 @@
-use async_trait::async_trait;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
-use std::time::Duration;
-
 use audit::AuditSvc;
 use authz::PermissionCheck;
+use core_price::CorePriceEvent;
 use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
 use job::*;
-use outbox::OutboxEventMarker;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
 
 use crate::{
     CoreCreditAction, CoreCreditEvent, CoreCreditObject, credit_facility::CreditFacilities,
     primitives::*,
 };
 
-#[serde_with::serde_as]
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct CreditFacilityCollateralizationFromPriceJobConfig<Perms, E> {
-    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
-    pub job_interval: Duration,
     pub upgrade_buffer_cvl_pct: CVLPct,
     pub _phantom: std::marker::PhantomData<(Perms, E)>,
 }
@@ -29,15 +25,20 @@ where
         From<CoreCreditAction> + From<GovernanceAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
         From<CoreCreditObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     type Initializer = CreditFacilityCollateralizationFromPriceInit<Perms, E>;
 }
 pub struct CreditFacilityCollateralizationFromPriceInit<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
+    outbox: Outbox<E>,
     credit_facilities: CreditFacilities<Perms, E>,
 }
 
@@ -48,10 +49,15 @@ where
         From<CoreCreditAction> + From<GovernanceAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
         From<CoreCreditObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
-    pub fn new(credit_facilities: CreditFacilities<Perms, E>) -> Self {
-        Self { credit_facilities }
+    pub fn new(outbox: &Outbox<E>, credit_facilities: &CreditFacilities<Perms, E>) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit_facilities: credit_facilities.clone(),
+        }
     }
 }
 
@@ -64,7 +70,9 @@ where
         From<CoreCreditAction> + From<GovernanceAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
         From<CoreCreditObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     fn job_type() -> JobType
     where
@@ -77,22 +85,38 @@ where
         Ok(Box::new(
             CreditFacilityCollateralizationFromPriceJobRunner::<Perms, E> {
                 config: job.config()?,
+                outbox: self.outbox.clone(),
                 credit_facilities: self.credit_facilities.clone(),
             },
         ))
     }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct CreditFacilityCollateralizationFromPriceData {
+    sequence: EventSequence,
 }
 
 pub struct CreditFacilityCollateralizationFromPriceJobRunner<Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     config: CreditFacilityCollateralizationFromPriceJobConfig<Perms, E>,
+    outbox: Outbox<E>,
     credit_facilities: CreditFacilities<Perms, E>,
 }
 
-#[async_trait]
+#[async_trait::async_trait]
 impl<Perms, E> JobRunner for CreditFacilityCollateralizationFromPriceJobRunner<Perms, E>
 where
     Perms: PermissionCheck,
@@ -100,16 +124,43 @@ where
         From<CoreCreditAction> + From<GovernanceAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
         From<CoreCreditObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+    E: OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     async fn run(
         &self,
-        _current_job: CurrentJob,
+        mut current_job: CurrentJob,
     ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
-        self.credit_facilities
-            .update_collateralization_from_price(self.config.upgrade_buffer_cvl_pct)
-            .await?;
+        let mut state = current_job
+            .execution_state::<CreditFacilityCollateralizationFromPriceData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(CorePriceEvent::PriceUpdated { price, .. }) => {
+                    self.credit_facilities
+                        .update_collateralization_from_price(
+                            *price,
+                            self.config.upgrade_buffer_cvl_pct,
+                        )
+                        .await?;
+                }
+                Some(CorePriceEvent::AssetPriceUpdated { price, .. }) => {
+                    self.credit_facilities
+                        .update_collateralization_from_asset_price(
+                            price.asset,
+                            self.config.upgrade_buffer_cvl_pct,
+                        )
+                        .await?;
+                }
+                None => continue,
+            }
+            state.sequence = message.sequence;
+            current_job.update_execution_state(state).await?;
+        }
 
-        Ok(JobCompletion::RescheduleIn(self.config.job_interval))
+        Ok(JobCompletion::RescheduleNow)
     }
 }
@@ -45,15 +45,29 @@ where
                     FacilityCreated { id, .. }
                     | FacilityApproved { id }
                     | FacilityActivated { id, .. }
+                    | FacilityTermsAmended { id, .. }
+                    | FacilityPrepaid { id, .. }
                     | FacilityCompleted { id, .. }
                     | FacilityRepaymentRecorded {
                         credit_facility_id: id,
                         ..
                     }
+                    | FacilityRepaymentReversed {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | FacilityCollateralUpdated {
                         credit_facility_id: id,
                         ..
                     }
+                    | FacilityCollateralAssetUpdated {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | FacilityCollateralWithdrawalApproved {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | FacilityCollateralizationChanged { id, .. }
                     | DisbursalSettled {
                         credit_facility_id: id,
@@ -67,6 +81,10 @@ where
                         credit_facility_id: id,
                         ..
                     }
+                    | ObligationRescheduled {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | ObligationDue {
                         credit_facility_id: id,
                         ..
@@ -87,9 +105,41 @@ where
                         credit_facility_id: id,
                         ..
                     }
+                    | LiquidationCollateralSent {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | LiquidationSaleRecorded {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | LiquidationProceedsApplied {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | LiquidationSurplusReturned {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | LiquidationShortfallWrittenOff {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | LiquidationProcessConcluded {
                         credit_facility_id: id,
                         ..
+                    }
+                    | MarginCallOpened {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | MarginCallCured {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | MarginCallEscalated {
+                        credit_facility_id: id,
+                        ..
                     } => *id,
                 };
 
@@ -0,0 +1,148 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, Disbursals,
+    disbursal_payout::DisbursalPayouts,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct DisbursalPayoutsJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for DisbursalPayoutsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = DisbursalPayoutsInit<Perms, E>;
+}
+
+pub struct DisbursalPayoutsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    disbursals: Disbursals<Perms, E>,
+    disbursal_payouts: DisbursalPayouts<Perms, E>,
+}
+
+impl<Perms, E> DisbursalPayoutsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        disbursals: &Disbursals<Perms, E>,
+        disbursal_payouts: &DisbursalPayouts<Perms, E>,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            disbursals: disbursals.clone(),
+            disbursal_payouts: disbursal_payouts.clone(),
+        }
+    }
+}
+
+const DISBURSAL_PAYOUTS_JOB: JobType = JobType::new("disbursal-payouts");
+impl<Perms, E> JobInitializer for DisbursalPayoutsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DISBURSAL_PAYOUTS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DisbursalPayoutsJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            disbursals: self.disbursals.clone(),
+            disbursal_payouts: self.disbursal_payouts.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct DisbursalPayoutsJobData {
+    sequence: EventSequence,
+}
+
+pub struct DisbursalPayoutsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    disbursals: Disbursals<Perms, E>,
+    disbursal_payouts: DisbursalPayouts<Perms, E>,
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for DisbursalPayoutsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<DisbursalPayoutsJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            if let Some(CoreCreditEvent::DisbursalSettled { ledger_tx_id, .. }) =
+                message.as_ref().as_event()
+            {
+                let disbursal = self
+                    .disbursals
+                    .find_by_concluded_tx_id_without_audit(*ledger_tx_id)
+                    .await?;
+                if disbursal.is_external() {
+                    self.disbursal_payouts.send(disbursal.id).await?;
+                }
+                state.sequence = message.sequence;
+                current_job.update_execution_state(state).await?;
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
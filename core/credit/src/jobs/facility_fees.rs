@@ -0,0 +1,215 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, Disbursals,
+    credit_facility::{CreditFacilities, FeeCharge},
+    ledger::CreditLedger,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FacilityFeesJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for FacilityFeesJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = FacilityFeesInit<Perms, E>;
+}
+
+pub struct FacilityFeesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    disbursals: Disbursals<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> FacilityFeesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        disbursals: &Disbursals<Perms, E>,
+        ledger: &CreditLedger,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit_facilities: credit_facilities.clone(),
+            disbursals: disbursals.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+}
+
+const FACILITY_FEES_JOB: JobType = JobType::new("credit-facility-fees");
+impl<Perms, E> JobInitializer for FacilityFeesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        FACILITY_FEES_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(FacilityFeesJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            disbursals: self.disbursals.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct FacilityFeesJobData {
+    sequence: EventSequence,
+}
+
+pub struct FacilityFeesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    disbursals: Disbursals<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> FacilityFeesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn charge(
+        &self,
+        credit_facility_id: crate::primitives::CreditFacilityId,
+        charge: FeeCharge,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut db = self.credit_facilities.begin_op().await?;
+        match self
+            .credit_facilities
+            .charge_fee_in_op(&mut db, credit_facility_id, charge)
+            .await?
+        {
+            Some(fee) => self.ledger.record_facility_fee(db, fee).await?,
+            None => db.commit().await?,
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for FacilityFeesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<FacilityFeesJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(CoreCreditEvent::AccrualPosted {
+                    credit_facility_id,
+                    period,
+                    ..
+                }) => {
+                    self.charge(
+                        *credit_facility_id,
+                        FeeCharge::Commitment { period: *period },
+                    )
+                    .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                Some(CoreCreditEvent::DisbursalSettled {
+                    credit_facility_id,
+                    ledger_tx_id,
+                    amount,
+                    recorded_at,
+                    ..
+                }) => {
+                    let credit_facility = self
+                        .credit_facilities
+                        .find_by_id_without_audit(*credit_facility_id)
+                        .await?;
+                    let disbursal = self
+                        .disbursals
+                        .find_by_concluded_tx_id_without_audit(*ledger_tx_id)
+                        .await?;
+                    // The structuring fee is booked as the first disbursal on activation
+                    // and must not attract a disbursal fee of its own.
+                    if disbursal.approval_process_id != credit_facility.approval_process_id {
+                        self.charge(
+                            *credit_facility_id,
+                            FeeCharge::Disbursal {
+                                ledger_tx_id: *ledger_tx_id,
+                                amount: *amount,
+                                recorded_at: *recorded_at,
+                            },
+                        )
+                        .await?;
+                    }
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
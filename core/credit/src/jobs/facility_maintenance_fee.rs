@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::{CreditFacilities, FeeCharge},
+    event::CoreCreditEvent,
+    ledger::CreditLedger,
+    primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FacilityMaintenanceFeeJobConfig<Perms, E> {
+    pub credit_facility_id: CreditFacilityId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for FacilityMaintenanceFeeJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = FacilityMaintenanceFeeInit<Perms, E>;
+}
+
+pub struct FacilityMaintenanceFeeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> FacilityMaintenanceFeeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(credit_facilities: &CreditFacilities<Perms, E>, ledger: &CreditLedger) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+}
+
+const FACILITY_MAINTENANCE_FEE_JOB: JobType = JobType::new("credit-facility-maintenance-fee");
+impl<Perms, E> JobInitializer for FacilityMaintenanceFeeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        FACILITY_MAINTENANCE_FEE_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(FacilityMaintenanceFeeJobRunner::<Perms, E> {
+            config: job.config()?,
+            credit_facilities: self.credit_facilities.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct FacilityMaintenanceFeeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    config: FacilityMaintenanceFeeJobConfig<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    ledger: CreditLedger,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for FacilityMaintenanceFeeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(self.config.credit_facility_id)
+            .await?;
+        let Some(due_at) = credit_facility.next_maintenance_fee_date() else {
+            return Ok(JobCompletion::Complete);
+        };
+        if due_at > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(due_at));
+        }
+
+        let mut db = self.credit_facilities.begin_op().await?;
+        let Some(fee) = self
+            .credit_facilities
+            .charge_fee_in_op(
+                &mut db,
+                credit_facility.id,
+                FeeCharge::Maintenance { due_at },
+            )
+            .await?
+        else {
+            return Ok(JobCompletion::Complete);
+        };
+
+        self.ledger.record_facility_fee(db, fee).await?;
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
@@ -152,14 +152,17 @@ where
             )
             .await?;
 
-        let (obligation, new_cycle_data) = self
+        let Some((obligation, new_cycle_data)) = self
             .credit_facilities
             .complete_interest_cycle_and_maybe_start_new_cycle(
                 &mut db,
                 self.config.credit_facility_id,
                 &audit_info,
             )
-            .await?;
+            .await?
+        else {
+            return Ok(JobCompletion::Complete);
+        };
 
         if let Some((new_accrual_cycle_id, first_accrual_end_date)) = new_cycle_data {
             self.jobs
@@ -122,15 +122,18 @@ where
 
         let mut db = self.credit_facilities.begin_op().await?;
 
-        let crate::ConfirmedAccrual {
+        let Some(crate::ConfirmedAccrual {
             accrual: interest_accrual,
             next_period: next_accrual_period,
             accrual_idx,
             accrued_count,
-        } = self
+        }) = self
             .credit_facilities
             .confirm_interest_accrual_in_op(&mut db, self.config.credit_facility_id)
-            .await?;
+            .await?
+        else {
+            return Ok(JobCompletion::Complete);
+        };
 
         if let Some(period) = next_accrual_period {
             self.ledger
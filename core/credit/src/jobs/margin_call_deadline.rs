@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilities, event::CoreCreditEvent, ledger::CreditLedger,
+    margin_call::MarginCalls, obligation::Obligations, primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MarginCallDeadlineJobConfig<Perms, E> {
+    pub margin_call_id: MarginCallId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for MarginCallDeadlineJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = MarginCallDeadlineInit<Perms, E>;
+}
+
+pub struct MarginCallDeadlineInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    margin_calls: MarginCalls<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> MarginCallDeadlineInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        margin_calls: &MarginCalls<Perms, E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        ledger: &CreditLedger,
+    ) -> Self {
+        Self {
+            margin_calls: margin_calls.clone(),
+            credit_facilities: credit_facilities.clone(),
+            obligations: obligations.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+}
+
+const MARGIN_CALL_DEADLINE_JOB: JobType = JobType::new("margin-call-deadline");
+impl<Perms, E> JobInitializer for MarginCallDeadlineInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        MARGIN_CALL_DEADLINE_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(MarginCallDeadlineJobRunner::<Perms, E> {
+            config: job.config()?,
+            margin_calls: self.margin_calls.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            obligations: self.obligations.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct MarginCallDeadlineJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    config: MarginCallDeadlineJobConfig<Perms, E>,
+    margin_calls: MarginCalls<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    ledger: CreditLedger,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for MarginCallDeadlineJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let margin_call = self
+            .margin_calls
+            .find_by_id_without_audit(self.config.margin_call_id)
+            .await?;
+        if !margin_call.is_open() {
+            return Ok(JobCompletion::Complete);
+        }
+
+        // The cure is recorded asynchronously from the collateralization
+        // events, so check the facility directly before escalating.
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(margin_call.credit_facility_id)
+            .await?;
+        if credit_facility.last_collateralization_state()
+            == CollateralizationState::FullyCollateralized
+        {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let effective = crate::time::now().date_naive();
+        let mut db = self.margin_calls.begin_op().await?;
+        if self
+            .margin_calls
+            .escalate_in_op(&mut db, margin_call.id, effective)
+            .await?
+            .is_none()
+        {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let accelerated = self
+            .obligations
+            .liquidate_facility_obligations_in_op(
+                &mut db,
+                margin_call.credit_facility_id,
+                effective,
+            )
+            .await?;
+        self.ledger.record_obligations_due(db, accelerated).await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
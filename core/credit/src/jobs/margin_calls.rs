@@ -0,0 +1,231 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    credit_facility::{CreditFacilities, error::CreditFacilityError},
+    event::CoreCreditEvent,
+    margin_call::MarginCalls,
+    primitives::*,
+};
+
+use super::margin_call_deadline;
+
+#[derive(Serialize, Deserialize)]
+pub struct MarginCallsJobConfig<Perms, E> {
+    pub cure_period_days: u32,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for MarginCallsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = MarginCallsInit<Perms, E>;
+}
+
+pub struct MarginCallsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    margin_calls: MarginCalls<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    jobs: Jobs,
+}
+
+impl<Perms, E> MarginCallsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        margin_calls: &MarginCalls<Perms, E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        jobs: &Jobs,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            margin_calls: margin_calls.clone(),
+            credit_facilities: credit_facilities.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+}
+
+const MARGIN_CALLS_JOB: JobType = JobType::new("margin-calls");
+impl<Perms, E> JobInitializer for MarginCallsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        MARGIN_CALLS_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(MarginCallsJobRunner::<Perms, E> {
+            config: job.config()?,
+            outbox: self.outbox.clone(),
+            margin_calls: self.margin_calls.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            jobs: self.jobs.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct MarginCallsJobData {
+    sequence: EventSequence,
+}
+
+pub struct MarginCallsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    config: MarginCallsJobConfig<Perms, E>,
+    outbox: Outbox<E>,
+    margin_calls: MarginCalls<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    jobs: Jobs,
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for MarginCallsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<MarginCallsJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            if let Some(CoreCreditEvent::FacilityCollateralizationChanged {
+                id,
+                state: collateralization_state,
+                recorded_at,
+                effective,
+                collateral,
+                outstanding,
+                price,
+            }) = message.as_ref().as_event()
+            {
+                let mut db = self.margin_calls.begin_op().await?;
+                match collateralization_state {
+                    // A facility can skip straight past the margin call threshold
+                    // on a sharp price move, so a call is opened there as well.
+                    CollateralizationState::UnderMarginCallThreshold
+                    | CollateralizationState::UnderLiquidationThreshold
+                        if self.is_active(*id).await? =>
+                    {
+                        let cure_deadline = *recorded_at
+                            + chrono::Duration::days(self.config.cure_period_days.into());
+                        if let Some(margin_call) = self
+                            .margin_calls
+                            .open_in_op(
+                                &mut db,
+                                *id,
+                                *collateral,
+                                outstanding.total(),
+                                *price,
+                                cure_deadline,
+                                *effective,
+                            )
+                            .await?
+                        {
+                            self.jobs
+                                .create_and_spawn_at_in_op(
+                                    &mut db,
+                                    margin_call.id,
+                                    margin_call_deadline::MarginCallDeadlineJobConfig::<Perms, E> {
+                                        margin_call_id: margin_call.id,
+                                        _phantom: std::marker::PhantomData,
+                                    },
+                                    margin_call.cure_deadline,
+                                )
+                                .await?;
+                        }
+                    }
+                    CollateralizationState::FullyCollateralized => {
+                        self.margin_calls
+                            .cure_in_op(&mut db, *id, *collateral, outstanding.total(), *effective)
+                            .await?;
+                    }
+                    CollateralizationState::NoCollateral if outstanding.total().is_zero() => {
+                        self.margin_calls
+                            .cure_in_op(&mut db, *id, *collateral, outstanding.total(), *effective)
+                            .await?;
+                    }
+                    CollateralizationState::UnderMarginCallThreshold
+                    | CollateralizationState::UnderLiquidationThreshold
+                    | CollateralizationState::NoCollateral => (),
+                }
+                state.sequence = message.sequence;
+                current_job
+                    .update_execution_state_in_tx(db.tx(), &state)
+                    .await?;
+                db.commit().await?;
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
+
+impl<Perms, E> MarginCallsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    // Pending facilities have nothing disbursed to call in and matured or
+    // closed ones are handled through their obligations, so only active
+    // facilities get margin calls opened against them.
+    async fn is_active(&self, id: CreditFacilityId) -> Result<bool, CreditFacilityError> {
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+        Ok(credit_facility.status() == CreditFacilityStatus::Active)
+    }
+}
@@ -1,10 +1,18 @@
+pub mod collateral_withdrawals;
 pub mod collateralization_from_events;
 pub mod collateralization_from_price;
 pub mod credit_facility_history;
 pub mod credit_facility_repayment_plan;
+pub mod disbursal_payouts;
+pub mod facility_fees;
+pub mod facility_maintenance_fee;
 pub mod interest_accrual_cycles;
 pub mod interest_accruals;
+pub mod margin_call_deadline;
+pub mod margin_calls;
 pub mod obligation_defaulted;
 pub mod obligation_due;
 pub mod obligation_liquidation;
 pub mod obligation_overdue;
+pub mod obligation_penalty;
+pub mod unapplied_funds;
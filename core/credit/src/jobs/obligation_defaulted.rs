@@ -8,6 +8,8 @@ use outbox::OutboxEventMarker;
 
 use crate::{event::CoreCreditEvent, ledger::CreditLedger, obligation::Obligations, primitives::*};
 
+use super::obligation_penalty;
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ObligationDefaultedJobConfig<Perms, E> {
     pub obligation_id: ObligationId,
@@ -30,6 +32,7 @@ where
 {
     obligations: Obligations<Perms, E>,
     ledger: CreditLedger,
+    jobs: Jobs,
 }
 
 impl<Perms, E> ObligationDefaultedInit<Perms, E>
@@ -39,10 +42,11 @@ where
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
     E: OutboxEventMarker<CoreCreditEvent>,
 {
-    pub fn new(ledger: &CreditLedger, obligations: &Obligations<Perms, E>) -> Self {
+    pub fn new(ledger: &CreditLedger, obligations: &Obligations<Perms, E>, jobs: &Jobs) -> Self {
         Self {
             ledger: ledger.clone(),
             obligations: obligations.clone(),
+            jobs: jobs.clone(),
         }
     }
 }
@@ -67,6 +71,7 @@ where
             config: job.config()?,
             obligations: self.obligations.clone(),
             ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
         }))
     }
 }
@@ -79,6 +84,7 @@ where
     config: ObligationDefaultedJobConfig<Perms, E>,
     obligations: Obligations<Perms, E>,
     ledger: CreditLedger,
+    jobs: Jobs,
 }
 
 #[async_trait]
@@ -95,7 +101,7 @@ where
     ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
         let mut db = self.obligations.begin_op().await?;
 
-        let data = self
+        let (obligation, data) = self
             .obligations
             .record_defaulted_in_op(&mut db, self.config.obligation_id, self.config.effective)
             .await?;
@@ -106,6 +112,21 @@ where
             return Ok(JobCompletion::Complete);
         };
 
+        if obligation.overdue_at().is_none()
+            && obligation.obligation_type != ObligationType::Penalty
+        {
+            self.jobs
+                .create_and_spawn_in_op(
+                    &mut db,
+                    JobId::new(),
+                    obligation_penalty::ObligationPenaltyJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+
         self.ledger
             .record_obligation_defaulted(db, defaulted)
             .await?;
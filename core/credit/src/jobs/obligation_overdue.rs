@@ -8,7 +8,7 @@ use outbox::OutboxEventMarker;
 
 use crate::{event::CoreCreditEvent, ledger::CreditLedger, obligation::Obligations, primitives::*};
 
-use super::{obligation_defaulted, obligation_liquidation};
+use super::{obligation_defaulted, obligation_liquidation, obligation_penalty};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ObligationOverdueJobConfig<Perms, E> {
@@ -139,6 +139,19 @@ where
                 .await?;
         }
 
+        if obligation.obligation_type != ObligationType::Penalty {
+            self.jobs
+                .create_and_spawn_in_op(
+                    &mut db,
+                    JobId::new(),
+                    obligation_penalty::ObligationPenaltyJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+
         self.ledger.record_obligation_overdue(db, overdue).await?;
 
         Ok(JobCompletion::Complete)
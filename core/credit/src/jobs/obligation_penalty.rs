@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilityRepo, event::CoreCreditEvent, ledger::CreditLedger,
+    obligation::Obligations, primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ObligationPenaltyJobConfig<Perms, E> {
+    pub obligation_id: ObligationId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for ObligationPenaltyJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    type Initializer = ObligationPenaltyInit<Perms, E>;
+}
+pub struct ObligationPenaltyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    obligations: Obligations<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> ObligationPenaltyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(
+        ledger: &CreditLedger,
+        obligations: &Obligations<Perms, E>,
+        credit_facility_repo: &CreditFacilityRepo<E>,
+    ) -> Self {
+        Self {
+            ledger: ledger.clone(),
+            obligations: obligations.clone(),
+            credit_facility_repo: credit_facility_repo.clone(),
+        }
+    }
+}
+
+const OBLIGATION_PENALTY_JOB: JobType = JobType::new("obligation-penalty");
+impl<Perms, E> JobInitializer for ObligationPenaltyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        OBLIGATION_PENALTY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ObligationPenaltyJobRunner::<Perms, E> {
+            config: job.config()?,
+            obligations: self.obligations.clone(),
+            credit_facility_repo: self.credit_facility_repo.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct ObligationPenaltyJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    config: ObligationPenaltyJobConfig<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    credit_facility_repo: CreditFacilityRepo<E>,
+    ledger: CreditLedger,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for ObligationPenaltyJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let obligation = self
+            .obligations
+            .find_by_id_without_audit(self.config.obligation_id)
+            .await?;
+        let credit_facility = self
+            .credit_facility_repo
+            .find_by_id(obligation.credit_facility_id)
+            .await?;
+        let terms = credit_facility.terms;
+        if terms.penalty_rate.is_none() {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let Some(period) = obligation.next_penalty_period(terms.accrual_interval) else {
+            return Ok(JobCompletion::Complete);
+        };
+        if period.end > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(period.end));
+        }
+
+        let mut db = self.obligations.begin_op().await?;
+        let (accruing, penalty) = self
+            .obligations
+            .accrue_penalty_in_op(
+                &mut db,
+                obligation.id,
+                period,
+                &terms,
+                credit_facility.account_ids.into(),
+            )
+            .await?;
+
+        match penalty {
+            Some(penalty) => self.ledger.record_obligation_penalty(db, penalty).await?,
+            None => db.commit().await?,
+        }
+
+        if !accruing {
+            return Ok(JobCompletion::Complete);
+        }
+        Ok(JobCompletion::RescheduleAt(period.next().end))
+    }
+}
@@ -0,0 +1,233 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, Payments,
+    credit_facility::CreditFacilities, ledger::CreditLedger, primitives::*,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct ApplyUnappliedFundsJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for ApplyUnappliedFundsJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = ApplyUnappliedFundsInit<Perms, E>;
+}
+
+pub struct ApplyUnappliedFundsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    payments: Payments<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> ApplyUnappliedFundsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        payments: &Payments<Perms, E>,
+        ledger: &CreditLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit_facilities: credit_facilities.clone(),
+            payments: payments.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const APPLY_UNAPPLIED_FUNDS_JOB: JobType = JobType::new("credit-facility-apply-unapplied-funds");
+impl<Perms, E> JobInitializer for ApplyUnappliedFundsInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        APPLY_UNAPPLIED_FUNDS_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ApplyUnappliedFundsJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            payments: self.payments.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct ApplyUnappliedFundsJobData {
+    sequence: EventSequence,
+}
+
+pub struct ApplyUnappliedFundsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    payments: Payments<Perms, E>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> ApplyUnappliedFundsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn apply_unapplied_funds(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        if credit_facility.unapplied_funds().is_zero() {
+            return Ok(());
+        }
+
+        let mut db = self.credit_facilities.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_RECORD_PAYMENT,
+            )
+            .await?;
+
+        let payment_id = PaymentId::new();
+        let allocations = self
+            .payments
+            .record_from_unapplied_funds_in_op(
+                &mut db,
+                payment_id,
+                &credit_facility,
+                credit_facility.unapplied_funds(),
+                effective,
+                &audit_info,
+            )
+            .await?;
+        let allocated = allocations
+            .iter()
+            .fold(UsdCents::ZERO, |total, allocation| {
+                total + allocation.amount
+            });
+
+        let Some(unapplied_funds) = self
+            .credit_facilities
+            .apply_unapplied_funds_in_op(
+                &mut db,
+                credit_facility_id,
+                payment_id,
+                allocated,
+                effective,
+                &audit_info,
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ledger
+            .apply_unapplied_funds(db, unapplied_funds, allocations)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for ApplyUnappliedFundsJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<ApplyUnappliedFundsJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            if let Some(CoreCreditEvent::ObligationCreated {
+                credit_facility_id,
+                effective,
+                ..
+            }) = message.as_ref().as_event()
+            {
+                // A facility whose funds cannot be applied must not hold up
+                // the events behind it; the funds stay in the pool until the
+                // next obligation is created.
+                if let Err(e) = self
+                    .apply_unapplied_funds(*credit_facility_id, *effective)
+                    .await
+                {
+                    tracing::error!(
+                        error = %e,
+                        %credit_facility_id,
+                        "could not apply unapplied funds"
+                    );
+                }
+                state.sequence = message.sequence;
+                current_job.update_execution_state(state).await?;
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
@@ -15,6 +15,38 @@ pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_SET_REF: &str =
 pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_REF: &str =
     "credit-facility-in-liquidation-omnibus-account";
 
+pub const CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Liquidator Omnibus Account Set";
+pub const CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_SET_REF: &str = "credit-liquidator-omnibus-account-set";
+pub const CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_REF: &str = "credit-liquidator-omnibus-account";
+
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Liquidation Proceeds Omnibus Account Set";
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-liquidation-proceeds-omnibus-account-set";
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-liquidation-proceeds-omnibus-account";
+
+pub const CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Loss Expense Omnibus Account Set";
+pub const CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-loss-expense-omnibus-account-set";
+pub const CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_REF: &str = "credit-loss-expense-omnibus-account";
+
+pub const CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Payment Rail In-Transit Omnibus Account Set";
+pub const CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-payment-rail-in-transit-omnibus-account-set";
+pub const CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-payment-rail-in-transit-omnibus-account";
+
+pub const CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Payment Rail Settlement Omnibus Account Set";
+pub const CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-payment-rail-settlement-omnibus-account-set";
+pub const CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-payment-rail-settlement-omnibus-account";
+
 // Summary Accounts
 pub const CREDIT_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
     "Credit Facility Remaining Account Set";
@@ -188,6 +220,27 @@ pub const CREDIT_INTEREST_INCOME_ACCOUNT_SET_REF: &str = "credit-interest-income
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Credit Fee Income Account Set";
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "credit-fee-income-account-set";
 
+pub const CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_NAME: &str = "Credit Unapplied Funds Account Set";
+pub const CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_REF: &str = "credit-unapplied-funds-account-set";
+
+pub const CREDIT_PENALTY_INCOME_ACCOUNT_SET_NAME: &str = "Credit Penalty Income Account Set";
+pub const CREDIT_PENALTY_INCOME_ACCOUNT_SET_REF: &str = "credit-penalty-income-account-set";
+
+pub const CREDIT_COMMITMENT_FEE_INCOME_ACCOUNT_SET_NAME: &str =
+    "Credit Commitment Fee Income Account Set";
+pub const CREDIT_COMMITMENT_FEE_INCOME_ACCOUNT_SET_REF: &str =
+    "credit-commitment-fee-income-account-set";
+
+pub const CREDIT_MAINTENANCE_FEE_INCOME_ACCOUNT_SET_NAME: &str =
+    "Credit Maintenance Fee Income Account Set";
+pub const CREDIT_MAINTENANCE_FEE_INCOME_ACCOUNT_SET_REF: &str =
+    "credit-maintenance-fee-income-account-set";
+
+pub const CREDIT_DISBURSAL_FEE_INCOME_ACCOUNT_SET_NAME: &str =
+    "Credit Disbursal Fee Income Account Set";
+pub const CREDIT_DISBURSAL_FEE_INCOME_ACCOUNT_SET_REF: &str =
+    "credit-disbursal-fee-income-account-set";
+
 // Velocity Controls
 pub(super) const CREDIT_FACILITY_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000002");
@@ -25,6 +25,11 @@ pub struct CreditFacilityAccountIds {
     pub interest_defaulted_account_id: CalaAccountId,
     pub interest_income_account_id: CalaAccountId,
     pub fee_income_account_id: CalaAccountId,
+    pub unapplied_funds_account_id: CalaAccountId,
+    pub penalty_income_account_id: CalaAccountId,
+    pub commitment_fee_income_account_id: CalaAccountId,
+    pub maintenance_fee_income_account_id: CalaAccountId,
+    pub disbursal_fee_income_account_id: CalaAccountId,
 }
 
 impl CreditFacilityAccountIds {
@@ -44,6 +49,11 @@ impl CreditFacilityAccountIds {
             interest_defaulted_account_id: CalaAccountId::new(),
             interest_income_account_id: CalaAccountId::new(),
             fee_income_account_id: CalaAccountId::new(),
+            unapplied_funds_account_id: CalaAccountId::new(),
+            penalty_income_account_id: CalaAccountId::new(),
+            commitment_fee_income_account_id: CalaAccountId::new(),
+            maintenance_fee_income_account_id: CalaAccountId::new(),
+            disbursal_fee_income_account_id: CalaAccountId::new(),
         }
     }
 }
@@ -53,6 +63,7 @@ pub struct CreditFacilityCompletion {
     pub tx_id: LedgerTxId,
     pub collateral: Satoshis,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub unapplied_funds_refund: Option<CreditFacilityUnappliedFunds>,
 }
 
 #[derive(Debug, Clone)]
@@ -81,3 +92,49 @@ pub struct CreditFacilityInterestAccrual {
     pub period: InterestPeriod,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
 }
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPostedInterest {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub interest: UsdCents,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPrepayment {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub interest_accrual: Option<CreditFacilityInterestAccrual>,
+    pub posted_interest: Option<CreditFacilityPostedInterest>,
+    pub outstanding: UsdCents,
+    pub prepayment_fee: UsdCents,
+    pub debit_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+    pub completion: CreditFacilityCompletion,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityUnappliedFunds {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub deposit_account_id: CalaAccountId,
+    pub unapplied_funds_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnappliedFundsReversal {
+    pub returned: Option<CreditFacilityUnappliedFunds>,
+    pub reversal: CreditFacilityUnappliedFunds,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityFee {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub receivable_account_id: CalaAccountId,
+    pub fee_income_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
@@ -24,6 +24,10 @@ pub enum CreditLedgerError {
         "CreditLedgerError - NonAccountMemberFoundInAccountSet: Found non-Account typed member in account set {0}"
     )]
     NonAccountMemberFoundInAccountSet(String),
+    #[error("CreditLedgerError - UnsupportedCollateralAsset: {0} has no ledger currency")]
+    UnsupportedCollateralAsset(core_money::CollateralAsset),
     #[error("CreditLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error("CreditLedgerError - UnappliedFundsAllocationMismatch: {0} released, {1} allocated")]
+    UnappliedFundsAllocationMismatch(core_money::UsdCents, core_money::UsdCents),
 }
@@ -21,13 +21,18 @@ use cala_ledger::{
 use crate::{
     ChartOfAccountsIntegrationConfig, FacilityDurationType, Obligation,
     ObligationDefaultedReallocationData, ObligationDueReallocationData,
-    ObligationOverdueReallocationData,
-    liquidation_process::LiquidationProcess,
-    payment_allocation::PaymentAllocation,
+    ObligationOverdueReallocationData, ObligationPenaltyData, ObligationRescheduleReallocationData,
+    ObligationWriteOffData,
+    liquidation_process::{
+        LiquidationCollateralTransfer, LiquidationConclusion, LiquidationProcess, LiquidationSale,
+        LiquidationSurplus,
+    },
+    payment_allocation::{PaymentAllocation, PaymentAllocationReversal},
     primitives::{
-        CalaAccountId, CalaAccountSetId, CollateralAction, CollateralUpdate, CreditFacilityId,
-        CustomerType, DisbursedReceivableAccountCategory, DisbursedReceivableAccountType,
-        InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId, Satoshis, UsdCents,
+        CalaAccountId, CalaAccountSetId, CollateralAction, CollateralAsset, CollateralUpdate,
+        CreditFacilityId, CustomerType, DisbursedReceivableAccountCategory,
+        DisbursedReceivableAccountType, InterestReceivableAccountType, LedgerOmnibusAccountIds,
+        LedgerTxId, Satoshis, UsdCents,
     },
 };
 
@@ -116,6 +121,11 @@ pub struct CreditFacilityInternalAccountSets {
     pub interest_defaulted: InternalAccountSetDetails,
     pub interest_income: InternalAccountSetDetails,
     pub fee_income: InternalAccountSetDetails,
+    pub unapplied_funds: InternalAccountSetDetails,
+    pub penalty_income: InternalAccountSetDetails,
+    pub commitment_fee_income: InternalAccountSetDetails,
+    pub maintenance_fee_income: InternalAccountSetDetails,
+    pub disbursal_fee_income: InternalAccountSetDetails,
 }
 
 impl CreditFacilityInternalAccountSets {
@@ -126,6 +136,11 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation,
             interest_income,
             fee_income,
+            unapplied_funds,
+            penalty_income,
+            commitment_fee_income,
+            maintenance_fee_income,
+            disbursal_fee_income,
 
             disbursed_receivable:
                 DisbursedReceivable {
@@ -148,6 +163,11 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation.id,
             interest_income.id,
             fee_income.id,
+            unapplied_funds.id,
+            penalty_income.id,
+            commitment_fee_income.id,
+            maintenance_fee_income.id,
+            disbursal_fee_income.id,
             disbursed_defaulted.id,
             interest_defaulted.id,
         ];
@@ -171,7 +191,13 @@ pub struct CreditLedger {
     journal_id: JournalId,
     facility_omnibus_account_ids: LedgerOmnibusAccountIds,
     collateral_omnibus_account_ids: LedgerOmnibusAccountIds,
+    asset_collateral_omnibus_account_ids: HashMap<CollateralAsset, LedgerOmnibusAccountIds>,
     in_liquidation_omnibus_account_ids: LedgerOmnibusAccountIds,
+    liquidator_omnibus_account_ids: LedgerOmnibusAccountIds,
+    liquidation_proceeds_omnibus_account_ids: LedgerOmnibusAccountIds,
+    credit_loss_expense_omnibus_account_ids: LedgerOmnibusAccountIds,
+    payment_rail_in_transit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    payment_rail_settlement_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
     credit_facility_control_id: VelocityControlId,
     usd: Currency,
@@ -185,15 +211,28 @@ impl CreditLedger {
         templates::ActivateCreditFacility::init(cala).await?;
         templates::RemoveCollateral::init(cala).await?;
         templates::RecordPaymentAllocation::init(cala).await?;
+        templates::ReversePaymentAllocation::init(cala).await?;
         templates::RecordObligationDueBalance::init(cala).await?;
         templates::RecordObligationOverdueBalance::init(cala).await?;
         templates::RecordObligationDefaultedBalance::init(cala).await?;
+        templates::RescheduleObligationBalance::init(cala).await?;
         templates::CreditFacilityAccrueInterest::init(cala).await?;
         templates::CreditFacilityPostAccruedInterest::init(cala).await?;
         templates::InitiateDisbursal::init(cala).await?;
         templates::CancelDisbursal::init(cala).await?;
         templates::ConfirmDisbursal::init(cala).await?;
+        templates::ClearDisbursalInTransit::init(cala).await?;
         templates::ReserveForLiquidation::init(cala).await?;
+        templates::SendCollateralToLiquidator::init(cala).await?;
+        templates::RecordLiquidationSale::init(cala).await?;
+        templates::ReturnLiquidationSurplus::init(cala).await?;
+        templates::WriteOffLiquidationShortfall::init(cala).await?;
+        templates::ConcludeLiquidation::init(cala).await?;
+        templates::RecordPrepaymentFee::init(cala).await?;
+        templates::RecordObligationPenalty::init(cala).await?;
+        templates::RecordFacilityFee::init(cala).await?;
+        templates::RecordUnappliedFunds::init(cala).await?;
+        templates::ReleaseUnappliedFunds::init(cala).await?;
 
         let collateral_omnibus_normal_balance_type = DebitOrCredit::Debit;
         let collateral_omnibus_account_ids = Self::find_or_create_omnibus_account(
@@ -206,6 +245,23 @@ impl CreditLedger {
         )
         .await?;
 
+        let mut asset_collateral_omnibus_account_ids = HashMap::new();
+        for asset in CollateralAsset::ALL
+            .into_iter()
+            .filter(|asset| *asset != CollateralAsset::Btc)
+        {
+            let ids = Self::find_or_create_omnibus_account(
+                cala,
+                journal_id,
+                format!("{journal_id}:{CREDIT_COLLATERAL_OMNIBUS_ACCOUNT_SET_REF}:{asset}"),
+                format!("{journal_id}:{CREDIT_COLLATERAL_OMNIBUS_ACCOUNT_REF}:{asset}"),
+                format!("{CREDIT_COLLATERAL_OMNIBUS_ACCOUNT_SET_NAME} ({asset})"),
+                collateral_omnibus_normal_balance_type,
+            )
+            .await?;
+            asset_collateral_omnibus_account_ids.insert(asset, ids);
+        }
+
         let facility_omnibus_normal_balance_type = DebitOrCredit::Debit;
         let facility_omnibus_account_ids = Self::find_or_create_omnibus_account(
             cala,
@@ -228,6 +284,61 @@ impl CreditLedger {
         )
         .await?;
 
+        let liquidator_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let liquidator_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_LIQUIDATOR_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            liquidator_omnibus_normal_balance_type,
+        )
+        .await?;
+
+        let liquidation_proceeds_omnibus_normal_balance_type = DebitOrCredit::Credit;
+        let liquidation_proceeds_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            liquidation_proceeds_omnibus_normal_balance_type,
+        )
+        .await?;
+
+        let credit_loss_expense_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let credit_loss_expense_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_LOSS_EXPENSE_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            credit_loss_expense_omnibus_normal_balance_type,
+        )
+        .await?;
+
+        let payment_rail_in_transit_omnibus_normal_balance_type = DebitOrCredit::Credit;
+        let payment_rail_in_transit_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_PAYMENT_RAIL_IN_TRANSIT_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            payment_rail_in_transit_omnibus_normal_balance_type,
+        )
+        .await?;
+
+        let payment_rail_settlement_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let payment_rail_settlement_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_PAYMENT_RAIL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            payment_rail_settlement_omnibus_normal_balance_type,
+        )
+        .await?;
+
         let facility_normal_balance_type = DebitOrCredit::Credit;
         let facility_account_set_id = Self::find_or_create_account_set(
             cala,
@@ -610,6 +721,56 @@ impl CreditLedger {
         )
         .await?;
 
+        let unapplied_funds_normal_balance_type = DebitOrCredit::Credit;
+        let unapplied_funds_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_REF}"),
+            CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_NAME.to_string(),
+            unapplied_funds_normal_balance_type,
+        )
+        .await?;
+
+        let penalty_income_normal_balance_type = DebitOrCredit::Credit;
+        let penalty_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PENALTY_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_PENALTY_INCOME_ACCOUNT_SET_NAME.to_string(),
+            penalty_income_normal_balance_type,
+        )
+        .await?;
+
+        let commitment_fee_income_normal_balance_type = DebitOrCredit::Credit;
+        let commitment_fee_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_COMMITMENT_FEE_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_COMMITMENT_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            commitment_fee_income_normal_balance_type,
+        )
+        .await?;
+
+        let maintenance_fee_income_normal_balance_type = DebitOrCredit::Credit;
+        let maintenance_fee_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_MAINTENANCE_FEE_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_MAINTENANCE_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            maintenance_fee_income_normal_balance_type,
+        )
+        .await?;
+
+        let disbursal_fee_income_normal_balance_type = DebitOrCredit::Credit;
+        let disbursal_fee_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_DISBURSAL_FEE_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_DISBURSAL_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            disbursal_fee_income_normal_balance_type,
+        )
+        .await?;
+
         let disbursed_receivable = DisbursedReceivable {
             short_term: DisbursedReceivableAccountSets {
                 individual: InternalAccountSetDetails {
@@ -797,6 +958,26 @@ impl CreditLedger {
                 id: fee_income_account_set_id,
                 normal_balance_type: fee_income_normal_balance_type,
             },
+            unapplied_funds: InternalAccountSetDetails {
+                id: unapplied_funds_account_set_id,
+                normal_balance_type: unapplied_funds_normal_balance_type,
+            },
+            penalty_income: InternalAccountSetDetails {
+                id: penalty_income_account_set_id,
+                normal_balance_type: penalty_income_normal_balance_type,
+            },
+            commitment_fee_income: InternalAccountSetDetails {
+                id: commitment_fee_income_account_set_id,
+                normal_balance_type: commitment_fee_income_normal_balance_type,
+            },
+            maintenance_fee_income: InternalAccountSetDetails {
+                id: maintenance_fee_income_account_set_id,
+                normal_balance_type: maintenance_fee_income_normal_balance_type,
+            },
+            disbursal_fee_income: InternalAccountSetDetails {
+                id: disbursal_fee_income_account_set_id,
+                normal_balance_type: disbursal_fee_income_normal_balance_type,
+            },
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -818,7 +999,13 @@ impl CreditLedger {
             journal_id,
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
+            asset_collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            liquidator_omnibus_account_ids,
+            liquidation_proceeds_omnibus_account_ids,
+            credit_loss_expense_omnibus_account_ids,
+            payment_rail_in_transit_omnibus_account_ids,
+            payment_rail_settlement_omnibus_account_ids,
             internal_account_sets,
             credit_facility_control_id,
             usd: Currency::USD,
@@ -960,6 +1147,11 @@ impl CreditLedger {
             in_liquidation_account_id: _,
             fee_income_account_id: _,
             interest_income_account_id: _,
+            unapplied_funds_account_id: _,
+            penalty_income_account_id: _,
+            commitment_fee_income_account_id: _,
+            maintenance_fee_income_account_id: _,
+            disbursal_fee_income_account_id: _,
         }: CreditFacilityAccountIds,
     ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
         let facility_id = (self.journal_id, facility_account_id, self.usd);
@@ -1104,34 +1296,96 @@ impl CreditLedger {
         })
     }
 
+    fn collateral_currency(&self, asset: CollateralAsset) -> Result<Currency, CreditLedgerError> {
+        match asset {
+            CollateralAsset::Btc => Ok(self.btc),
+            _ => asset
+                .currency_code()
+                .parse()
+                .map_err(|_| CreditLedgerError::UnsupportedCollateralAsset(asset)),
+        }
+    }
+
+    fn collateral_omnibus_account_id(&self, asset: CollateralAsset) -> CalaAccountId {
+        match asset {
+            CollateralAsset::Btc => self.collateral_omnibus_account_ids.account_id,
+            _ => {
+                self.asset_collateral_omnibus_account_ids
+                    .get(&asset)
+                    .expect("omnibus account created for every collateral asset")
+                    .account_id
+            }
+        }
+    }
+
+    /// Opens the account holding `asset` pledged to a facility. BTC uses the
+    /// collateral account created with the facility itself.
+    pub async fn create_collateral_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        asset: CollateralAsset,
+        account_id: CalaAccountId,
+    ) -> Result<(), CreditLedgerError> {
+        self.collateral_currency(asset)?;
+
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let reference = &format!("credit-facility-collateral:{credit_facility_id}:{asset}");
+        let name = &format!("Credit Facility {asset} Collateral Account for {credit_facility_id}");
+        self.create_account_in_op(
+            &mut op,
+            account_id,
+            self.internal_account_sets.collateral,
+            reference,
+            name,
+            name,
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn update_credit_facility_collateral(
         &self,
         op: es_entity::DbOp<'_>,
+        update: CollateralUpdate,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.update_credit_facility_collateral_in_op(&mut op, update)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn update_credit_facility_collateral_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
         CollateralUpdate {
             tx_id,
+            collateral_account_id,
             abs_diff,
             action,
             effective,
         }: CollateralUpdate,
-        credit_facility_account_ids: CreditFacilityAccountIds,
     ) -> Result<(), CreditLedgerError> {
-        let mut op = self.cala.ledger_operation_from_db_op(op);
+        let asset = abs_diff.asset();
+        let currency = self.collateral_currency(asset)?;
+        let amount = abs_diff.to_decimal();
+        let bank_collateral_account_id = self.collateral_omnibus_account_id(asset);
+
         match action {
             CollateralAction::Add => {
                 self.cala
                     .post_transaction_in_op(
-                        &mut op,
+                        op,
                         tx_id,
                         templates::ADD_COLLATERAL_CODE,
                         templates::AddCollateralParams {
                             journal_id: self.journal_id,
-                            currency: self.btc,
-                            amount: abs_diff.to_btc(),
-                            collateral_account_id: credit_facility_account_ids
-                                .collateral_account_id,
-                            bank_collateral_account_id: self
-                                .collateral_omnibus_account_ids
-                                .account_id,
+                            currency,
+                            amount,
+                            collateral_account_id,
+                            bank_collateral_account_id,
                             effective,
                         },
                     )
@@ -1140,25 +1394,21 @@ impl CreditLedger {
             CollateralAction::Remove => {
                 self.cala
                     .post_transaction_in_op(
-                        &mut op,
+                        op,
                         tx_id,
                         templates::REMOVE_COLLATERAL_CODE,
                         templates::RemoveCollateralParams {
                             journal_id: self.journal_id,
-                            currency: self.btc,
-                            amount: abs_diff.to_btc(),
-                            collateral_account_id: credit_facility_account_ids
-                                .collateral_account_id,
-                            bank_collateral_account_id: self
-                                .collateral_omnibus_account_ids
-                                .account_id,
+                            currency,
+                            amount,
+                            collateral_account_id,
+                            bank_collateral_account_id,
                             effective,
                         },
                     )
                     .await
             }
         }?;
-        op.commit().await?;
         Ok(())
     }
 
@@ -1199,9 +1449,106 @@ impl CreditLedger {
         &self,
         op: es_entity::DbOp<'_>,
         payments: Vec<PaymentAllocation>,
+        unapplied_funds: Option<CreditFacilityUnappliedFunds>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        for payment in payments {
+            self.record_obligation_repayment_in_op(&mut op, payment)
+                .await?;
+        }
+
+        if let Some(unapplied_funds) = unapplied_funds {
+            self.record_unapplied_funds_in_op(&mut op, unapplied_funds)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn record_unapplied_funds_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        CreditFacilityUnappliedFunds {
+            tx_id,
+            tx_ref,
+            amount,
+            deposit_account_id,
+            unapplied_funds_account_id,
+            effective,
+        }: CreditFacilityUnappliedFunds,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::RECORD_UNAPPLIED_FUNDS_CODE,
+                templates::RecordUnappliedFundsParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    deposit_account_id,
+                    unapplied_funds_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn release_unapplied_funds_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        CreditFacilityUnappliedFunds {
+            tx_id,
+            tx_ref,
+            amount,
+            deposit_account_id,
+            unapplied_funds_account_id,
+            effective,
+        }: CreditFacilityUnappliedFunds,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::RELEASE_UNAPPLIED_FUNDS_CODE,
+                templates::ReleaseUnappliedFundsParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    deposit_account_id,
+                    unapplied_funds_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn apply_unapplied_funds(
+        &self,
+        op: es_entity::DbOp<'_>,
+        unapplied_funds: CreditFacilityUnappliedFunds,
+        payments: Vec<PaymentAllocation>,
     ) -> Result<(), CreditLedgerError> {
+        let allocated = payments
+            .iter()
+            .fold(UsdCents::ZERO, |total, payment| total + payment.amount);
+        if allocated != unapplied_funds.amount {
+            return Err(CreditLedgerError::UnappliedFundsAllocationMismatch(
+                unapplied_funds.amount,
+                allocated,
+            ));
+        }
+
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
+        self.release_unapplied_funds_in_op(&mut op, unapplied_funds)
+            .await?;
         for payment in payments {
             self.record_obligation_repayment_in_op(&mut op, payment)
                 .await?;
@@ -1211,9 +1558,80 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn reverse_payment(
+        &self,
+        op: es_entity::DbOp<'_>,
+        reversals: Vec<PaymentAllocationReversal>,
+        unapplied_funds: Option<UnappliedFundsReversal>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        for PaymentAllocationReversal {
+            tx_id,
+            tx_ref,
+            amount,
+            receivable_account_id,
+            account_to_be_debited_id,
+            effective,
+        } in reversals
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::REVERSE_PAYMENT_ALLOCATION_CODE,
+                    templates::ReversePaymentAllocationParams {
+                        journal_id: self.journal_id,
+                        currency: self.usd,
+                        amount: amount.to_usd(),
+                        account_to_be_debited_id,
+                        receivable_account_id,
+                        tx_ref,
+                        effective,
+                    },
+                )
+                .await?;
+        }
+
+        if let Some(UnappliedFundsReversal { returned, reversal }) = unapplied_funds {
+            if let Some(returned) = returned {
+                self.record_unapplied_funds_in_op(&mut op, returned).await?;
+            }
+            self.release_unapplied_funds_in_op(&mut op, reversal)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn record_obligation_due(
         &self,
         op: es_entity::DbOp<'_>,
+        data: ObligationDueReallocationData,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.record_obligation_due_in_op(&mut op, data).await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_obligations_due(
+        &self,
+        op: es_entity::DbOp<'_>,
+        obligations: Vec<ObligationDueReallocationData>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        for data in obligations {
+            self.record_obligation_due_in_op(&mut op, data).await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn record_obligation_due_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
         ObligationDueReallocationData {
             tx_id,
             amount: outstanding_amount,
@@ -1223,10 +1641,9 @@ impl CreditLedger {
             ..
         }: ObligationDueReallocationData,
     ) -> Result<(), CreditLedgerError> {
-        let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
-                &mut op,
+                op,
                 tx_id,
                 templates::RECORD_OBLIGATION_DUE_BALANCE_CODE,
                 templates::RecordObligationDueBalanceParams {
@@ -1238,7 +1655,6 @@ impl CreditLedger {
                 },
             )
             .await?;
-        op.commit().await?;
         Ok(())
     }
 
@@ -1273,6 +1689,39 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn record_obligations_rescheduled(
+        &self,
+        op: es_entity::DbOp<'_>,
+        reallocations: Vec<ObligationRescheduleReallocationData>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        for ObligationRescheduleReallocationData {
+            tx_id,
+            amount,
+            receivable_account_id,
+            not_yet_due_account_id,
+            effective,
+        } in reallocations
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::RESCHEDULE_OBLIGATION_BALANCE_CODE,
+                    templates::RescheduleObligationBalanceParams {
+                        journal_id: self.journal_id,
+                        amount: amount.to_usd(),
+                        receivable_not_yet_due_account_id: not_yet_due_account_id,
+                        receivable_account_id,
+                        effective,
+                    },
+                )
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn record_obligation_defaulted(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1304,30 +1753,31 @@ impl CreditLedger {
         Ok(())
     }
 
-    pub async fn reserve_for_liquidation(
+    pub async fn record_obligation_penalty(
         &self,
         op: es_entity::DbOp<'_>,
-        LiquidationProcess {
-            ledger_tx_id,
-            initial_amount: outstanding,
-            in_liquidation_account_id,
+        ObligationPenaltyData {
+            tx_id,
+            tx_ref,
+            amount,
+            receivable_account_id,
+            penalty_income_account_id,
             effective,
-            ..
-        }: LiquidationProcess,
+        }: ObligationPenaltyData,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
                 &mut op,
-                ledger_tx_id,
-                templates::RESERVE_FOR_LIQUIDATION_CODE,
-                templates::ReserveForLiquidationParams {
+                tx_id,
+                templates::RECORD_OBLIGATION_PENALTY_CODE,
+                templates::RecordObligationPenaltyParams {
                     journal_id: self.journal_id,
-                    amount: outstanding.to_usd(),
-                    liquidation_omnibus_account_id: self
-                        .in_liquidation_omnibus_account_ids
-                        .account_id,
-                    facility_liquidation_account_id: in_liquidation_account_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    receivable_account_id,
+                    penalty_income_account_id,
+                    external_id: tx_ref,
                     effective,
                 },
             )
@@ -1336,21 +1786,287 @@ impl CreditLedger {
         Ok(())
     }
 
-    pub async fn complete_credit_facility(
+    pub async fn record_facility_fee(
         &self,
         op: es_entity::DbOp<'_>,
-        CreditFacilityCompletion {
+        CreditFacilityFee {
             tx_id,
-            collateral,
-            credit_facility_account_ids,
-        }: CreditFacilityCompletion,
+            tx_ref,
+            amount,
+            receivable_account_id,
+            fee_income_account_id,
+            effective,
+        }: CreditFacilityFee,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
         self.cala
             .post_transaction_in_op(
                 &mut op,
                 tx_id,
-                templates::REMOVE_COLLATERAL_CODE,
+                templates::RECORD_FACILITY_FEE_CODE,
+                templates::RecordFacilityFeeParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    receivable_account_id,
+                    fee_income_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn reserve_for_liquidation(
+        &self,
+        op: es_entity::DbOp<'_>,
+        LiquidationProcess {
+            ledger_tx_id,
+            initial_amount: outstanding,
+            in_liquidation_account_id,
+            effective,
+            ..
+        }: LiquidationProcess,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                ledger_tx_id,
+                templates::RESERVE_FOR_LIQUIDATION_CODE,
+                templates::ReserveForLiquidationParams {
+                    journal_id: self.journal_id,
+                    amount: outstanding.to_usd(),
+                    liquidation_omnibus_account_id: self
+                        .in_liquidation_omnibus_account_ids
+                        .account_id,
+                    facility_liquidation_account_id: in_liquidation_account_id,
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub fn liquidation_proceeds_account_id(&self) -> CalaAccountId {
+        self.liquidation_proceeds_omnibus_account_ids.account_id
+    }
+
+    pub async fn send_collateral_to_liquidator(
+        &self,
+        op: es_entity::DbOp<'_>,
+        LiquidationCollateralTransfer {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+        }: LiquidationCollateralTransfer,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::SEND_COLLATERAL_TO_LIQUIDATOR_CODE,
+                templates::SendCollateralToLiquidatorParams {
+                    journal_id: self.journal_id,
+                    amount: amount.to_btc(),
+                    collateral_omnibus_account_id: self.collateral_omnibus_account_ids.account_id,
+                    liquidator_omnibus_account_id: self.liquidator_omnibus_account_ids.account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_liquidation_sale(
+        &self,
+        op: es_entity::DbOp<'_>,
+        LiquidationSale {
+            tx_id,
+            tx_ref,
+            sold,
+            proceeds,
+            collateral_account_id,
+            effective,
+        }: LiquidationSale,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::RECORD_LIQUIDATION_SALE_CODE,
+                templates::RecordLiquidationSaleParams {
+                    journal_id: self.journal_id,
+                    sold_amount: sold.to_btc(),
+                    proceeds_amount: proceeds.to_usd(),
+                    collateral_account_id,
+                    liquidator_omnibus_account_id: self.liquidator_omnibus_account_ids.account_id,
+                    liquidation_proceeds_omnibus_account_id: self
+                        .liquidation_proceeds_omnibus_account_ids
+                        .account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn apply_liquidation_proceeds(
+        &self,
+        op: es_entity::DbOp<'_>,
+        allocations: Vec<PaymentAllocation>,
+        conclusion: Option<LiquidationConclusion>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        for allocation in allocations {
+            self.record_obligation_repayment_in_op(&mut op, allocation)
+                .await?;
+        }
+
+        if let Some(conclusion) = conclusion {
+            self.conclude_liquidation_in_op(&mut op, conclusion).await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn return_liquidation_surplus(
+        &self,
+        op: es_entity::DbOp<'_>,
+        LiquidationSurplus {
+            tx_id,
+            tx_ref,
+            amount,
+            deposit_account_id,
+            effective,
+        }: LiquidationSurplus,
+        conclusion: LiquidationConclusion,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::RETURN_LIQUIDATION_SURPLUS_CODE,
+                templates::ReturnLiquidationSurplusParams {
+                    journal_id: self.journal_id,
+                    amount: amount.to_usd(),
+                    liquidation_proceeds_omnibus_account_id: self
+                        .liquidation_proceeds_omnibus_account_ids
+                        .account_id,
+                    deposit_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        self.conclude_liquidation_in_op(&mut op, conclusion).await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn write_off_liquidation_shortfall(
+        &self,
+        op: es_entity::DbOp<'_>,
+        ObligationWriteOffData {
+            tx_id,
+            tx_ref,
+            amount,
+            receivable_account_id,
+            effective,
+        }: ObligationWriteOffData,
+        conclusion: LiquidationConclusion,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::WRITE_OFF_LIQUIDATION_SHORTFALL_CODE,
+                templates::WriteOffLiquidationShortfallParams {
+                    journal_id: self.journal_id,
+                    amount: amount.to_usd(),
+                    receivable_account_id,
+                    credit_loss_expense_account_id: self
+                        .credit_loss_expense_omnibus_account_ids
+                        .account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        self.conclude_liquidation_in_op(&mut op, conclusion).await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn conclude_liquidation_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        LiquidationConclusion {
+            tx_id,
+            tx_ref,
+            amount,
+            in_liquidation_account_id,
+            effective,
+        }: LiquidationConclusion,
+    ) -> Result<(), CreditLedgerError> {
+        self.cala
+            .post_transaction_in_op(
+                op,
+                tx_id,
+                templates::CONCLUDE_LIQUIDATION_CODE,
+                templates::ConcludeLiquidationParams {
+                    journal_id: self.journal_id,
+                    amount: amount.to_usd(),
+                    liquidation_omnibus_account_id: self
+                        .in_liquidation_omnibus_account_ids
+                        .account_id,
+                    facility_liquidation_account_id: in_liquidation_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn complete_credit_facility(
+        &self,
+        op: es_entity::DbOp<'_>,
+        CreditFacilityCompletion {
+            tx_id,
+            collateral,
+            credit_facility_account_ids,
+            unapplied_funds_refund,
+        }: CreditFacilityCompletion,
+        released_assets: Vec<CollateralUpdate>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        if let Some(refund) = unapplied_funds_refund {
+            self.release_unapplied_funds_in_op(&mut op, refund).await?;
+        }
+        for update in released_assets {
+            self.update_credit_facility_collateral_in_op(&mut op, update)
+                .await?;
+        }
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::REMOVE_COLLATERAL_CODE,
                 templates::RemoveCollateralParams {
                     journal_id: self.journal_id,
                     currency: self.btc,
@@ -1365,6 +2081,139 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn record_prepayment(
+        &self,
+        op: es_entity::DbOp<'_>,
+        CreditFacilityPrepayment {
+            tx_id,
+            tx_ref,
+            interest_accrual,
+            posted_interest,
+            prepayment_fee,
+            debit_account_id,
+            effective,
+            completion:
+                CreditFacilityCompletion {
+                    tx_id: completion_tx_id,
+                    collateral,
+                    credit_facility_account_ids,
+                    unapplied_funds_refund,
+                },
+            ..
+        }: CreditFacilityPrepayment,
+        allocations: Vec<PaymentAllocation>,
+        released_assets: Vec<CollateralUpdate>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if let Some(CreditFacilityInterestAccrual {
+            tx_id,
+            tx_ref,
+            interest,
+            period,
+            ..
+        }) = interest_accrual
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::CREDIT_FACILITY_ACCRUE_INTEREST_CODE,
+                    templates::CreditFacilityAccrueInterestParams {
+                        journal_id: self.journal_id,
+
+                        credit_facility_interest_receivable_account: credit_facility_account_ids
+                            .interest_receivable_not_yet_due_account_id,
+                        credit_facility_interest_income_account: credit_facility_account_ids
+                            .interest_income_account_id,
+                        interest_amount: interest.to_usd(),
+                        external_id: tx_ref,
+                        effective: period.end.date_naive(),
+                    },
+                )
+                .await?;
+        }
+
+        if let Some(CreditFacilityPostedInterest {
+            tx_id,
+            tx_ref,
+            interest,
+        }) = posted_interest
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::CREDIT_FACILITY_POST_ACCRUED_INTEREST_CODE,
+                    templates::CreditFacilityPostAccruedInterestParams {
+                        journal_id: self.journal_id,
+
+                        credit_facility_interest_receivable_account: credit_facility_account_ids
+                            .interest_receivable_not_yet_due_account_id,
+                        credit_facility_interest_income_account: credit_facility_account_ids
+                            .interest_income_account_id,
+                        interest_amount: interest.to_usd(),
+                        external_id: tx_ref,
+                        effective,
+                    },
+                )
+                .await?;
+        }
+
+        for allocation in allocations {
+            self.record_obligation_repayment_in_op(&mut op, allocation)
+                .await?;
+        }
+
+        if !prepayment_fee.is_zero() {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::RECORD_PREPAYMENT_FEE_CODE,
+                    templates::RecordPrepaymentFeeParams {
+                        journal_id: self.journal_id,
+                        currency: self.usd,
+                        fee_amount: prepayment_fee.to_usd(),
+                        debit_account_id,
+                        facility_fee_income_account: credit_facility_account_ids
+                            .fee_income_account_id,
+                        external_id: tx_ref,
+                        effective,
+                    },
+                )
+                .await?;
+        }
+
+        if let Some(refund) = unapplied_funds_refund {
+            self.release_unapplied_funds_in_op(&mut op, refund).await?;
+        }
+
+        for update in released_assets {
+            self.update_credit_facility_collateral_in_op(&mut op, update)
+                .await?;
+        }
+
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                completion_tx_id,
+                templates::REMOVE_COLLATERAL_CODE,
+                templates::RemoveCollateralParams {
+                    journal_id: self.journal_id,
+                    currency: self.btc,
+                    amount: collateral.to_btc(),
+                    collateral_account_id: credit_facility_account_ids.collateral_account_id,
+                    bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                    effective,
+                },
+            )
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
     async fn create_credit_facility(
         &self,
         mut op: cala_ledger::LedgerOperation<'_>,
@@ -1556,16 +2405,54 @@ impl CreditLedger {
     pub async fn settle_disbursal(
         &self,
         op: es_entity::DbOp<'_>,
-        obligation: Obligation,
+        obligations: Vec<Obligation>,
+        facility_account_id: CalaAccountId,
+    ) -> Result<(), CreditLedgerError> {
+        self.confirm_disbursal(op, obligations, facility_account_id, None)
+            .await
+    }
+
+    /// Confirms a disbursal that is paid out over a payment rail. Funds are
+    /// parked in the in-transit omnibus account until the rail reports the
+    /// payment as settled or returned.
+    pub async fn settle_disbursal_to_payment_rail(
+        &self,
+        op: es_entity::DbOp<'_>,
+        obligations: Vec<Obligation>,
         facility_account_id: CalaAccountId,
     ) -> Result<(), CreditLedgerError> {
+        self.confirm_disbursal(
+            op,
+            obligations,
+            facility_account_id,
+            Some(self.payment_rail_in_transit_omnibus_account_ids.account_id),
+        )
+        .await
+    }
+
+    async fn confirm_disbursal(
+        &self,
+        op: es_entity::DbOp<'_>,
+        obligations: Vec<Obligation>,
+        facility_account_id: CalaAccountId,
+        payout_account_id: Option<CalaAccountId>,
+    ) -> Result<(), CreditLedgerError> {
+        let amount = obligations
+            .iter()
+            .fold(UsdCents::ZERO, |total, obligation| {
+                total + obligation.initial_amount
+            });
+        let obligation = obligations
+            .into_iter()
+            .next()
+            .expect("disbursal should have at least one obligation");
         let facility_disbursed_receivable_account =
             obligation.not_yet_due_accounts().receivable_account_id;
-        let account_to_be_credited_id = obligation.not_yet_due_accounts().account_to_be_credited_id;
+        let account_to_be_credited_id = payout_account_id
+            .unwrap_or(obligation.not_yet_due_accounts().account_to_be_credited_id);
         let Obligation {
             tx_id,
             reference: external_id,
-            initial_amount: amount,
             ..
         } = obligation;
 
@@ -1590,6 +2477,69 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn record_disbursal_payment_settled(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        tx_ref: String,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), CreditLedgerError> {
+        self.clear_disbursal_in_transit(
+            op,
+            tx_id,
+            amount,
+            self.payment_rail_settlement_omnibus_account_ids.account_id,
+            tx_ref,
+            effective,
+        )
+        .await
+    }
+
+    pub async fn record_disbursal_payment_returned(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        deposit_account_id: CalaAccountId,
+        tx_ref: String,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), CreditLedgerError> {
+        self.clear_disbursal_in_transit(op, tx_id, amount, deposit_account_id, tx_ref, effective)
+            .await
+    }
+
+    async fn clear_disbursal_in_transit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        account_to_be_credited_id: CalaAccountId,
+        tx_ref: String,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::CLEAR_DISBURSAL_IN_TRANSIT_CODE,
+                templates::ClearDisbursalInTransitParams {
+                    journal_id: self.journal_id,
+                    amount: amount.to_usd(),
+                    in_transit_account_id: self
+                        .payment_rail_in_transit_omnibus_account_ids
+                        .account_id,
+                    account_to_be_credited_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn create_credit_facility_control(
         cala: &CalaLedger,
     ) -> Result<VelocityControlId, CreditLedgerError> {
@@ -1771,6 +2721,11 @@ impl CreditLedger {
             interest_defaulted_account_id,
             interest_income_account_id,
             fee_income_account_id,
+            unapplied_funds_account_id,
+            penalty_income_account_id,
+            commitment_fee_income_account_id,
+            maintenance_fee_income_account_id,
+            disbursal_fee_income_account_id,
         } = account_ids;
 
         let collateral_reference = &format!("credit-facility-collateral:{credit_facility_id}");
@@ -1960,6 +2915,76 @@ impl CreditLedger {
         )
         .await?;
 
+        let unapplied_funds_reference =
+            &format!("credit-facility-unapplied-funds:{credit_facility_id}");
+        let unapplied_funds_name =
+            &format!("Unapplied Funds Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            unapplied_funds_account_id,
+            self.internal_account_sets.unapplied_funds,
+            unapplied_funds_reference,
+            unapplied_funds_name,
+            unapplied_funds_name,
+        )
+        .await?;
+
+        let penalty_income_reference =
+            &format!("credit-facility-penalty-income:{credit_facility_id}");
+        let penalty_income_name =
+            &format!("Penalty Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            penalty_income_account_id,
+            self.internal_account_sets.penalty_income,
+            penalty_income_reference,
+            penalty_income_name,
+            penalty_income_name,
+        )
+        .await?;
+
+        let commitment_fee_income_reference =
+            &format!("credit-facility-commitment-fee-income:{credit_facility_id}");
+        let commitment_fee_income_name =
+            &format!("Commitment Fee Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            commitment_fee_income_account_id,
+            self.internal_account_sets.commitment_fee_income,
+            commitment_fee_income_reference,
+            commitment_fee_income_name,
+            commitment_fee_income_name,
+        )
+        .await?;
+
+        let maintenance_fee_income_reference =
+            &format!("credit-facility-maintenance-fee-income:{credit_facility_id}");
+        let maintenance_fee_income_name =
+            &format!("Maintenance Fee Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            maintenance_fee_income_account_id,
+            self.internal_account_sets.maintenance_fee_income,
+            maintenance_fee_income_reference,
+            maintenance_fee_income_name,
+            maintenance_fee_income_name,
+        )
+        .await?;
+
+        let disbursal_fee_income_reference =
+            &format!("credit-facility-disbursal-fee-income:{credit_facility_id}");
+        let disbursal_fee_income_name =
+            &format!("Disbursal Fee Income Account for Credit Facility {credit_facility_id}");
+        self.create_account_in_op(
+            op,
+            disbursal_fee_income_account_id,
+            self.internal_account_sets.disbursal_fee_income,
+            disbursal_fee_income_reference,
+            disbursal_fee_income_name,
+            disbursal_fee_income_name,
+        )
+        .await?;
+
         Ok(())
     }
 
@@ -2035,9 +3060,15 @@ impl CreditLedger {
         let Self {
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
+            asset_collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            liquidator_omnibus_account_ids,
+            liquidation_proceeds_omnibus_account_ids,
+            credit_loss_expense_omnibus_account_ids,
             internal_account_sets,
 
+            payment_rail_in_transit_omnibus_account_ids: _,
+            payment_rail_settlement_omnibus_account_ids: _,
             cala: _,
             journal_id: _,
             credit_facility_control_id: _,
@@ -2049,7 +3080,15 @@ impl CreditLedger {
             facility_omnibus_account_ids.account_set_id,
             collateral_omnibus_account_ids.account_set_id,
             in_liquidation_omnibus_account_ids.account_set_id,
+            liquidator_omnibus_account_ids.account_set_id,
+            liquidation_proceeds_omnibus_account_ids.account_set_id,
+            credit_loss_expense_omnibus_account_ids.account_set_id,
         ];
+        account_set_ids.extend(
+            asset_collateral_omnibus_account_ids
+                .values()
+                .map(|ids| ids.account_set_id),
+        );
         account_set_ids.extend(internal_account_sets.account_set_ids());
         let mut account_sets = self
             .cala
@@ -2064,11 +3103,19 @@ impl CreditLedger {
             facility_omnibus_parent_account_set_id,
             collateral_omnibus_parent_account_set_id,
             in_liquidation_omnibus_parent_account_set_id,
+            liquidator_omnibus_parent_account_set_id,
+            liquidation_proceeds_omnibus_parent_account_set_id,
+            credit_loss_expense_omnibus_parent_account_set_id,
             facility_parent_account_set_id,
             collateral_parent_account_set_id,
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            unapplied_funds_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            commitment_fee_income_parent_account_set_id,
+            maintenance_fee_income_parent_account_set_id,
+            disbursal_fee_income_parent_account_set_id,
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
             short_term_interest_integration_meta,
@@ -2096,6 +3143,18 @@ impl CreditLedger {
         )
         .await?;
 
+        for ids in asset_collateral_omnibus_account_ids.values() {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                ids.account_set_id,
+                *collateral_omnibus_parent_account_set_id,
+                &charts_integration_meta,
+                |meta| meta.collateral_omnibus_parent_account_set_id,
+            )
+            .await?;
+        }
+
         self.attach_charts_account_set(
             &mut op,
             &mut account_sets,
@@ -2106,6 +3165,36 @@ impl CreditLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.liquidator_omnibus_account_ids.account_set_id,
+            *liquidator_omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.liquidator_omnibus_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.liquidation_proceeds_omnibus_account_ids.account_set_id,
+            *liquidation_proceeds_omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.liquidation_proceeds_omnibus_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.credit_loss_expense_omnibus_account_ids.account_set_id,
+            *credit_loss_expense_omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.credit_loss_expense_omnibus_parent_account_set_id,
+        )
+        .await?;
+
         self.attach_charts_account_set(
             &mut op,
             &mut account_sets,
@@ -2151,6 +3240,51 @@ impl CreditLedger {
             |meta| meta.fee_income_parent_account_set_id,
         )
         .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.unapplied_funds.id,
+            *unapplied_funds_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.unapplied_funds_parent_account_set_id,
+        )
+        .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.penalty_income.id,
+            *penalty_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.penalty_income_parent_account_set_id,
+        )
+        .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.commitment_fee_income.id,
+            *commitment_fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.commitment_fee_income_parent_account_set_id,
+        )
+        .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.maintenance_fee_income.id,
+            *maintenance_fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.maintenance_fee_income_parent_account_set_id,
+        )
+        .await?;
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.disbursal_fee_income.id,
+            *disbursal_fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.disbursal_fee_income_parent_account_set_id,
+        )
+        .await?;
 
         self.attach_short_term_disbursed_receivable_account_sets(
             &mut op,
@@ -2829,11 +3963,19 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub facility_omnibus_parent_account_set_id: CalaAccountSetId,
     pub collateral_omnibus_parent_account_set_id: CalaAccountSetId,
     pub in_liquidation_omnibus_parent_account_set_id: CalaAccountSetId,
+    pub liquidator_omnibus_parent_account_set_id: CalaAccountSetId,
+    pub liquidation_proceeds_omnibus_parent_account_set_id: CalaAccountSetId,
+    pub credit_loss_expense_omnibus_parent_account_set_id: CalaAccountSetId,
     pub facility_parent_account_set_id: CalaAccountSetId,
     pub collateral_parent_account_set_id: CalaAccountSetId,
     pub in_liquidation_parent_account_set_id: CalaAccountSetId,
     pub interest_income_parent_account_set_id: CalaAccountSetId,
     pub fee_income_parent_account_set_id: CalaAccountSetId,
+    pub unapplied_funds_parent_account_set_id: CalaAccountSetId,
+    pub penalty_income_parent_account_set_id: CalaAccountSetId,
+    pub commitment_fee_income_parent_account_set_id: CalaAccountSetId,
+    pub maintenance_fee_income_parent_account_set_id: CalaAccountSetId,
+    pub disbursal_fee_income_parent_account_set_id: CalaAccountSetId,
 
     pub short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta,
     pub long_term_disbursed_integration_meta: LongTermDisbursedIntegrationMeta,
@@ -0,0 +1,131 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CLEAR_DISBURSAL_IN_TRANSIT_CODE: &str = "CLEAR_DISBURSAL_IN_TRANSIT";
+
+#[derive(Debug)]
+pub struct ClearDisbursalInTransitParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub in_transit_account_id: CalaAccountId,
+    pub account_to_be_credited_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl ClearDisbursalInTransitParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("in_transit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("account_to_be_credited_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ClearDisbursalInTransitParams> for Params {
+    fn from(
+        ClearDisbursalInTransitParams {
+            journal_id,
+            amount,
+            in_transit_account_id,
+            account_to_be_credited_id,
+            external_id,
+            effective,
+        }: ClearDisbursalInTransitParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert("in_transit_account_id", in_transit_account_id);
+        params.insert("account_to_be_credited_id", account_to_be_credited_id);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct ClearDisbursalInTransit;
+
+impl ClearDisbursalInTransit {
+    #[instrument(name = "ledger.clear_disbursal_in_transit.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Clear a disbursal sent over a payment rail'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'CLEAR_DISBURSAL_IN_TRANSIT_DR'")
+                .currency("'USD'")
+                .account_id("params.in_transit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CLEAR_DISBURSAL_IN_TRANSIT_CR'")
+                .currency("'USD'")
+                .account_id("params.account_to_be_credited_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ClearDisbursalInTransitParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CLEAR_DISBURSAL_IN_TRANSIT_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CONCLUDE_LIQUIDATION_CODE: &str = "CONCLUDE_LIQUIDATION";
+
+#[derive(Debug)]
+pub struct ConcludeLiquidationParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub liquidation_omnibus_account_id: CalaAccountId,
+    pub facility_liquidation_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl ConcludeLiquidationParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidation_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_liquidation_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ConcludeLiquidationParams> for Params {
+    fn from(
+        ConcludeLiquidationParams {
+            journal_id,
+            amount,
+            liquidation_omnibus_account_id,
+            facility_liquidation_account_id,
+            external_id,
+            effective,
+        }: ConcludeLiquidationParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "liquidation_omnibus_account_id",
+            liquidation_omnibus_account_id,
+        );
+        params.insert(
+            "facility_liquidation_account_id",
+            facility_liquidation_account_id,
+        );
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct ConcludeLiquidation;
+
+impl ConcludeLiquidation {
+    #[instrument(name = "ledger.conclude_liquidation.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Release the amount reserved for liquidation'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'CONCLUDE_LIQUIDATION_DR'")
+                .currency("'USD'")
+                .account_id("params.facility_liquidation_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CONCLUDE_LIQUIDATION_CR'")
+                .currency("'USD'")
+                .account_id("params.liquidation_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ConcludeLiquidationParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CONCLUDE_LIQUIDATION_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
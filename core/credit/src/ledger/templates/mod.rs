@@ -2,6 +2,8 @@ mod accrue_interest;
 mod activate_credit_facility;
 mod add_collateral;
 mod cancel_disbursal;
+mod clear_disbursal_in_transit;
+mod conclude_liquidation;
 mod confirm_disbursal;
 mod create_credit_facility;
 mod initiate_disbursal;
@@ -10,13 +12,26 @@ mod obligation_due_balance;
 mod obligation_overdue_balance;
 mod payment_allocation;
 mod post_accrued_interest;
+mod record_facility_fee;
+mod record_liquidation_sale;
+mod record_obligation_penalty;
+mod record_prepayment_fee;
+mod record_unapplied_funds;
+mod release_unapplied_funds;
 mod remove_collateral;
+mod reschedule_obligation_balance;
 mod reserve_for_liquidation;
+mod return_liquidation_surplus;
+mod reverse_payment_allocation;
+mod send_collateral_to_liquidator;
+mod write_off_liquidation_shortfall;
 
 pub use accrue_interest::*;
 pub use activate_credit_facility::*;
 pub use add_collateral::*;
 pub use cancel_disbursal::*;
+pub use clear_disbursal_in_transit::*;
+pub use conclude_liquidation::*;
 pub use confirm_disbursal::*;
 pub use create_credit_facility::*;
 pub use initiate_disbursal::*;
@@ -25,5 +40,16 @@ pub use obligation_due_balance::*;
 pub use obligation_overdue_balance::*;
 pub use payment_allocation::*;
 pub use post_accrued_interest::*;
+pub use record_facility_fee::*;
+pub use record_liquidation_sale::*;
+pub use record_obligation_penalty::*;
+pub use record_prepayment_fee::*;
+pub use record_unapplied_funds::*;
+pub use release_unapplied_funds::*;
 pub use remove_collateral::*;
+pub use reschedule_obligation_balance::*;
 pub use reserve_for_liquidation::*;
+pub use return_liquidation_surplus::*;
+pub use reverse_payment_allocation::*;
+pub use send_collateral_to_liquidator::*;
+pub use write_off_liquidation_shortfall::*;
@@ -0,0 +1,171 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_LIQUIDATION_SALE_CODE: &str = "RECORD_LIQUIDATION_SALE";
+
+#[derive(Debug)]
+pub struct RecordLiquidationSaleParams {
+    pub journal_id: JournalId,
+    pub sold_amount: Decimal,
+    pub proceeds_amount: Decimal,
+    pub collateral_account_id: CalaAccountId,
+    pub liquidator_omnibus_account_id: CalaAccountId,
+    pub liquidation_proceeds_omnibus_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RecordLiquidationSaleParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("sold_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("proceeds_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("collateral_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidator_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidation_proceeds_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<RecordLiquidationSaleParams> for Params {
+    fn from(
+        RecordLiquidationSaleParams {
+            journal_id,
+            sold_amount,
+            proceeds_amount,
+            collateral_account_id,
+            liquidator_omnibus_account_id,
+            liquidation_proceeds_omnibus_account_id,
+            external_id,
+            effective,
+        }: RecordLiquidationSaleParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("sold_amount", sold_amount);
+        params.insert("proceeds_amount", proceeds_amount);
+        params.insert("collateral_account_id", collateral_account_id);
+        params.insert(
+            "liquidator_omnibus_account_id",
+            liquidator_omnibus_account_id,
+        );
+        params.insert(
+            "liquidation_proceeds_omnibus_account_id",
+            liquidation_proceeds_omnibus_account_id,
+        );
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct RecordLiquidationSale;
+
+impl RecordLiquidationSale {
+    #[instrument(name = "ledger.record_liquidation_sale.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Record collateral sold by a liquidator and the proceeds received'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_LIQUIDATION_SALE_COLLATERAL_DR'")
+                .currency("'BTC'")
+                .account_id("params.collateral_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.sold_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_LIQUIDATION_SALE_COLLATERAL_CR'")
+                .currency("'BTC'")
+                .account_id("params.liquidator_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.sold_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_LIQUIDATION_SALE_PROCEEDS_DR'")
+                .currency("'USD'")
+                .account_id("params.liquidator_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.proceeds_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_LIQUIDATION_SALE_PROCEEDS_CR'")
+                .currency("'USD'")
+                .account_id("params.liquidation_proceeds_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.proceeds_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordLiquidationSaleParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_LIQUIDATION_SALE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_OBLIGATION_PENALTY_CODE: &str = "RECORD_OBLIGATION_PENALTY";
+
+#[derive(Debug)]
+pub struct RecordObligationPenaltyParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub receivable_account_id: CalaAccountId,
+    pub penalty_income_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RecordObligationPenaltyParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receivable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("penalty_income_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<RecordObligationPenaltyParams> for Params {
+    fn from(
+        RecordObligationPenaltyParams {
+            journal_id,
+            currency,
+            amount,
+            receivable_account_id,
+            penalty_income_account_id,
+            external_id,
+            effective,
+        }: RecordObligationPenaltyParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("receivable_account_id", receivable_account_id);
+        params.insert("penalty_income_account_id", penalty_income_account_id);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct RecordObligationPenalty;
+
+impl RecordObligationPenalty {
+    #[instrument(name = "ledger.record_obligation_penalty.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Record penalty interest on overdue obligation'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .account_id("params.receivable_account_id")
+                .units("params.amount")
+                .currency("params.currency")
+                .entry_type("'RECORD_OBLIGATION_PENALTY_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.penalty_income_account_id")
+                .units("params.amount")
+                .currency("params.currency")
+                .entry_type("'RECORD_OBLIGATION_PENALTY_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordObligationPenaltyParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_OBLIGATION_PENALTY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RESCHEDULE_OBLIGATION_BALANCE_CODE: &str = "RESCHEDULE_OBLIGATION_BALANCE";
+
+#[derive(Debug)]
+pub struct RescheduleObligationBalanceParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub receivable_not_yet_due_account_id: CalaAccountId,
+    pub receivable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RescheduleObligationBalanceParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receivable_not_yet_due_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receivable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl From<RescheduleObligationBalanceParams> for Params {
+    fn from(
+        RescheduleObligationBalanceParams {
+            journal_id,
+            amount,
+            receivable_not_yet_due_account_id,
+            receivable_account_id,
+            effective,
+        }: RescheduleObligationBalanceParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "receivable_not_yet_due_account_id",
+            receivable_not_yet_due_account_id,
+        );
+        params.insert("receivable_account_id", receivable_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct RescheduleObligationBalance;
+
+impl RescheduleObligationBalance {
+    #[instrument(name = "ledger.reschedule_obligation_balance.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Move an obligation balance back to not yet due'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESCHEDULE_OBLIGATION_BALANCE_CR'")
+                .currency("'USD'")
+                .account_id("params.receivable_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESCHEDULE_OBLIGATION_BALANCE_DR'")
+                .currency("'USD'")
+                .account_id("params.receivable_not_yet_due_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RescheduleObligationBalanceParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RESCHEDULE_OBLIGATION_BALANCE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
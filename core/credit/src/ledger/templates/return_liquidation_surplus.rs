@@ -0,0 +1,136 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RETURN_LIQUIDATION_SURPLUS_CODE: &str = "RETURN_LIQUIDATION_SURPLUS";
+
+#[derive(Debug)]
+pub struct ReturnLiquidationSurplusParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub liquidation_proceeds_omnibus_account_id: CalaAccountId,
+    pub deposit_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl ReturnLiquidationSurplusParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidation_proceeds_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ReturnLiquidationSurplusParams> for Params {
+    fn from(
+        ReturnLiquidationSurplusParams {
+            journal_id,
+            amount,
+            liquidation_proceeds_omnibus_account_id,
+            deposit_account_id,
+            external_id,
+            effective,
+        }: ReturnLiquidationSurplusParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "liquidation_proceeds_omnibus_account_id",
+            liquidation_proceeds_omnibus_account_id,
+        );
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct ReturnLiquidationSurplus;
+
+impl ReturnLiquidationSurplus {
+    #[instrument(name = "ledger.return_liquidation_surplus.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description(
+                "'Return liquidation proceeds in excess of the obligation to the borrower'",
+            )
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RETURN_LIQUIDATION_SURPLUS_DR'")
+                .currency("'USD'")
+                .account_id("params.liquidation_proceeds_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RETURN_LIQUIDATION_SURPLUS_CR'")
+                .currency("'USD'")
+                .account_id("params.deposit_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ReturnLiquidationSurplusParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RETURN_LIQUIDATION_SURPLUS_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const SEND_COLLATERAL_TO_LIQUIDATOR_CODE: &str = "SEND_COLLATERAL_TO_LIQUIDATOR";
+
+#[derive(Debug)]
+pub struct SendCollateralToLiquidatorParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub collateral_omnibus_account_id: CalaAccountId,
+    pub liquidator_omnibus_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl SendCollateralToLiquidatorParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("collateral_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidator_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<SendCollateralToLiquidatorParams> for Params {
+    fn from(
+        SendCollateralToLiquidatorParams {
+            journal_id,
+            amount,
+            collateral_omnibus_account_id,
+            liquidator_omnibus_account_id,
+            external_id,
+            effective,
+        }: SendCollateralToLiquidatorParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "collateral_omnibus_account_id",
+            collateral_omnibus_account_id,
+        );
+        params.insert(
+            "liquidator_omnibus_account_id",
+            liquidator_omnibus_account_id,
+        );
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct SendCollateralToLiquidator;
+
+impl SendCollateralToLiquidator {
+    #[instrument(name = "ledger.send_collateral_to_liquidator.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Send collateral held in custody to a liquidator'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'SEND_COLLATERAL_TO_LIQUIDATOR_DR'")
+                .currency("'BTC'")
+                .account_id("params.liquidator_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'SEND_COLLATERAL_TO_LIQUIDATOR_CR'")
+                .currency("'BTC'")
+                .account_id("params.collateral_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = SendCollateralToLiquidatorParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(SEND_COLLATERAL_TO_LIQUIDATOR_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
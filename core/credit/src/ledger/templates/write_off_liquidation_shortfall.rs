@@ -0,0 +1,136 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const WRITE_OFF_LIQUIDATION_SHORTFALL_CODE: &str = "WRITE_OFF_LIQUIDATION_SHORTFALL";
+
+#[derive(Debug)]
+pub struct WriteOffLiquidationShortfallParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub receivable_account_id: CalaAccountId,
+    pub credit_loss_expense_account_id: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl WriteOffLiquidationShortfallParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("receivable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_loss_expense_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<WriteOffLiquidationShortfallParams> for Params {
+    fn from(
+        WriteOffLiquidationShortfallParams {
+            journal_id,
+            amount,
+            receivable_account_id,
+            credit_loss_expense_account_id,
+            external_id,
+            effective,
+        }: WriteOffLiquidationShortfallParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert("receivable_account_id", receivable_account_id);
+        params.insert(
+            "credit_loss_expense_account_id",
+            credit_loss_expense_account_id,
+        );
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct WriteOffLiquidationShortfall;
+
+impl WriteOffLiquidationShortfall {
+    #[instrument(name = "ledger.write_off_liquidation_shortfall.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description(
+                "'Write off the part of an obligation not covered by liquidation proceeds'",
+            )
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'WRITE_OFF_LIQUIDATION_SHORTFALL_DR'")
+                .currency("'USD'")
+                .account_id("params.credit_loss_expense_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'WRITE_OFF_LIQUIDATION_SHORTFALL_CR'")
+                .currency("'USD'")
+                .account_id("params.receivable_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = WriteOffLiquidationShortfallParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(WRITE_OFF_LIQUIDATION_SHORTFALL_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod collateral;
 mod config;
 mod credit_facility;
 mod disbursal;
+mod disbursal_payout;
 pub mod error;
 mod event;
 mod for_subject;
@@ -11,13 +12,17 @@ mod interest_accrual_cycle;
 mod jobs;
 pub mod ledger;
 mod liquidation_process;
+mod margin_call;
 mod obligation;
 mod payment;
 mod payment_allocation;
+mod payment_rail;
 mod primitives;
 mod processes;
 mod publisher;
+mod reference_rate;
 mod repayment_plan;
+mod stress_test;
 mod terms;
 mod terms_template;
 mod time;
@@ -29,7 +34,8 @@ use core_custody::{
     CoreCustody, CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject, CustodianId,
 };
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
-use core_price::Price;
+use core_price::{CorePriceEvent, Price};
+use document_storage::DocumentStorage;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
@@ -44,6 +50,7 @@ pub use config::*;
 pub use credit_facility::error::CreditFacilityError;
 pub use credit_facility::*;
 pub use disbursal::{disbursal_cursor::*, *};
+pub use disbursal_payout::{error as disbursal_payout_error, *};
 use error::*;
 pub use event::*;
 use for_subject::CreditFacilitiesForSubject;
@@ -51,15 +58,26 @@ pub use history::*;
 pub use interest_accrual_cycle::*;
 use jobs::*;
 pub use ledger::*;
+pub use liquidation_process::LiquidationProcess;
+pub use margin_call::{error::*, *};
 pub use obligation::{error::*, obligation_cursor::*, *};
 pub use payment::*;
 pub use payment_allocation::*;
+pub use payment_rail::{
+    FileBatchPaymentRail, MockPaymentRail, PaymentInstruction, PaymentOutcome, PaymentRail,
+    PaymentRailNotification, PaymentRailSubmission, WebhookPaymentRail,
+    error as payment_rail_error,
+};
 pub use primitives::*;
 use processes::activate_credit_facility::*;
+pub use processes::approve_collateral_withdrawal::*;
 pub use processes::approve_credit_facility::*;
+pub use processes::approve_credit_facility_restructuring::*;
 pub use processes::approve_disbursal::*;
 use publisher::CreditFacilityPublisher;
+pub use reference_rate::{error as reference_rate_error, *};
 pub use repayment_plan::*;
+pub use stress_test::{error as price_shock_simulation_error, *};
 pub use terms::*;
 pub use terms_template::{error as terms_template_error, *};
 
@@ -68,8 +86,9 @@ pub mod event_schema {
     pub use crate::{
         TermsTemplateEvent, collateral::CollateralEvent, credit_facility::CreditFacilityEvent,
         disbursal::DisbursalEvent, interest_accrual_cycle::InterestAccrualCycleEvent,
-        liquidation_process::LiquidationProcessEvent, obligation::ObligationEvent,
-        payment::PaymentEvent, payment_allocation::PaymentAllocationEvent,
+        liquidation_process::LiquidationProcessEvent, margin_call::MarginCallEvent,
+        obligation::ObligationEvent, payment::PaymentEvent,
+        payment_allocation::PaymentAllocationEvent, reference_rate::ReferenceRateEvent,
     };
 }
 
@@ -79,11 +98,13 @@ where
     E: OutboxEventMarker<CoreCreditEvent>
         + OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<CoreCustodyEvent>
-        + OutboxEventMarker<CoreCustomerEvent>,
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     authz: Perms,
     facilities: CreditFacilities<Perms, E>,
     disbursals: Disbursals<Perms, E>,
+    disbursal_payouts: DisbursalPayouts<Perms, E>,
     payments: Payments<Perms, E>,
     history_repo: HistoryRepo,
     repayment_plan_repo: RepaymentPlanRepo,
@@ -97,9 +118,12 @@ where
     approve_credit_facility: ApproveCreditFacility<Perms, E>,
     obligations: Obligations<Perms, E>,
     collaterals: Collaterals<Perms, E>,
+    margin_calls: MarginCalls<Perms, E>,
     custody: CoreCustody<Perms, E>,
     chart_of_accounts_integrations: ChartOfAccountsIntegrations<Perms>,
     terms_templates: TermsTemplates<Perms>,
+    reference_rates: ReferenceRates<Perms>,
+    price_shock_simulations: PriceShockSimulations<Perms, E>,
 }
 
 impl<Perms, E> Clone for CoreCredit<Perms, E>
@@ -108,7 +132,8 @@ where
     E: OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<CoreCreditEvent>
         + OutboxEventMarker<CoreCustodyEvent>
-        + OutboxEventMarker<CoreCustomerEvent>,
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -116,8 +141,10 @@ where
             facilities: self.facilities.clone(),
             obligations: self.obligations.clone(),
             collaterals: self.collaterals.clone(),
+            margin_calls: self.margin_calls.clone(),
             custody: self.custody.clone(),
             disbursals: self.disbursals.clone(),
+            disbursal_payouts: self.disbursal_payouts.clone(),
             payments: self.payments.clone(),
             history_repo: self.history_repo.clone(),
             repayment_plan_repo: self.repayment_plan_repo.clone(),
@@ -131,6 +158,8 @@ where
             approve_credit_facility: self.approve_credit_facility.clone(),
             chart_of_accounts_integrations: self.chart_of_accounts_integrations.clone(),
             terms_templates: self.terms_templates.clone(),
+            reference_rates: self.reference_rates.clone(),
+            price_shock_simulations: self.price_shock_simulations.clone(),
         }
     }
 }
@@ -149,7 +178,8 @@ where
     E: OutboxEventMarker<GovernanceEvent>
         + OutboxEventMarker<CoreCreditEvent>
         + OutboxEventMarker<CoreCustodyEvent>
-        + OutboxEventMarker<CoreCustomerEvent>,
+        + OutboxEventMarker<CoreCustomerEvent>
+        + OutboxEventMarker<CorePriceEvent>,
 {
     #[allow(clippy::too_many_arguments)]
     pub async fn init(
@@ -161,6 +191,7 @@ where
         customer: &Customers<Perms, E>,
         custody: &CoreCustody<Perms, E>,
         price: &Price,
+        document_storage: DocumentStorage,
         outbox: &Outbox<E>,
         cala: &CalaLedger,
         journal_id: cala_ledger::JournalId,
@@ -168,18 +199,28 @@ where
         let publisher = CreditFacilityPublisher::new(outbox);
         let ledger = CreditLedger::init(cala, journal_id).await?;
         let obligations = Obligations::new(pool, authz, cala, jobs, &publisher);
+        let reference_rates = ReferenceRates::new(pool, authz);
+        let collaterals = Collaterals::new(pool, authz, &publisher, governance).await;
         let credit_facilities = CreditFacilities::new(
             pool,
             authz,
             &obligations,
+            &collaterals,
+            &reference_rates,
             &ledger,
             price,
             &publisher,
             governance,
         )
         .await;
-        let collaterals = Collaterals::new(pool, authz, &publisher);
+        let margin_calls = MarginCalls::new(pool, authz, &publisher);
         let disbursals = Disbursals::new(pool, authz, &publisher, &obligations, governance).await;
+        let disbursal_payouts = DisbursalPayouts::new(
+            authz,
+            &disbursals,
+            &ledger,
+            config.payment_rail.as_ref().map(payment_rail::from_config),
+        );
         let payments = Payments::new(pool, authz, &obligations, &publisher);
         let history_repo = HistoryRepo::new(pool);
         let repayment_plan_repo = RepaymentPlanRepo::new(pool);
@@ -198,15 +239,16 @@ where
         );
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(authz, &ledger);
         let terms_templates = TermsTemplates::new(pool, authz);
+        let price_shock_simulations =
+            PriceShockSimulations::new(authz, &credit_facilities, price, document_storage);
 
         jobs
             .add_initializer_and_spawn_unique(
                 collateralization_from_price::CreditFacilityCollateralizationFromPriceInit::<
                     Perms,
                     E,
-                >::new(credit_facilities.clone()),
+                >::new(outbox, &credit_facilities),
                 collateralization_from_price::CreditFacilityCollateralizationFromPriceJobConfig {
-                    job_interval: std::time::Duration::from_secs(30),
                     upgrade_buffer_cvl_pct: config.upgrade_buffer_cvl_pct,
                     _phantom: std::marker::PhantomData,
                 },
@@ -224,6 +266,40 @@ where
                 },
             )
             .await?;
+        jobs.add_initializer_and_spawn_unique(
+            margin_calls::MarginCallsInit::<Perms, E>::new(
+                outbox,
+                &margin_calls,
+                &credit_facilities,
+                jobs,
+            ),
+            margin_calls::MarginCallsJobConfig {
+                cure_period_days: config.margin_call_cure_period_days,
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
+        jobs.add_initializer(
+            margin_call_deadline::MarginCallDeadlineInit::<Perms, E>::new(
+                &margin_calls,
+                &credit_facilities,
+                &obligations,
+                &ledger,
+            ),
+        );
+        jobs.add_initializer_and_spawn_unique(
+            unapplied_funds::ApplyUnappliedFundsInit::<Perms, E>::new(
+                outbox,
+                &credit_facilities,
+                &payments,
+                &ledger,
+                authz.audit(),
+            ),
+            unapplied_funds::ApplyUnappliedFundsJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             credit_facility_history::HistoryProjectionInit::<E>::new(outbox, &history_repo),
             credit_facility_history::HistoryProjectionConfig {
@@ -273,18 +349,89 @@ where
             ),
         );
         jobs.add_initializer(
-            obligation_defaulted::ObligationDefaultedInit::<Perms, E>::new(&ledger, &obligations),
+            obligation_defaulted::ObligationDefaultedInit::<Perms, E>::new(
+                &ledger,
+                &obligations,
+                jobs,
+            ),
         );
+        jobs.add_initializer(obligation_penalty::ObligationPenaltyInit::<Perms, E>::new(
+            &ledger,
+            &obligations,
+            &CreditFacilityRepo::new(pool, &publisher),
+        ));
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityApprovalInit::new(outbox, &approve_credit_facility),
             CreditFacilityApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            CreditFacilityRestructuringApprovalInit::new(
+                outbox,
+                &ApproveCreditFacilityRestructuring::new(&credit_facilities, &ledger, jobs),
+            ),
+            CreditFacilityRestructuringApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+        jobs.add_initializer_and_spawn_unique(
+            CollateralWithdrawalApprovalInit::new(
+                outbox,
+                &ApproveCollateralWithdrawal::new(
+                    &collaterals,
+                    &credit_facilities,
+                    &ledger,
+                    price,
+                    authz.audit(),
+                ),
+            ),
+            CollateralWithdrawalApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+        jobs.add_initializer_and_spawn_unique(
+            collateral_withdrawals::CollateralWithdrawalsInit::<Perms, E>::new(
+                outbox,
+                &collaterals,
+                custody,
+                &ledger,
+                authz.audit(),
+            ),
+            collateral_withdrawals::CollateralWithdrawalsJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             DisbursalApprovalInit::new(outbox, &approve_disbursal),
             DisbursalApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            disbursal_payouts::DisbursalPayoutsInit::<Perms, E>::new(
+                outbox,
+                &disbursals,
+                &disbursal_payouts,
+            ),
+            disbursal_payouts::DisbursalPayoutsJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
+        jobs.add_initializer_and_spawn_unique(
+            facility_fees::FacilityFeesInit::<Perms, E>::new(
+                outbox,
+                &credit_facilities,
+                &disbursals,
+                &ledger,
+            ),
+            facility_fees::FacilityFeesJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
+        jobs.add_initializer(facility_maintenance_fee::FacilityMaintenanceFeeInit::<
+            Perms,
+            E,
+        >::new(&credit_facilities, &ledger));
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityActivationInit::new(outbox, &activate_credit_facility),
             CreditFacilityActivationJobConfig::<Perms, E>::new(),
@@ -297,8 +444,10 @@ where
             facilities: credit_facilities,
             obligations,
             collaterals,
+            margin_calls,
             custody: custody.clone(),
             disbursals,
+            disbursal_payouts,
             payments,
             history_repo,
             repayment_plan_repo,
@@ -311,6 +460,8 @@ where
             approve_credit_facility,
             chart_of_accounts_integrations,
             terms_templates,
+            reference_rates,
+            price_shock_simulations,
         })
     }
 
@@ -322,10 +473,18 @@ where
         &self.collaterals
     }
 
+    pub fn margin_calls(&self) -> &MarginCalls<Perms, E> {
+        &self.margin_calls
+    }
+
     pub fn disbursals(&self) -> &Disbursals<Perms, E> {
         &self.disbursals
     }
 
+    pub fn disbursal_payouts(&self) -> &DisbursalPayouts<Perms, E> {
+        &self.disbursal_payouts
+    }
+
     pub fn facilities(&self) -> &CreditFacilities<Perms, E> {
         &self.facilities
     }
@@ -342,6 +501,14 @@ where
         &self.terms_templates
     }
 
+    pub fn reference_rates(&self) -> &ReferenceRates<Perms> {
+        &self.reference_rates
+    }
+
+    pub fn price_shock_simulations(&self) -> &PriceShockSimulations<Perms, E> {
+        &self.price_shock_simulations
+    }
+
     pub async fn subject_can_create(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -374,9 +541,12 @@ where
             &self.facilities,
             &self.disbursals,
             &self.payments,
+            &self.collaterals,
+            &self.customer,
             &self.history_repo,
             &self.repayment_plan_repo,
             &self.ledger,
+            &self.price,
         ))
     }
 
@@ -405,6 +575,12 @@ where
             return Err(CoreCreditError::CustomerNotActive);
         }
 
+        if let Some(floating_rate) = terms.floating_rate {
+            self.reference_rates
+                .find_by_id_without_audit(floating_rate.reference_rate_id)
+                .await?;
+        }
+
         let id = CreditFacilityId::new();
         let account_ids = CreditFacilityAccountIds::new();
         let collateral_id = CollateralId::new();
@@ -452,6 +628,7 @@ where
                 id,
                 wallet_id,
                 account_ids.collateral_account_id,
+                CollateralAsset::Btc,
             )
             .await?;
 
@@ -530,6 +707,40 @@ where
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         credit_facility_id: CreditFacilityId,
         amount: UsdCents,
+    ) -> Result<Disbursal, CoreCreditError> {
+        self.initiate_disbursal_with_payout(sub, credit_facility_id, amount, None)
+            .await
+    }
+
+    /// Initiates a disbursal that is paid out over the payment rail to one of
+    /// the customer's beneficiary bank accounts once approved.
+    #[instrument(
+        name = "credit_facility.initiate_disbursal_to_beneficiary",
+        skip(self),
+        err
+    )]
+    pub async fn initiate_disbursal_to_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        beneficiary_account_id: BeneficiaryAccountId,
+    ) -> Result<Disbursal, CoreCreditError> {
+        self.initiate_disbursal_with_payout(
+            sub,
+            credit_facility_id,
+            amount,
+            Some(beneficiary_account_id),
+        )
+        .await
+    }
+
+    async fn initiate_disbursal_with_payout(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        beneficiary_account_id: Option<BeneficiaryAccountId>,
     ) -> Result<Disbursal, CoreCreditError> {
         let audit_info = self
             .subject_can_initiate_disbursal(sub, true)
@@ -541,6 +752,10 @@ where
             .find_by_id_without_audit(credit_facility_id)
             .await?;
 
+        if beneficiary_account_id.is_some() && !self.disbursal_payouts.is_configured() {
+            return Err(CoreCreditError::PaymentRailNotConfigured);
+        }
+
         let customer_id = facility.customer_id;
         let customer = self
             .customer
@@ -550,6 +765,14 @@ where
         if self.config.customer_active_check_enabled && customer.status.is_inactive() {
             return Err(CoreCreditError::CustomerNotActive);
         }
+        let beneficiary_account = beneficiary_account_id
+            .map(|id| {
+                customer
+                    .beneficiary_account(id)
+                    .cloned()
+                    .ok_or(CoreCreditError::BeneficiaryAccountNotFound(id))
+            })
+            .transpose()?;
 
         if !facility.is_activated() {
             return Err(CreditFacilityError::NotActivatedYet.into());
@@ -590,6 +813,7 @@ where
             .due_date(due_date)
             .overdue_date(overdue_date)
             .liquidation_date(liquidation_date)
+            .beneficiary_account(beneficiary_account)
             .audit_info(audit_info)
             .build()?;
 
@@ -667,7 +891,7 @@ where
             .record_manual_collateral_update_in_op(
                 &mut db,
                 credit_facility.collateral_id,
-                updated_collateral,
+                updated_collateral.into(),
                 effective,
                 &audit_info,
             )
@@ -679,7 +903,102 @@ where
         };
 
         self.ledger
-            .update_credit_facility_collateral(db, collateral_update, credit_facility.account_ids)
+            .update_credit_facility_collateral(db, collateral_update)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
+    /// Allows `asset` to be pledged to the facility alongside its BTC
+    /// collateral. The pledged amount starts at zero.
+    #[instrument(name = "credit_facility.add_collateral_asset", skip(self), err)]
+    pub async fn add_collateral_asset(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        asset: CollateralAsset,
+    ) -> Result<Collateral, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.subject_can_update_collateral(sub, true).await?;
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        if self
+            .collaterals
+            .find_for_credit_facility_and_asset_without_audit(credit_facility.id, asset)
+            .await?
+            .is_some()
+        {
+            return Err(collateral::error::CollateralError::AssetAlreadyPledged(asset).into());
+        }
+
+        let account_id = CalaAccountId::new();
+        let mut db = self.collaterals.begin_op().await?;
+        let collateral = self
+            .collaterals
+            .create_in_op(
+                &mut db,
+                CollateralId::new(),
+                credit_facility.id,
+                None,
+                account_id,
+                asset,
+            )
+            .await?;
+        self.ledger
+            .create_collateral_account(db, credit_facility.id, asset, account_id)
+            .await?;
+
+        Ok(collateral)
+    }
+
+    /// Sets the pledged amount of a non-BTC asset, in its smallest unit.
+    #[instrument(name = "credit_facility.update_collateral_asset", skip(self), err)]
+    pub async fn update_collateral_asset(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        asset: CollateralAsset,
+        updated_units: u64,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+        let effective = effective.into();
+
+        let audit_info = self
+            .subject_can_update_collateral(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        let collateral = self
+            .collaterals
+            .find_for_credit_facility_and_asset_without_audit(credit_facility.id, asset)
+            .await?
+            .ok_or(collateral::error::CollateralError::AssetNotPledged(asset))?;
+
+        let mut db = self.collaterals.begin_op().await?;
+        let Some(collateral_update) = self
+            .collaterals
+            .record_manual_collateral_update_in_op(
+                &mut db,
+                collateral.id,
+                CollateralAmount::new(asset, updated_units),
+                effective,
+                &audit_info,
+            )
+            .await?
+        else {
+            return Ok(credit_facility);
+        };
+
+        self.ledger
+            .update_credit_facility_collateral(db, collateral_update)
             .await?;
 
         Ok(credit_facility)
@@ -712,6 +1031,11 @@ where
     ) -> Result<CreditFacility, CoreCreditError> {
         let credit_facility_id = credit_facility_id.into();
 
+        let audit_info = self
+            .subject_can_record_payment(sub, true)
+            .await?
+            .expect("audit info missing");
+
         let credit_facility = self
             .facilities
             .find_by_id_without_audit(credit_facility_id)
@@ -721,11 +1045,421 @@ where
 
         let allocations = self
             .payments
-            .record_in_op(sub, &mut db, credit_facility_id, amount, effective)
+            .record_in_op(
+                &mut db,
+                PaymentId::new(),
+                &credit_facility,
+                amount,
+                effective,
+                vec![],
+                &audit_info,
+            )
             .await?;
 
+        let allocated = allocations
+            .iter()
+            .fold(UsdCents::ZERO, |total, allocation| {
+                total + allocation.amount
+            });
+        let (credit_facility, unapplied_funds) = if allocated < amount {
+            let (credit_facility, unapplied_funds) = self
+                .facilities
+                .record_unapplied_funds_in_op(
+                    &mut db,
+                    credit_facility_id,
+                    amount - allocated,
+                    effective.into(),
+                    &audit_info,
+                )
+                .await?;
+            (credit_facility, Some(unapplied_funds))
+        } else {
+            (credit_facility, None)
+        };
+
         self.ledger
-            .record_obligation_repayments(db, allocations)
+            .record_obligation_repayments(db, allocations, unapplied_funds)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
+    pub async fn subject_can_record_liquidation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_RECORD_LIQUIDATION,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(
+        name = "credit_facility.send_collateral_to_liquidator",
+        skip(self),
+        err
+    )]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn send_collateral_to_liquidator(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        amount: Satoshis,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .subject_can_record_liquidation(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(liquidation_process.credit_facility_id)
+            .await?;
+        let collateral = self
+            .collaterals
+            .find_by_id_without_audit(credit_facility.collateral_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let (liquidation_process, transfer) = self
+            .obligations
+            .send_collateral_to_liquidator_in_op(
+                &mut db,
+                liquidation_process_id,
+                amount,
+                Satoshis::try_from(collateral.amount)
+                    .map_err(collateral::error::CollateralError::from)?,
+                effective.into(),
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger
+            .send_collateral_to_liquidator(db, transfer)
+            .await?;
+
+        Ok(liquidation_process)
+    }
+
+    #[instrument(name = "credit_facility.record_liquidation_sale", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn record_liquidation_sale(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        sold: Satoshis,
+        proceeds: UsdCents,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+        let effective = effective.into();
+
+        let audit_info = self
+            .subject_can_record_liquidation(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(liquidation_process.credit_facility_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let tx_id = self
+            .collaterals
+            .record_liquidation_sale_in_op(
+                &mut db,
+                credit_facility.collateral_id,
+                sold,
+                effective,
+                &audit_info,
+            )
+            .await?
+            .tx_id;
+
+        let (liquidation_process, sale) = self
+            .obligations
+            .record_liquidation_sale_in_op(
+                &mut db,
+                liquidation_process_id,
+                tx_id,
+                sold,
+                proceeds,
+                credit_facility.account_ids.collateral_account_id,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger.record_liquidation_sale(db, sale).await?;
+
+        Ok(liquidation_process)
+    }
+
+    #[instrument(name = "credit_facility.apply_liquidation_proceeds", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn apply_liquidation_proceeds(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .subject_can_record_liquidation(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let amount = std::cmp::min(amount, liquidation_process.unapplied_proceeds());
+        if amount.is_zero() {
+            return Ok(liquidation_process);
+        }
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(liquidation_process.credit_facility_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let (allocations, conclusion) = self
+            .payments
+            .record_liquidation_proceeds_in_op(
+                &mut db,
+                &credit_facility,
+                liquidation_process_id,
+                amount,
+                self.ledger.liquidation_proceeds_account_id(),
+                effective.into(),
+                &audit_info,
+            )
+            .await?;
+        if allocations.is_empty() {
+            return Ok(liquidation_process);
+        }
+
+        self.ledger
+            .apply_liquidation_proceeds(db, allocations, conclusion)
+            .await?;
+
+        Ok(self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.return_liquidation_surplus", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn return_liquidation_surplus(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .subject_can_record_liquidation(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(liquidation_process.credit_facility_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let Some((surplus, conclusion)) = self
+            .obligations
+            .return_liquidation_surplus_in_op(
+                &mut db,
+                liquidation_process_id,
+                credit_facility.disbursal_credit_account_id,
+                effective.into(),
+                &audit_info,
+            )
+            .await?
+        else {
+            return Ok(liquidation_process);
+        };
+
+        self.ledger
+            .return_liquidation_surplus(db, surplus, conclusion)
+            .await?;
+
+        Ok(self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?)
+    }
+
+    #[instrument(
+        name = "credit_facility.write_off_liquidation_shortfall",
+        skip(self),
+        err
+    )]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn write_off_liquidation_shortfall(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .subject_can_record_liquidation(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let Some((write_off, conclusion)) = self
+            .obligations
+            .write_off_liquidation_shortfall_in_op(
+                &mut db,
+                liquidation_process_id,
+                effective.into(),
+                &audit_info,
+            )
+            .await?
+        else {
+            return Ok(self
+                .obligations
+                .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+                .await?);
+        };
+
+        self.ledger
+            .write_off_liquidation_shortfall(db, write_off, conclusion)
+            .await?;
+
+        Ok(self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?)
+    }
+
+    pub async fn subject_can_reverse_payment(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_REVERSE_PAYMENT,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.reverse_payment", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn reverse_payment(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        payment_id: impl Into<PaymentId> + std::fmt::Debug + Copy,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let payment_id = payment_id.into();
+
+        let audit_info = self
+            .subject_can_reverse_payment(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let payment = self.payments.find_by_id_without_audit(payment_id).await?;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(payment.credit_facility_id)
+            .await?;
+        if credit_facility.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted.into());
+        }
+
+        let effective = crate::time::now().date_naive();
+        let mut db = self.facilities.begin_op().await?;
+
+        let Some((payment, mut reversals)) = self
+            .payments
+            .reverse_in_op(&mut db, payment_id, effective, &audit_info)
+            .await?
+        else {
+            return Ok(credit_facility);
+        };
+
+        let unallocated = payment.unallocated_amount();
+        let (credit_facility, unapplied_funds) = if !unallocated.is_zero() {
+            // The unallocated part may already have been applied to later
+            // obligations. Unwind those applications, most recent first, until
+            // the pool covers the reversal again.
+            let mut returned = UsdCents::ZERO;
+            for applied_payment_id in credit_facility.unapplied_funds_payment_ids() {
+                if credit_facility.unapplied_funds() + returned >= unallocated {
+                    break;
+                }
+                let Some((_, unwound)) = self
+                    .payments
+                    .reverse_in_op(&mut db, applied_payment_id, effective, &audit_info)
+                    .await?
+                else {
+                    continue;
+                };
+                returned += unwound
+                    .iter()
+                    .fold(UsdCents::ZERO, |total, reversal| total + reversal.amount);
+                reversals.extend(unwound);
+            }
+
+            let (credit_facility, unapplied_funds) = self
+                .facilities
+                .reverse_unapplied_funds_in_op(
+                    &mut db,
+                    payment.credit_facility_id,
+                    unallocated,
+                    returned,
+                    effective,
+                    &audit_info,
+                )
+                .await?;
+            (credit_facility, Some(unapplied_funds))
+        } else {
+            (credit_facility, None)
+        };
+
+        self.ledger
+            .reverse_payment(db, reversals, unapplied_funds)
             .await?;
 
         Ok(credit_facility)
@@ -771,17 +1505,24 @@ where
             CompletionOutcome::Ignored(facility) => facility,
 
             CompletionOutcome::Completed((facility, completion)) => {
+                let effective = crate::time::now().date_naive();
                 self.collaterals
                     .record_manual_collateral_update_in_op(
                         &mut db,
                         facility.collateral_id,
-                        Satoshis::ZERO,
-                        crate::time::now().date_naive(),
+                        Satoshis::ZERO.into(),
+                        effective,
                         &audit_info,
                     )
                     .await?;
+                let released_assets = self
+                    .collaterals
+                    .release_assets_in_op(&mut db, facility.id, effective, &audit_info)
+                    .await?;
 
-                self.ledger.complete_credit_facility(db, completion).await?;
+                self.ledger
+                    .complete_credit_facility(db, completion, released_assets)
+                    .await?;
                 facility
             }
         };
@@ -789,6 +1530,141 @@ where
         Ok(credit_facility)
     }
 
+    pub async fn subject_can_prepay(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_PREPAY,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.prepay", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true, max_retries = 15)]
+    pub async fn prepay_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let id = credit_facility_id.into();
+
+        let audit_info = self
+            .subject_can_prepay(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let PrepaymentData {
+            credit_facility,
+            prepayment,
+            interest_obligation,
+        } = match self
+            .facilities
+            .prepay_in_op(&mut db, id, &audit_info)
+            .await?
+        {
+            PrepaymentOutcome::Ignored(facility) => return Ok(facility),
+            PrepaymentOutcome::Prepaid(data) => data,
+        };
+
+        let allocations = self
+            .payments
+            .record_in_op(
+                &mut db,
+                PaymentId::new(),
+                &credit_facility,
+                prepayment.outstanding,
+                prepayment.effective,
+                interest_obligation.into_iter().collect(),
+                &audit_info,
+            )
+            .await?;
+
+        let allocated = allocations
+            .iter()
+            .fold(UsdCents::ZERO, |total, allocation| {
+                total + allocation.amount
+            });
+        if allocated != prepayment.outstanding {
+            return Err(CreditFacilityError::PrepaymentNotFullyAllocated(
+                allocated,
+                prepayment.outstanding,
+            )
+            .into());
+        }
+
+        self.collaterals
+            .record_manual_collateral_update_in_op(
+                &mut db,
+                credit_facility.collateral_id,
+                Satoshis::ZERO.into(),
+                prepayment.effective,
+                &audit_info,
+            )
+            .await?;
+        let released_assets = self
+            .collaterals
+            .release_assets_in_op(
+                &mut db,
+                credit_facility.id,
+                prepayment.effective,
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger
+            .record_prepayment(db, prepayment, allocations, released_assets)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
+    pub async fn subject_can_restructure(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_RESTRUCTURE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit_facility.restructure", skip(self, terms), err)]
+    pub async fn restructure_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        terms: TermValues,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_restructure(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut db = self.facilities.begin_op().await?;
+        let credit_facility = self
+            .facilities
+            .restructure_in_op(&mut db, credit_facility_id.into(), terms, audit_info)
+            .await?;
+        db.commit().await?;
+
+        Ok(credit_facility)
+    }
+
     pub async fn can_be_completed(&self, entity: &CreditFacility) -> Result<bool, CoreCreditError> {
         Ok(self.outstanding(entity).await?.is_zero())
     }
@@ -8,6 +8,8 @@ use es_entity::*;
 
 use crate::primitives::*;
 
+use super::error::LiquidationProcessError;
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -23,11 +25,81 @@ pub enum LiquidationProcessEvent {
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
+    CollateralSentToLiquidator {
+        ledger_tx_id: LedgerTxId,
+        amount: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    SaleRecorded {
+        ledger_tx_id: LedgerTxId,
+        sold: Satoshis,
+        proceeds: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    ProceedsApplied {
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    SurplusReturned {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    ShortfallWrittenOff {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     Completed {
+        ledger_tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
 }
 
+#[derive(Debug, Clone)]
+pub struct LiquidationCollateralTransfer {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: Satoshis,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationSale {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub sold: Satoshis,
+    pub proceeds: UsdCents,
+    pub collateral_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationSurplus {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub deposit_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+/// Releases the amount reserved when the liquidation was started.
+#[derive(Debug, Clone)]
+pub struct LiquidationConclusion {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub in_liquidation_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
 #[derive(EsEntity, Builder)]
 #[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
 pub struct LiquidationProcess {
@@ -41,6 +113,299 @@ pub struct LiquidationProcess {
     events: EntityEvents<LiquidationProcessEvent>,
 }
 
+impl LiquidationProcess {
+    pub fn is_completed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, LiquidationProcessEvent::Completed { .. }))
+    }
+
+    pub fn collateral_sent_to_liquidator(&self) -> Satoshis {
+        self.events
+            .iter_all()
+            .fold(Satoshis::ZERO, |total, e| match e {
+                LiquidationProcessEvent::CollateralSentToLiquidator { amount, .. } => {
+                    total + *amount
+                }
+                _ => total,
+            })
+    }
+
+    pub fn collateral_sold(&self) -> Satoshis {
+        self.events
+            .iter_all()
+            .fold(Satoshis::ZERO, |total, e| match e {
+                LiquidationProcessEvent::SaleRecorded { sold, .. } => total + *sold,
+                _ => total,
+            })
+    }
+
+    pub fn collateral_at_liquidator(&self) -> Satoshis {
+        self.collateral_sent_to_liquidator() - self.collateral_sold()
+    }
+
+    pub fn proceeds(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, e| match e {
+                LiquidationProcessEvent::SaleRecorded { proceeds, .. } => total + *proceeds,
+                _ => total,
+            })
+    }
+
+    /// Proceeds received from the liquidator that have neither been applied
+    /// to the obligation nor returned to the borrower.
+    pub fn unapplied_proceeds(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, e| match e {
+                LiquidationProcessEvent::SaleRecorded { proceeds, .. } => total + *proceeds,
+                LiquidationProcessEvent::ProceedsApplied { amount, .. }
+                | LiquidationProcessEvent::SurplusReturned { amount, .. } => total - *amount,
+                _ => total,
+            })
+    }
+
+    pub(crate) fn send_collateral_to_liquidator(
+        &mut self,
+        amount: Satoshis,
+        available_collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<LiquidationCollateralTransfer, LiquidationProcessError> {
+        if self.is_completed() {
+            return Err(LiquidationProcessError::AlreadyCompleted);
+        }
+        if amount.is_zero() {
+            return Err(LiquidationProcessError::ZeroAmount);
+        }
+        let at_liquidator = self.collateral_at_liquidator();
+        let held_in_custody = if available_collateral > at_liquidator {
+            available_collateral - at_liquidator
+        } else {
+            Satoshis::ZERO
+        };
+        if amount > held_in_custody {
+            return Err(LiquidationProcessError::InsufficientCollateral(
+                amount,
+                held_in_custody,
+            ));
+        }
+
+        let tx_id = LedgerTxId::new();
+        let tx_ref = format!(
+            "{}-send-collateral-{}",
+            self.id,
+            self.events
+                .iter_all()
+                .filter(|e| matches!(
+                    e,
+                    LiquidationProcessEvent::CollateralSentToLiquidator { .. }
+                ))
+                .count()
+                + 1
+        );
+        self.events
+            .push(LiquidationProcessEvent::CollateralSentToLiquidator {
+                ledger_tx_id: tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Ok(LiquidationCollateralTransfer {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+        })
+    }
+
+    pub(crate) fn record_sale(
+        &mut self,
+        tx_id: LedgerTxId,
+        sold: Satoshis,
+        proceeds: UsdCents,
+        collateral_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<LiquidationSale, LiquidationProcessError> {
+        if self.is_completed() {
+            return Err(LiquidationProcessError::AlreadyCompleted);
+        }
+        if sold.is_zero() {
+            return Err(LiquidationProcessError::ZeroAmount);
+        }
+        let at_liquidator = self.collateral_at_liquidator();
+        if sold > at_liquidator {
+            return Err(LiquidationProcessError::SaleExceedsCollateralAtLiquidator(
+                sold,
+                at_liquidator,
+            ));
+        }
+
+        let tx_ref = format!(
+            "{}-sale-{}",
+            self.id,
+            self.events
+                .iter_all()
+                .filter(|e| matches!(e, LiquidationProcessEvent::SaleRecorded { .. }))
+                .count()
+                + 1
+        );
+        self.events.push(LiquidationProcessEvent::SaleRecorded {
+            ledger_tx_id: tx_id,
+            sold,
+            proceeds,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Ok(LiquidationSale {
+            tx_id,
+            tx_ref,
+            sold,
+            proceeds,
+            collateral_account_id,
+            effective,
+        })
+    }
+
+    /// Records proceeds allocated to the obligation. Completes the process
+    /// once the obligation is settled and nothing is left to return.
+    pub(crate) fn record_proceeds_applied(
+        &mut self,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        obligation_outstanding: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<Option<LiquidationConclusion>> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            LiquidationProcessEvent::ProceedsApplied { payment_id: id, .. } if *id == payment_id
+        );
+        if self.is_completed() {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(LiquidationProcessEvent::ProceedsApplied {
+            payment_id,
+            amount,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        if obligation_outstanding.is_zero() && self.unapplied_proceeds().is_zero() {
+            return Idempotent::Executed(Some(self.complete(effective, audit_info)));
+        }
+
+        Idempotent::Executed(None)
+    }
+
+    pub(crate) fn return_surplus(
+        &mut self,
+        obligation_outstanding: UsdCents,
+        deposit_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<(LiquidationSurplus, LiquidationConclusion)>, LiquidationProcessError>
+    {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            LiquidationProcessEvent::Completed { .. }
+        );
+
+        if !obligation_outstanding.is_zero() {
+            return Err(LiquidationProcessError::ObligationNotSettled(
+                obligation_outstanding,
+            ));
+        }
+        let amount = self.unapplied_proceeds();
+        if amount.is_zero() {
+            return Ok(Idempotent::Ignored);
+        }
+
+        let tx_id = LedgerTxId::new();
+        self.events.push(LiquidationProcessEvent::SurplusReturned {
+            ledger_tx_id: tx_id,
+            amount,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+        let conclusion = self.complete(effective, audit_info);
+
+        Ok(Idempotent::Executed((
+            LiquidationSurplus {
+                tx_id,
+                tx_ref: format!("{}-surplus", self.id),
+                amount,
+                deposit_account_id,
+                effective,
+            },
+            conclusion,
+        )))
+    }
+
+    pub(crate) fn write_off_shortfall(
+        &mut self,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<LiquidationConclusion>, LiquidationProcessError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            LiquidationProcessEvent::Completed { .. }
+        );
+
+        let at_liquidator = self.collateral_at_liquidator();
+        if !at_liquidator.is_zero() {
+            return Err(LiquidationProcessError::CollateralStillAtLiquidator(
+                at_liquidator,
+            ));
+        }
+        let unapplied = self.unapplied_proceeds();
+        if !unapplied.is_zero() {
+            return Err(LiquidationProcessError::UnappliedProceeds(unapplied));
+        }
+        if amount.is_zero() {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events
+            .push(LiquidationProcessEvent::ShortfallWrittenOff {
+                ledger_tx_id: tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        Ok(Idempotent::Executed(self.complete(effective, audit_info)))
+    }
+
+    fn complete(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> LiquidationConclusion {
+        let tx_id = LedgerTxId::new();
+        self.events.push(LiquidationProcessEvent::Completed {
+            ledger_tx_id: tx_id,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        LiquidationConclusion {
+            tx_id,
+            tx_ref: format!("{}-conclude", self.id),
+            amount: self.initial_amount,
+            in_liquidation_account_id: self.in_liquidation_account_id,
+            effective,
+        }
+    }
+}
+
 impl TryFromEvents<LiquidationProcessEvent> for LiquidationProcess {
     fn try_from_events(
         events: EntityEvents<LiquidationProcessEvent>,
@@ -67,6 +432,11 @@ impl TryFromEvents<LiquidationProcessEvent> for LiquidationProcess {
                         .initial_amount(*initial_amount)
                         .effective(*effective)
                 }
+                LiquidationProcessEvent::CollateralSentToLiquidator { .. } => (),
+                LiquidationProcessEvent::SaleRecorded { .. } => (),
+                LiquidationProcessEvent::ProceedsApplied { .. } => (),
+                LiquidationProcessEvent::SurplusReturned { .. } => (),
+                LiquidationProcessEvent::ShortfallWrittenOff { .. } => (),
                 LiquidationProcessEvent::Completed { .. } => (),
             }
         }
@@ -115,3 +485,257 @@ impl IntoEvents<LiquidationProcessEvent> for NewLiquidationProcess {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn effective() -> chrono::NaiveDate {
+        chrono::Utc::now().date_naive()
+    }
+
+    fn liquidation_process() -> LiquidationProcess {
+        let id = LiquidationProcessId::new();
+        let events = vec![LiquidationProcessEvent::Initialized {
+            id,
+            ledger_tx_id: LedgerTxId::new(),
+            obligation_id: ObligationId::new(),
+            credit_facility_id: CreditFacilityId::new(),
+            in_liquidation_account_id: CalaAccountId::new(),
+            initial_amount: UsdCents::from(1_000),
+            effective: effective(),
+            audit_info: dummy_audit_info(),
+        }];
+        LiquidationProcess::try_from_events(EntityEvents::init(id, events)).unwrap()
+    }
+
+    #[test]
+    fn cannot_send_more_collateral_than_held_in_custody() {
+        let mut process = liquidation_process();
+        process
+            .send_collateral_to_liquidator(
+                Satoshis::from(600),
+                Satoshis::from(1_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+
+        let res = process.send_collateral_to_liquidator(
+            Satoshis::from(500),
+            Satoshis::from(1_000),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::InsufficientCollateral(_, _))
+        ));
+    }
+
+    #[test]
+    fn cannot_sell_more_than_sent_to_liquidator() {
+        let mut process = liquidation_process();
+        process
+            .send_collateral_to_liquidator(
+                Satoshis::from(600),
+                Satoshis::from(1_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        process
+            .record_sale(
+                LedgerTxId::new(),
+                Satoshis::from(400),
+                UsdCents::from(500),
+                CalaAccountId::new(),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(process.collateral_at_liquidator(), Satoshis::from(200));
+
+        let res = process.record_sale(
+            LedgerTxId::new(),
+            Satoshis::from(300),
+            UsdCents::from(500),
+            CalaAccountId::new(),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::SaleExceedsCollateralAtLiquidator(
+                _,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn completes_when_proceeds_settle_obligation() {
+        let mut process = liquidation_process();
+        process
+            .send_collateral_to_liquidator(
+                Satoshis::from(1_000),
+                Satoshis::from(1_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        process
+            .record_sale(
+                LedgerTxId::new(),
+                Satoshis::from(1_000),
+                UsdCents::from(1_000),
+                CalaAccountId::new(),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+
+        let conclusion = process
+            .record_proceeds_applied(
+                PaymentId::new(),
+                UsdCents::from(1_000),
+                UsdCents::ZERO,
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .expect("process should be completed");
+        assert_eq!(conclusion.amount, process.initial_amount);
+        assert!(process.is_completed());
+    }
+
+    #[test]
+    fn surplus_returned_once_obligation_settled() {
+        let mut process = liquidation_process();
+        process
+            .send_collateral_to_liquidator(
+                Satoshis::from(1_000),
+                Satoshis::from(1_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        process
+            .record_sale(
+                LedgerTxId::new(),
+                Satoshis::from(1_000),
+                UsdCents::from(1_500),
+                CalaAccountId::new(),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+
+        let res = process.return_surplus(
+            UsdCents::from(1_000),
+            CalaAccountId::new(),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::ObligationNotSettled(_))
+        ));
+
+        let applied = process.record_proceeds_applied(
+            PaymentId::new(),
+            UsdCents::from(1_000),
+            UsdCents::ZERO,
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(applied, Idempotent::Executed(None)));
+        assert_eq!(process.unapplied_proceeds(), UsdCents::from(500));
+
+        let (surplus, _) = process
+            .return_surplus(
+                UsdCents::ZERO,
+                CalaAccountId::new(),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(surplus.amount, UsdCents::from(500));
+        assert!(process.is_completed());
+        assert!(
+            process
+                .return_surplus(
+                    UsdCents::ZERO,
+                    CalaAccountId::new(),
+                    effective(),
+                    &dummy_audit_info(),
+                )
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn shortfall_written_off_once_collateral_sold() {
+        let mut process = liquidation_process();
+        process
+            .send_collateral_to_liquidator(
+                Satoshis::from(1_000),
+                Satoshis::from(1_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+
+        let res = process.write_off_shortfall(
+            LedgerTxId::new(),
+            UsdCents::from(1_000),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::CollateralStillAtLiquidator(_))
+        ));
+
+        process
+            .record_sale(
+                LedgerTxId::new(),
+                Satoshis::from(1_000),
+                UsdCents::from(700),
+                CalaAccountId::new(),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        let _ = process.record_proceeds_applied(
+            PaymentId::new(),
+            UsdCents::from(700),
+            UsdCents::from(300),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(!process.is_completed());
+
+        let res = process
+            .write_off_shortfall(
+                LedgerTxId::new(),
+                UsdCents::from(300),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(res.did_execute());
+        assert!(process.is_completed());
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use core_money::{Satoshis, UsdCents};
+
 #[derive(Error, Debug)]
 pub enum LiquidationProcessError {
     #[error("LiquidationProcessError - Sqlx: {0}")]
@@ -8,6 +10,22 @@ pub enum LiquidationProcessError {
     EsEntityError(es_entity::EsEntityError),
     #[error("LiquidationProcessError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("LiquidationProcessError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("LiquidationProcessError - ZeroAmount")]
+    ZeroAmount,
+    #[error("LiquidationProcessError - InsufficientCollateral: {0} requested, {1} held in custody")]
+    InsufficientCollateral(Satoshis, Satoshis),
+    #[error(
+        "LiquidationProcessError - SaleExceedsCollateralAtLiquidator: {0} sold, {1} at liquidator"
+    )]
+    SaleExceedsCollateralAtLiquidator(Satoshis, Satoshis),
+    #[error("LiquidationProcessError - ObligationNotSettled: {0} outstanding")]
+    ObligationNotSettled(UsdCents),
+    #[error("LiquidationProcessError - CollateralStillAtLiquidator: {0}")]
+    CollateralStillAtLiquidator(Satoshis),
+    #[error("LiquidationProcessError - UnappliedProceeds: {0}")]
+    UnappliedProceeds(UsdCents),
 }
 
 es_entity::from_es_entity_error!(LiquidationProcessError);
@@ -0,0 +1,302 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "MarginCallId")]
+pub enum MarginCallEvent {
+    Initialized {
+        id: MarginCallId,
+        credit_facility_id: CreditFacilityId,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        price: PriceOfOneBTC,
+        cure_deadline: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Cured {
+        reason: MarginCallCureReason,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Escalated {
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct MarginCall {
+    pub id: MarginCallId,
+    pub credit_facility_id: CreditFacilityId,
+    pub collateral: Satoshis,
+    pub outstanding: UsdCents,
+    pub price: PriceOfOneBTC,
+    pub cure_deadline: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    events: EntityEvents<MarginCallEvent>,
+}
+
+impl MarginCall {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn status(&self) -> MarginCallStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                MarginCallEvent::Cured { .. } => Some(MarginCallStatus::Cured),
+                MarginCallEvent::Escalated { .. } => Some(MarginCallStatus::Escalated),
+                _ => None,
+            })
+            .unwrap_or(MarginCallStatus::Open)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status() == MarginCallStatus::Open
+    }
+
+    pub fn cure_reason(&self) -> Option<MarginCallCureReason> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            MarginCallEvent::Cured { reason, .. } => Some(*reason),
+            _ => None,
+        })
+    }
+
+    /// Closes the margin call once the facility is back above the margin call
+    /// threshold. The reason is derived by comparing against the position at
+    /// the time the call was opened.
+    pub(crate) fn cure(
+        &mut self,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<MarginCallCureReason> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            MarginCallEvent::Cured { .. } | MarginCallEvent::Escalated { .. }
+        );
+
+        let reason = if collateral > self.collateral {
+            MarginCallCureReason::CollateralTopUp
+        } else if outstanding < self.outstanding {
+            MarginCallCureReason::Repayment
+        } else {
+            MarginCallCureReason::PriceRecovery
+        };
+
+        self.events.push(MarginCallEvent::Cured {
+            reason,
+            collateral,
+            outstanding,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(reason)
+    }
+
+    pub(crate) fn escalate(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            MarginCallEvent::Cured { .. } | MarginCallEvent::Escalated { .. }
+        );
+
+        self.events.push(MarginCallEvent::Escalated {
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<MarginCallEvent> for MarginCall {
+    fn try_from_events(events: EntityEvents<MarginCallEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = MarginCallBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                MarginCallEvent::Initialized {
+                    id,
+                    credit_facility_id,
+                    collateral,
+                    outstanding,
+                    price,
+                    cure_deadline,
+                    effective,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .credit_facility_id(*credit_facility_id)
+                        .collateral(*collateral)
+                        .outstanding(*outstanding)
+                        .price(*price)
+                        .cure_deadline(*cure_deadline)
+                        .effective(*effective)
+                }
+                MarginCallEvent::Cured { .. } => (),
+                MarginCallEvent::Escalated { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewMarginCall {
+    #[builder(setter(into))]
+    pub(super) id: MarginCallId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    pub(super) collateral: Satoshis,
+    pub(super) outstanding: UsdCents,
+    pub(super) price: PriceOfOneBTC,
+    pub(super) cure_deadline: DateTime<Utc>,
+    pub(super) effective: chrono::NaiveDate,
+    #[builder(setter(into))]
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewMarginCall {
+    pub fn builder() -> NewMarginCallBuilder {
+        NewMarginCallBuilder::default()
+    }
+}
+
+impl IntoEvents<MarginCallEvent> for NewMarginCall {
+    fn into_events(self) -> EntityEvents<MarginCallEvent> {
+        EntityEvents::init(
+            self.id,
+            [MarginCallEvent::Initialized {
+                id: self.id,
+                credit_facility_id: self.credit_facility_id,
+                collateral: self.collateral,
+                outstanding: self.outstanding,
+                price: self.price,
+                cure_deadline: self.cure_deadline,
+                effective: self.effective,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn effective() -> chrono::NaiveDate {
+        Utc::now().date_naive()
+    }
+
+    fn margin_call() -> MarginCall {
+        let id = MarginCallId::new();
+        let events = vec![MarginCallEvent::Initialized {
+            id,
+            credit_facility_id: CreditFacilityId::new(),
+            collateral: Satoshis::from(100_000),
+            outstanding: UsdCents::from(50_000),
+            price: PriceOfOneBTC::new(UsdCents::from(5_000_000)),
+            cure_deadline: Utc::now() + chrono::Duration::days(3),
+            effective: effective(),
+            audit_info: dummy_audit_info(),
+        }];
+        MarginCall::try_from_events(EntityEvents::init(id, events)).unwrap()
+    }
+
+    #[test]
+    fn cure_reason_follows_position_change() {
+        let mut call = margin_call();
+        let reason = call.cure(
+            Satoshis::from(150_000),
+            UsdCents::from(50_000),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            reason,
+            Idempotent::Executed(MarginCallCureReason::CollateralTopUp)
+        ));
+
+        let mut call = margin_call();
+        let reason = call.cure(
+            Satoshis::from(100_000),
+            UsdCents::from(20_000),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            reason,
+            Idempotent::Executed(MarginCallCureReason::Repayment)
+        ));
+
+        let mut call = margin_call();
+        let reason = call.cure(
+            Satoshis::from(100_000),
+            UsdCents::from(50_000),
+            effective(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(
+            reason,
+            Idempotent::Executed(MarginCallCureReason::PriceRecovery)
+        ));
+        assert_eq!(call.status(), MarginCallStatus::Cured);
+    }
+
+    #[test]
+    fn escalated_margin_call_cannot_be_cured() {
+        let mut call = margin_call();
+        assert!(
+            call.escalate(effective(), &dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(call.status(), MarginCallStatus::Escalated);
+
+        assert!(
+            call.cure(
+                Satoshis::from(150_000),
+                UsdCents::from(50_000),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .was_ignored()
+        );
+        assert!(
+            call.escalate(effective(), &dummy_audit_info())
+                .was_ignored()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MarginCallError {
+    #[error("MarginCallError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("MarginCallError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("MarginCallError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("MarginCallError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("MarginCallError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+}
+
+es_entity::from_es_entity_error!(MarginCallError);
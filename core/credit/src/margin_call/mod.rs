@@ -0,0 +1,237 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use es_entity::Idempotent;
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreCreditEvent, primitives::*, publisher::CreditFacilityPublisher};
+
+pub use entity::MarginCall;
+
+#[cfg(feature = "json-schema")]
+pub use entity::MarginCallEvent;
+pub(crate) use entity::*;
+use error::MarginCallError;
+use repo::MarginCallRepo;
+
+pub struct MarginCalls<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    authz: Perms,
+    repo: MarginCallRepo<E>,
+}
+
+impl<Perms, E> Clone for MarginCalls<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> MarginCalls<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(pool: &sqlx::PgPool, authz: &Perms, publisher: &CreditFacilityPublisher<E>) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: MarginCallRepo::new(pool, publisher),
+        }
+    }
+
+    pub(crate) async fn begin_op(&self) -> Result<es_entity::DbOp<'_>, MarginCallError> {
+        Ok(self.repo.begin_op().await?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn open_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        price: PriceOfOneBTC,
+        cure_deadline: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    ) -> Result<Option<MarginCall>, MarginCallError> {
+        if self
+            .find_open_for_credit_facility(credit_facility_id)
+            .await?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE,
+            )
+            .await?;
+
+        let new_margin_call = NewMarginCall::builder()
+            .id(MarginCallId::new())
+            .credit_facility_id(credit_facility_id)
+            .collateral(collateral)
+            .outstanding(outstanding)
+            .price(price)
+            .cure_deadline(cure_deadline)
+            .effective(effective)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new margin call");
+
+        Ok(Some(self.repo.create_in_op(db, new_margin_call).await?))
+    }
+
+    pub(crate) async fn cure_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        collateral: Satoshis,
+        outstanding: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<Option<MarginCall>, MarginCallError> {
+        let Some(mut margin_call) = self
+            .find_open_for_credit_facility(credit_facility_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE,
+            )
+            .await?;
+
+        if margin_call
+            .cure(collateral, outstanding, effective, &audit_info)
+            .was_ignored()
+        {
+            return Ok(None);
+        }
+        self.repo.update_in_op(db, &mut margin_call).await?;
+
+        Ok(Some(margin_call))
+    }
+
+    pub(crate) async fn escalate_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: MarginCallId,
+        effective: chrono::NaiveDate,
+    ) -> Result<Option<MarginCall>, MarginCallError> {
+        let mut margin_call = self.repo.find_by_id(id).await?;
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(margin_call.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE,
+            )
+            .await?;
+
+        if let Idempotent::Ignored = margin_call.escalate(effective, &audit_info) {
+            return Ok(None);
+        }
+        self.repo.update_in_op(db, &mut margin_call).await?;
+
+        Ok(Some(margin_call))
+    }
+
+    pub async fn find_by_id_without_audit(
+        &self,
+        id: impl Into<MarginCallId> + std::fmt::Debug,
+    ) -> Result<MarginCall, MarginCallError> {
+        self.repo.find_by_id(id.into()).await
+    }
+
+    async fn find_open_for_credit_facility(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Option<MarginCall>, MarginCallError> {
+        let res = self
+            .repo
+            .list_for_credit_facility_id_by_created_at(
+                credit_facility_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?;
+
+        Ok(res
+            .entities
+            .into_iter()
+            .find(|margin_call| margin_call.is_open()))
+    }
+
+    #[instrument(
+        name = "core_credit.margin_call.list_for_credit_facility",
+        skip(self),
+        err
+    )]
+    pub async fn list_for_credit_facility_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<MarginCall>, MarginCallError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        let mut margin_calls = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at(
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+
+            margin_calls.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(margin_calls)
+    }
+}
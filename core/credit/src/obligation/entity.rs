@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -9,8 +10,11 @@ use audit::AuditInfo;
 use es_entity::*;
 
 use crate::{
-    CreditFacilityId, liquidation_process::NewLiquidationProcess,
-    payment_allocation::NewPaymentAllocation, primitives::*,
+    CreditFacilityId,
+    liquidation_process::NewLiquidationProcess,
+    payment_allocation::NewPaymentAllocation,
+    primitives::*,
+    terms::{InterestInterval, InterestPeriod, TermValues},
 };
 
 use super::{error::ObligationError, primitives::*};
@@ -55,12 +59,37 @@ pub enum ObligationEvent {
         defaulted_amount: UsdCents,
         audit_info: AuditInfo,
     },
+    Rescheduled {
+        ledger_tx_id: Option<LedgerTxId>,
+        amount: UsdCents,
+        due_date: DateTime<Utc>,
+        overdue_date: Option<DateTime<Utc>>,
+        liquidation_date: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    },
     PaymentAllocated {
         ledger_tx_id: LedgerTxId,
         payment_id: PaymentId,
         payment_allocation_id: PaymentAllocationId,
         payment_allocation_amount: UsdCents,
     },
+    PaymentAllocationReversed {
+        payment_allocation_id: PaymentAllocationId,
+        payment_allocation_amount: UsdCents,
+        audit_info: AuditInfo,
+    },
+    PenaltyAccrued {
+        period: InterestPeriod,
+        accrued: Decimal,
+        audit_info: AuditInfo,
+    },
+    PenaltyPosted {
+        penalty_obligation_id: ObligationId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     LiquidationProcessStarted {
         liquidation_process_id: LiquidationProcessId,
         ledger_tx_id: LedgerTxId,
@@ -68,6 +97,13 @@ pub enum ObligationEvent {
         initial_amount: UsdCents,
         audit_info: AuditInfo,
     },
+    LiquidationShortfallWrittenOff {
+        liquidation_process_id: LiquidationProcessId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     LiquidationProcessConcluded {
         liquidation_process_id: LiquidationProcessId,
         audit_info: AuditInfo,
@@ -101,27 +137,33 @@ impl Obligation {
     pub fn due_at(&self) -> DateTime<Utc> {
         self.events
             .iter_all()
+            .rev()
             .find_map(|e| match e {
-                ObligationEvent::Initialized { due_date, .. } => Some(*due_date),
+                ObligationEvent::Initialized { due_date, .. }
+                | ObligationEvent::Rescheduled { due_date, .. } => Some(*due_date),
                 _ => None,
             })
             .expect("Entity was not Initialized")
     }
 
     pub fn overdue_at(&self) -> Option<DateTime<Utc>> {
-        self.events.iter_all().find_map(|e| match e {
-            ObligationEvent::Initialized { overdue_date, .. } => *overdue_date,
+        self.events.iter_all().rev().find_map(|e| match e {
+            ObligationEvent::Initialized { overdue_date, .. }
+            | ObligationEvent::Rescheduled { overdue_date, .. } => Some(*overdue_date),
             _ => None,
-        })
+        })?
     }
 
     pub fn liquidation_at(&self) -> Option<DateTime<Utc>> {
-        self.events.iter_all().find_map(|e| match e {
+        self.events.iter_all().rev().find_map(|e| match e {
             ObligationEvent::Initialized {
                 liquidation_date, ..
-            } => *liquidation_date,
+            }
+            | ObligationEvent::Rescheduled {
+                liquidation_date, ..
+            } => Some(*liquidation_date),
             _ => None,
-        })
+        })?
     }
 
     pub fn defaulted_at(&self) -> Option<DateTime<Utc>> {
@@ -244,29 +286,24 @@ impl Obligation {
         }
     }
 
-    fn expected_status(&self, now: DateTime<Utc>) -> ObligationStatus {
-        let mut paid = false;
-        let (due_date, overdue_date, defaulted_date) = self
-            .events
+    fn is_paid(&self) -> bool {
+        self.events
             .iter_all()
             .rev()
             .find_map(|e| match e {
-                ObligationEvent::Initialized {
-                    due_date,
-                    overdue_date,
-                    defaulted_date,
-                    ..
-                } => Some((*due_date, *overdue_date, *defaulted_date)),
-                ObligationEvent::Completed { .. } => {
-                    paid = true;
-                    None
-                }
+                ObligationEvent::Completed { .. } => Some(true),
+                ObligationEvent::PaymentAllocationReversed { .. } => Some(false),
                 _ => None,
             })
-            .expect("Entity was not Initialized");
-        if paid {
+            .unwrap_or(false)
+    }
+
+    fn expected_status(&self, now: DateTime<Utc>) -> ObligationStatus {
+        if self.is_paid() {
             return ObligationStatus::Paid;
         }
+        let (due_date, overdue_date, defaulted_date) =
+            (self.due_at(), self.overdue_at(), self.defaulted_at());
 
         if let Some(defaulted_date) = defaulted_date {
             if now >= defaulted_date {
@@ -288,14 +325,20 @@ impl Obligation {
     }
 
     pub fn status(&self) -> ObligationStatus {
+        let mut reopened = false;
         self.events
             .iter_all()
             .rev()
             .find_map(|event| match event {
+                ObligationEvent::Rescheduled { .. } => Some(ObligationStatus::NotYetDue),
                 ObligationEvent::DueRecorded { .. } => Some(ObligationStatus::Due),
                 ObligationEvent::OverdueRecorded { .. } => Some(ObligationStatus::Overdue),
                 ObligationEvent::DefaultedRecorded { .. } => Some(ObligationStatus::Defaulted),
-                ObligationEvent::Completed { .. } => Some(ObligationStatus::Paid),
+                ObligationEvent::Completed { .. } if !reopened => Some(ObligationStatus::Paid),
+                ObligationEvent::PaymentAllocationReversed { .. } => {
+                    reopened = true;
+                    None
+                }
                 _ => None,
             })
             .unwrap_or(ObligationStatus::NotYetDue)
@@ -313,12 +356,24 @@ impl Obligation {
                     ObligationEvent::Initialized { amount, .. } => {
                         total_sum += *amount;
                     }
+                    ObligationEvent::Rescheduled { amount, .. } => {
+                        total_sum = *amount;
+                    }
                     ObligationEvent::PaymentAllocated {
                         payment_allocation_amount: amount,
                         ..
                     } => {
                         total_sum -= *amount;
                     }
+                    ObligationEvent::PaymentAllocationReversed {
+                        payment_allocation_amount: amount,
+                        ..
+                    } => {
+                        total_sum += *amount;
+                    }
+                    ObligationEvent::LiquidationShortfallWrittenOff { amount, .. } => {
+                        total_sum -= *amount;
+                    }
                     _ => (),
                 }
                 total_sum
@@ -345,10 +400,25 @@ impl Obligation {
         &mut self,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
+    ) -> Idempotent<ObligationDueReallocationData> {
+        if effective < self.due_at().date_naive() {
+            return Idempotent::Ignored;
+        }
+
+        self.accelerate(effective, audit_info)
+    }
+
+    /// Makes a not yet due obligation due ahead of its due date, e.g. when an
+    /// uncured margin call sends the facility into liquidation.
+    pub(crate) fn accelerate(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
     ) -> Idempotent<ObligationDueReallocationData> {
         idempotency_guard!(
             self.events.iter_all().rev(),
-            ObligationEvent::DueRecorded { .. }
+            ObligationEvent::DueRecorded { .. },
+            => ObligationEvent::Rescheduled { .. }
         );
 
         match self.status() {
@@ -373,6 +443,103 @@ impl Obligation {
         Idempotent::Executed(res)
     }
 
+    /// Moves the obligation onto a new due date with `amount` outstanding, as part
+    /// of re-amortizing the facility's principal. A due or overdue balance goes
+    /// back to not-yet-due; an obligation rescheduled to zero is completed.
+    pub(crate) fn reschedule(
+        &mut self,
+        due_date: DateTime<Utc>,
+        amount: UsdCents,
+        terms: &TermValues,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<Option<ObligationRescheduleReallocationData>> {
+        if self.is_in_liquidation() {
+            return Idempotent::Ignored;
+        }
+        let receivable_account_id = match self.status() {
+            ObligationStatus::NotYetDue => None,
+            ObligationStatus::Due => Some(self.due_accounts().receivable_account_id),
+            ObligationStatus::Overdue => Some(self.overdue_accounts().receivable_account_id),
+            _ => return Idempotent::Ignored,
+        };
+        let outstanding = self.outstanding();
+        if receivable_account_id.is_none() && self.due_at() == due_date && outstanding == amount {
+            return Idempotent::Ignored;
+        }
+
+        let res = receivable_account_id
+            .filter(|_| !outstanding.is_zero())
+            .map(
+                |receivable_account_id| ObligationRescheduleReallocationData {
+                    tx_id: LedgerTxId::new(),
+                    amount: outstanding,
+                    receivable_account_id,
+                    not_yet_due_account_id: self.not_yet_due_accounts().receivable_account_id,
+                    effective,
+                },
+            );
+
+        self.events.push(ObligationEvent::Rescheduled {
+            ledger_tx_id: res.as_ref().map(|res| res.tx_id),
+            amount,
+            due_date,
+            overdue_date: terms
+                .obligation_overdue_duration_from_due
+                .map(|d| d.end_date(due_date)),
+            liquidation_date: terms
+                .obligation_liquidation_duration_from_due
+                .map(|d| d.end_date(due_date)),
+            audit_info: audit_info.clone(),
+        });
+        if amount.is_zero() {
+            self.events.push(ObligationEvent::Completed {
+                effective,
+                audit_info: audit_info.clone(),
+            });
+        }
+
+        Idempotent::Executed(res)
+    }
+
+    /// Builds an additional principal installment booked against the same
+    /// accounts, for when re-amortizing needs more installments than exist.
+    pub(crate) fn new_installment(
+        &self,
+        amount: UsdCents,
+        due_date: DateTime<Utc>,
+        terms: &TermValues,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> NewObligation {
+        NewObligation::builder()
+            .id(ObligationId::new())
+            .credit_facility_id(self.credit_facility_id)
+            .obligation_type(self.obligation_type)
+            .amount(amount)
+            .tx_id(self.tx_id)
+            .not_yet_due_accounts(self.not_yet_due_accounts())
+            .due_accounts(self.due_accounts())
+            .overdue_accounts(self.overdue_accounts())
+            .in_liquidation_account_id(self.in_liquidation_account())
+            .defaulted_account_id(self.defaulted_account())
+            .due_date(due_date)
+            .overdue_date(
+                terms
+                    .obligation_overdue_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .liquidation_date(
+                terms
+                    .obligation_liquidation_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .effective(effective)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new installment obligation")
+    }
+
     pub(crate) fn record_overdue(
         &mut self,
         effective: chrono::NaiveDate,
@@ -380,8 +547,15 @@ impl Obligation {
     ) -> Result<Idempotent<ObligationOverdueReallocationData>, ObligationError> {
         idempotency_guard!(
             self.events.iter_all().rev(),
-            ObligationEvent::OverdueRecorded { .. }
+            ObligationEvent::OverdueRecorded { .. },
+            => ObligationEvent::Rescheduled { .. }
         );
+        if self
+            .overdue_at()
+            .is_some_and(|overdue_at| effective < overdue_at.date_naive())
+        {
+            return Ok(Idempotent::Ignored);
+        }
 
         match self.status() {
             ObligationStatus::NotYetDue => {
@@ -443,6 +617,151 @@ impl Obligation {
         Ok(Idempotent::Executed(res))
     }
 
+    pub fn next_penalty_period(&self, interval: InterestInterval) -> Option<InterestPeriod> {
+        let last_period = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                ObligationEvent::PenaltyAccrued { period, .. } => Some(Some(*period)),
+                ObligationEvent::Rescheduled { .. } => Some(None),
+                _ => None,
+            })
+            .flatten();
+        match last_period {
+            Some(period) => Some(period.next()),
+            None => self
+                .overdue_at()
+                .or_else(|| self.defaulted_at())
+                .map(|start| interval.period_from(start)),
+        }
+    }
+
+    /// Records the penalty for one accrual period. Nothing is posted here; the
+    /// unrounded amounts are summed until `post_penalty` is called at the end of
+    /// the accrual cycle.
+    pub(crate) fn accrue_penalty(
+        &mut self,
+        period: InterestPeriod,
+        terms: &TermValues,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::PenaltyAccrued { period: accrued, .. } if accrued.start == period.start
+        );
+
+        if self.obligation_type == ObligationType::Penalty {
+            return Idempotent::Ignored;
+        }
+        match self.status() {
+            ObligationStatus::Overdue | ObligationStatus::Defaulted => (),
+            _ => return Idempotent::Ignored,
+        }
+
+        self.events.push(ObligationEvent::PenaltyAccrued {
+            period,
+            accrued: terms.penalty_for_period(self.outstanding(), &period),
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(())
+    }
+
+    /// Penalty accrued but not yet posted, in cents. The fractional part stays
+    /// behind when posting and is carried into the next cycle.
+    pub fn unposted_penalty(&self) -> Decimal {
+        self.events
+            .iter_all()
+            .fold(Decimal::ZERO, |total, event| match event {
+                ObligationEvent::PenaltyAccrued { accrued, .. } => total + *accrued,
+                ObligationEvent::PenaltyPosted { amount, .. } => {
+                    total - Decimal::from(amount.into_inner())
+                }
+                _ => total,
+            })
+    }
+
+    pub(crate) fn post_penalty(
+        &mut self,
+        posted_at: DateTime<Utc>,
+        terms: &TermValues,
+        account_ids: PenaltyAccountIds,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<(ObligationPenaltyData, NewObligation)> {
+        let amount = UsdCents::from(
+            self.unposted_penalty()
+                .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+                .to_u64()
+                .expect("penalty should be a valid amount"),
+        );
+        if amount.is_zero() {
+            return Idempotent::Ignored;
+        }
+
+        let penalty_obligation_id = ObligationId::new();
+        let tx_id = LedgerTxId::new();
+        let tx_ref = format!("{}-penalty-{}", self.id, posted_at.date_naive());
+        let effective = posted_at.date_naive();
+
+        self.events.push(ObligationEvent::PenaltyPosted {
+            penalty_obligation_id,
+            ledger_tx_id: tx_id,
+            amount,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        let due_date = posted_at;
+        let new_obligation = NewObligation::builder()
+            .id(penalty_obligation_id)
+            .credit_facility_id(self.credit_facility_id)
+            .obligation_type(ObligationType::Penalty)
+            .reference(tx_ref.clone())
+            .amount(amount)
+            .tx_id(tx_id)
+            .not_yet_due_accounts(ObligationAccounts {
+                receivable_account_id: account_ids.receivable_not_yet_due_account_id,
+                account_to_be_credited_id: account_ids.penalty_income_account_id,
+            })
+            .due_accounts(ObligationAccounts {
+                receivable_account_id: account_ids.receivable_due_account_id,
+                account_to_be_credited_id: account_ids.penalty_income_account_id,
+            })
+            .overdue_accounts(ObligationAccounts {
+                receivable_account_id: account_ids.receivable_overdue_account_id,
+                account_to_be_credited_id: account_ids.penalty_income_account_id,
+            })
+            .in_liquidation_account_id(account_ids.in_liquidation_account_id)
+            .defaulted_account_id(account_ids.defaulted_account_id)
+            .due_date(due_date)
+            .overdue_date(
+                terms
+                    .obligation_overdue_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .liquidation_date(
+                terms
+                    .obligation_liquidation_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+            )
+            .effective(effective)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new penalty obligation");
+
+        let data = ObligationPenaltyData {
+            tx_id,
+            tx_ref,
+            amount,
+            receivable_account_id: account_ids.receivable_not_yet_due_account_id,
+            penalty_income_account_id: account_ids.penalty_income_account_id,
+            effective,
+        };
+
+        Idempotent::Executed((data, new_obligation))
+    }
+
     pub(crate) fn start_liquidation(
         &mut self,
         effective: chrono::NaiveDate,
@@ -455,6 +774,9 @@ impl Obligation {
         );
 
         match self.status() {
+            ObligationStatus::NotYetDue if effective < self.due_at().date_naive() => {
+                return Idempotent::Ignored;
+            }
             ObligationStatus::NotYetDue | ObligationStatus::Due | ObligationStatus::Overdue => (),
             _ => return Idempotent::Ignored,
         }
@@ -501,15 +823,61 @@ impl Obligation {
             self.events.iter_all().rev(),
             ObligationEvent::PaymentAllocated {payment_id: id, .. }  if *id == payment_id
         );
-        let pre_payment_outstanding = self.outstanding();
-        if pre_payment_outstanding.is_zero() {
+        if self.outstanding().is_zero() {
             return Idempotent::Ignored;
         }
         if self.is_in_liquidation() {
             return Idempotent::Ignored;
         }
 
-        let payment_amount = std::cmp::min(pre_payment_outstanding, amount);
+        let account_to_be_debited_id = self
+            .account_to_be_credited_id()
+            .expect("Obligation was already paid");
+        Idempotent::Executed(self.push_payment_allocation(
+            amount,
+            payment_id,
+            account_to_be_debited_id,
+            effective,
+            audit_info,
+        ))
+    }
+
+    /// Allocates proceeds from the sale of liquidated collateral, which are
+    /// held in `proceeds_account_id` rather than the borrower's account.
+    pub(crate) fn allocate_liquidation_proceeds(
+        &mut self,
+        amount: UsdCents,
+        payment_id: PaymentId,
+        proceeds_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<NewPaymentAllocation> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::PaymentAllocated {payment_id: id, .. }  if *id == payment_id
+        );
+        if self.outstanding().is_zero() || !self.is_in_liquidation() {
+            return Idempotent::Ignored;
+        }
+
+        Idempotent::Executed(self.push_payment_allocation(
+            amount,
+            payment_id,
+            proceeds_account_id,
+            effective,
+            audit_info,
+        ))
+    }
+
+    fn push_payment_allocation(
+        &mut self,
+        amount: UsdCents,
+        payment_id: PaymentId,
+        account_to_be_debited_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> NewPaymentAllocation {
+        let payment_amount = std::cmp::min(self.outstanding(), amount);
         let allocation_id = PaymentAllocationId::new();
         self.events.push(ObligationEvent::PaymentAllocated {
             ledger_tx_id: allocation_id.into(),
@@ -534,10 +902,7 @@ impl Obligation {
                 self.receivable_account_id()
                     .expect("Obligation was already paid"),
             )
-            .account_to_be_debited_id(
-                self.account_to_be_credited_id()
-                    .expect("Obligation was already paid"),
-            )
+            .account_to_be_debited_id(account_to_be_debited_id)
             .effective(effective)
             .amount(payment_amount)
             .audit_info(audit_info.clone())
@@ -551,7 +916,105 @@ impl Obligation {
             });
         }
 
-        Idempotent::Executed(allocation)
+        allocation
+    }
+
+    pub(crate) fn write_off_liquidation_shortfall(
+        &mut self,
+        liquidation_process_id: LiquidationProcessId,
+        tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<ObligationWriteOffData> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::LiquidationShortfallWrittenOff { .. }
+        );
+        if !self.is_in_liquidation() {
+            return Idempotent::Ignored;
+        }
+        let amount = self.outstanding();
+        let Some(receivable_account_id) = self.receivable_account_id() else {
+            return Idempotent::Ignored;
+        };
+        if amount.is_zero() {
+            return Idempotent::Ignored;
+        }
+
+        self.events
+            .push(ObligationEvent::LiquidationShortfallWrittenOff {
+                liquidation_process_id,
+                ledger_tx_id: tx_id,
+                amount,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+        self.events.push(ObligationEvent::Completed {
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(ObligationWriteOffData {
+            tx_id,
+            tx_ref: format!("{}-write-off", self.id),
+            amount,
+            receivable_account_id,
+            effective,
+        })
+    }
+
+    pub(crate) fn conclude_liquidation(
+        &mut self,
+        liquidation_process_id: LiquidationProcessId,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::LiquidationProcessConcluded { .. },
+            => ObligationEvent::LiquidationProcessStarted { .. }
+        );
+
+        self.events
+            .push(ObligationEvent::LiquidationProcessConcluded {
+                liquidation_process_id,
+                audit_info: audit_info.clone(),
+            });
+
+        Idempotent::Executed(())
+    }
+
+    pub(crate) fn reverse_payment_allocation(
+        &mut self,
+        payment_allocation_id: PaymentAllocationId,
+        amount: UsdCents,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<CalaAccountId> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::PaymentAllocationReversed { payment_allocation_id: id, .. }
+                if *id == payment_allocation_id
+        );
+        if !self.events.iter_all().any(|e| {
+            matches!(
+                e,
+                ObligationEvent::PaymentAllocated { payment_allocation_id: id, .. }
+                    if *id == payment_allocation_id
+            )
+        }) {
+            return Idempotent::Ignored;
+        }
+
+        self.events
+            .push(ObligationEvent::PaymentAllocationReversed {
+                payment_allocation_id,
+                payment_allocation_amount: amount,
+                audit_info: audit_info.clone(),
+            });
+
+        Idempotent::Executed(
+            self.receivable_account_id()
+                .expect("Obligation is still paid after reversal"),
+        )
     }
 }
 
@@ -580,10 +1043,15 @@ impl TryFromEvents<ObligationEvent> for Obligation {
                         .effective(*effective)
                 }
                 ObligationEvent::DueRecorded { .. } => (),
+                ObligationEvent::Rescheduled { .. } => (),
                 ObligationEvent::OverdueRecorded { .. } => (),
                 ObligationEvent::DefaultedRecorded { .. } => (),
                 ObligationEvent::PaymentAllocated { .. } => (),
+                ObligationEvent::PaymentAllocationReversed { .. } => (),
+                ObligationEvent::PenaltyAccrued { .. } => (),
+                ObligationEvent::PenaltyPosted { .. } => (),
                 ObligationEvent::LiquidationProcessStarted { .. } => (),
+                ObligationEvent::LiquidationShortfallWrittenOff { .. } => (),
                 ObligationEvent::LiquidationProcessConcluded { .. } => (),
                 ObligationEvent::Completed { .. } => (),
             }
@@ -666,11 +1134,22 @@ impl IntoEvents<ObligationEvent> for NewObligation {
 impl Ord for Obligation {
     fn cmp(&self, other: &Self) -> Ordering {
         match (&self.obligation_type, &other.obligation_type) {
-            (ObligationType::Interest, ObligationType::Disbursal) => Ordering::Less,
-            (ObligationType::Disbursal, ObligationType::Interest) => Ordering::Greater,
+            (
+                ObligationType::Penalty,
+                ObligationType::Fee | ObligationType::Interest | ObligationType::Disbursal,
+            )
+            | (ObligationType::Fee, ObligationType::Interest | ObligationType::Disbursal)
+            | (ObligationType::Interest, ObligationType::Disbursal) => Ordering::Less,
+            (
+                ObligationType::Fee | ObligationType::Interest | ObligationType::Disbursal,
+                ObligationType::Penalty,
+            )
+            | (ObligationType::Interest | ObligationType::Disbursal, ObligationType::Fee)
+            | (ObligationType::Disbursal, ObligationType::Interest) => Ordering::Greater,
             _ => self
                 .effective
                 .cmp(&other.effective)
+                .then_with(|| self.due_at().cmp(&other.due_at()))
                 .then_with(|| self.created_at().cmp(&other.created_at())),
         }
     }
@@ -778,6 +1257,36 @@ mod test {
         assert!(matches!(res, Idempotent::Ignored));
     }
 
+    #[test]
+    fn accelerate_makes_obligation_due_before_due_date() {
+        let mut events = initial_events();
+        if let ObligationEvent::Initialized { due_date, .. } = &mut events[0] {
+            *due_date = Utc::now() + chrono::Duration::days(30);
+        }
+        let mut obligation = obligation_from(events);
+        let today = Utc::now().date_naive();
+
+        assert!(
+            obligation
+                .record_due(today, dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(
+            obligation
+                .start_liquidation(today, &dummy_audit_info())
+                .was_ignored()
+        );
+
+        let res = obligation.accelerate(today, dummy_audit_info()).unwrap();
+        assert_eq!(res.amount, obligation.initial_amount);
+        assert_eq!(obligation.status(), ObligationStatus::Due);
+        assert!(
+            obligation
+                .start_liquidation(today, &dummy_audit_info())
+                .did_execute()
+        );
+    }
+
     #[test]
     fn can_record_overdue() {
         let mut obligation = obligation_from(initial_events());
@@ -905,6 +1414,382 @@ mod test {
         );
     }
 
+    #[test]
+    fn liquidation_proceeds_allocated_in_liquidation() {
+        let mut obligation = obligation_from(initial_events());
+        let proceeds_account_id = CalaAccountId::new();
+        assert!(
+            obligation
+                .allocate_liquidation_proceeds(
+                    UsdCents::ONE,
+                    PaymentId::new(),
+                    proceeds_account_id,
+                    Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .was_ignored()
+        );
+
+        let _ = obligation.start_liquidation(Utc::now().date_naive(), &dummy_audit_info());
+        let allocation = obligation
+            .allocate_liquidation_proceeds(
+                UsdCents::from(100),
+                PaymentId::new(),
+                proceeds_account_id,
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(allocation.amount, obligation.initial_amount);
+        assert_eq!(allocation.account_to_be_debited_id, proceeds_account_id);
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+    }
+
+    #[test]
+    fn shortfall_write_off_completes_obligation() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.start_liquidation(Utc::now().date_naive(), &dummy_audit_info());
+        let _ = obligation.allocate_liquidation_proceeds(
+            UsdCents::from(4),
+            PaymentId::new(),
+            CalaAccountId::new(),
+            Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+
+        let liquidation_process_id = LiquidationProcessId::new();
+        let write_off = obligation
+            .write_off_liquidation_shortfall(
+                liquidation_process_id,
+                LedgerTxId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(write_off.amount, UsdCents::from(6));
+        assert!(obligation.outstanding().is_zero());
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+
+        let _ = obligation.conclude_liquidation(liquidation_process_id, &dummy_audit_info());
+        assert!(!obligation.is_in_liquidation());
+        assert!(
+            obligation
+                .conclude_liquidation(liquidation_process_id, &dummy_audit_info())
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn reversal_reopens_paid_obligation() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let allocation = obligation
+            .allocate_payment(
+                obligation.outstanding(),
+                PaymentId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+
+        let receivable_account_id = obligation
+            .reverse_payment_allocation(allocation.id, allocation.amount, &dummy_audit_info())
+            .unwrap();
+        assert_eq!(obligation.status(), ObligationStatus::Due);
+        assert_eq!(obligation.outstanding(), obligation.initial_amount);
+        assert_eq!(
+            receivable_account_id,
+            obligation.due_accounts().receivable_account_id
+        );
+    }
+
+    #[test]
+    fn reversal_is_idempotent() {
+        let mut obligation = obligation_from(initial_events());
+        let allocation = obligation
+            .allocate_payment(
+                UsdCents::ONE,
+                PaymentId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+
+        assert!(
+            obligation
+                .reverse_payment_allocation(allocation.id, allocation.amount, &dummy_audit_info())
+                .did_execute()
+        );
+        assert!(
+            obligation
+                .reverse_payment_allocation(allocation.id, allocation.amount, &dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(
+            obligation
+                .reverse_payment_allocation(
+                    PaymentAllocationId::new(),
+                    UsdCents::ONE,
+                    &dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        assert_eq!(obligation.outstanding(), obligation.initial_amount);
+    }
+
+    #[test]
+    fn paid_again_after_reversal() {
+        let mut obligation = obligation_from(initial_events());
+        let allocation = obligation
+            .allocate_payment(
+                obligation.outstanding(),
+                PaymentId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        let _ = obligation.reverse_payment_allocation(
+            allocation.id,
+            allocation.amount,
+            &dummy_audit_info(),
+        );
+        assert_eq!(obligation.status(), ObligationStatus::NotYetDue);
+
+        obligation
+            .allocate_payment(
+                obligation.outstanding(),
+                PaymentId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+    }
+
+    fn penalty_terms() -> TermValues {
+        use crate::terms::{
+            AnnualRatePct, FacilityDuration, ObligationDuration, OneTimeFeeRatePct,
+        };
+        use rust_decimal_macros::dec;
+
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::from(dec!(1)))
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .penalty_rate(Some(AnnualRatePct::from(dec!(24))))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn penalty_account_ids() -> PenaltyAccountIds {
+        PenaltyAccountIds {
+            receivable_not_yet_due_account_id: CalaAccountId::new(),
+            receivable_due_account_id: CalaAccountId::new(),
+            receivable_overdue_account_id: CalaAccountId::new(),
+            defaulted_account_id: CalaAccountId::new(),
+            penalty_income_account_id: CalaAccountId::new(),
+            in_liquidation_account_id: CalaAccountId::new(),
+        }
+    }
+
+    #[test]
+    fn accrues_penalty_on_overdue_obligation() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_overdue(Utc::now().date_naive(), dummy_audit_info());
+
+        let period = obligation
+            .next_penalty_period(InterestInterval::EndOfDay)
+            .unwrap();
+        assert!(
+            !obligation
+                .accrue_penalty(period, &penalty_terms(), &dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(obligation.unposted_penalty() > Decimal::ZERO);
+        assert_eq!(
+            obligation.next_penalty_period(InterestInterval::EndOfDay),
+            Some(period.next())
+        );
+
+        let unposted = obligation.unposted_penalty();
+        let res = obligation.post_penalty(
+            period.end,
+            &penalty_terms(),
+            penalty_account_ids(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(res, Idempotent::Ignored));
+        assert_eq!(obligation.unposted_penalty(), unposted);
+
+        let res = obligation.accrue_penalty(period, &penalty_terms(), &dummy_audit_info());
+        assert!(matches!(res, Idempotent::Ignored));
+    }
+
+    #[test]
+    fn posts_accrued_penalty_and_carries_fractional_cents() {
+        let mut events = initial_events();
+        if let ObligationEvent::Initialized { amount, .. } = &mut events[0] {
+            *amount = UsdCents::from(1_000_000);
+        }
+        let mut obligation = obligation_from(events);
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_overdue(Utc::now().date_naive(), dummy_audit_info());
+
+        let mut period = obligation
+            .next_penalty_period(InterestInterval::EndOfDay)
+            .unwrap();
+        for _ in 0..3 {
+            let _ = obligation.accrue_penalty(period, &penalty_terms(), &dummy_audit_info());
+            period = period.next();
+        }
+        let unposted = obligation.unposted_penalty();
+
+        let (data, new_obligation) = obligation
+            .post_penalty(
+                period.end,
+                &penalty_terms(),
+                penalty_account_ids(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(Decimal::from(data.amount.into_inner()), unposted.trunc());
+        assert_eq!(new_obligation.amount, data.amount);
+        assert_eq!(new_obligation.obligation_type, ObligationType::Penalty);
+        assert_eq!(obligation.unposted_penalty(), unposted.fract());
+
+        let res = obligation.post_penalty(
+            period.end,
+            &penalty_terms(),
+            penalty_account_ids(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(res, Idempotent::Ignored));
+    }
+
+    #[test]
+    fn ignores_penalty_if_not_overdue() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+
+        let period = obligation
+            .next_penalty_period(InterestInterval::EndOfDay)
+            .unwrap();
+        let res = obligation.accrue_penalty(period, &penalty_terms(), &dummy_audit_info());
+        assert!(matches!(res, Idempotent::Ignored));
+    }
+
+    #[test]
+    fn ignores_penalty_on_penalty_obligation() {
+        let mut events = initial_events();
+        if let ObligationEvent::Initialized {
+            obligation_type, ..
+        } = &mut events[0]
+        {
+            *obligation_type = ObligationType::Penalty;
+        }
+        let mut obligation = obligation_from(events);
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_overdue(Utc::now().date_naive(), dummy_audit_info());
+
+        let period = obligation
+            .next_penalty_period(InterestInterval::EndOfDay)
+            .unwrap();
+        let res = obligation.accrue_penalty(period, &penalty_terms(), &dummy_audit_info());
+        assert!(matches!(res, Idempotent::Ignored));
+    }
+
+    #[test]
+    fn reschedule_moves_due_date_and_skips_stale_due() {
+        let mut obligation = obligation_from(initial_events());
+        let original_due_at = obligation.due_at();
+        let new_due_at = original_due_at + chrono::Duration::days(90);
+
+        let res = obligation.reschedule(
+            new_due_at,
+            UsdCents::from(6),
+            &penalty_terms(),
+            Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        assert!(matches!(res, Idempotent::Executed(None)));
+        assert_eq!(obligation.due_at(), new_due_at);
+        assert_eq!(obligation.overdue_at(), None);
+        assert_eq!(obligation.outstanding(), UsdCents::from(6));
+
+        let res = obligation.record_due(original_due_at.date_naive(), dummy_audit_info());
+        assert!(res.was_ignored());
+        assert_eq!(obligation.status(), ObligationStatus::NotYetDue);
+    }
+
+    #[test]
+    fn reschedule_moves_due_balance_back_to_not_yet_due() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let new_due_at = Utc::now() + chrono::Duration::days(90);
+
+        let reallocation = obligation
+            .reschedule(
+                new_due_at,
+                UsdCents::from(4),
+                &penalty_terms(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .expect("due balance should be reallocated");
+        assert_eq!(reallocation.amount, obligation.initial_amount);
+        assert_eq!(
+            reallocation.receivable_account_id,
+            obligation.due_accounts().receivable_account_id
+        );
+        assert_eq!(obligation.status(), ObligationStatus::NotYetDue);
+        assert_eq!(obligation.outstanding(), UsdCents::from(4));
+
+        let res = obligation.record_due(new_due_at.date_naive(), dummy_audit_info());
+        assert_eq!(res.unwrap().amount, UsdCents::from(4));
+    }
+
+    #[test]
+    fn reschedule_to_zero_completes_obligation() {
+        let mut obligation = obligation_from(initial_events());
+
+        let _ = obligation.reschedule(
+            Utc::now() + chrono::Duration::days(90),
+            UsdCents::ZERO,
+            &penalty_terms(),
+            Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+    }
+
+    #[test]
+    fn reschedule_ignored_in_liquidation() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.start_liquidation(Utc::now().date_naive(), &dummy_audit_info());
+
+        let res = obligation.reschedule(
+            Utc::now() + chrono::Duration::days(90),
+            obligation.outstanding(),
+            &penalty_terms(),
+            Utc::now().date_naive(),
+            &dummy_audit_info(),
+        );
+        assert!(res.was_ignored());
+    }
+
     mod is_status_up_to_date {
 
         use super::*;
@@ -1,9 +1,11 @@
 mod entity;
 pub mod error;
-// mod payment_allocator;
+mod payment_allocator;
 mod primitives;
 mod repo;
 
+use chrono::{DateTime, Utc};
+
 use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
@@ -13,14 +15,22 @@ use outbox::OutboxEventMarker;
 
 use crate::{
     event::CoreCreditEvent,
-    jobs::obligation_due,
-    liquidation_process::{LiquidationProcess, LiquidationProcessRepo},
-    payment_allocation::NewPaymentAllocation,
+    jobs::{
+        obligation_defaulted, obligation_due, obligation_liquidation, obligation_overdue,
+        obligation_penalty,
+    },
+    liquidation_process::{
+        LiquidationCollateralTransfer, LiquidationConclusion, LiquidationProcess,
+        LiquidationProcessRepo, LiquidationSale, LiquidationSurplus,
+    },
+    payment_allocation::{NewPaymentAllocation, PaymentAllocation},
     primitives::{
-        CoreCreditAction, CoreCreditObject, CreditFacilityId, ObligationId, ObligationType,
-        PaymentId, UsdCents,
+        CalaAccountId, CoreCreditAction, CoreCreditObject, CreditFacilityId, LedgerTxId,
+        LiquidationProcessId, ObligationId, ObligationStatus, ObligationType, PaymentId, Satoshis,
+        UsdCents,
     },
     publisher::CreditFacilityPublisher,
+    terms::{InterestPeriod, TermValues},
 };
 
 pub use entity::Obligation;
@@ -29,6 +39,7 @@ pub use entity::Obligation;
 pub use entity::ObligationEvent;
 pub(crate) use entity::*;
 use error::ObligationError;
+pub(crate) use payment_allocator::PaymentAllocator;
 pub use primitives::*;
 pub use repo::obligation_cursor;
 use repo::*;
@@ -176,7 +187,7 @@ where
         db: &mut es_entity::DbOp<'_>,
         id: ObligationId,
         effective: chrono::NaiveDate,
-    ) -> Result<Option<ObligationDefaultedReallocationData>, ObligationError> {
+    ) -> Result<(Obligation, Option<ObligationDefaultedReallocationData>), ObligationError> {
         let mut obligation = self.repo.find_by_id(id).await?;
 
         let audit_info = self
@@ -199,7 +210,57 @@ where
             None
         };
 
-        Ok(data)
+        Ok((obligation, data))
+    }
+
+    /// Accrues the penalty for `period` and posts the accumulated penalty as a
+    /// new obligation once the accrual cycle ends, or once the obligation stops
+    /// accruing. Returns whether the obligation is still accruing along with
+    /// the posting, if any.
+    pub async fn accrue_penalty_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: ObligationId,
+        period: InterestPeriod,
+        terms: &TermValues,
+        account_ids: PenaltyAccountIds,
+    ) -> Result<(bool, Option<ObligationPenaltyData>), ObligationError> {
+        let mut obligation = self.repo.find_by_id(id).await?;
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::obligation(id),
+                CoreCreditAction::OBLIGATION_RECORD_PENALTY,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let accruing = !obligation
+            .accrue_penalty(period, terms, &audit_info)
+            .was_ignored();
+        let cycle_end = terms.accrual_cycle_interval.period_from(period.start).end;
+
+        let new_penalty = if !accruing || period.end >= cycle_end {
+            match obligation.post_penalty(period.end, terms, account_ids, &audit_info) {
+                Idempotent::Executed(posted) => Some(posted),
+                Idempotent::Ignored => None,
+            }
+        } else {
+            None
+        };
+
+        if accruing || new_penalty.is_some() {
+            self.repo.update_in_op(db, &mut obligation).await?;
+        }
+        let Some((data, new_obligation)) = new_penalty else {
+            return Ok((accruing, None));
+        };
+        self.create_with_jobs_in_op(db, new_obligation).await?;
+
+        Ok((accruing, Some(data)))
     }
 
     pub async fn start_liquidation_process_in_op(
@@ -238,6 +299,329 @@ where
         Ok((obligation, liquidation_process))
     }
 
+    /// Schedules liquidation of every obligation of the facility that still
+    /// has an outstanding balance, e.g. after an uncured margin call. Not yet
+    /// due obligations are accelerated to due first; the returned reallocations
+    /// must be posted to the ledger in the same op.
+    pub(crate) async fn liquidate_facility_obligations_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        effective: chrono::NaiveDate,
+    ) -> Result<Vec<ObligationDueReallocationData>, ObligationError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_UPDATE_STATUS,
+            )
+            .await
+            .map_err(authz::error::AuthorizationError::from)?;
+
+        let mut accelerated = Vec::new();
+        for mut obligation in self.facility_obligations(credit_facility_id).await? {
+            if !obligation.has_outstanding_balance() || obligation.is_in_liquidation() {
+                continue;
+            }
+            if let Idempotent::Executed(due) = obligation.accelerate(effective, audit_info.clone())
+            {
+                self.repo.update_in_op(db, &mut obligation).await?;
+                accelerated.push(due);
+            }
+            if !matches!(
+                obligation.status(),
+                ObligationStatus::Due | ObligationStatus::Overdue
+            ) {
+                continue;
+            }
+
+            self.jobs
+                .create_and_spawn_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_liquidation::ObligationLiquidationJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        effective,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+
+        Ok(accelerated)
+    }
+
+    pub async fn find_liquidation_process_by_id_without_audit(
+        &self,
+        id: LiquidationProcessId,
+    ) -> Result<LiquidationProcess, ObligationError> {
+        Ok(self.liquidation_process_repo.find_by_id(id).await?)
+    }
+
+    pub async fn send_collateral_to_liquidator_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        amount: Satoshis,
+        available_collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<(LiquidationProcess, LiquidationCollateralTransfer), ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+        let transfer = liquidation_process.send_collateral_to_liquidator(
+            amount,
+            available_collateral,
+            effective,
+            audit_info,
+        )?;
+        self.liquidation_process_repo
+            .update_in_op(db, &mut liquidation_process)
+            .await?;
+
+        Ok((liquidation_process, transfer))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_liquidation_sale_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        tx_id: LedgerTxId,
+        sold: Satoshis,
+        proceeds: UsdCents,
+        collateral_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<(LiquidationProcess, LiquidationSale), ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+        let sale = liquidation_process.record_sale(
+            tx_id,
+            sold,
+            proceeds,
+            collateral_account_id,
+            effective,
+            audit_info,
+        )?;
+        self.liquidation_process_repo
+            .update_in_op(db, &mut liquidation_process)
+            .await?;
+
+        Ok((liquidation_process, sale))
+    }
+
+    /// Allocates liquidation proceeds to the obligation under liquidation,
+    /// concluding the liquidation once nothing is left to settle.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn allocate_liquidation_proceeds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        proceeds_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<(PaymentAllocationResult, Option<LiquidationConclusion>), ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+        let amount = std::cmp::min(amount, liquidation_process.unapplied_proceeds());
+        if amount.is_zero() {
+            return Ok((PaymentAllocationResult::new(vec![]), None));
+        }
+        let mut obligation = self
+            .repo
+            .find_by_id(liquidation_process.obligation_id)
+            .await?;
+
+        let Idempotent::Executed(allocation) = obligation.allocate_liquidation_proceeds(
+            amount,
+            payment_id,
+            proceeds_account_id,
+            effective,
+            audit_info,
+        ) else {
+            return Ok((PaymentAllocationResult::new(vec![]), None));
+        };
+
+        let conclusion = match liquidation_process.record_proceeds_applied(
+            payment_id,
+            allocation.amount,
+            obligation.outstanding(),
+            effective,
+            audit_info,
+        ) {
+            Idempotent::Executed(conclusion) => conclusion,
+            Idempotent::Ignored => return Ok((PaymentAllocationResult::new(vec![]), None)),
+        };
+        if conclusion.is_some() {
+            let _ = obligation.conclude_liquidation(liquidation_process.id, audit_info);
+        }
+
+        self.repo.update_in_op(db, &mut obligation).await?;
+        self.liquidation_process_repo
+            .update_in_op(db, &mut liquidation_process)
+            .await?;
+
+        Ok((PaymentAllocationResult::new(vec![allocation]), conclusion))
+    }
+
+    pub async fn return_liquidation_surplus_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        deposit_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<(LiquidationSurplus, LiquidationConclusion)>, ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+        let mut obligation = self
+            .repo
+            .find_by_id(liquidation_process.obligation_id)
+            .await?;
+
+        let Idempotent::Executed(res) = liquidation_process.return_surplus(
+            obligation.outstanding(),
+            deposit_account_id,
+            effective,
+            audit_info,
+        )?
+        else {
+            return Ok(None);
+        };
+        if obligation
+            .conclude_liquidation(liquidation_process.id, audit_info)
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut obligation).await?;
+        }
+        self.liquidation_process_repo
+            .update_in_op(db, &mut liquidation_process)
+            .await?;
+
+        Ok(Some(res))
+    }
+
+    pub async fn write_off_liquidation_shortfall_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<(ObligationWriteOffData, LiquidationConclusion)>, ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+        let mut obligation = self
+            .repo
+            .find_by_id(liquidation_process.obligation_id)
+            .await?;
+
+        let Idempotent::Executed(write_off) = obligation.write_off_liquidation_shortfall(
+            liquidation_process.id,
+            LedgerTxId::new(),
+            effective,
+            audit_info,
+        ) else {
+            return Ok(None);
+        };
+        let Idempotent::Executed(conclusion) = liquidation_process.write_off_shortfall(
+            write_off.tx_id,
+            write_off.amount,
+            effective,
+            audit_info,
+        )?
+        else {
+            return Ok(None);
+        };
+        let _ = obligation.conclude_liquidation(liquidation_process.id, audit_info);
+
+        self.repo.update_in_op(db, &mut obligation).await?;
+        self.liquidation_process_repo
+            .update_in_op(db, &mut liquidation_process)
+            .await?;
+
+        Ok(Some((write_off, conclusion)))
+    }
+
+    /// Re-amortizes the facility's outstanding principal over the installments
+    /// of the amended terms, up to the new maturity. Existing principal
+    /// obligations are reused in due-date order; extra installments get new
+    /// obligations and surplus ones are rescheduled to zero. Returns the due and
+    /// overdue balances that have to move back to not-yet-due in the ledger.
+    pub async fn reschedule_for_facility_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        maturity_date: DateTime<Utc>,
+        terms: &TermValues,
+        audit_info: &AuditInfo,
+    ) -> Result<Vec<ObligationRescheduleReallocationData>, ObligationError> {
+        let mut obligations: Vec<Obligation> = self
+            .facility_obligations(credit_facility_id)
+            .await?
+            .into_iter()
+            .filter(|obligation| {
+                obligation.obligation_type == ObligationType::Disbursal
+                    && matches!(
+                        obligation.status(),
+                        ObligationStatus::NotYetDue
+                            | ObligationStatus::Due
+                            | ObligationStatus::Overdue
+                    )
+                    && obligation.has_outstanding_balance()
+                    && !obligation.is_in_liquidation()
+            })
+            .collect();
+        obligations.sort_by_key(|obligation| obligation.due_at());
+        if obligations.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let now = crate::time::now();
+        let effective = now.date_naive();
+        let principal = obligations
+            .iter()
+            .fold(UsdCents::ZERO, |total, obligation| {
+                total + obligation.outstanding()
+            });
+        let mut installments = terms.principal_installments(principal, now, maturity_date);
+        let extra_installments = installments.split_off(installments.len().min(obligations.len()));
+        let new_obligations: Vec<NewObligation> = extra_installments
+            .into_iter()
+            .map(|installment| {
+                obligations[0].new_installment(
+                    installment.amount,
+                    installment.due_at,
+                    terms,
+                    effective,
+                    audit_info,
+                )
+            })
+            .collect();
+
+        let mut installments = installments.into_iter();
+        let mut reallocations = Vec::new();
+        for mut obligation in obligations {
+            let (due_date, amount) = installments
+                .next()
+                .map(|installment| (installment.due_at, installment.amount))
+                .unwrap_or((maturity_date, UsdCents::ZERO));
+            let Idempotent::Executed(reallocation) =
+                obligation.reschedule(due_date, amount, terms, effective, audit_info)
+            else {
+                continue;
+            };
+            self.repo.update_in_op(db, &mut obligation).await?;
+            self.schedule_next_status_transition_in_op(db, &obligation)
+                .await?;
+            reallocations.extend(reallocation);
+        }
+        for new_obligation in new_obligations {
+            self.create_with_jobs_in_op(db, new_obligation).await?;
+        }
+
+        Ok(reallocations)
+    }
+
     pub async fn find_by_id_without_audit(
         &self,
         id: ObligationId,
@@ -249,33 +633,142 @@ where
         &self,
         db: &mut es_entity::DbOp<'_>,
         credit_facility_id: CreditFacilityId,
-        payment_id: PaymentId,
-        amount: UsdCents,
+        allocator: PaymentAllocator,
         effective: chrono::NaiveDate,
         audit_info: &AuditInfo,
+        created_in_op: Vec<Obligation>,
     ) -> Result<PaymentAllocationResult, ObligationError> {
         let mut obligations = self.facility_obligations(credit_facility_id).await?;
+        obligations.retain(|obligation| !created_in_op.iter().any(|o| o.id == obligation.id));
+        obligations.extend(created_in_op);
 
-        obligations.sort();
-
-        let mut remaining = amount;
-        let mut new_allocations = Vec::new();
+        let new_allocations = allocator.allocate(&mut obligations, effective, audit_info);
         for obligation in obligations.iter_mut() {
-            if let es_entity::Idempotent::Executed(new_allocation) =
-                obligation.allocate_payment(remaining, payment_id, effective, audit_info)
+            if new_allocations
+                .iter()
+                .any(|allocation| allocation.obligation_id == obligation.id)
             {
                 self.repo.update_in_op(db, obligation).await?;
-                remaining -= new_allocation.amount;
-                new_allocations.push(new_allocation);
-                if remaining == UsdCents::ZERO {
-                    break;
-                }
             }
         }
 
         Ok(PaymentAllocationResult::new(new_allocations))
     }
 
+    pub async fn reverse_payment_allocation_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        allocation: &PaymentAllocation,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<CalaAccountId>, ObligationError> {
+        let mut obligation = self.repo.find_by_id(allocation.obligation_id).await?;
+
+        let was_paid = obligation.status() == ObligationStatus::Paid;
+        let Idempotent::Executed(receivable_account_id) =
+            obligation.reverse_payment_allocation(allocation.id, allocation.amount, audit_info)
+        else {
+            return Ok(None);
+        };
+        self.repo.update_in_op(db, &mut obligation).await?;
+
+        if was_paid {
+            self.schedule_next_status_transition_in_op(db, &obligation)
+                .await?;
+        }
+
+        Ok(Some(receivable_account_id))
+    }
+
+    /// Status jobs stop chaining once an obligation is paid, so a reopened
+    /// obligation needs the next transition scheduled again.
+    async fn schedule_next_status_transition_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        obligation: &Obligation,
+    ) -> Result<(), ObligationError> {
+        let status = obligation.status();
+        if status == ObligationStatus::NotYetDue {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_due::ObligationDueJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        effective: obligation.due_at().date_naive(),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    obligation.due_at(),
+                )
+                .await?;
+            return Ok(());
+        }
+        if matches!(
+            status,
+            ObligationStatus::Overdue | ObligationStatus::Defaulted
+        ) && obligation.obligation_type != ObligationType::Penalty
+        {
+            self.jobs
+                .create_and_spawn_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_penalty::ObligationPenaltyJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+        if !matches!(status, ObligationStatus::Due | ObligationStatus::Overdue) {
+            return Ok(());
+        }
+
+        if let Some(overdue_at) = obligation
+            .overdue_at()
+            .filter(|_| status == ObligationStatus::Due)
+        {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_overdue::ObligationOverdueJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        effective: overdue_at.date_naive(),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    overdue_at,
+                )
+                .await?;
+        } else if let Some(liquidation_at) = obligation.liquidation_at() {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_liquidation::ObligationLiquidationJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        effective: liquidation_at.date_naive(),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    liquidation_at,
+                )
+                .await?;
+        } else if let Some(defaulted_at) = obligation.defaulted_at() {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    db,
+                    JobId::new(),
+                    obligation_defaulted::ObligationDefaultedJobConfig::<Perms, E> {
+                        obligation_id: obligation.id,
+                        effective: defaulted_at.date_naive(),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    defaulted_at,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn check_facility_obligations_status_updated(
         &self,
         credit_facility_id: CreditFacilityId,
@@ -290,6 +783,34 @@ where
         Ok(true)
     }
 
+    /// Outstanding fees and penalties of the facility that are not in default
+    /// or liquidation. They are booked to the interest receivable accounts,
+    /// so they are part of the facility's interest balances.
+    pub async fn outstanding_fees_and_penalties(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<(UsdCents, UsdCents), ObligationError> {
+        let mut fees = UsdCents::ZERO;
+        let mut penalties = UsdCents::ZERO;
+        for obligation in self.facility_obligations(credit_facility_id).await? {
+            if obligation.is_in_liquidation()
+                || !matches!(
+                    obligation.status(),
+                    ObligationStatus::NotYetDue | ObligationStatus::Due | ObligationStatus::Overdue
+                )
+            {
+                continue;
+            }
+            match obligation.obligation_type {
+                ObligationType::Fee => fees += obligation.outstanding(),
+                ObligationType::Penalty => penalties += obligation.outstanding(),
+                ObligationType::Disbursal | ObligationType::Interest => (),
+            }
+        }
+
+        Ok((fees, penalties))
+    }
+
     async fn facility_obligations(
         &self,
         credit_facility_id: CreditFacilityId,
@@ -350,7 +871,8 @@ impl PaymentAllocationResult {
             .fold(UsdCents::from(0), |mut total, allocation| {
                 if let NewPaymentAllocation {
                     amount,
-                    obligation_type: ObligationType::Interest,
+                    obligation_type:
+                        ObligationType::Interest | ObligationType::Penalty | ObligationType::Fee,
                     ..
                 } = allocation
                 {
@@ -1,4 +1,4 @@
-use crate::primitives::*;
+use crate::{ledger::CreditFacilityAccountIds, primitives::*};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -26,6 +26,14 @@ pub struct ObligationOverdueReallocationData {
     pub effective: chrono::NaiveDate,
 }
 
+pub struct ObligationRescheduleReallocationData {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub receivable_account_id: CalaAccountId,
+    pub not_yet_due_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
 pub struct ObligationDefaultedReallocationData {
     pub tx_id: LedgerTxId,
     pub amount: UsdCents,
@@ -34,6 +42,49 @@ pub struct ObligationDefaultedReallocationData {
     pub effective: chrono::NaiveDate,
 }
 
+pub struct ObligationWriteOffData {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub receivable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+pub struct ObligationPenaltyData {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub receivable_account_id: CalaAccountId,
+    pub penalty_income_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PenaltyAccountIds {
+    pub receivable_not_yet_due_account_id: CalaAccountId,
+    pub receivable_due_account_id: CalaAccountId,
+    pub receivable_overdue_account_id: CalaAccountId,
+    pub defaulted_account_id: CalaAccountId,
+    pub penalty_income_account_id: CalaAccountId,
+    pub in_liquidation_account_id: CalaAccountId,
+}
+
+impl From<CreditFacilityAccountIds> for PenaltyAccountIds {
+    fn from(credit_facility_account_ids: CreditFacilityAccountIds) -> Self {
+        Self {
+            receivable_not_yet_due_account_id: credit_facility_account_ids
+                .interest_receivable_not_yet_due_account_id,
+            receivable_due_account_id: credit_facility_account_ids
+                .interest_receivable_due_account_id,
+            receivable_overdue_account_id: credit_facility_account_ids
+                .interest_receivable_overdue_account_id,
+            defaulted_account_id: credit_facility_account_ids.interest_defaulted_account_id,
+            penalty_income_account_id: credit_facility_account_ids.penalty_income_account_id,
+            in_liquidation_account_id: credit_facility_account_ids.in_liquidation_account_id,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ObligationsAmounts {
     pub disbursed: UsdCents,
@@ -39,6 +39,10 @@ pub enum PaymentEvent {
         interest: UsdCents,
         audit_info: AuditInfo,
     },
+    Reversed {
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -68,6 +72,7 @@ impl TryFromEvents<PaymentEvent> for Payment {
                         .amount(*amount)
                 }
                 PaymentEvent::PaymentAllocated { .. } => (),
+                PaymentEvent::Reversed { .. } => (),
             }
         }
         builder.events(events).build()
@@ -117,6 +122,32 @@ impl Payment {
 
         Idempotent::Executed(())
     }
+
+    pub fn unallocated_amount(&self) -> UsdCents {
+        let allocated = self.allocated_amounts();
+        self.amount - allocated.disbursal - allocated.interest
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, PaymentEvent::Reversed { .. }))
+    }
+
+    pub fn reverse(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(self.events.iter_all().rev(), PaymentEvent::Reversed { .. });
+
+        self.events.push(PaymentEvent::Reversed {
+            effective,
+            audit_info,
+        });
+
+        Idempotent::Executed(())
+    }
 }
 
 #[derive(Debug, Builder)]
@@ -4,13 +4,15 @@ mod repo;
 
 use tracing::instrument;
 
-use audit::AuditSvc;
+use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
 use outbox::OutboxEventMarker;
 
 use crate::{
-    CoreCreditAction, CoreCreditEvent, CoreCreditObject, Obligations, PaymentAllocation,
-    PaymentAllocationRepo, primitives::*, publisher::CreditFacilityPublisher,
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacility, Obligation, Obligations,
+    PaymentAllocation, PaymentAllocationRepo, PaymentAllocationReversal,
+    liquidation_process::LiquidationConclusion, obligation::PaymentAllocator, primitives::*,
+    publisher::CreditFacilityPublisher,
 };
 
 pub use entity::Payment;
@@ -71,42 +73,143 @@ where
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) async fn record_in_op(
         &self,
-        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         db: &mut es_entity::DbOp<'_>,
-        credit_facility_id: CreditFacilityId,
+        payment_id: PaymentId,
+        credit_facility: &CreditFacility,
         amount: UsdCents,
         effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+        obligations_created_in_op: Vec<Obligation>,
+        audit_info: &AuditInfo,
     ) -> Result<Vec<PaymentAllocation>, PaymentError> {
-        let audit_info = self
-            .authz
-            .enforce_permission(
-                sub,
-                CoreCreditObject::all_obligations(),
-                CoreCreditAction::OBLIGATION_RECORD_PAYMENT,
+        let new_payment = NewPayment::builder()
+            .id(payment_id)
+            .amount(amount)
+            .credit_facility_id(credit_facility.id)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new payment");
+
+        let mut payment = self.repo.create_in_op(db, new_payment).await?;
+
+        let res = self
+            .obligations
+            .allocate_payment_in_op(
+                db,
+                credit_facility.id,
+                PaymentAllocator::new(
+                    payment.id,
+                    amount,
+                    credit_facility.terms.payment_allocation_strategy,
+                ),
+                effective.into(),
+                audit_info,
+                obligations_created_in_op,
             )
             .await?;
 
+        let _ = payment.record_allocated(
+            res.disbursed_amount(),
+            res.interest_amount(),
+            audit_info.clone(),
+        );
+        self.repo.update_in_op(db, &mut payment).await?;
+
+        let allocations = self
+            .payment_allocation_repo
+            .create_all_in_op(db, res.allocations)
+            .await?;
+
+        Ok(allocations)
+    }
+
+    /// Allocates up to `available` of the facility's unapplied funds and
+    /// records a payment for exactly the allocated amount. Nothing is
+    /// recorded when no obligation can take the funds.
+    pub(super) async fn record_from_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        payment_id: PaymentId,
+        credit_facility: &CreditFacility,
+        available: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Vec<PaymentAllocation>, PaymentError> {
+        let res = self
+            .obligations
+            .allocate_payment_in_op(
+                db,
+                credit_facility.id,
+                PaymentAllocator::new(
+                    payment_id,
+                    available,
+                    credit_facility.terms.payment_allocation_strategy,
+                ),
+                effective,
+                audit_info,
+                vec![],
+            )
+            .await?;
+        if res.allocations.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let new_payment = NewPayment::builder()
+            .id(payment_id)
+            .amount(res.disbursed_amount() + res.interest_amount())
+            .credit_facility_id(credit_facility.id)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new payment");
+        let mut payment = self.repo.create_in_op(db, new_payment).await?;
+        let _ = payment.record_allocated(
+            res.disbursed_amount(),
+            res.interest_amount(),
+            audit_info.clone(),
+        );
+        self.repo.update_in_op(db, &mut payment).await?;
+
+        let allocations = self
+            .payment_allocation_repo
+            .create_all_in_op(db, res.allocations)
+            .await?;
+
+        Ok(allocations)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn record_liquidation_proceeds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility: &CreditFacility,
+        liquidation_process_id: LiquidationProcessId,
+        amount: UsdCents,
+        proceeds_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<(Vec<PaymentAllocation>, Option<LiquidationConclusion>), PaymentError> {
         let new_payment = NewPayment::builder()
             .id(PaymentId::new())
             .amount(amount)
-            .credit_facility_id(credit_facility_id)
+            .credit_facility_id(credit_facility.id)
             .audit_info(audit_info.clone())
             .build()
             .expect("could not build new payment");
 
         let mut payment = self.repo.create_in_op(db, new_payment).await?;
 
-        let res = self
+        let (res, conclusion) = self
             .obligations
-            .allocate_payment_in_op(
+            .allocate_liquidation_proceeds_in_op(
                 db,
-                credit_facility_id,
+                liquidation_process_id,
                 payment.id,
                 amount,
-                effective.into(),
-                &audit_info,
+                proceeds_account_id,
+                effective,
+                audit_info,
             )
             .await?;
 
@@ -122,9 +225,82 @@ where
             .create_all_in_op(db, res.allocations)
             .await?;
 
+        Ok((allocations, conclusion))
+    }
+
+    pub(super) async fn reverse_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        payment_id: PaymentId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<(Payment, Vec<PaymentAllocationReversal>)>, PaymentError> {
+        let mut payment = self.repo.find_by_id(payment_id).await?;
+        if payment.reverse(effective, audit_info.clone()).was_ignored() {
+            return Ok(None);
+        }
+        self.repo.update_in_op(db, &mut payment).await?;
+
+        let mut reversals = Vec::new();
+        for mut allocation in self.allocations_for_payment(payment_id).await? {
+            let Some(receivable_account_id) = self
+                .obligations
+                .reverse_payment_allocation_in_op(db, &allocation, audit_info)
+                .await?
+            else {
+                continue;
+            };
+
+            if let es_entity::Idempotent::Executed(reversal) =
+                allocation.reverse(receivable_account_id, effective, audit_info)
+            {
+                self.payment_allocation_repo
+                    .update_in_op(db, &mut allocation)
+                    .await?;
+                reversals.push(reversal);
+            }
+        }
+
+        Ok(Some((payment, reversals)))
+    }
+
+    async fn allocations_for_payment(
+        &self,
+        payment_id: PaymentId,
+    ) -> Result<Vec<PaymentAllocation>, PaymentError> {
+        let mut allocations = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .payment_allocation_repo
+                .list_for_payment_id_by_created_at(
+                    payment_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            allocations.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
         Ok(allocations)
     }
 
+    pub(super) async fn find_by_id_without_audit(
+        &self,
+        payment_id: impl Into<PaymentId> + std::fmt::Debug,
+    ) -> Result<Payment, PaymentError> {
+        let payment = self.repo.find_by_id(payment_id.into()).await?;
+
+        Ok(payment)
+    }
+
     pub(super) async fn find_allocation_by_id_without_audit(
         &self,
         payment_allocation_id: impl Into<PaymentAllocationId> + std::fmt::Debug,
@@ -28,12 +28,29 @@ pub enum PaymentAllocationEvent {
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
+    Reversed {
+        ledger_tx_id: LedgerTxId,
+        receivable_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentAllocationReversal {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub receivable_account_id: CalaAccountId,
+    pub account_to_be_debited_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
 }
 
 #[derive(EsEntity, Builder)]
 #[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
 pub struct PaymentAllocation {
     pub id: PaymentAllocationId,
+    pub payment_id: PaymentId,
     pub obligation_id: ObligationId,
     pub obligation_allocation_idx: usize,
     pub obligation_type: ObligationType,
@@ -65,6 +82,7 @@ impl TryFromEvents<PaymentAllocationEvent> for PaymentAllocation {
             match event {
                 PaymentAllocationEvent::Initialized {
                     id,
+                    payment_id,
                     obligation_id,
                     obligation_allocation_idx,
                     obligation_type,
@@ -78,6 +96,7 @@ impl TryFromEvents<PaymentAllocationEvent> for PaymentAllocation {
                 } => {
                     builder = builder
                         .id(*id)
+                        .payment_id(*payment_id)
                         .obligation_id(*obligation_id)
                         .obligation_allocation_idx(*obligation_allocation_idx)
                         .obligation_type(*obligation_type)
@@ -88,6 +107,7 @@ impl TryFromEvents<PaymentAllocationEvent> for PaymentAllocation {
                         .receivable_account_id(*receivable_account_id)
                         .effective(*effective)
                 }
+                PaymentAllocationEvent::Reversed { .. } => (),
             }
         }
         builder.events(events).build()
@@ -100,6 +120,42 @@ impl PaymentAllocation {
             .entity_first_persisted_at()
             .expect("entity_first_persisted_at not found")
     }
+
+    pub fn is_reversed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, PaymentAllocationEvent::Reversed { .. }))
+    }
+
+    pub(crate) fn reverse(
+        &mut self,
+        receivable_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<PaymentAllocationReversal> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            PaymentAllocationEvent::Reversed { .. }
+        );
+
+        let reversal = PaymentAllocationReversal {
+            tx_id: LedgerTxId::new(),
+            tx_ref: format!("{}-reversal", self.tx_ref()),
+            amount: self.amount,
+            receivable_account_id,
+            account_to_be_debited_id: self.account_to_be_debited_id,
+            effective,
+        };
+
+        self.events.push(PaymentAllocationEvent::Reversed {
+            ledger_tx_id: reversal.tx_id,
+            receivable_account_id,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(reversal)
+    }
 }
 
 #[derive(Debug, Builder, Clone)]
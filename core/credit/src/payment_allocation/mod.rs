@@ -2,7 +2,7 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::PaymentAllocation;
+pub use entity::{PaymentAllocation, PaymentAllocationReversal};
 
 #[cfg(feature = "json-schema")]
 pub use entity::PaymentAllocationEvent;
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PaymentRailError {
+    #[error("PaymentRailError - Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("PaymentRailError - Reqwest: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("PaymentRailError - SerdeJson: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+    #[error("PaymentRailError - MissingField: {0}")]
+    MissingField(String),
+    #[error("PaymentRailError - UnsupportedBankAccount: {0} cannot pay to {1}")]
+    UnsupportedBankAccount(&'static str, String),
+    #[error("PaymentRailError - InvalidRoutingNumber: {0}")]
+    InvalidRoutingNumber(String),
+    #[error("PaymentRailError - AmountTooLarge: {0}")]
+    AmountTooLarge(core_money::UsdCents),
+    #[error("PaymentRailError - InvalidWebhookSecret")]
+    InvalidWebhookSecret,
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+
+use std::path::PathBuf;
+
+use crate::config::{PaymentFileFormat, PaymentOriginator};
+
+use super::{
+    PaymentInstruction, PaymentRail, PaymentRailSubmission, error::PaymentRailError, nacha, pain001,
+};
+
+/// Writes one payment file per disbursal into `output_dir` for pickup by the
+/// bank's file transfer. Files are named after the instruction reference, so
+/// a retried send overwrites rather than duplicates the payment.
+#[derive(Clone)]
+pub struct FileBatchPaymentRail {
+    format: PaymentFileFormat,
+    output_dir: PathBuf,
+    originator: PaymentOriginator,
+}
+
+impl FileBatchPaymentRail {
+    pub fn new(
+        format: PaymentFileFormat,
+        output_dir: impl Into<PathBuf>,
+        originator: PaymentOriginator,
+    ) -> Self {
+        Self {
+            format,
+            output_dir: output_dir.into(),
+            originator,
+        }
+    }
+}
+
+#[async_trait]
+impl PaymentRail for FileBatchPaymentRail {
+    fn name(&self) -> &str {
+        match self.format {
+            PaymentFileFormat::Nacha => "nacha",
+            PaymentFileFormat::Pain001 => "pain001",
+        }
+    }
+
+    async fn send(
+        &self,
+        instruction: &PaymentInstruction,
+    ) -> Result<PaymentRailSubmission, PaymentRailError> {
+        let reference = instruction.reference();
+        let instructions = std::slice::from_ref(instruction);
+        let (contents, extension) = match self.format {
+            PaymentFileFormat::Nacha => (
+                nacha::write_batch(&self.originator, instructions, instruction.requested_at)?,
+                "ach",
+            ),
+            PaymentFileFormat::Pain001 => (
+                pain001::write_batch(
+                    &self.originator,
+                    &reference,
+                    instructions,
+                    instruction.requested_at,
+                )?,
+                "xml",
+            ),
+        };
+
+        std::fs::create_dir_all(&self.output_dir)?;
+        let path = self.output_dir.join(format!("{reference}.{extension}"));
+        let tmp_path = path.with_extension(format!("{extension}.tmp"));
+        std::fs::write(&tmp_path, contents)?;
+        std::fs::rename(&tmp_path, &path)?;
+
+        Ok(PaymentRailSubmission {
+            external_id: reference,
+            settled: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::payment_rail::test_utils::*;
+
+    #[tokio::test]
+    async fn writes_file_named_after_reference() {
+        let dir = tempfile::tempdir().unwrap();
+        let rail = FileBatchPaymentRail::new(PaymentFileFormat::Pain001, dir.path(), originator());
+        let instruction = instruction(iban());
+
+        let submission = rail.send(&instruction).await.unwrap();
+        rail.send(&instruction).await.unwrap();
+
+        assert_eq!(submission.external_id, instruction.reference());
+        assert!(!submission.settled);
+        let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        assert!(
+            dir.path()
+                .join(format!("{}.xml", instruction.reference()))
+                .exists()
+        );
+    }
+}
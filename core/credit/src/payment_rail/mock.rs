@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use super::{PaymentInstruction, PaymentRail, PaymentRailSubmission, error::PaymentRailError};
+
+/// Local stand-in that accepts and settles every payment on submission.
+#[derive(Clone)]
+pub struct MockPaymentRail;
+
+#[async_trait]
+impl PaymentRail for MockPaymentRail {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn send(
+        &self,
+        instruction: &PaymentInstruction,
+    ) -> Result<PaymentRailSubmission, PaymentRailError> {
+        Ok(PaymentRailSubmission {
+            external_id: format!("mock-{}", instruction.reference()),
+            settled: true,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+pub mod error;
+mod file_batch;
+mod mock;
+mod nacha;
+mod pain001;
+mod webhook;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use std::sync::Arc;
+
+use crate::{config::PaymentRailConfig, primitives::*};
+
+use error::PaymentRailError;
+
+pub use file_batch::FileBatchPaymentRail;
+pub use mock::MockPaymentRail;
+pub use webhook::WebhookPaymentRail;
+
+/// A single disbursal payout handed to a [`PaymentRail`].
+#[derive(Debug, Clone)]
+pub struct PaymentInstruction {
+    pub disbursal_id: DisbursalId,
+    pub amount: UsdCents,
+    pub beneficiary: BeneficiaryAccount,
+    pub requested_at: DateTime<Utc>,
+}
+
+impl PaymentInstruction {
+    /// End-to-end reference carried through the rail, 32 characters so it fits
+    /// the ISO 20022 `EndToEndId` limit.
+    pub fn reference(&self) -> String {
+        uuid::Uuid::from(self.disbursal_id)
+            .simple()
+            .to_string()
+            .to_uppercase()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentRailSubmission {
+    pub external_id: String,
+    /// The rail has already moved the funds; no confirmation will follow.
+    pub settled: bool,
+}
+
+/// Settlement outcome reported back by a rail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PaymentOutcome {
+    Settled,
+    Returned { reason: String },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentRailNotification {
+    pub external_id: String,
+    #[serde(flatten)]
+    pub outcome: PaymentOutcome,
+}
+
+/// Moves disbursed funds to an external bank account.
+///
+/// `send` may be retried for the same instruction if recording the submission
+/// fails, so implementations must treat [`PaymentInstruction::reference`] as an
+/// idempotency key.
+#[async_trait]
+pub trait PaymentRail: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn send(
+        &self,
+        instruction: &PaymentInstruction,
+    ) -> Result<PaymentRailSubmission, PaymentRailError>;
+
+    /// Checks the shared secret presented on an inbound notification. Rails
+    /// without a webhook reject every notification.
+    fn verify_webhook_secret(&self, _secret: Option<&str>) -> Result<(), PaymentRailError> {
+        Err(PaymentRailError::InvalidWebhookSecret)
+    }
+}
+
+pub(crate) fn from_config(config: &PaymentRailConfig) -> Arc<dyn PaymentRail> {
+    match config {
+        PaymentRailConfig::Mock => Arc::new(MockPaymentRail),
+        PaymentRailConfig::FileBatch {
+            format,
+            output_dir,
+            originator,
+        } => Arc::new(FileBatchPaymentRail::new(
+            *format,
+            output_dir.clone(),
+            originator.clone(),
+        )),
+        PaymentRailConfig::Webhook {
+            url,
+            api_key,
+            webhook_secret,
+        } => Arc::new(WebhookPaymentRail::new(
+            url.clone(),
+            api_key.clone(),
+            webhook_secret.clone(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::*;
+
+    pub fn originator() -> crate::config::PaymentOriginator {
+        crate::config::PaymentOriginator {
+            name: "Lana Bank".to_string(),
+            id: "1234567890".to_string(),
+            routing_number: "021000021".to_string(),
+            account_number: "9876543210".to_string(),
+            bic: Some("LANAUS33".to_string()),
+        }
+    }
+
+    pub fn instruction(details: BankAccountDetails) -> PaymentInstruction {
+        PaymentInstruction {
+            disbursal_id: DisbursalId::new(),
+            amount: UsdCents::from(123_456),
+            beneficiary: BeneficiaryAccount {
+                id: BeneficiaryAccountId::new(),
+                holder_name: "Jane Borrower".to_string(),
+                bank_name: "Receiving Bank".to_string(),
+                details,
+            },
+            requested_at: "2025-03-14T09:26:53Z".parse().unwrap(),
+        }
+    }
+
+    pub fn aba() -> BankAccountDetails {
+        BankAccountDetails::Aba {
+            routing_number: "011000015".to_string(),
+            account_number: "12345678".to_string(),
+        }
+    }
+
+    pub fn iban() -> BankAccountDetails {
+        BankAccountDetails::Iban {
+            iban: "DE89370400440532013000".to_string(),
+            bic: "COBADEFFXXX".to_string(),
+        }
+    }
+}
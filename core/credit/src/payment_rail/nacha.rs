@@ -0,0 +1,245 @@
+use chrono::{DateTime, Utc};
+
+use crate::{config::PaymentOriginator, primitives::*};
+
+use super::{PaymentInstruction, error::PaymentRailError};
+
+const RECORD_LENGTH: usize = 94;
+const BLOCKING_FACTOR: usize = 10;
+const SERVICE_CLASS_CREDITS_ONLY: &str = "220";
+const CHECKING_CREDIT: &str = "22";
+const MAX_ENTRY_AMOUNT: u64 = 9_999_999_999;
+
+/// Renders a NACHA file with a single PPD credit batch, one entry per
+/// instruction. Only ABA-routed beneficiary accounts can be paid over ACH.
+pub(super) fn write_batch(
+    originator: &PaymentOriginator,
+    instructions: &[PaymentInstruction],
+    created_at: DateTime<Utc>,
+) -> Result<String, PaymentRailError> {
+    let odfi = &routing_number(&originator.routing_number)?[..8];
+    let mut records = vec![
+        [
+            "1",
+            "01",
+            &numeric_padded_left(&originator.routing_number, 10, ' '),
+            &alpha(&originator.id, 10),
+            &created_at.format("%y%m%d%H%M").to_string(),
+            "A",
+            "094",
+            "10",
+            "1",
+            &alpha("", 23),
+            &alpha(&originator.name, 23),
+            &alpha("", 8),
+        ]
+        .concat(),
+        [
+            "5",
+            SERVICE_CLASS_CREDITS_ONLY,
+            &alpha(&originator.name, 16),
+            &alpha("", 20),
+            &alpha(&originator.id, 10),
+            "PPD",
+            &alpha("DISBURSAL", 10),
+            &alpha("", 6),
+            &created_at.format("%y%m%d").to_string(),
+            &alpha("", 3),
+            "1",
+            &numeric(odfi, 8),
+            &numeric("1", 7),
+        ]
+        .concat(),
+    ];
+
+    let mut entry_hash: u64 = 0;
+    let mut total_credit: u64 = 0;
+    for (idx, instruction) in instructions.iter().enumerate() {
+        let (routing_number, account_number) = match &instruction.beneficiary.details {
+            BankAccountDetails::Aba {
+                routing_number: aba,
+                account_number,
+            } => (routing_number(aba)?, account_number),
+            BankAccountDetails::Iban { iban, .. } => {
+                return Err(PaymentRailError::UnsupportedBankAccount(
+                    "nacha",
+                    iban.clone(),
+                ));
+            }
+        };
+        let amount = instruction.amount.into_inner();
+        if amount > MAX_ENTRY_AMOUNT {
+            return Err(PaymentRailError::AmountTooLarge(instruction.amount));
+        }
+
+        entry_hash += routing_number[..8]
+            .parse::<u64>()
+            .expect("routing number is validated");
+        total_credit += amount;
+        records.push(
+            [
+                "6",
+                CHECKING_CREDIT,
+                &routing_number[..8],
+                &routing_number[8..9],
+                &alpha(account_number, 17),
+                &numeric(&amount.to_string(), 10),
+                &alpha(&instruction.reference(), 15),
+                &alpha(&instruction.beneficiary.holder_name, 22),
+                &alpha("", 2),
+                "0",
+                &numeric(odfi, 8),
+                &numeric(&(idx + 1).to_string(), 7),
+            ]
+            .concat(),
+        );
+    }
+
+    let entry_count = instructions.len().to_string();
+    let entry_hash = (entry_hash % 10_000_000_000).to_string();
+    let total_credit = total_credit.to_string();
+    records.push(
+        [
+            "8",
+            SERVICE_CLASS_CREDITS_ONLY,
+            &numeric(&entry_count, 6),
+            &numeric(&entry_hash, 10),
+            &numeric("0", 12),
+            &numeric(&total_credit, 12),
+            &alpha(&originator.id, 10),
+            &alpha("", 19),
+            &alpha("", 6),
+            &numeric(odfi, 8),
+            &numeric("1", 7),
+        ]
+        .concat(),
+    );
+
+    let block_count = (records.len() + 1).div_ceil(BLOCKING_FACTOR).to_string();
+    records.push(
+        [
+            "9",
+            &numeric("1", 6),
+            &numeric(&block_count, 6),
+            &numeric(&entry_count, 8),
+            &numeric(&entry_hash, 10),
+            &numeric("0", 12),
+            &numeric(&total_credit, 12),
+            &alpha("", 39),
+        ]
+        .concat(),
+    );
+    while records.len() % BLOCKING_FACTOR != 0 {
+        records.push("9".repeat(RECORD_LENGTH));
+    }
+
+    let mut file = records.join("\n");
+    file.push('\n');
+    Ok(file)
+}
+
+/// ABA routing numbers are exactly nine digits, the last being the check digit.
+fn routing_number(value: &str) -> Result<&str, PaymentRailError> {
+    if value.len() != 9 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(PaymentRailError::InvalidRoutingNumber(value.to_string()));
+    }
+    Ok(value)
+}
+
+fn alpha(value: &str, width: usize) -> String {
+    let value: String = value
+        .chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .take(width)
+        .collect::<String>()
+        .to_uppercase();
+    format!("{value:<width$}")
+}
+
+fn numeric(value: &str, width: usize) -> String {
+    numeric_padded_left(value, width, '0')
+}
+
+fn numeric_padded_left(value: &str, width: usize, pad: char) -> String {
+    let value = &value[value.len().saturating_sub(width)..];
+    format!("{}{value}", pad.to_string().repeat(width - value.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::payment_rail::test_utils::*;
+
+    #[test]
+    fn records_are_fixed_width_and_blocked() {
+        let file = write_batch(
+            &originator(),
+            &[instruction(aba()), instruction(aba())],
+            "2025-03-14T09:26:53Z".parse().unwrap(),
+        )
+        .unwrap();
+
+        let records: Vec<&str> = file.lines().collect();
+        assert_eq!(records.len(), 10);
+        assert!(records.iter().all(|r| r.len() == RECORD_LENGTH));
+        assert_eq!(
+            records.iter().map(|r| &r[..1]).collect::<Vec<_>>().concat(),
+            "1566899999"
+        );
+    }
+
+    #[test]
+    fn control_records_carry_totals() {
+        let file = write_batch(
+            &originator(),
+            &[instruction(aba()), instruction(aba())],
+            "2025-03-14T09:26:53Z".parse().unwrap(),
+        )
+        .unwrap();
+        let records: Vec<&str> = file.lines().collect();
+
+        let entry = records[2];
+        assert_eq!(&entry[1..3], CHECKING_CREDIT);
+        assert_eq!(&entry[3..12], "011000015");
+        assert_eq!(&entry[29..39], "0000123456");
+
+        let batch_control = records[4];
+        assert_eq!(&batch_control[4..10], "000002");
+        assert_eq!(&batch_control[10..20], "0002200002");
+        assert_eq!(&batch_control[32..44], "000000246912");
+
+        let file_control = records[5];
+        assert_eq!(&file_control[7..13], "000001");
+        assert_eq!(&file_control[43..55], "000000246912");
+    }
+
+    #[test]
+    fn rejects_iban_beneficiaries() {
+        assert!(matches!(
+            write_batch(
+                &originator(),
+                &[instruction(iban())],
+                "2025-03-14T09:26:53Z".parse().unwrap(),
+            ),
+            Err(PaymentRailError::UnsupportedBankAccount(..))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_routing_numbers() {
+        for routing_number in ["01100001", "01100001Å", "0110000150"] {
+            assert!(matches!(
+                write_batch(
+                    &originator(),
+                    &[instruction(BankAccountDetails::Aba {
+                        routing_number: routing_number.to_string(),
+                        account_number: "12345678".to_string(),
+                    })],
+                    "2025-03-14T09:26:53Z".parse().unwrap(),
+                ),
+                Err(PaymentRailError::InvalidRoutingNumber(_))
+            ));
+        }
+    }
+}
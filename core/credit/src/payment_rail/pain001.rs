@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+
+use std::fmt::Write;
+
+use crate::{config::PaymentOriginator, primitives::*};
+
+use super::{PaymentInstruction, error::PaymentRailError};
+
+const NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.03";
+
+/// Renders an ISO 20022 `pain.001.001.03` customer credit transfer initiation
+/// with one payment information block for all instructions.
+pub(super) fn write_batch(
+    originator: &PaymentOriginator,
+    message_id: &str,
+    instructions: &[PaymentInstruction],
+    created_at: DateTime<Utc>,
+) -> Result<String, PaymentRailError> {
+    let count = instructions.len();
+    let control_sum = format_amount(
+        instructions
+            .iter()
+            .fold(UsdCents::ZERO, |total, i| total + i.amount),
+    );
+
+    let mut xml = String::new();
+    let _ = write!(
+        xml,
+        r#"<?xml version="1.0" encoding="UTF-8"?><Document xmlns="{NAMESPACE}"><CstmrCdtTrfInitn><GrpHdr><MsgId>{msg_id}</MsgId><CreDtTm>{created}</CreDtTm><NbOfTxs>{count}</NbOfTxs><CtrlSum>{control_sum}</CtrlSum><InitgPty><Nm>{name}</Nm><Id><OrgId><Othr><Id>{id}</Id></Othr></OrgId></Id></InitgPty></GrpHdr>"#,
+        msg_id = escape(message_id),
+        created = created_at.format("%Y-%m-%dT%H:%M:%S"),
+        name = escape(&originator.name),
+        id = escape(&originator.id),
+    );
+    let _ = write!(
+        xml,
+        r#"<PmtInf><PmtInfId>{msg_id}</PmtInfId><PmtMtd>TRF</PmtMtd><NbOfTxs>{count}</NbOfTxs><CtrlSum>{control_sum}</CtrlSum><ReqdExctnDt>{execution_date}</ReqdExctnDt><Dbtr><Nm>{name}</Nm></Dbtr><DbtrAcct><Id><Othr><Id>{account}</Id></Othr></Id><Ccy>USD</Ccy></DbtrAcct><DbtrAgt>{agent}</DbtrAgt>"#,
+        msg_id = escape(message_id),
+        execution_date = created_at.format("%Y-%m-%d"),
+        name = escape(&originator.name),
+        account = escape(&originator.account_number),
+        agent = match &originator.bic {
+            Some(bic) => bic_agent(bic),
+            None => aba_agent(&originator.routing_number),
+        },
+    );
+
+    for instruction in instructions {
+        let (agent, account) = match &instruction.beneficiary.details {
+            BankAccountDetails::Aba {
+                routing_number,
+                account_number,
+            } => (
+                aba_agent(routing_number),
+                format!("<Othr><Id>{}</Id></Othr>", escape(account_number)),
+            ),
+            BankAccountDetails::Iban { iban, bic } => {
+                (bic_agent(bic), format!("<IBAN>{}</IBAN>", escape(iban)))
+            }
+        };
+        let _ = write!(
+            xml,
+            r#"<CdtTrfTxInf><PmtId><EndToEndId>{reference}</EndToEndId></PmtId><Amt><InstdAmt Ccy="USD">{amount}</InstdAmt></Amt><CdtrAgt>{agent}</CdtrAgt><Cdtr><Nm>{name}</Nm></Cdtr><CdtrAcct><Id>{account}</Id></CdtrAcct></CdtTrfTxInf>"#,
+            reference = instruction.reference(),
+            amount = format_amount(instruction.amount),
+            name = escape(&instruction.beneficiary.holder_name),
+        );
+    }
+    xml.push_str("</PmtInf></CstmrCdtTrfInitn></Document>\n");
+
+    Ok(xml)
+}
+
+fn bic_agent(bic: &str) -> String {
+    format!("<FinInstnId><BIC>{}</BIC></FinInstnId>", escape(bic))
+}
+
+fn aba_agent(routing_number: &str) -> String {
+    format!(
+        "<FinInstnId><ClrSysMmbId><ClrSysId><Cd>USABA</Cd></ClrSysId><MmbId>{}</MmbId></ClrSysMmbId></FinInstnId>",
+        escape(routing_number)
+    )
+}
+
+fn format_amount(amount: UsdCents) -> String {
+    format!("{:.2}", amount.to_usd())
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::payment_rail::test_utils::*;
+
+    #[test]
+    fn group_header_carries_totals() {
+        let xml = write_batch(
+            &originator(),
+            "MSG-1",
+            &[instruction(iban()), instruction(aba())],
+            "2025-03-14T09:26:53Z".parse().unwrap(),
+        )
+        .unwrap();
+
+        assert!(xml.contains(
+            "<MsgId>MSG-1</MsgId><CreDtTm>2025-03-14T09:26:53</CreDtTm><NbOfTxs>2</NbOfTxs><CtrlSum>2469.12</CtrlSum>"
+        ));
+        assert!(xml.contains("<ReqdExctnDt>2025-03-14</ReqdExctnDt>"));
+        assert_eq!(xml.matches("<CdtTrfTxInf>").count(), 2);
+    }
+
+    #[test]
+    fn creditor_account_follows_bank_details() {
+        let xml = write_batch(
+            &originator(),
+            "MSG-1",
+            &[instruction(iban()), instruction(aba())],
+            "2025-03-14T09:26:53Z".parse().unwrap(),
+        )
+        .unwrap();
+
+        assert!(xml.contains(
+            "<CdtrAgt><FinInstnId><BIC>COBADEFFXXX</BIC></FinInstnId></CdtrAgt><Cdtr><Nm>Jane Borrower</Nm></Cdtr><CdtrAcct><Id><IBAN>DE89370400440532013000</IBAN></Id></CdtrAcct>"
+        ));
+        assert!(xml.contains("<MmbId>011000015</MmbId>"));
+        assert!(xml.contains("<Othr><Id>12345678</Id></Othr>"));
+    }
+
+    #[test]
+    fn escapes_names() {
+        let mut instruction = instruction(iban());
+        instruction.beneficiary.holder_name = "Smith & <Sons>".to_string();
+        let xml = write_batch(
+            &originator(),
+            "MSG-1",
+            &[instruction],
+            "2025-03-14T09:26:53Z".parse().unwrap(),
+        )
+        .unwrap();
+
+        assert!(xml.contains("<Nm>Smith &amp; &lt;Sons&gt;</Nm>"));
+    }
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use reqwest::Client as ReqwestClient;
+use serde_json::json;
+
+use crate::primitives::BankAccountDetails;
+
+use super::{PaymentInstruction, PaymentRail, PaymentRailSubmission, error::PaymentRailError};
+
+/// Posts each payment to a payment provider's HTTP API. The provider reports
+/// the outcome later on the payment rail webhook, authenticated with
+/// `webhook_secret`.
+#[derive(Clone)]
+pub struct WebhookPaymentRail {
+    url: String,
+    api_key: Option<String>,
+    webhook_secret: String,
+    client: ReqwestClient,
+}
+
+impl WebhookPaymentRail {
+    pub fn new(url: String, api_key: Option<String>, webhook_secret: String) -> Self {
+        Self {
+            url,
+            api_key,
+            webhook_secret,
+            client: ReqwestClient::builder()
+                .use_rustls_tls()
+                .build()
+                .expect("should always build WebhookPaymentRail client"),
+        }
+    }
+}
+
+#[async_trait]
+impl PaymentRail for WebhookPaymentRail {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn send(
+        &self,
+        instruction: &PaymentInstruction,
+    ) -> Result<PaymentRailSubmission, PaymentRailError> {
+        let reference = instruction.reference();
+        let beneficiary = &instruction.beneficiary;
+        let account = match &beneficiary.details {
+            BankAccountDetails::Aba {
+                routing_number,
+                account_number,
+            } => json!({
+                "routing_number": routing_number,
+                "account_number": account_number,
+            }),
+            BankAccountDetails::Iban { iban, bic } => json!({
+                "iban": iban,
+                "bic": bic,
+            }),
+        };
+        let body = json!({
+            "reference": reference,
+            "amount": instruction.amount.to_usd().to_string(),
+            "currency": "USD",
+            "beneficiary": {
+                "name": beneficiary.holder_name,
+                "bank_name": beneficiary.bank_name,
+                "account": account,
+            },
+        });
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("idempotency-key", &reference)
+            .json(&body);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response: serde_json::Value = request.send().await?.error_for_status()?.json().await?;
+        let external_id = response
+            .get("id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| PaymentRailError::MissingField("id".to_string()))?;
+
+        Ok(PaymentRailSubmission {
+            external_id: external_id.to_string(),
+            settled: false,
+        })
+    }
+
+    fn verify_webhook_secret(&self, secret: Option<&str>) -> Result<(), PaymentRailError> {
+        match secret {
+            Some(secret) if constant_time_eq(self.webhook_secret.as_bytes(), secret.as_bytes()) => {
+                Ok(())
+            }
+            _ => Err(PaymentRailError::InvalidWebhookSecret),
+        }
+    }
+}
+
+/// Compares without short-circuiting on the first differing byte so the
+/// response time does not reveal how much of the secret was guessed.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -58,6 +58,16 @@ impl CVLPct {
         CVLPct::from(ratio)
     }
 
+    /// BTC price in USD cents at which a facility whose collateral to
+    /// outstanding ratio (sats per cent) is `collateralization_ratio` sits
+    /// exactly at this CVL.
+    pub fn btc_price_at_ratio(&self, collateralization_ratio: Decimal) -> Option<Decimal> {
+        if collateralization_ratio.is_zero() {
+            return None;
+        }
+        Some(self.0 * dec!(1_000_000) / collateralization_ratio)
+    }
+
     pub fn is_significantly_lower_than(&self, other: CVLPct, buffer: CVLPct) -> bool {
         other > *self + buffer
     }
@@ -168,4 +178,13 @@ mod test {
             CVLPct::from_loan_amounts(collateral_value, outstanding_amount);
         assert!(cvl.is_significantly_lower_than(significantly_higher_cvl, buffer));
     }
+
+    #[test]
+    fn btc_price_at_ratio() {
+        // 1 BTC of collateral against $50,000 outstanding
+        let ratio = Decimal::from(100_000_000) / Decimal::from(5_000_000);
+        let cvl = CVLPct::new(125);
+        assert_eq!(cvl.btc_price_at_ratio(ratio), Some(dec!(6_250_000)));
+        assert_eq!(cvl.btc_price_at_ratio(Decimal::ZERO), None);
+    }
 }
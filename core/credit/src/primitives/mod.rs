@@ -15,7 +15,9 @@ pub use cala_ledger::primitives::{
     DebitOrCredit as LedgerDebitOrCredit, JournalId as LedgerJournalId,
     TransactionId as LedgerTxId, TxTemplateId as LedgerTxTemplateId,
 };
-pub use core_customer::{CustomerId, CustomerType};
+pub use core_customer::{
+    BankAccountDetails, BeneficiaryAccount, BeneficiaryAccountId, CustomerId, CustomerType,
+};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use governance::ApprovalProcessId;
@@ -31,7 +33,9 @@ es_entity::entity_id! {
     CollateralId,
     ObligationId,
     LiquidationProcessId,
+    MarginCallId,
     InterestAccrualCycleId,
+    ReferenceRateId,
     TermsTemplateId;
 
     CreditFacilityId => governance::ApprovalProcessId,
@@ -40,6 +44,7 @@ es_entity::entity_id! {
     CreditFacilityId => job::JobId,
     InterestAccrualCycleId => job::JobId,
     ObligationId => job::JobId,
+    MarginCallId => job::JobId,
 
     DisbursalId => LedgerTxId,
     PaymentAllocationId => LedgerTxId,
@@ -59,6 +64,17 @@ pub enum ObligationStatus {
 pub enum ObligationType {
     Disbursal,
     Interest,
+    Penalty,
+    Fee,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FacilityFeeType {
+    Commitment,
+    Maintenance,
+    Disbursal,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
@@ -66,6 +82,8 @@ pub enum ObligationType {
 pub enum BalanceUpdatedType {
     Disbursal,
     InterestAccrual,
+    PenaltyAccrual,
+    FeeCharge,
 }
 
 impl From<ObligationType> for BalanceUpdatedType {
@@ -73,6 +91,8 @@ impl From<ObligationType> for BalanceUpdatedType {
         match obligation_type {
             ObligationType::Disbursal => Self::Disbursal,
             ObligationType::Interest => Self::InterestAccrual,
+            ObligationType::Penalty => Self::PenaltyAccrual,
+            ObligationType::Fee => Self::FeeCharge,
         }
     }
 }
@@ -116,6 +136,7 @@ pub type CreditFacilityAllOrOne = AllOrOne<CreditFacilityId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type DisbursalAllOrOne = AllOrOne<DisbursalId>;
 pub type ObligationAllOrOne = AllOrOne<ObligationId>;
+pub type ReferenceRateAllOrOne = AllOrOne<ReferenceRateId>;
 pub type TermsTemplateAllOrOne = AllOrOne<TermsTemplateId>;
 
 pub const PERMISSION_SET_CREDIT_WRITER: &str = "credit_writer";
@@ -129,6 +150,7 @@ pub enum CoreCreditObject {
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Disbursal(DisbursalAllOrOne),
     Obligation(ObligationAllOrOne),
+    ReferenceRate(ReferenceRateAllOrOne),
     TermsTemplate(TermsTemplateAllOrOne),
 }
 
@@ -161,6 +183,14 @@ impl CoreCreditObject {
         CoreCreditObject::Obligation(AllOrOne::All)
     }
 
+    pub fn reference_rate(id: ReferenceRateId) -> Self {
+        CoreCreditObject::ReferenceRate(AllOrOne::ById(id))
+    }
+
+    pub fn all_reference_rates() -> Self {
+        CoreCreditObject::ReferenceRate(AllOrOne::All)
+    }
+
     pub fn terms_template(id: TermsTemplateId) -> Self {
         CoreCreditObject::TermsTemplate(AllOrOne::ById(id))
     }
@@ -179,6 +209,7 @@ impl std::fmt::Display for CoreCreditObject {
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Disbursal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Obligation(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            ReferenceRate(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             TermsTemplate(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
@@ -207,6 +238,10 @@ impl FromStr for CoreCreditObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
                 CoreCreditObject::Disbursal(obj_ref)
             }
+            ReferenceRate => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
+                CoreCreditObject::ReferenceRate(obj_ref)
+            }
             TermsTemplate => {
                 let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
                 CoreCreditObject::TermsTemplate(obj_ref)
@@ -224,6 +259,7 @@ pub enum CoreCreditAction {
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Disbursal(DisbursalAction),
     Obligation(ObligationAction),
+    ReferenceRate(ReferenceRateAction),
     TermsTemplate(TermsTemplateAction),
 }
 
@@ -240,12 +276,22 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::Activate);
     pub const CREDIT_FACILITY_RECORD_INTEREST: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::RecordInterest);
+    pub const CREDIT_FACILITY_RECORD_FEE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::RecordFee);
     pub const CREDIT_FACILITY_COMPLETE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::Complete);
+    pub const CREDIT_FACILITY_PREPAY: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Prepay);
+    pub const CREDIT_FACILITY_RESTRUCTURE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Restructure);
     pub const CREDIT_FACILITY_UPDATE_COLLATERAL: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateral);
+    pub const CREDIT_FACILITY_WITHDRAW_COLLATERAL: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::WithdrawCollateral);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
+    pub const CREDIT_FACILITY_SIMULATE_PRICE_SHOCK: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::SimulatePriceShock);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ: Self =
         CoreCreditAction::ChartOfAccountsIntegrationConfig(
@@ -266,6 +312,21 @@ impl CoreCreditAction {
         CoreCreditAction::Obligation(ObligationAction::UpdateStatus);
     pub const OBLIGATION_RECORD_PAYMENT: Self =
         CoreCreditAction::Obligation(ObligationAction::RecordPaymentAllocation);
+    pub const OBLIGATION_REVERSE_PAYMENT: Self =
+        CoreCreditAction::Obligation(ObligationAction::ReversePaymentAllocation);
+    pub const OBLIGATION_RECORD_PENALTY: Self =
+        CoreCreditAction::Obligation(ObligationAction::RecordPenalty);
+    pub const OBLIGATION_RECORD_LIQUIDATION: Self =
+        CoreCreditAction::Obligation(ObligationAction::RecordLiquidation);
+
+    pub const REFERENCE_RATE_CREATE: Self =
+        CoreCreditAction::ReferenceRate(ReferenceRateAction::Create);
+    pub const REFERENCE_RATE_READ: Self =
+        CoreCreditAction::ReferenceRate(ReferenceRateAction::Read);
+    pub const REFERENCE_RATE_LIST: Self =
+        CoreCreditAction::ReferenceRate(ReferenceRateAction::List);
+    pub const REFERENCE_RATE_PUBLISH_FIXING: Self =
+        CoreCreditAction::ReferenceRate(ReferenceRateAction::PublishFixing);
 
     pub const TERMS_TEMPLATE_CREATE: Self =
         CoreCreditAction::TermsTemplate(TermsTemplateAction::Create);
@@ -292,6 +353,7 @@ impl CoreCreditAction {
                 }
                 Disbursal => DisbursalAction::describe(),
                 Obligation => ObligationAction::describe(),
+                ReferenceRate => ReferenceRateAction::describe(),
                 TermsTemplate => TermsTemplateAction::describe(),
             };
 
@@ -311,6 +373,7 @@ impl std::fmt::Display for CoreCreditAction {
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Disbursal(action) => action.fmt(f),
             Obligation(action) => action.fmt(f),
+            ReferenceRate(action) => action.fmt(f),
             TermsTemplate(action) => action.fmt(f),
         }
     }
@@ -331,6 +394,7 @@ impl FromStr for CoreCreditAction {
             }
             Disbursal => CoreCreditAction::from(action.parse::<DisbursalAction>()?),
             Obligation => CoreCreditAction::from(action.parse::<ObligationAction>()?),
+            ReferenceRate => CoreCreditAction::from(action.parse::<ReferenceRateAction>()?),
             TermsTemplate => CoreCreditAction::from(action.parse::<TermsTemplateAction>()?),
         };
         Ok(res)
@@ -347,8 +411,13 @@ pub enum CreditFacilityAction {
     Activate,
     UpdateCollateral,
     RecordInterest,
+    RecordFee,
     Complete,
+    Prepay,
+    Restructure,
     UpdateCollateralizationState,
+    WithdrawCollateral,
+    SimulatePriceShock,
 }
 
 impl CreditFacilityAction {
@@ -376,10 +445,22 @@ impl CreditFacilityAction {
                 Self::RecordInterest => {
                     ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
                 }
+                Self::RecordFee => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
                 Self::Complete => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Prepay => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Restructure => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
                 Self::UpdateCollateralizationState => {
                     ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
                 }
+                Self::WithdrawCollateral => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+                Self::SimulatePriceShock => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
             };
             res.push(action_description);
         }
@@ -471,6 +552,9 @@ pub enum ObligationAction {
     Read,
     UpdateStatus,
     RecordPaymentAllocation,
+    ReversePaymentAllocation,
+    RecordPenalty,
+    RecordLiquidation,
 }
 
 impl ObligationAction {
@@ -489,6 +573,15 @@ impl ObligationAction {
                 Self::RecordPaymentAllocation => {
                     ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
                 }
+                Self::ReversePaymentAllocation => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+                Self::RecordPenalty => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+                Self::RecordLiquidation => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -503,6 +596,47 @@ impl From<ObligationAction> for CoreCreditAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ReferenceRateAction {
+    Create,
+    Read,
+    List,
+    PublishFixing,
+}
+
+impl ReferenceRateAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::PublishFixing => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<ReferenceRateAction> for CoreCreditAction {
+    fn from(action: ReferenceRateAction) -> Self {
+        Self::ReferenceRate(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum TermsTemplateAction {
@@ -573,6 +707,8 @@ pub enum DisbursalStatus {
     Approved,
     Denied,
     Confirmed,
+    Sent,
+    Returned,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Hash, Deserialize, sqlx::Type)]
@@ -622,9 +758,32 @@ pub enum CollateralizationState {
     NoCollateral,
 }
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum MarginCallStatus {
+    Open,
+    Cured,
+    Escalated,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum MarginCallCureReason {
+    CollateralTopUp,
+    Repayment,
+    PriceRecovery,
+}
+
 pub struct CollateralUpdate {
     pub tx_id: LedgerTxId,
-    pub abs_diff: Satoshis,
+    pub collateral_account_id: CalaAccountId,
+    pub abs_diff: CollateralAmount,
     pub action: CollateralAction,
     pub effective: chrono::NaiveDate,
 }
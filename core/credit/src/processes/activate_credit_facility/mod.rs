@@ -14,7 +14,7 @@ use crate::{
     disbursal::{Disbursals, NewDisbursal},
     error::CoreCreditError,
     event::CoreCreditEvent,
-    jobs::interest_accruals,
+    jobs::{facility_maintenance_fee, interest_accruals},
     ledger::CreditLedger,
     primitives::{CoreCreditAction, CoreCreditObject, CreditFacilityId, DisbursalId},
 };
@@ -119,7 +119,12 @@ where
                     .expect("could not build new disbursal");
 
                 self.disbursals
-                    .create_first_disbursal_in_op(&mut db, new_disbursal, &audit_info)
+                    .create_first_disbursal_in_op(
+                        &mut db,
+                        new_disbursal,
+                        &credit_facility.terms,
+                        &audit_info,
+                    )
                     .await?;
 
                 let accrual_id = credit_facility
@@ -138,6 +143,20 @@ where
                     )
                     .await?;
 
+                if let Some(first_fee_date) = credit_facility.next_maintenance_fee_date() {
+                    self.jobs
+                        .create_and_spawn_at_in_op(
+                            &mut db,
+                            ::job::JobId::new(),
+                            facility_maintenance_fee::FacilityMaintenanceFeeJobConfig::<Perms, E> {
+                                credit_facility_id: id,
+                                _phantom: std::marker::PhantomData,
+                            },
+                            first_fee_date,
+                        )
+                        .await?;
+                }
+
                 self.ledger
                     .activate_credit_facility(db, credit_facility_activation)
                     .await?;
@@ -0,0 +1,153 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_custody::{CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject};
+use governance::{
+    ApprovalProcessId, ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    Collaterals, CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacilities,
+    CreditFacilityId, CreditLedger, Price, error::CoreCreditError,
+};
+
+pub use job::*;
+pub const APPROVE_COLLATERAL_WITHDRAWAL_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("collateral-withdrawal");
+
+pub struct ApproveCollateralWithdrawal<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    collaterals: Collaterals<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    ledger: CreditLedger,
+    price: Price,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> Clone for ApproveCollateralWithdrawal<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            collaterals: self.collaterals.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            ledger: self.ledger.clone(),
+            price: self.price.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveCollateralWithdrawal<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    pub fn new(
+        collaterals: &Collaterals<Perms, E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        ledger: &CreditLedger,
+        price: &Price,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            collaterals: collaterals.clone(),
+            credit_facilities: credit_facilities.clone(),
+            ledger: ledger.clone(),
+            price: price.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(
+        name = "credit_facility.collateral_withdrawal_approval.execute",
+        skip(self)
+    )]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<CreditFacilityId>,
+        approval_process_id: impl es_entity::RetryableInto<ApprovalProcessId>,
+        approved: bool,
+    ) -> Result<(), CoreCreditError> {
+        let approval_process_id = approval_process_id.into();
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(id.into())
+            .await?;
+        let collateral = self
+            .collaterals
+            .find_by_id_without_audit(credit_facility.collateral_id)
+            .await?;
+        let Some(pending) = collateral
+            .pending_withdrawal()
+            .filter(|p| p.approval_process_id == approval_process_id)
+        else {
+            return Ok(());
+        };
+
+        // The price may have moved since the request was made, so the
+        // withdrawal is only executed if the amount is still releasable.
+        let approved = approved && {
+            let price = self.price.usd_cents_per_btc().await?;
+            pending.amount
+                <= self
+                    .credit_facilities
+                    .releasable_collateral_without_audit(&credit_facility, price)
+                    .await?
+        };
+
+        let mut db = self.collaterals.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(credit_facility.id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        // The funds are sent by the collateral withdrawals job once the
+        // concluded withdrawal has been committed.
+        let Some(collateral_update) = self
+            .collaterals
+            .conclude_withdrawal_in_op(
+                &mut db,
+                collateral.id,
+                approval_process_id,
+                approved,
+                crate::time::now().date_naive(),
+                &audit_info,
+            )
+            .await?
+        else {
+            db.commit().await?;
+            return Ok(());
+        };
+
+        self.ledger
+            .update_credit_facility_collateral(db, collateral_update)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcessId, ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacilities, CreditFacility,
+    CreditFacilityId, Jobs,
+    credit_facility::{RestructuringData, RestructuringOutcome},
+    error::CoreCreditError,
+    jobs::interest_accruals,
+    ledger::CreditLedger,
+};
+
+pub use job::*;
+pub const APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("credit-facility-restructuring");
+
+pub struct ApproveCreditFacilityRestructuring<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    ledger: CreditLedger,
+    jobs: Jobs,
+}
+
+impl<Perms, E> Clone for ApproveCreditFacilityRestructuring<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            credit_facilities: self.credit_facilities.clone(),
+            ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveCreditFacilityRestructuring<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(
+        credit_facilities: &CreditFacilities<Perms, E>,
+        ledger: &CreditLedger,
+        jobs: &Jobs,
+    ) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            ledger: ledger.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "credit_facility.restructuring_approval.execute", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<CreditFacilityId>,
+        approval_process_id: impl es_entity::RetryableInto<ApprovalProcessId>,
+        approved: bool,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let id = id.into();
+        let mut db = self.credit_facilities.begin_op().await?;
+
+        match self
+            .credit_facilities
+            .conclude_restructuring_in_op(&mut db, id, approval_process_id.into(), approved)
+            .await?
+        {
+            RestructuringOutcome::Ignored(credit_facility) => Ok(credit_facility),
+            RestructuringOutcome::Denied(credit_facility) => {
+                db.commit().await?;
+                Ok(credit_facility)
+            }
+            RestructuringOutcome::Amended(RestructuringData {
+                credit_facility,
+                rescheduled,
+                resumed_accrual_period,
+            }) => {
+                if let Some(accrual_period) = resumed_accrual_period {
+                    let accrual_id = credit_facility
+                        .interest_accrual_cycle_in_progress()
+                        .expect("Resumed accrual not found")
+                        .id;
+                    self.jobs
+                        .create_and_spawn_at_in_op(
+                            &mut db,
+                            accrual_id,
+                            interest_accruals::InterestAccrualJobConfig::<Perms, E> {
+                                credit_facility_id: id,
+                                _phantom: std::marker::PhantomData,
+                            },
+                            accrual_period.end,
+                        )
+                        .await?;
+                }
+
+                self.ledger
+                    .record_obligations_rescheduled(db, rescheduled)
+                    .await?;
+
+                Ok(credit_facility)
+            }
+        }
+    }
+}
@@ -106,40 +106,48 @@ where
         id: impl es_entity::RetryableInto<DisbursalId>,
         approved: bool,
     ) -> Result<Disbursal, CoreCreditError> {
+        let id = id.into();
+        let disbursal = self.disbursals.find_by_id_without_audit(id).await?;
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(disbursal.facility_id)
+            .await?;
+
         let mut db = self.disbursals.begin_op().await?;
 
         let tx_id = LedgerTxId::new();
         let disbursal = match self
             .disbursals
-            .conclude_approval_process_in_op(&mut db, id.into(), approved, tx_id)
+            .conclude_approval_process_in_op(&mut db, id, approved, &credit_facility.terms, tx_id)
             .await?
         {
             crate::ApprovalProcessOutcome::Ignored(disbursal) => {
                 tracing::Span::current().record("already_applied", true);
                 disbursal
             }
-            crate::ApprovalProcessOutcome::Approved((disbursal, obligation)) => {
+            crate::ApprovalProcessOutcome::Approved((disbursal, obligations)) => {
                 tracing::Span::current().record("already_applied", false);
-
-                let credit_facility = self
-                    .credit_facilities
-                    .find_by_id_without_audit(disbursal.facility_id) // changed for now
-                    .await?;
-                self.ledger
-                    .settle_disbursal(
-                        db,
-                        obligation,
-                        credit_facility.account_ids.facility_account_id,
-                    )
-                    .await?;
+                if disbursal.is_external() {
+                    self.ledger
+                        .settle_disbursal_to_payment_rail(
+                            db,
+                            obligations,
+                            credit_facility.account_ids.facility_account_id,
+                        )
+                        .await?;
+                } else {
+                    self.ledger
+                        .settle_disbursal(
+                            db,
+                            obligations,
+                            credit_facility.account_ids.facility_account_id,
+                        )
+                        .await?;
+                }
                 disbursal
             }
             crate::ApprovalProcessOutcome::Denied(disbursal) => {
                 tracing::Span::current().record("already_applied", false);
-                let credit_facility = self
-                    .credit_facilities
-                    .find_by_id_without_audit(disbursal.facility_id) // changed for now
-                    .await?;
                 self.ledger
                     .cancel_disbursal(
                         db,
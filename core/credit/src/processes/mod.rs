@@ -1,3 +1,5 @@
 pub mod activate_credit_facility;
+pub mod approve_collateral_withdrawal;
 pub mod approve_credit_facility;
+pub mod approve_credit_facility_restructuring;
 pub mod approve_disbursal;
@@ -11,10 +11,12 @@ use crate::{
     liquidation_process::{
         LiquidationProcess, LiquidationProcessEvent, error::LiquidationProcessError,
     },
+    margin_call::{MarginCall, MarginCallEvent, error::MarginCallError},
     obligation::{Obligation, ObligationEvent, error::ObligationError},
     payment_allocation::{
         PaymentAllocation, PaymentAllocationEvent, error::PaymentAllocationError,
     },
+    primitives::{CollateralAsset, Satoshis},
 };
 
 pub struct CreditFacilityPublisher<E>
@@ -73,6 +75,32 @@ where
                     activated_at: *activated_at,
                     amount: entity.amount,
                 }),
+                TermsAmended {
+                    previous_terms,
+                    terms,
+                    matures_at,
+                    ..
+                } => Some(CoreCreditEvent::FacilityTermsAmended {
+                    id: entity.id,
+                    previous_terms: *previous_terms,
+                    terms: *terms,
+                    matures_at: *matures_at,
+                    recorded_at: event.recorded_at,
+                }),
+                Prepaid {
+                    ledger_tx_id,
+                    outstanding,
+                    prepayment_fee,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityPrepaid {
+                    id: entity.id,
+                    ledger_tx_id: *ledger_tx_id,
+                    outstanding: *outstanding,
+                    prepayment_fee: *prepayment_fee,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
                 Completed { .. } => Some(CoreCreditEvent::FacilityCompleted {
                     id: entity.id,
                     completed_at: event.recorded_at,
@@ -111,6 +139,23 @@ where
         use CollateralEvent::*;
         let events = new_events
             .filter_map(|event| match &event.event {
+                Updated {
+                    abs_diff,
+                    action,
+                    ledger_tx_id,
+                    ..
+                } if entity.asset != CollateralAsset::Btc => {
+                    Some(CoreCreditEvent::FacilityCollateralAssetUpdated {
+                        credit_facility_id: entity.credit_facility_id,
+                        asset: entity.asset,
+                        ledger_tx_id: *ledger_tx_id,
+                        new_units: entity.amount.units(),
+                        abs_diff_units: *abs_diff,
+                        action: *action,
+                        recorded_at: event.recorded_at,
+                        effective: event.recorded_at.date_naive(),
+                    })
+                }
                 Updated {
                     abs_diff,
                     action,
@@ -118,13 +163,29 @@ where
                     ..
                 } => Some(CoreCreditEvent::FacilityCollateralUpdated {
                     ledger_tx_id: *ledger_tx_id,
-                    abs_diff: *abs_diff,
+                    abs_diff: Satoshis::from(*abs_diff),
                     action: *action,
                     recorded_at: event.recorded_at,
                     effective: event.recorded_at.date_naive(),
-                    new_amount: entity.amount,
+                    new_amount: Satoshis::from(entity.amount.units()),
                     credit_facility_id: entity.credit_facility_id,
                 }),
+                WithdrawalConcluded {
+                    approval_process_id,
+                    approved: true,
+                    ..
+                } => entity
+                    .withdrawal_request(*approval_process_id)
+                    .map(
+                        |request| CoreCreditEvent::FacilityCollateralWithdrawalApproved {
+                            credit_facility_id: entity.credit_facility_id,
+                            collateral_id: entity.id,
+                            approval_process_id: request.approval_process_id,
+                            address: request.address,
+                            amount: request.amount,
+                            recorded_at: event.recorded_at,
+                        },
+                    ),
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -184,6 +245,7 @@ where
                     ledger_tx_id: *tx_id,
                     amount: *total,
                     period: entity.period,
+                    rate_fixing: entity.rate_fixing,
                     recorded_at: event.recorded_at,
                     effective: *effective,
                 }),
@@ -222,6 +284,15 @@ where
                     recorded_at: event.recorded_at,
                     effective: *effective,
                 },
+                Reversed { effective, .. } => CoreCreditEvent::FacilityRepaymentReversed {
+                    credit_facility_id: entity.credit_facility_id,
+                    obligation_id: entity.obligation_id,
+                    obligation_type: entity.obligation_type,
+                    payment_id: entity.id,
+                    amount: entity.amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
             })
             .collect::<Vec<_>>();
         self.outbox
@@ -251,6 +322,19 @@ where
                     recorded_at: event.recorded_at,
                     effective: *effective,
                 }),
+                Rescheduled {
+                    amount,
+                    due_date,
+                    overdue_date,
+                    ..
+                } => Some(CoreCreditEvent::ObligationRescheduled {
+                    id: entity.id,
+                    credit_facility_id: entity.credit_facility_id,
+                    amount: *amount,
+                    due_at: *due_date,
+                    overdue_at: *overdue_date,
+                    recorded_at: event.recorded_at,
+                }),
                 DueRecorded {
                     due_amount: amount, ..
                 } => Some(CoreCreditEvent::ObligationDue {
@@ -314,6 +398,78 @@ where
                     ledger_tx_id: *ledger_tx_id,
                     recorded_at: event.recorded_at,
                 },
+                CollateralSentToLiquidator {
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => CoreCreditEvent::LiquidationCollateralSent {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                SaleRecorded {
+                    ledger_tx_id,
+                    sold,
+                    proceeds,
+                    effective,
+                    ..
+                } => CoreCreditEvent::LiquidationSaleRecorded {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    sold: *sold,
+                    proceeds: *proceeds,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                ProceedsApplied {
+                    payment_id,
+                    amount,
+                    effective,
+                    ..
+                } => CoreCreditEvent::LiquidationProceedsApplied {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                    payment_id: *payment_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                SurplusReturned {
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => CoreCreditEvent::LiquidationSurplusReturned {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                ShortfallWrittenOff {
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => CoreCreditEvent::LiquidationShortfallWrittenOff {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
                 Completed { .. } => CoreCreditEvent::LiquidationProcessConcluded {
                     id: entity.id,
                     obligation_id: entity.obligation_id,
@@ -326,4 +482,55 @@ where
             .await?;
         Ok(())
     }
+
+    pub async fn publish_margin_call(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &MarginCall,
+        new_events: es_entity::LastPersisted<'_, MarginCallEvent>,
+    ) -> Result<(), MarginCallError> {
+        use MarginCallEvent::*;
+        let publish_events = new_events
+            .map(|event| match &event.event {
+                Initialized {
+                    id,
+                    credit_facility_id,
+                    collateral,
+                    outstanding,
+                    price,
+                    cure_deadline,
+                    effective,
+                    ..
+                } => CoreCreditEvent::MarginCallOpened {
+                    id: *id,
+                    credit_facility_id: *credit_facility_id,
+                    collateral: *collateral,
+                    outstanding: *outstanding,
+                    price: *price,
+                    cure_deadline: *cure_deadline,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                Cured {
+                    reason, effective, ..
+                } => CoreCreditEvent::MarginCallCured {
+                    id: entity.id,
+                    credit_facility_id: entity.credit_facility_id,
+                    reason: *reason,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+                Escalated { effective, .. } => CoreCreditEvent::MarginCallEscalated {
+                    id: entity.id,
+                    credit_facility_id: entity.credit_facility_id,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                },
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
 }
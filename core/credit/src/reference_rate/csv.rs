@@ -0,0 +1,79 @@
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, Trim};
+use rust_decimal::Decimal;
+use std::io::Cursor;
+
+use thiserror::Error;
+
+use super::entity::ReferenceRateFixing;
+
+#[derive(Error, Debug)]
+pub enum FixingsCsvParseError {
+    #[error("FixingsCsvParseError - Csv: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("FixingsCsvParseError - InvalidRow: {0}")]
+    InvalidRow(usize),
+}
+
+/// Parses `effective_date,rate` rows, e.g. `2025-01-01,4.33`. A leading header row is skipped.
+pub struct FixingsCsvParser {
+    data: String,
+}
+
+impl FixingsCsvParser {
+    pub fn new(data: String) -> Self {
+        Self { data }
+    }
+
+    pub fn fixings(self) -> Result<Vec<ReferenceRateFixing>, FixingsCsvParseError> {
+        let mut rdr = ReaderBuilder::new()
+            .trim(Trim::All)
+            .has_headers(false)
+            .from_reader(Cursor::new(self.data));
+
+        let mut fixings = vec![];
+        for (idx, result) in rdr.records().enumerate() {
+            let record = result?;
+            if record.iter().all(|field| field.is_empty()) {
+                continue;
+            }
+
+            let effective_date = record.get(0).and_then(|f| f.parse::<NaiveDate>().ok());
+            let rate = record.get(1).and_then(|f| f.parse::<Decimal>().ok());
+            match (effective_date, rate) {
+                (Some(effective_date), Some(rate)) => fixings.push(ReferenceRateFixing {
+                    effective_date,
+                    rate: rate.into(),
+                }),
+                (None, _) if idx == 0 => continue,
+                _ => return Err(FixingsCsvParseError::InvalidRow(idx + 1)),
+            }
+        }
+
+        Ok(fixings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn parse_with_header() {
+        let data = "effective_date,rate\n2025-01-01,4.33\n\n2025-02-01, 4.31\n";
+        let fixings = FixingsCsvParser::new(data.to_string()).fixings().unwrap();
+        assert_eq!(fixings.len(), 2);
+        assert_eq!(fixings[1].rate, dec!(4.31).into());
+    }
+
+    #[test]
+    fn reject_invalid_row() {
+        let data = "2025-01-01,4.33\n2025-02-31,4.31\n";
+        assert!(matches!(
+            FixingsCsvParser::new(data.to_string()).fixings(),
+            Err(FixingsCsvParseError::InvalidRow(2))
+        ));
+    }
+}
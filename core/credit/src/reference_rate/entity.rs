@@ -0,0 +1,276 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::{
+    primitives::*,
+    terms::{AnnualRatePct, FloatingRate},
+};
+
+use super::error::ReferenceRateError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ReferenceRateId")]
+pub enum ReferenceRateEvent {
+    Initialized {
+        id: ReferenceRateId,
+        name: String,
+        audit_info: AuditInfo,
+    },
+    FixingPublished {
+        effective_date: NaiveDate,
+        rate: AnnualRatePct,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ReferenceRateFixing {
+    pub effective_date: NaiveDate,
+    pub rate: AnnualRatePct,
+}
+
+/// The fixing an interest accrual cycle was priced with, kept alongside the
+/// spread so the applied rate can be reproduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct AppliedRateFixing {
+    pub reference_rate_id: ReferenceRateId,
+    pub effective_date: NaiveDate,
+    pub reference_rate: AnnualRatePct,
+    pub spread: AnnualRatePct,
+}
+
+impl AppliedRateFixing {
+    pub fn annual_rate(&self) -> AnnualRatePct {
+        self.reference_rate.plus_spread(self.spread)
+    }
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct ReferenceRate {
+    pub id: ReferenceRateId,
+    pub name: String,
+    events: EntityEvents<ReferenceRateEvent>,
+}
+
+impl ReferenceRate {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("ReferenceRate has never been persisted")
+    }
+
+    pub fn fixings(&self) -> Vec<ReferenceRateFixing> {
+        let mut fixings: Vec<_> = self
+            .events
+            .iter_all()
+            .filter_map(|event| match event {
+                ReferenceRateEvent::FixingPublished {
+                    effective_date,
+                    rate,
+                    ..
+                } => Some(ReferenceRateFixing {
+                    effective_date: *effective_date,
+                    rate: *rate,
+                }),
+                _ => None,
+            })
+            .collect();
+        fixings.sort_by_key(|fixing| fixing.effective_date);
+        fixings
+    }
+
+    pub fn fixing_as_of(&self, date: NaiveDate) -> Option<ReferenceRateFixing> {
+        self.fixings()
+            .into_iter()
+            .rev()
+            .find(|fixing| fixing.effective_date <= date)
+    }
+
+    pub(crate) fn applied_fixing(
+        &self,
+        floating_rate: FloatingRate,
+        date: NaiveDate,
+    ) -> Option<AppliedRateFixing> {
+        if floating_rate.reference_rate_id != self.id {
+            return None;
+        }
+
+        self.fixing_as_of(date).map(|fixing| AppliedRateFixing {
+            reference_rate_id: self.id,
+            effective_date: fixing.effective_date,
+            reference_rate: fixing.rate,
+            spread: floating_rate.spread,
+        })
+    }
+
+    pub(crate) fn publish_fixing(
+        &mut self,
+        effective_date: NaiveDate,
+        rate: AnnualRatePct,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ReferenceRateError> {
+        if let Some(existing) = self
+            .fixings()
+            .into_iter()
+            .find(|fixing| fixing.effective_date == effective_date)
+        {
+            if existing.rate == rate {
+                return Ok(Idempotent::Ignored);
+            }
+            return Err(ReferenceRateError::FixingAlreadyPublished(effective_date));
+        }
+
+        self.events.push(ReferenceRateEvent::FixingPublished {
+            effective_date,
+            rate,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<ReferenceRateEvent> for ReferenceRate {
+    fn try_from_events(events: EntityEvents<ReferenceRateEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = ReferenceRateBuilder::default();
+
+        for event in events.iter_all() {
+            match event {
+                ReferenceRateEvent::Initialized { id, name, .. } => {
+                    builder = builder.id(*id).name(name.clone());
+                }
+                ReferenceRateEvent::FixingPublished { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Builder)]
+pub struct NewReferenceRate {
+    #[builder(setter(into))]
+    pub id: ReferenceRateId,
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewReferenceRate {
+    pub fn builder() -> NewReferenceRateBuilder {
+        NewReferenceRateBuilder::default()
+    }
+}
+
+impl IntoEvents<ReferenceRateEvent> for NewReferenceRate {
+    fn into_events(self) -> EntityEvents<ReferenceRateEvent> {
+        EntityEvents::init(
+            self.id,
+            [ReferenceRateEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn reference_rate() -> ReferenceRate {
+        let id = ReferenceRateId::new();
+        ReferenceRate::try_from_events(EntityEvents::init(
+            id,
+            [ReferenceRateEvent::Initialized {
+                id,
+                name: "SOFR".to_string(),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn fixing_as_of_uses_latest_published_before_date() {
+        let mut rate = reference_rate();
+        assert!(rate.fixing_as_of(date("2025-01-01")).is_none());
+
+        rate.publish_fixing(date("2025-02-01"), dec!(4.5).into(), dummy_audit_info())
+            .unwrap();
+        rate.publish_fixing(date("2025-01-01"), dec!(4.25).into(), dummy_audit_info())
+            .unwrap();
+
+        assert!(rate.fixing_as_of(date("2024-12-31")).is_none());
+        assert_eq!(
+            rate.fixing_as_of(date("2025-01-15")).unwrap().rate,
+            AnnualRatePct::from(dec!(4.25))
+        );
+        assert_eq!(
+            rate.fixing_as_of(date("2025-02-01")).unwrap().rate,
+            AnnualRatePct::from(dec!(4.5))
+        );
+    }
+
+    #[test]
+    fn republishing_a_fixing() {
+        let mut rate = reference_rate();
+        rate.publish_fixing(date("2025-01-01"), dec!(4.25).into(), dummy_audit_info())
+            .unwrap();
+
+        assert!(
+            rate.publish_fixing(date("2025-01-01"), dec!(4.25).into(), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(matches!(
+            rate.publish_fixing(date("2025-01-01"), dec!(4.3).into(), dummy_audit_info()),
+            Err(ReferenceRateError::FixingAlreadyPublished(_))
+        ));
+    }
+
+    #[test]
+    fn applied_fixing_adds_spread() {
+        let mut rate = reference_rate();
+        rate.publish_fixing(date("2025-01-01"), dec!(4.25).into(), dummy_audit_info())
+            .unwrap();
+
+        let applied = rate
+            .applied_fixing(
+                FloatingRate {
+                    reference_rate_id: rate.id,
+                    spread: dec!(3).into(),
+                },
+                date("2025-01-15"),
+            )
+            .unwrap();
+        assert_eq!(applied.effective_date, date("2025-01-01"));
+        assert_eq!(applied.annual_rate(), AnnualRatePct::from(dec!(7.25)));
+    }
+}
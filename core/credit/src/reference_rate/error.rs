@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::primitives::ReferenceRateId;
+
+#[derive(Error, Debug)]
+pub enum ReferenceRateError {
+    #[error("ReferenceRateError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ReferenceRateError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ReferenceRateError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("ReferenceRateError - CouldNotFindById: {0}")]
+    CouldNotFindById(ReferenceRateId),
+    #[error("ReferenceRateError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ReferenceRateError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("ReferenceRateError - FixingAlreadyPublished: a different rate exists for {0}")]
+    FixingAlreadyPublished(chrono::NaiveDate),
+    #[error("ReferenceRateError - CsvParseError: {0}")]
+    CsvParse(#[from] super::csv::FixingsCsvParseError),
+}
+
+es_entity::from_es_entity_error!(ReferenceRateError);
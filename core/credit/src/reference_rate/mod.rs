@@ -0,0 +1,211 @@
+mod csv;
+pub mod entity;
+pub mod error;
+mod repo;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use chrono::NaiveDate;
+use tracing::instrument;
+
+use crate::{
+    CoreCreditAction, CoreCreditObject, primitives::ReferenceRateId, terms::AnnualRatePct,
+};
+
+pub use entity::*;
+
+use csv::FixingsCsvParser;
+#[cfg(feature = "json-schema")]
+pub use entity::ReferenceRateEvent;
+use error::ReferenceRateError;
+use repo::ReferenceRateRepo;
+
+#[derive(Clone)]
+pub struct ReferenceRates<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: ReferenceRateRepo,
+}
+
+impl<Perms> ReferenceRates<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub fn new(pool: &sqlx::PgPool, authz: &Perms) -> Self {
+        let repo = ReferenceRateRepo::new(pool);
+        Self {
+            authz: authz.clone(),
+            repo,
+        }
+    }
+
+    pub async fn subject_can_create_reference_rate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<audit::AuditInfo>, ReferenceRateError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_reference_rates(),
+                CoreCreditAction::REFERENCE_RATE_CREATE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_credit.reference_rate.create", skip(self), err)]
+    pub async fn create_reference_rate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: String,
+    ) -> Result<ReferenceRate, ReferenceRateError> {
+        let audit_info = self
+            .subject_can_create_reference_rate(sub, true)
+            .await?
+            .expect("audit info missing");
+        let new_reference_rate = NewReferenceRate::builder()
+            .id(ReferenceRateId::new())
+            .name(name)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build ReferenceRate");
+
+        let reference_rate = self.repo.create(new_reference_rate).await?;
+        Ok(reference_rate)
+    }
+
+    pub async fn subject_can_publish_fixing(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: ReferenceRateId,
+        enforce: bool,
+    ) -> Result<Option<audit::AuditInfo>, ReferenceRateError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::reference_rate(id),
+                CoreCreditAction::REFERENCE_RATE_PUBLISH_FIXING,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_credit.reference_rate.publish_fixing", skip(self), err)]
+    pub async fn publish_fixing(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReferenceRateId> + std::fmt::Debug,
+        effective_date: NaiveDate,
+        rate: AnnualRatePct,
+    ) -> Result<ReferenceRate, ReferenceRateError> {
+        let id = id.into();
+        let audit_info = self
+            .subject_can_publish_fixing(sub, id, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut reference_rate = self.repo.find_by_id(id).await?;
+        if reference_rate
+            .publish_fixing(effective_date, rate, audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut reference_rate).await?;
+        }
+
+        Ok(reference_rate)
+    }
+
+    #[instrument(
+        name = "core_credit.reference_rate.import_fixings_from_csv",
+        skip(self, data),
+        err
+    )]
+    pub async fn import_fixings_from_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReferenceRateId> + std::fmt::Debug,
+        data: impl AsRef<str>,
+    ) -> Result<ReferenceRate, ReferenceRateError> {
+        let id = id.into();
+        let audit_info = self
+            .subject_can_publish_fixing(sub, id, true)
+            .await?
+            .expect("audit info missing");
+
+        let fixings = FixingsCsvParser::new(data.as_ref().to_string()).fixings()?;
+
+        let mut reference_rate = self.repo.find_by_id(id).await?;
+        let mut published = false;
+        for fixing in fixings {
+            published |= reference_rate
+                .publish_fixing(fixing.effective_date, fixing.rate, audit_info.clone())?
+                .did_execute();
+        }
+        if published {
+            self.repo.update(&mut reference_rate).await?;
+        }
+
+        Ok(reference_rate)
+    }
+
+    #[instrument(name = "core_credit.reference_rate.find_by_id", skip(self))]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReferenceRateId> + std::fmt::Debug + Copy,
+    ) -> Result<Option<ReferenceRate>, ReferenceRateError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::reference_rate(id.into()),
+                CoreCreditAction::REFERENCE_RATE_READ,
+            )
+            .await?;
+        match self.repo.find_by_id(id.into()).await {
+            Ok(reference_rate) => Ok(Some(reference_rate)),
+            Err(ReferenceRateError::CouldNotFindById(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub(crate) async fn find_by_id_without_audit(
+        &self,
+        id: ReferenceRateId,
+    ) -> Result<ReferenceRate, ReferenceRateError> {
+        self.repo.find_by_id(id).await
+    }
+
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<ReferenceRate>, ReferenceRateError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_reference_rates(),
+                CoreCreditAction::REFERENCE_RATE_LIST,
+            )
+            .await?;
+        Ok(self
+            .repo
+            .list_by_name(Default::default(), es_entity::ListDirection::Ascending)
+            .await?
+            .entities)
+    }
+
+    pub async fn find_all<T: From<ReferenceRate>>(
+        &self,
+        ids: &[ReferenceRateId],
+    ) -> Result<HashMap<ReferenceRateId, T>, ReferenceRateError> {
+        self.repo.find_all(ids).await
+    }
+}
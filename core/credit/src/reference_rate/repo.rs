@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "ReferenceRate",
+    err = "ReferenceRateError",
+    columns(name(ty = "String", list_by)),
+    tbl_prefix = "core"
+)]
+pub struct ReferenceRateRepo {
+    pool: PgPool,
+}
+
+impl ReferenceRateRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -28,6 +28,7 @@ pub struct ObligationDataForEntry {
 pub enum CreditFacilityRepaymentPlanEntry {
     Disbursal(ObligationDataForEntry),
     Interest(ObligationDataForEntry),
+    Fee(ObligationDataForEntry),
 }
 
 impl PartialOrd for CreditFacilityRepaymentPlanEntry {
@@ -40,22 +41,24 @@ impl Ord for CreditFacilityRepaymentPlanEntry {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let ord = {
             let self_due_at = match self {
-                Self::Disbursal(o) | Self::Interest(o) => o.due_at,
+                Self::Disbursal(o) | Self::Interest(o) | Self::Fee(o) => o.due_at,
             };
             let other_due_at = match other {
-                Self::Disbursal(o) | Self::Interest(o) => o.due_at,
+                Self::Disbursal(o) | Self::Interest(o) | Self::Fee(o) => o.due_at,
             };
             self_due_at.cmp(&other_due_at)
         };
 
         ord.then_with(|| match (self, other) {
             (
-                CreditFacilityRepaymentPlanEntry::Interest(_),
+                CreditFacilityRepaymentPlanEntry::Interest(_)
+                | CreditFacilityRepaymentPlanEntry::Fee(_),
                 CreditFacilityRepaymentPlanEntry::Disbursal(_),
             ) => std::cmp::Ordering::Less,
             (
                 CreditFacilityRepaymentPlanEntry::Disbursal(_),
-                CreditFacilityRepaymentPlanEntry::Interest(_),
+                CreditFacilityRepaymentPlanEntry::Interest(_)
+                | CreditFacilityRepaymentPlanEntry::Fee(_),
             ) => std::cmp::Ordering::Greater,
             _ => std::cmp::Ordering::Equal,
         })
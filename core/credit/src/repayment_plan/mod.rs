@@ -19,6 +19,8 @@ pub struct CreditFacilityRepaymentPlan {
     activated_at: Option<DateTime<Utc>>,
     last_interest_accrual_at: Option<DateTime<Utc>>,
     last_updated_on_sequence: EventSequence,
+    #[serde(default)]
+    prepaid: bool,
 
     pub entries: Vec<CreditFacilityRepaymentPlanEntry>,
 }
@@ -34,6 +36,7 @@ impl CreditFacilityRepaymentPlan {
             .filter_map(|entry| match entry {
                 CreditFacilityRepaymentPlanEntry::Disbursal(data)
                 | CreditFacilityRepaymentPlanEntry::Interest(data)
+                | CreditFacilityRepaymentPlanEntry::Fee(data)
                     if data.id.is_some() =>
                 {
                     Some(*entry)
@@ -51,40 +54,35 @@ impl CreditFacilityRepaymentPlan {
         let activated_at = self.activated_at();
         let maturity_date = terms.duration.maturity_date(activated_at);
 
-        vec![
-            CreditFacilityRepaymentPlanEntry::Disbursal(ObligationDataForEntry {
-                id: None,
-                status: RepaymentStatus::Upcoming,
-
-                initial: structuring_fee,
-                outstanding: structuring_fee,
-
-                due_at: maturity_date,
-                overdue_at: None,
-                defaulted_at: None,
-                recorded_at: activated_at,
-                effective: activated_at.date_naive(),
-            }),
-            CreditFacilityRepaymentPlanEntry::Disbursal(ObligationDataForEntry {
-                id: None,
-                status: RepaymentStatus::Upcoming,
+        [structuring_fee, facility_amount]
+            .into_iter()
+            .flat_map(|amount| terms.principal_installments(amount, activated_at, maturity_date))
+            .map(|installment| {
+                CreditFacilityRepaymentPlanEntry::Disbursal(ObligationDataForEntry {
+                    id: None,
+                    status: RepaymentStatus::Upcoming,
 
-                initial: facility_amount,
-                outstanding: facility_amount,
+                    initial: installment.amount,
+                    outstanding: installment.amount,
 
-                due_at: maturity_date,
-                overdue_at: None,
-                defaulted_at: None,
-                recorded_at: activated_at,
-                effective: activated_at.date_naive(),
-            }),
-        ]
+                    due_at: installment.due_at,
+                    overdue_at: None,
+                    defaulted_at: None,
+                    recorded_at: activated_at,
+                    effective: activated_at.date_naive(),
+                })
+            })
+            .collect()
     }
 
     fn planned_interest_accruals(
         &self,
         updated_entries: &[CreditFacilityRepaymentPlanEntry],
     ) -> Vec<CreditFacilityRepaymentPlanEntry> {
+        if self.prepaid {
+            return vec![];
+        }
+
         let terms = self.terms.expect("Missing FacilityCreated event");
         let activated_at = self.activated_at();
 
@@ -103,19 +101,21 @@ impl CreditFacilityRepaymentPlan {
                     .truncate(maturity_date)
             };
 
-        let disbursed_outstanding = updated_entries
-            .iter()
-            .filter_map(|entry| match entry {
-                CreditFacilityRepaymentPlanEntry::Disbursal(data) => Some(data.outstanding),
-                _ => None,
-            })
-            .fold(UsdCents::ZERO, |acc, outstanding| acc + outstanding);
-
         let mut planned_interest_entries = vec![];
         while let Some(period) = next_interest_period {
-            let interest = terms
-                .annual_rate
-                .interest_for_time_period(disbursed_outstanding, period.days());
+            let disbursed_outstanding = updated_entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    CreditFacilityRepaymentPlanEntry::Disbursal(data)
+                        if data.due_at > period.start =>
+                    {
+                        Some(data.outstanding)
+                    }
+                    _ => None,
+                })
+                .fold(UsdCents::ZERO, |acc, outstanding| acc + outstanding);
+
+            let interest = terms.interest_for_period(disbursed_outstanding, &period);
 
             planned_interest_entries.push(CreditFacilityRepaymentPlanEntry::Interest(
                 ObligationDataForEntry {
@@ -155,6 +155,12 @@ impl CreditFacilityRepaymentPlan {
             CoreCreditEvent::FacilityActivated { activated_at, .. } => {
                 self.activated_at = Some(*activated_at);
             }
+            CoreCreditEvent::FacilityPrepaid { .. } => {
+                self.prepaid = true;
+            }
+            CoreCreditEvent::FacilityTermsAmended { terms, .. } => {
+                self.terms = Some(*terms);
+            }
             CoreCreditEvent::ObligationCreated {
                 id,
                 obligation_type,
@@ -187,10 +193,37 @@ impl CreditFacilityRepaymentPlan {
                         self.last_interest_accrual_at = Some(effective.end_of_day());
                         CreditFacilityRepaymentPlanEntry::Interest(data)
                     }
+                    ObligationType::Penalty => CreditFacilityRepaymentPlanEntry::Interest(data),
+                    ObligationType::Fee => CreditFacilityRepaymentPlanEntry::Fee(data),
                 };
 
                 existing_obligations.push(entry);
             }
+            CoreCreditEvent::ObligationRescheduled {
+                id: obligation_id,
+                amount,
+                due_at,
+                overdue_at,
+                ..
+            } => {
+                if let Some(data) = existing_obligations.iter_mut().find_map(|entry| {
+                    let data = match entry {
+                        CreditFacilityRepaymentPlanEntry::Disbursal(data)
+                        | CreditFacilityRepaymentPlanEntry::Interest(data)
+                        | CreditFacilityRepaymentPlanEntry::Fee(data) => data,
+                    };
+
+                    (data.id == Some(*obligation_id)).then_some(data)
+                }) {
+                    data.status = RepaymentStatus::NotYetDue;
+                    data.initial = *amount;
+                    data.outstanding = *amount;
+                    data.due_at = *due_at;
+                    data.overdue_at = *overdue_at;
+                } else {
+                    return false;
+                }
+            }
             CoreCreditEvent::FacilityRepaymentRecorded {
                 obligation_id,
                 amount,
@@ -199,7 +232,8 @@ impl CreditFacilityRepaymentPlan {
                 if let Some(data) = existing_obligations.iter_mut().find_map(|entry| {
                     let data = match entry {
                         CreditFacilityRepaymentPlanEntry::Disbursal(data)
-                        | CreditFacilityRepaymentPlanEntry::Interest(data) => data,
+                        | CreditFacilityRepaymentPlanEntry::Interest(data)
+                        | CreditFacilityRepaymentPlanEntry::Fee(data) => data,
                     };
 
                     (data.id == Some(*obligation_id)).then_some(data)
@@ -209,6 +243,56 @@ impl CreditFacilityRepaymentPlan {
                     return false;
                 }
             }
+            CoreCreditEvent::LiquidationShortfallWrittenOff {
+                obligation_id,
+                amount,
+                ..
+            } => {
+                if let Some(data) = existing_obligations.iter_mut().find_map(|entry| {
+                    let data = match entry {
+                        CreditFacilityRepaymentPlanEntry::Disbursal(data)
+                        | CreditFacilityRepaymentPlanEntry::Interest(data)
+                        | CreditFacilityRepaymentPlanEntry::Fee(data) => data,
+                    };
+
+                    (data.id == Some(*obligation_id)).then_some(data)
+                }) {
+                    data.outstanding -= *amount;
+                } else {
+                    return false;
+                }
+            }
+            CoreCreditEvent::FacilityRepaymentReversed {
+                obligation_id,
+                amount,
+                recorded_at,
+                ..
+            } => {
+                if let Some(data) = existing_obligations.iter_mut().find_map(|entry| {
+                    let data = match entry {
+                        CreditFacilityRepaymentPlanEntry::Disbursal(data)
+                        | CreditFacilityRepaymentPlanEntry::Interest(data)
+                        | CreditFacilityRepaymentPlanEntry::Fee(data) => data,
+                    };
+
+                    (data.id == Some(*obligation_id)).then_some(data)
+                }) {
+                    data.outstanding += *amount;
+                    if data.status == RepaymentStatus::Paid {
+                        data.status = if data.defaulted_at.is_some_and(|d| d <= *recorded_at) {
+                            RepaymentStatus::Defaulted
+                        } else if data.overdue_at.is_some_and(|d| d <= *recorded_at) {
+                            RepaymentStatus::Overdue
+                        } else if data.due_at <= *recorded_at {
+                            RepaymentStatus::Due
+                        } else {
+                            RepaymentStatus::NotYetDue
+                        };
+                    }
+                } else {
+                    return false;
+                }
+            }
             CoreCreditEvent::ObligationDue {
                 id: obligation_id, ..
             }
@@ -224,7 +308,8 @@ impl CreditFacilityRepaymentPlan {
                 if let Some(data) = existing_obligations.iter_mut().find_map(|entry| {
                     let data = match entry {
                         CreditFacilityRepaymentPlanEntry::Disbursal(data)
-                        | CreditFacilityRepaymentPlanEntry::Interest(data) => data,
+                        | CreditFacilityRepaymentPlanEntry::Interest(data)
+                        | CreditFacilityRepaymentPlanEntry::Fee(data) => data,
                     };
 
                     (data.id == Some(*obligation_id)).then_some(data)
@@ -266,7 +351,10 @@ impl CreditFacilityRepaymentPlan {
 mod tests {
     use rust_decimal_macros::dec;
 
-    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+    use crate::terms::{
+        FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct,
+        PrincipalRepayment,
+    };
 
     use super::*;
 
@@ -349,12 +437,21 @@ mod tests {
                 CreditFacilityRepaymentPlanEntry::Interest(ObligationDataForEntry {
                     status: RepaymentStatus::Upcoming,
                     ..
+                })
+                | CreditFacilityRepaymentPlanEntry::Fee(ObligationDataForEntry {
+                    status: RepaymentStatus::Upcoming,
+                    ..
                 }) => res.interest_upcoming += 1,
                 CreditFacilityRepaymentPlanEntry::Interest(ObligationDataForEntry {
                     status: RepaymentStatus::Paid,
                     ..
+                })
+                | CreditFacilityRepaymentPlanEntry::Fee(ObligationDataForEntry {
+                    status: RepaymentStatus::Paid,
+                    ..
                 }) => res.interest_paid += 1,
-                CreditFacilityRepaymentPlanEntry::Interest(ObligationDataForEntry { .. }) => {
+                CreditFacilityRepaymentPlanEntry::Interest(ObligationDataForEntry { .. })
+                | CreditFacilityRepaymentPlanEntry::Fee(ObligationDataForEntry { .. }) => {
                     res.interest_unpaid += 1
                 }
             }
@@ -472,6 +569,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prepaid_facility_has_no_upcoming_interest() {
+        let mut plan = initial_plan();
+
+        let disbursal_recorded_at = default_start_date();
+        let prepaid_at = default_start_date_with_days(10);
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                id: CreditFacilityId::new(),
+                activation_tx_id: LedgerTxId::new(),
+                activated_at: default_start_date(),
+                amount: default_facility_amount(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: ObligationId::new(),
+                obligation_type: ObligationType::Disbursal,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(100_000_00),
+                due_at: disbursal_recorded_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: disbursal_recorded_at,
+                effective: disbursal_recorded_at.date_naive(),
+            },
+            CoreCreditEvent::FacilityPrepaid {
+                id: CreditFacilityId::new(),
+                ledger_tx_id: LedgerTxId::new(),
+                outstanding: UsdCents::from(100_300_00),
+                prepayment_fee: UsdCents::ZERO,
+                recorded_at: prepaid_at,
+                effective: prepaid_at.date_naive(),
+            },
+        ];
+        process_events(&mut plan, events);
+
+        let counts = count_entries(&plan);
+        assert_eq!(
+            counts,
+            EntriesCount {
+                interest_unpaid: 0,
+                interest_paid: 0,
+                interest_upcoming: 0,
+                disbursals_unpaid: 1,
+                disbursals_paid: 0,
+                disbursals_upcoming: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn terms_amendment_reschedules_installments() {
+        let disbursal_id = ObligationId::new();
+        let installment_id = ObligationId::new();
+
+        let mut plan = initial_plan();
+
+        let previous_terms = default_terms();
+        let mut terms = default_terms();
+        terms.duration = FacilityDuration::Months(6);
+        let previous_matures_at = previous_terms.duration.maturity_date(default_start_date());
+        let matures_at = terms.duration.maturity_date(default_start_date());
+        let amended_at = default_start_date_with_days(10);
+
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                id: CreditFacilityId::new(),
+                activation_tx_id: LedgerTxId::new(),
+                activated_at: default_start_date(),
+                amount: default_facility_amount(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: disbursal_id,
+                obligation_type: ObligationType::Disbursal,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(100_000_00),
+                due_at: previous_matures_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: default_start_date(),
+                effective: default_start_date().date_naive(),
+            },
+            CoreCreditEvent::FacilityTermsAmended {
+                id: CreditFacilityId::new(),
+                previous_terms,
+                terms,
+                matures_at,
+                recorded_at: amended_at,
+            },
+            CoreCreditEvent::ObligationRescheduled {
+                id: disbursal_id,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(50_000_00),
+                due_at: default_start_date_with_days(90),
+                overdue_at: None,
+                recorded_at: amended_at,
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: installment_id,
+                obligation_type: ObligationType::Disbursal,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(50_000_00),
+                due_at: matures_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: amended_at,
+                effective: amended_at.date_naive(),
+            },
+        ];
+        process_events(&mut plan, events);
+
+        let counts = count_entries(&plan);
+        assert_eq!(
+            counts,
+            EntriesCount {
+                interest_unpaid: 0,
+                interest_paid: 0,
+                interest_upcoming: 7,
+                disbursals_unpaid: 2,
+                disbursals_paid: 0,
+                disbursals_upcoming: 0,
+            }
+        );
+        let disbursals: Vec<_> = plan
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CreditFacilityRepaymentPlanEntry::Disbursal(data) => {
+                    Some((data.due_at, data.outstanding))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            disbursals,
+            vec![
+                (default_start_date_with_days(90), UsdCents::from(50_000_00)),
+                (matures_at, UsdCents::from(50_000_00)),
+            ]
+        );
+    }
+
     #[test]
     fn with_first_interest_partial_payment() {
         let interest_obligation_id = ObligationId::new();
@@ -649,6 +887,86 @@ mod tests {
         assert_eq!(*interest_entry_status, RepaymentStatus::Paid);
     }
 
+    #[test]
+    fn with_first_interest_payment_reversed() {
+        let interest_obligation_id = ObligationId::new();
+        let payment_id = PaymentAllocationId::new();
+
+        let mut plan = initial_plan();
+
+        let disbursal_recorded_at = default_start_date();
+        let interest_recorded_at = default_start_date_with_days(30);
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                id: CreditFacilityId::new(),
+                activation_tx_id: LedgerTxId::new(),
+                activated_at: default_start_date(),
+                amount: default_facility_amount(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: ObligationId::new(),
+                obligation_type: ObligationType::Disbursal,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(100_000_00),
+                due_at: disbursal_recorded_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: disbursal_recorded_at,
+                effective: disbursal_recorded_at.date_naive(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: interest_obligation_id,
+                obligation_type: ObligationType::Interest,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(1_000_00),
+                due_at: interest_recorded_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: interest_recorded_at,
+                effective: interest_recorded_at.date_naive(),
+            },
+            CoreCreditEvent::FacilityRepaymentRecorded {
+                credit_facility_id: CreditFacilityId::new(),
+                obligation_id: interest_obligation_id,
+                obligation_type: ObligationType::Interest,
+                payment_id,
+                amount: UsdCents::from(1_000_00),
+                recorded_at: interest_recorded_at,
+                effective: interest_recorded_at.date_naive(),
+            },
+            CoreCreditEvent::ObligationCompleted {
+                id: interest_obligation_id,
+                credit_facility_id: CreditFacilityId::new(),
+            },
+            CoreCreditEvent::FacilityRepaymentReversed {
+                credit_facility_id: CreditFacilityId::new(),
+                obligation_id: interest_obligation_id,
+                obligation_type: ObligationType::Interest,
+                payment_id,
+                amount: UsdCents::from(1_000_00),
+                recorded_at: interest_recorded_at,
+                effective: interest_recorded_at.date_naive(),
+            },
+        ];
+        process_events(&mut plan, events);
+
+        let (outstanding, status) = plan
+            .entries
+            .iter()
+            .find_map(|e| match e {
+                CreditFacilityRepaymentPlanEntry::Interest(ObligationDataForEntry {
+                    id,
+                    outstanding,
+                    status,
+                    ..
+                }) if id.is_some() => Some((outstanding, status)),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(*outstanding, UsdCents::from(1_000_00));
+        assert_eq!(*status, RepaymentStatus::Due);
+    }
+
     #[test]
     fn with_all_interest_obligations_created() {
         let mut plan = initial_plan();
@@ -736,4 +1054,54 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn amortizing_facility_activated() {
+        let mut terms = default_terms();
+        terms.principal_repayment = PrincipalRepayment::StraightLine;
+
+        let mut plan = CreditFacilityRepaymentPlan::default();
+        process_events(
+            &mut plan,
+            vec![
+                CoreCreditEvent::FacilityCreated {
+                    id: CreditFacilityId::new(),
+                    terms,
+                    amount: default_facility_amount(),
+                    created_at: default_start_date(),
+                },
+                CoreCreditEvent::FacilityActivated {
+                    id: CreditFacilityId::new(),
+                    activation_tx_id: LedgerTxId::new(),
+                    activated_at: default_start_date(),
+                    amount: default_facility_amount(),
+                },
+            ],
+        );
+
+        let counts = count_entries(&plan);
+        assert_eq!(counts.disbursals_upcoming, 8);
+        assert_eq!(counts.interest_upcoming, 4);
+
+        let total_principal = plan
+            .entries
+            .iter()
+            .filter_map(|e| match e {
+                CreditFacilityRepaymentPlanEntry::Disbursal(data) => Some(data.initial),
+                _ => None,
+            })
+            .fold(UsdCents::ZERO, |acc, amount| acc + amount);
+        let structuring_fee = terms.one_time_fee_rate.apply(default_facility_amount());
+        assert_eq!(total_principal, default_facility_amount() + structuring_fee);
+
+        let interest: Vec<UsdCents> = plan
+            .entries
+            .iter()
+            .filter_map(|e| match e {
+                CreditFacilityRepaymentPlanEntry::Interest(data) => Some(data.initial),
+                _ => None,
+            })
+            .collect();
+        assert!(interest[0] > interest[2]);
+    }
 }
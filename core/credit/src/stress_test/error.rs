@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PriceShockSimulationError {
+    #[error("PriceShockSimulationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("PriceShockSimulationError - CreditFacilityError: {0}")]
+    CreditFacilityError(#[from] crate::credit_facility::error::CreditFacilityError),
+    #[error("PriceShockSimulationError - PriceError: {0}")]
+    PriceError(#[from] core_price::error::PriceError),
+    #[error("PriceShockSimulationError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("PriceShockSimulationError - DocumentNotFound: {0}")]
+    DocumentNotFound(document_storage::DocumentId),
+    #[error("PriceShockSimulationError - CsvError: {0}")]
+    CsvError(String),
+    #[error("PriceShockSimulationError - InvalidPriceChangePct: {0}")]
+    InvalidPriceChangePct(rust_decimal::Decimal),
+    #[error("PriceShockSimulationError - ShockedPriceOutOfRange: {0}")]
+    ShockedPriceOutOfRange(rust_decimal::Decimal),
+}
@@ -0,0 +1,197 @@
+pub mod error;
+mod simulation;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{
+    Document, DocumentId, DocumentStorage, DocumentType, GeneratedDocumentDownloadLink, ReferenceId,
+};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacilities, Price, time::now,
+};
+
+use error::PriceShockSimulationError;
+pub use simulation::*;
+
+pub const PRICE_SHOCK_SIMULATION_CSV: DocumentType =
+    DocumentType::new("price_shock_simulation_csv");
+
+/// Read-only what-if evaluation of all active facilities at a hypothetical
+/// BTC price.
+pub struct PriceShockSimulations<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    facilities: CreditFacilities<Perms, E>,
+    price: Price,
+    document_storage: DocumentStorage,
+}
+
+impl<Perms, E> Clone for PriceShockSimulations<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            facilities: self.facilities.clone(),
+            price: self.price.clone(),
+            document_storage: self.document_storage.clone(),
+        }
+    }
+}
+
+impl<Perms, E> PriceShockSimulations<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        authz: &Perms,
+        facilities: &CreditFacilities<Perms, E>,
+        price: &Price,
+        document_storage: DocumentStorage,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            facilities: facilities.clone(),
+            price: price.clone(),
+            document_storage,
+        }
+    }
+
+    #[instrument(name = "core_credit.price_shock_simulation.simulate", skip(self), err)]
+    pub async fn simulate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        shock: PriceShock,
+    ) -> Result<PriceShockSimulation, PriceShockSimulationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_SIMULATE_PRICE_SHOCK,
+            )
+            .await?;
+
+        self.simulate_without_audit(shock).await
+    }
+
+    #[instrument(
+        name = "core_credit.price_shock_simulation.create_csv",
+        skip(self),
+        err
+    )]
+    pub async fn create_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        shock: PriceShock,
+    ) -> Result<Document, PriceShockSimulationError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_SIMULATE_PRICE_SHOCK,
+            )
+            .await?;
+
+        let simulation = self.simulate_without_audit(shock).await?;
+        let content = simulation.to_csv()?;
+
+        let document = self
+            .document_storage
+            .create_and_upload(
+                audit_info,
+                content,
+                format!(
+                    "price-shock-{}-{}.csv",
+                    simulation.shocked_price.into_inner(),
+                    simulation.simulated_at.format("%Y%m%dT%H%M%S")
+                ),
+                "text/csv",
+                ReferenceId::new(),
+                PRICE_SHOCK_SIMULATION_CSV,
+            )
+            .await?;
+
+        Ok(document)
+    }
+
+    #[instrument(
+        name = "core_credit.price_shock_simulation.generate_download_link",
+        skip(self),
+        err
+    )]
+    pub async fn generate_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        document_id: impl Into<DocumentId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, PriceShockSimulationError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_SIMULATE_PRICE_SHOCK,
+            )
+            .await?;
+
+        let document_id = document_id.into();
+        match self.document_storage.find_by_id(document_id).await? {
+            Some(document) if document.document_type() == &PRICE_SHOCK_SIMULATION_CSV => (),
+            _ => return Err(PriceShockSimulationError::DocumentNotFound(document_id)),
+        }
+
+        let link = self
+            .document_storage
+            .generate_download_link(audit_info, document_id)
+            .await?;
+
+        Ok(link)
+    }
+
+    async fn simulate_without_audit(
+        &self,
+        shock: PriceShock,
+    ) -> Result<PriceShockSimulation, PriceShockSimulationError> {
+        let current_price = self.price.usd_cents_per_btc().await?;
+        let shocked_price = shock.shocked_price(current_price)?;
+
+        let facilities = self
+            .facilities
+            .list_active_with_balances_at_price_without_audit(shocked_price)
+            .await?
+            .into_iter()
+            .map(|(credit_facility, balances)| {
+                PriceShockFacilityResult::new(
+                    credit_facility.id,
+                    credit_facility.customer_id,
+                    credit_facility.last_collateralization_state(),
+                    &credit_facility.terms,
+                    balances,
+                    shocked_price,
+                )
+            })
+            .collect();
+
+        Ok(PriceShockSimulation {
+            current_price,
+            shocked_price,
+            simulated_at: now(),
+            facilities,
+        })
+    }
+}
@@ -0,0 +1,365 @@
+use chrono::{DateTime, Utc};
+use csv::Writer;
+use rust_decimal::{Decimal, prelude::*};
+use rust_decimal_macros::dec;
+
+use crate::{CreditFacilityBalanceSummary, TermValues, primitives::*};
+
+use super::error::PriceShockSimulationError;
+
+/// Hypothetical BTC price to evaluate all active facilities at.
+#[derive(Debug, Clone, Copy)]
+pub enum PriceShock {
+    Price(PriceOfOneBTC),
+    /// Relative move from the current price, e.g. `-30` for a 30% drop.
+    ChangePct(PriceChangePct),
+}
+
+impl PriceShock {
+    pub fn change_pct(pct: Decimal) -> Result<Self, PriceShockSimulationError> {
+        Ok(Self::ChangePct(PriceChangePct::try_from(pct)?))
+    }
+
+    pub fn shocked_price(
+        &self,
+        current_price: PriceOfOneBTC,
+    ) -> Result<PriceOfOneBTC, PriceShockSimulationError> {
+        match self {
+            Self::Price(price) => Ok(*price),
+            Self::ChangePct(pct) => {
+                let cents = Decimal::from(current_price.into_inner().into_inner())
+                    .checked_mul(Decimal::ONE + pct.0 / dec!(100))
+                    .and_then(|cents| {
+                        cents
+                            .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+                            .to_u64()
+                    })
+                    .ok_or(PriceShockSimulationError::ShockedPriceOutOfRange(pct.0))?;
+                Ok(PriceOfOneBTC::new(UsdCents::from(cents)))
+            }
+        }
+    }
+}
+
+/// Price move in percent, bounded so the shocked price stays a valid amount.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceChangePct(Decimal);
+
+impl PriceChangePct {
+    const MIN: Decimal = dec!(-100);
+    const MAX: Decimal = dec!(1000);
+}
+
+impl TryFrom<Decimal> for PriceChangePct {
+    type Error = PriceShockSimulationError;
+
+    fn try_from(pct: Decimal) -> Result<Self, Self::Error> {
+        if pct < Self::MIN || pct > Self::MAX {
+            return Err(PriceShockSimulationError::InvalidPriceChangePct(pct));
+        }
+        Ok(Self(pct))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PriceShockFacilityResult {
+    pub credit_facility_id: CreditFacilityId,
+    pub customer_id: CustomerId,
+    pub current_state: CollateralizationState,
+    pub simulated_state: CollateralizationState,
+    pub simulated_cvl: CVLPct,
+    pub collateral: Satoshis,
+    pub collateral_value: UsdCents,
+    pub outstanding: UsdCents,
+    pub uncovered_exposure: UsdCents,
+}
+
+impl PriceShockFacilityResult {
+    pub(super) fn new(
+        credit_facility_id: CreditFacilityId,
+        customer_id: CustomerId,
+        current_state: CollateralizationState,
+        terms: &TermValues,
+        balances: CreditFacilityBalanceSummary,
+        shocked_price: PriceOfOneBTC,
+    ) -> Self {
+        let simulated_cvl = balances.current_cvl(shocked_price);
+        let collateral = balances.collateral();
+        let collateral_value = shocked_price.sats_to_cents_round_down(collateral);
+        let outstanding = balances.total_outstanding();
+        let uncovered_exposure = if outstanding > collateral_value {
+            outstanding - collateral_value
+        } else {
+            UsdCents::ZERO
+        };
+
+        Self {
+            credit_facility_id,
+            customer_id,
+            current_state,
+            simulated_state: terms.collateralization(simulated_cvl),
+            simulated_cvl,
+            collateral,
+            collateral_value,
+            outstanding,
+            uncovered_exposure,
+        }
+    }
+
+    pub fn state_changed(&self) -> bool {
+        self.current_state != self.simulated_state
+    }
+}
+
+/// Totals for all facilities that end up in the same collateralization state.
+#[derive(Debug, Clone, Copy)]
+pub struct PriceShockBucket {
+    pub state: CollateralizationState,
+    pub facility_count: usize,
+    pub collateral_value: UsdCents,
+    pub outstanding: UsdCents,
+    pub uncovered_exposure: UsdCents,
+}
+
+#[derive(Debug, Clone)]
+pub struct PriceShockSimulation {
+    pub current_price: PriceOfOneBTC,
+    pub shocked_price: PriceOfOneBTC,
+    pub simulated_at: DateTime<Utc>,
+    pub facilities: Vec<PriceShockFacilityResult>,
+}
+
+impl PriceShockSimulation {
+    const BUCKET_STATES: [CollateralizationState; 4] = [
+        CollateralizationState::FullyCollateralized,
+        CollateralizationState::UnderMarginCallThreshold,
+        CollateralizationState::UnderLiquidationThreshold,
+        CollateralizationState::NoCollateral,
+    ];
+
+    pub fn buckets(&self) -> Vec<PriceShockBucket> {
+        Self::BUCKET_STATES
+            .iter()
+            .map(|state| {
+                self.facilities
+                    .iter()
+                    .filter(|facility| facility.simulated_state == *state)
+                    .fold(
+                        PriceShockBucket {
+                            state: *state,
+                            facility_count: 0,
+                            collateral_value: UsdCents::ZERO,
+                            outstanding: UsdCents::ZERO,
+                            uncovered_exposure: UsdCents::ZERO,
+                        },
+                        |mut bucket, facility| {
+                            bucket.facility_count += 1;
+                            bucket.collateral_value += facility.collateral_value;
+                            bucket.outstanding += facility.outstanding;
+                            bucket.uncovered_exposure += facility.uncovered_exposure;
+                            bucket
+                        },
+                    )
+            })
+            .collect()
+    }
+
+    pub fn total_uncovered_exposure(&self) -> UsdCents {
+        self.facilities
+            .iter()
+            .fold(UsdCents::ZERO, |total, facility| {
+                total + facility.uncovered_exposure
+            })
+    }
+
+    pub fn to_csv(&self) -> Result<Vec<u8>, PriceShockSimulationError> {
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record([
+            "Credit Facility ID",
+            "Customer ID",
+            "Current State",
+            "Simulated State",
+            "Simulated CVL",
+            "Collateral (BTC)",
+            "Collateral Value (USD)",
+            "Outstanding (USD)",
+            "Uncovered Exposure (USD)",
+        ])
+        .map_err(|e| PriceShockSimulationError::CsvError(e.to_string()))?;
+
+        for facility in self.facilities.iter() {
+            wtr.write_record(&[
+                facility.credit_facility_id.to_string(),
+                facility.customer_id.to_string(),
+                facility.current_state.to_string(),
+                facility.simulated_state.to_string(),
+                facility.simulated_cvl.to_string(),
+                facility.collateral.to_btc().to_string(),
+                facility.collateral_value.to_usd().to_string(),
+                facility.outstanding.to_usd().to_string(),
+                facility.uncovered_exposure.to_usd().to_string(),
+            ])
+            .map_err(|e| PriceShockSimulationError::CsvError(e.to_string()))?;
+        }
+
+        wtr.into_inner()
+            .map_err(|e| PriceShockSimulationError::CsvError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::new(1))
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn price() -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(100_000_00))
+    }
+
+    fn balances(collateral: Satoshis, disbursed: UsdCents) -> CreditFacilityBalanceSummary {
+        CreditFacilityBalanceSummary {
+            facility: disbursed,
+            collateral,
+            disbursed,
+            not_yet_due_disbursed_outstanding: disbursed,
+            ..Default::default()
+        }
+    }
+
+    fn result(
+        collateral: Satoshis,
+        disbursed: UsdCents,
+        shocked_price: PriceOfOneBTC,
+    ) -> PriceShockFacilityResult {
+        PriceShockFacilityResult::new(
+            CreditFacilityId::new(),
+            CustomerId::new(),
+            CollateralizationState::FullyCollateralized,
+            &terms(),
+            balances(collateral, disbursed),
+            shocked_price,
+        )
+    }
+
+    #[test]
+    fn change_pct_moves_current_price() {
+        assert_eq!(
+            PriceShock::change_pct(dec!(-30))
+                .unwrap()
+                .shocked_price(price())
+                .unwrap(),
+            PriceOfOneBTC::new(UsdCents::from(70_000_00))
+        );
+        assert_eq!(
+            PriceShock::change_pct(dec!(-100))
+                .unwrap()
+                .shocked_price(price())
+                .unwrap(),
+            PriceOfOneBTC::ZERO
+        );
+    }
+
+    #[test]
+    fn change_pct_out_of_range_is_rejected() {
+        assert!(matches!(
+            PriceShock::change_pct(dec!(-150)),
+            Err(PriceShockSimulationError::InvalidPriceChangePct(_))
+        ));
+        assert!(matches!(
+            PriceShock::change_pct(Decimal::MAX),
+            Err(PriceShockSimulationError::InvalidPriceChangePct(_))
+        ));
+    }
+
+    #[test]
+    fn shocked_price_overflow_is_an_error() {
+        let price = PriceOfOneBTC::new(UsdCents::from(u64::MAX));
+        assert!(matches!(
+            PriceShock::change_pct(dec!(1000))
+                .unwrap()
+                .shocked_price(price),
+            Err(PriceShockSimulationError::ShockedPriceOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn facility_moves_to_lower_state_at_shocked_price() {
+        // 1 BTC against $60,000 outstanding
+        let shocked_price = PriceShock::change_pct(dec!(-30))
+            .unwrap()
+            .shocked_price(price())
+            .unwrap();
+        let res = result(
+            Satoshis::from(100_000_000),
+            UsdCents::from(60_000_00),
+            shocked_price,
+        );
+
+        assert!(res.state_changed());
+        assert_eq!(
+            res.simulated_state,
+            CollateralizationState::UnderMarginCallThreshold
+        );
+        assert_eq!(res.collateral_value, UsdCents::from(70_000_00));
+        assert_eq!(res.uncovered_exposure, UsdCents::ZERO);
+    }
+
+    #[test]
+    fn buckets_aggregate_uncovered_exposure() {
+        let shocked_price = PriceOfOneBTC::new(UsdCents::from(50_000_00));
+        let simulation = PriceShockSimulation {
+            current_price: price(),
+            shocked_price,
+            simulated_at: Utc::now(),
+            facilities: vec![
+                result(
+                    Satoshis::from(100_000_000),
+                    UsdCents::from(20_000_00),
+                    shocked_price,
+                ),
+                result(
+                    Satoshis::from(100_000_000),
+                    UsdCents::from(60_000_00),
+                    shocked_price,
+                ),
+                result(
+                    Satoshis::from(50_000_000),
+                    UsdCents::from(30_000_00),
+                    shocked_price,
+                ),
+            ],
+        };
+
+        let buckets = simulation.buckets();
+        let liquidation = buckets
+            .iter()
+            .find(|bucket| bucket.state == CollateralizationState::UnderLiquidationThreshold)
+            .unwrap();
+        assert_eq!(liquidation.facility_count, 2);
+        assert_eq!(liquidation.outstanding, UsdCents::from(90_000_00));
+        assert_eq!(liquidation.uncovered_exposure, UsdCents::from(15_000_00));
+        assert_eq!(
+            simulation.total_uncovered_exposure(),
+            UsdCents::from(15_000_00)
+        );
+        assert_eq!(buckets.iter().map(|b| b.facility_count).sum::<usize>(), 3);
+    }
+}
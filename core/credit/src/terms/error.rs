@@ -19,6 +19,8 @@ pub enum TermsError {
         "TermsError - MarginCallBelowLiquidationLimit: margin_call_cvl {0} <= liquidation_cvl {1}"
     )]
     MarginCallBelowLiquidationLimit(CVLPct, CVLPct),
+    #[error("TermsError - InvalidCollateralHaircut: haircut for {0} must be in [0, 100)")]
+    InvalidCollateralHaircut(crate::primitives::CollateralAsset),
     #[error("TermsError - UninitializedField: {0}")]
     UninitializedField(#[from] derive_builder::UninitializedFieldError),
 }
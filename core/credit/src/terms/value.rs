@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
 use derive_builder::{Builder, UninitializedFieldError};
 use rust_decimal::{Decimal, prelude::*};
 use rust_decimal_macros::dec;
@@ -10,14 +10,16 @@ use schemars::JsonSchema;
 use crate::{
     ledger::CreditFacilityBalanceSummary,
     primitives::{
-        CVLPct, CollateralizationState, DisbursedReceivableAccountCategory, PriceOfOneBTC,
-        Satoshis, UsdCents,
+        CVLPct, CollateralAsset, CollateralizationState, DisbursedReceivableAccountCategory,
+        PriceOfOneBTC, ReferenceRateId, Satoshis, UsdCents,
     },
 };
 
 use super::error::TermsError;
 
 const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+const NUMBER_OF_DAYS_IN_LEAP_YEAR: u64 = 366;
+const NUMBER_OF_DAYS_IN_BANKING_YEAR: u64 = 360;
 const SHORT_TERM_DURATION_MONTHS_THRESHOLD: u32 = 12;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -28,6 +30,12 @@ pub struct AnnualRatePct(Decimal);
 async_graphql::scalar!(AnnualRatePct);
 
 impl AnnualRatePct {
+    pub const ZERO: Self = Self(dec!(0));
+
+    pub fn plus_spread(&self, spread: AnnualRatePct) -> AnnualRatePct {
+        AnnualRatePct((self.0 + spread.0).max(Decimal::ZERO))
+    }
+
     pub fn interest_for_time_period(&self, principal: UsdCents, days: u32) -> UsdCents {
         let cents = principal.to_usd() * Decimal::from(days) * self.0
             / Decimal::from(NUMBER_OF_DAYS_IN_YEAR);
@@ -39,6 +47,39 @@ impl AnnualRatePct {
                 .expect("should return a valid integer"),
         )
     }
+
+    pub fn interest_for_period(
+        &self,
+        principal: UsdCents,
+        period: &InterestPeriod,
+        day_count_convention: DayCountConvention,
+    ) -> UsdCents {
+        let cents = self.unrounded_interest_for_period(principal, period, day_count_convention);
+
+        UsdCents::from(
+            cents
+                .round_dp_with_strategy(0, RoundingStrategy::AwayFromZero)
+                .to_u64()
+                .expect("should return a valid integer"),
+        )
+    }
+
+    /// Interest in cents before rounding, for callers that carry the
+    /// fractional part forward between periods.
+    pub fn unrounded_interest_for_period(
+        &self,
+        principal: UsdCents,
+        period: &InterestPeriod,
+        day_count_convention: DayCountConvention,
+    ) -> Decimal {
+        day_count_convention
+            .accrual_segments(period)
+            .into_iter()
+            .fold(Decimal::ZERO, |acc, (days, days_in_year)| {
+                acc + principal.to_usd() * Decimal::from(days) * self.0
+                    / Decimal::from(days_in_year)
+            })
+    }
 }
 
 impl From<Decimal> for AnnualRatePct {
@@ -47,8 +88,17 @@ impl From<Decimal> for AnnualRatePct {
     }
 }
 
+/// Indexes the facility rate to a published reference rate. Each interest accrual
+/// cycle applies the fixing in effect at its start plus `spread`.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct FloatingRate {
+    pub reference_rate_id: ReferenceRateId,
+    pub spread: AnnualRatePct,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct OneTimeFeeRatePct(Decimal);
 #[cfg(feature = "graphql")]
@@ -74,6 +124,85 @@ impl From<Decimal> for OneTimeFeeRatePct {
         OneTimeFeeRatePct(value)
     }
 }
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct HaircutPct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(HaircutPct);
+
+impl HaircutPct {
+    pub const ZERO: Self = Self(dec!(0));
+
+    pub fn new(pct: u64) -> Self {
+        HaircutPct(Decimal::from(pct))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.0 >= Decimal::ZERO && self.0 < dec!(100)
+    }
+
+    /// Share of the market value that still counts towards the CVL.
+    pub fn retained_fraction(&self) -> Decimal {
+        Decimal::ONE - self.0 / dec!(100)
+    }
+
+    pub fn apply(&self, value: UsdCents) -> UsdCents {
+        let retained = (value.to_usd() * self.retained_fraction())
+            .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+
+        UsdCents::try_from_usd(retained).expect("Unexpected negative number")
+    }
+}
+
+impl From<Decimal> for HaircutPct {
+    fn from(value: Decimal) -> Self {
+        HaircutPct(value)
+    }
+}
+
+/// Per-asset discount on the market value of collateral when computing the CVL.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct CollateralHaircuts {
+    #[serde(default)]
+    pub btc: HaircutPct,
+    #[serde(default)]
+    pub eth: HaircutPct,
+    #[serde(default)]
+    pub usdt: HaircutPct,
+    #[serde(default)]
+    pub tokenized_treasury: HaircutPct,
+}
+
+impl CollateralHaircuts {
+    pub fn for_asset(&self, asset: CollateralAsset) -> HaircutPct {
+        match asset {
+            CollateralAsset::Btc => self.btc,
+            CollateralAsset::Eth => self.eth,
+            CollateralAsset::Usdt => self.usdt,
+            CollateralAsset::TokenizedTreasury => self.tokenized_treasury,
+        }
+    }
+}
+
+/// Facility-level fees charged on top of the one-time structuring fee. Every fee
+/// is booked as an obligation of its own.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct FeeSchedule {
+    /// Accrued every interest accrual cycle on the undrawn facility amount.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commitment_fee_rate: Option<AnnualRatePct>,
+    /// Charged at activation and on every anniversary before maturity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_maintenance_fee: Option<UsdCents>,
+    /// Charged on the amount of every settled disbursal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disbursal_fee_rate: Option<OneTimeFeeRatePct>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
@@ -174,10 +303,102 @@ impl InterestPeriod {
     }
 
     pub fn days(&self) -> u32 {
-        self.end.day() - self.start.day() + 1
+        let days = (self.end.date_naive() - self.start.date_naive()).num_days() + 1;
+        u32::try_from(days).expect("period should not end before it starts")
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DayCountConvention {
+    #[default]
+    Actual365,
+    Actual360,
+    ActualActual,
+    Thirty360,
+}
+
+impl DayCountConvention {
+    pub fn year_fraction(&self, period: &InterestPeriod) -> Decimal {
+        self.accrual_segments(period)
+            .into_iter()
+            .fold(Decimal::ZERO, |acc, (days, days_in_year)| {
+                acc + Decimal::from(days) / Decimal::from(days_in_year)
+            })
+    }
+
+    /// Splits a period into (days, days in year) pairs whose ratios add up to the year fraction.
+    fn accrual_segments(&self, period: &InterestPeriod) -> Vec<(u64, u64)> {
+        let start = period.start.date_naive();
+        let end = period.end.date_naive() + chrono::Days::new(1);
+
+        match self {
+            DayCountConvention::Actual365 => {
+                vec![(actual_days(start, end), NUMBER_OF_DAYS_IN_YEAR)]
+            }
+            DayCountConvention::Actual360 => {
+                vec![(actual_days(start, end), NUMBER_OF_DAYS_IN_BANKING_YEAR)]
+            }
+            DayCountConvention::Thirty360 => {
+                vec![(thirty_360_days(start, end), NUMBER_OF_DAYS_IN_BANKING_YEAR)]
+            }
+            DayCountConvention::ActualActual => {
+                let mut segments = vec![];
+                let mut segment_start = start;
+                while segment_start < end {
+                    let next_year = NaiveDate::from_ymd_opt(segment_start.year() + 1, 1, 1)
+                        .expect("should return a valid date");
+                    let segment_end = next_year.min(end);
+                    let days_in_year =
+                        if NaiveDate::from_ymd_opt(segment_start.year(), 2, 29).is_some() {
+                            NUMBER_OF_DAYS_IN_LEAP_YEAR
+                        } else {
+                            NUMBER_OF_DAYS_IN_YEAR
+                        };
+                    segments.push((actual_days(segment_start, segment_end), days_in_year));
+                    segment_start = segment_end;
+                }
+                segments
+            }
+        }
+    }
+}
+
+fn actual_days(start: NaiveDate, end_exclusive: NaiveDate) -> u64 {
+    u64::try_from((end_exclusive - start).num_days()).expect("end should not be before start")
+}
+
+// 30/360 US bond basis
+fn thirty_360_days(start: NaiveDate, end_exclusive: NaiveDate) -> u64 {
+    let d1 = start.day().min(30);
+    let d2 = if end_exclusive.day() == 31 && d1 == 30 {
+        30
+    } else {
+        end_exclusive.day()
+    };
+    let days = 360 * (end_exclusive.year() - start.year())
+        + 30 * (end_exclusive.month() as i32 - start.month() as i32)
+        + (d2 as i32 - d1 as i32);
+    u64::try_from(days).expect("end should not be before start")
+}
+
+/// Order in which a payment is applied across a facility's open obligations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentAllocationStrategy {
+    /// Interest before principal, oldest first within each.
+    #[default]
+    InterestFirst,
+    /// Defaulted obligations oldest first, then interest before principal.
+    DefaultedFirst,
+    /// Principal before interest, oldest first within each.
+    PrincipalFirst,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -224,6 +445,23 @@ impl InterestInterval {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PrincipalRepayment {
+    #[default]
+    Bullet,
+    StraightLine,
+    EqualInstallments,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrincipalInstallment {
+    pub due_at: DateTime<Utc>,
+    pub amount: UsdCents,
+}
+
 #[derive(Builder, Debug, Serialize, Deserialize, Clone, Copy)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[builder(build_fn(validate = "Self::validate", error = "TermsError"))]
@@ -250,6 +488,33 @@ pub struct TermValues {
     pub margin_call_cvl: CVLPct,
     #[builder(setter(into))]
     pub initial_cvl: CVLPct,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub principal_repayment: PrincipalRepayment,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub day_count_convention: DayCountConvention,
+    /// When set, `annual_rate` is only used for projections.
+    #[builder(setter(into), default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floating_rate: Option<FloatingRate>,
+    /// Charged on the outstanding principal when the facility is paid off before maturity.
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub prepayment_fee_rate: OneTimeFeeRatePct,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub payment_allocation_strategy: PaymentAllocationStrategy,
+    /// Accrued on the outstanding amount of overdue and defaulted obligations.
+    #[builder(setter(into), default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub collateral_haircuts: CollateralHaircuts,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub fee_schedule: FeeSchedule,
 }
 
 impl TermValues {
@@ -278,6 +543,63 @@ impl TermValues {
         TermValuesBuilder::default()
     }
 
+    pub fn with_annual_rate(self, annual_rate: AnnualRatePct) -> Self {
+        Self {
+            annual_rate,
+            ..self
+        }
+    }
+
+    pub fn interest_for_period(&self, principal: UsdCents, period: &InterestPeriod) -> UsdCents {
+        self.annual_rate
+            .interest_for_period(principal, period, self.day_count_convention)
+    }
+
+    /// Penalty in cents for the period, unrounded so that daily accruals can be
+    /// summed over an accrual cycle before posting.
+    pub fn penalty_for_period(&self, outstanding: UsdCents, period: &InterestPeriod) -> Decimal {
+        self.penalty_rate
+            .map(|rate| {
+                rate.unrounded_interest_for_period(outstanding, period, self.day_count_convention)
+            })
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    pub fn commitment_fee_for_period(
+        &self,
+        undrawn: UsdCents,
+        period: &InterestPeriod,
+    ) -> UsdCents {
+        self.fee_schedule
+            .commitment_fee_rate
+            .map(|rate| rate.interest_for_period(undrawn, period, self.day_count_convention))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    pub fn disbursal_fee(&self, amount: UsdCents) -> UsdCents {
+        self.fee_schedule
+            .disbursal_fee_rate
+            .map(|rate| rate.apply(amount))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    /// Dates on which the annual maintenance fee falls due: activation and
+    /// every anniversary strictly before maturity.
+    pub fn maintenance_fee_dates(
+        &self,
+        activated_at: DateTime<Utc>,
+        matures_at: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        if self.fee_schedule.annual_maintenance_fee.is_none() {
+            return vec![];
+        }
+
+        (0..)
+            .map_while(|year| activated_at.checked_add_months(chrono::Months::new(12 * year)))
+            .take_while(|date| *date < matures_at)
+            .collect()
+    }
+
     pub fn required_collateral(
         &self,
         desired_principal: UsdCents,
@@ -287,6 +609,103 @@ impl TermValues {
         price.cents_to_sats_round_up(collateral_value)
     }
 
+    pub fn principal_installments(
+        &self,
+        principal: UsdCents,
+        start_date: DateTime<Utc>,
+        maturity_date: DateTime<Utc>,
+    ) -> Vec<PrincipalInstallment> {
+        let mut periods = vec![];
+        let mut next_period = self
+            .accrual_cycle_interval
+            .period_from(start_date)
+            .truncate(maturity_date);
+        while let Some(period) = next_period {
+            if period.start < maturity_date {
+                periods.push(period);
+            }
+            next_period = period.next().truncate(maturity_date);
+        }
+
+        if self.principal_repayment == PrincipalRepayment::Bullet
+            || periods.len() <= 1
+            || principal.is_zero()
+        {
+            return vec![PrincipalInstallment {
+                due_at: maturity_date,
+                amount: principal,
+            }];
+        }
+
+        let amounts = match self.principal_repayment {
+            PrincipalRepayment::Bullet => unreachable!(),
+            PrincipalRepayment::StraightLine => {
+                let per_period = principal.into_inner() / periods.len() as u64;
+                vec![UsdCents::from(per_period); periods.len()]
+            }
+            PrincipalRepayment::EqualInstallments => {
+                let rates: Vec<Decimal> = periods
+                    .iter()
+                    .map(|period| {
+                        self.annual_rate.0 / dec!(100)
+                            * self.day_count_convention.year_fraction(period)
+                    })
+                    .collect();
+
+                // Annuity payment that amortizes the principal to zero over periods
+                // with (possibly) different lengths.
+                let growth: Decimal = rates.iter().map(|r| Decimal::ONE + r).product();
+                let mut remaining_growth = Decimal::ONE;
+                let mut discount_sum = Decimal::ZERO;
+                for rate in rates.iter().rev() {
+                    discount_sum += remaining_growth;
+                    remaining_growth *= Decimal::ONE + rate;
+                }
+                let principal_usd = principal.to_usd();
+                let payment = principal_usd * growth / discount_sum;
+
+                let mut balance = principal_usd;
+                rates
+                    .iter()
+                    .map(|rate| {
+                        let principal_part = (payment - balance * rate)
+                            .round_dp_with_strategy(2, RoundingStrategy::ToZero)
+                            .max(Decimal::ZERO)
+                            .min(balance);
+                        balance -= principal_part;
+                        UsdCents::try_from_usd(principal_part)
+                            .expect("principal part should not be negative")
+                    })
+                    .collect()
+            }
+        };
+
+        let mut remaining = principal;
+        let last_idx = periods.len() - 1;
+        periods
+            .into_iter()
+            .zip(amounts)
+            .enumerate()
+            .map(|(idx, (period, amount))| {
+                let amount = if idx == last_idx {
+                    remaining
+                } else {
+                    std::cmp::min(amount, remaining)
+                };
+                remaining -= amount;
+                PrincipalInstallment {
+                    due_at: if idx == last_idx {
+                        maturity_date
+                    } else {
+                        period.end
+                    },
+                    amount,
+                }
+            })
+            .filter(|installment| !installment.amount.is_zero())
+            .collect()
+    }
+
     pub fn collateralization(&self, cvl: CVLPct) -> CollateralizationState {
         let margin_call_cvl = self.margin_call_cvl;
         let liquidation_cvl = self.liquidation_cvl;
@@ -302,6 +721,30 @@ impl TermValues {
         }
     }
 
+    /// Collateralization of a facility that has not been activated yet. It
+    /// only counts as fully collateralized once the collateral covers the
+    /// facility amount at the initial CVL.
+    pub fn pending_collateralization(&self, facility_amount_cvl: CVLPct) -> CollateralizationState {
+        match self.collateralization(facility_amount_cvl) {
+            CollateralizationState::FullyCollateralized
+                if facility_amount_cvl < self.initial_cvl =>
+            {
+                CollateralizationState::UnderMarginCallThreshold
+            }
+            state => state,
+        }
+    }
+
+    pub fn pending_collateralization_update(
+        &self,
+        facility_amount_cvl: CVLPct,
+        last_collateralization_state: CollateralizationState,
+    ) -> Option<CollateralizationState> {
+        let calculated_collateralization = self.pending_collateralization(facility_amount_cvl);
+        (calculated_collateralization != last_collateralization_state)
+            .then_some(calculated_collateralization)
+    }
+
     pub fn collateralization_update(
         &self,
         current_cvl: CVLPct,
@@ -390,6 +833,14 @@ impl TermValuesBuilder {
             ));
         }
 
+        if let Some(haircuts) = self.collateral_haircuts
+            && let Some(asset) = CollateralAsset::ALL
+                .into_iter()
+                .find(|asset| !haircuts.for_asset(*asset).is_valid())
+        {
+            return Err(TermsError::InvalidCollateralHaircut(asset));
+        }
+
         Ok(())
     }
 }
@@ -480,6 +931,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn invalid_term_values_full_collateral_haircut() {
+        let result = TermValues::builder()
+            .annual_rate(AnnualRatePct(dec!(12)))
+            .duration(FacilityDuration::Months(3))
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .one_time_fee_rate(OneTimeFeeRatePct(dec!(1)))
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .collateral_haircuts(CollateralHaircuts {
+                eth: HaircutPct::new(100),
+                ..Default::default()
+            })
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            TermsError::InvalidCollateralHaircut(CollateralAsset::Eth)
+        ));
+    }
+
+    #[test]
+    fn haircut_applies_to_collateral_value() {
+        let haircut = HaircutPct::from(dec!(15));
+        assert_eq!(
+            haircut.apply(UsdCents::from(1_000_00)),
+            UsdCents::from(850_00)
+        );
+        assert_eq!(
+            HaircutPct::ZERO.apply(UsdCents::from(99)),
+            UsdCents::from(99)
+        );
+    }
+
     #[test]
     fn required_collateral() {
         let price =
@@ -545,6 +1031,175 @@ mod test {
         assert_eq!(interest, UsdCents::from(757));
     }
 
+    #[test]
+    fn penalty_calculation() {
+        let period = InterestInterval::EndOfMonth
+            .period_from("2024-01-09T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        let outstanding = UsdCents::try_from_usd(dec!(1000)).unwrap();
+
+        let terms = terms();
+        assert_eq!(
+            terms.penalty_for_period(outstanding, &period),
+            Decimal::ZERO
+        );
+
+        let terms = TermValues {
+            penalty_rate: Some(AnnualRatePct(dec!(12))),
+            ..terms
+        };
+        let penalty = terms.penalty_for_period(outstanding, &period);
+        assert!(!penalty.fract().is_zero());
+        assert_eq!(
+            penalty.round_dp_with_strategy(0, RoundingStrategy::AwayFromZero),
+            Decimal::from(
+                AnnualRatePct(dec!(12))
+                    .interest_for_time_period(outstanding, 23)
+                    .into_inner()
+            )
+        );
+    }
+
+    #[test]
+    fn fee_schedule_calculations() {
+        let period = InterestInterval::EndOfMonth
+            .period_from("2024-01-09T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        let amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+
+        let terms = terms();
+        assert_eq!(
+            terms.commitment_fee_for_period(amount, &period),
+            UsdCents::ZERO
+        );
+        assert_eq!(terms.disbursal_fee(amount), UsdCents::ZERO);
+
+        let terms = TermValues {
+            fee_schedule: FeeSchedule {
+                commitment_fee_rate: Some(AnnualRatePct(dec!(1))),
+                annual_maintenance_fee: None,
+                disbursal_fee_rate: Some(OneTimeFeeRatePct(dec!(2))),
+            },
+            ..terms
+        };
+        assert_eq!(
+            terms.commitment_fee_for_period(amount, &period),
+            AnnualRatePct(dec!(1)).interest_for_time_period(amount, 23)
+        );
+        assert_eq!(terms.disbursal_fee(amount), UsdCents::from(2000));
+    }
+
+    #[test]
+    fn maintenance_fee_dates_until_maturity() {
+        let activated_at = "2024-03-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let matures_at = "2026-09-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let terms = terms();
+        assert!(
+            terms
+                .maintenance_fee_dates(activated_at, matures_at)
+                .is_empty()
+        );
+
+        let terms = TermValues {
+            fee_schedule: FeeSchedule {
+                annual_maintenance_fee: Some(UsdCents::from(50_000)),
+                ..Default::default()
+            },
+            ..terms
+        };
+        assert_eq!(
+            terms.maintenance_fee_dates(activated_at, matures_at),
+            vec![
+                activated_at,
+                "2025-03-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+                "2026-03-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap(),
+            ]
+        );
+    }
+
+    mod day_count_convention {
+        use super::*;
+
+        fn period(start: &str, end: &str) -> InterestPeriod {
+            InterestPeriod {
+                interval: InterestInterval::EndOfMonth,
+                start: start.parse::<DateTime<Utc>>().unwrap(),
+                end: end.parse::<DateTime<Utc>>().unwrap(),
+            }
+        }
+
+        #[test]
+        fn actual_365_matches_days_based_calculation() {
+            let principal = UsdCents::try_from_usd(dec!(1000)).unwrap();
+            let period = period("2024-01-01T00:00:00Z", "2024-01-23T23:59:59Z");
+            let rate = AnnualRatePct(dec!(12));
+            assert_eq!(period.days(), 23);
+            assert_eq!(
+                rate.interest_for_period(principal, &period, DayCountConvention::Actual365),
+                rate.interest_for_time_period(principal, 23)
+            );
+        }
+
+        #[test]
+        fn actual_360_accrues_more_than_actual_365() {
+            let principal = UsdCents::try_from_usd(dec!(1000)).unwrap();
+            let period = period("2024-01-01T00:00:00Z", "2024-01-31T23:59:59Z");
+            let rate = AnnualRatePct(dec!(12));
+            assert_eq!(
+                rate.interest_for_period(principal, &period, DayCountConvention::Actual360),
+                UsdCents::from(1033)
+            );
+            assert_eq!(
+                rate.interest_for_period(principal, &period, DayCountConvention::Actual365),
+                UsdCents::from(1019)
+            );
+        }
+
+        #[test]
+        fn actual_actual_uses_leap_year_length() {
+            let leap_february = period("2024-02-01T00:00:00Z", "2024-02-29T23:59:59Z");
+            assert_eq!(leap_february.days(), 29);
+            assert_eq!(
+                DayCountConvention::ActualActual.year_fraction(&leap_february),
+                Decimal::from(29) / Decimal::from(366)
+            );
+
+            let year_end = period("2023-12-15T00:00:00Z", "2024-01-14T23:59:59Z");
+            assert_eq!(year_end.days(), 31);
+            assert_eq!(
+                DayCountConvention::ActualActual.year_fraction(&year_end),
+                Decimal::from(17) / Decimal::from(365) + Decimal::from(14) / Decimal::from(366)
+            );
+        }
+
+        #[test]
+        fn thirty_360_counts_every_month_as_30_days() {
+            let convention = DayCountConvention::Thirty360;
+            for (start, end) in [
+                ("2024-01-01T00:00:00Z", "2024-01-31T23:59:59Z"),
+                ("2024-02-01T00:00:00Z", "2024-02-29T23:59:59Z"),
+                ("2023-02-01T00:00:00Z", "2023-02-28T23:59:59Z"),
+                ("2024-04-01T00:00:00Z", "2024-04-30T23:59:59Z"),
+            ] {
+                assert_eq!(
+                    convention.year_fraction(&period(start, end)),
+                    Decimal::from(30) / Decimal::from(360)
+                );
+            }
+
+            let from_month_end = period("2024-01-31T00:00:00Z", "2024-03-30T23:59:59Z");
+            assert_eq!(
+                convention.year_fraction(&from_month_end),
+                Decimal::from(60) / Decimal::from(360)
+            );
+        }
+
+        #[test]
+        fn days_spans_month_boundary() {
+            let period = period("2024-12-20T00:00:00Z", "2025-01-05T23:59:59Z");
+            assert_eq!(period.days(), 17);
+        }
+    }
+
     #[test]
     fn maturity_date() {
         let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
@@ -825,6 +1480,91 @@ mod test {
         }
     }
 
+    mod principal_installments {
+        use super::*;
+
+        fn terms_with(principal_repayment: PrincipalRepayment) -> TermValues {
+            let mut terms = default_terms();
+            terms.principal_repayment = principal_repayment;
+            terms
+        }
+
+        fn start_date() -> DateTime<Utc> {
+            "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        }
+
+        fn maturity_date() -> DateTime<Utc> {
+            FacilityDuration::Months(3).maturity_date(start_date())
+        }
+
+        #[test]
+        fn bullet_is_single_installment_at_maturity() {
+            let principal = UsdCents::from(1_000_000);
+            let installments = terms_with(PrincipalRepayment::Bullet).principal_installments(
+                principal,
+                start_date(),
+                maturity_date(),
+            );
+            assert_eq!(
+                installments,
+                vec![PrincipalInstallment {
+                    due_at: maturity_date(),
+                    amount: principal,
+                }]
+            );
+        }
+
+        #[test]
+        fn straight_line_splits_principal_evenly() {
+            let principal = UsdCents::from(1_000_001);
+            let installments = terms_with(PrincipalRepayment::StraightLine).principal_installments(
+                principal,
+                start_date(),
+                maturity_date(),
+            );
+
+            assert_eq!(installments.len(), 3);
+            assert_eq!(installments[0].amount, UsdCents::from(333_333));
+            assert_eq!(installments[1].amount, UsdCents::from(333_333));
+            assert_eq!(installments[2].amount, UsdCents::from(333_335));
+            assert_eq!(
+                installments[0].due_at,
+                "2025-01-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap()
+            );
+            assert_eq!(installments[2].due_at, maturity_date());
+        }
+
+        #[test]
+        fn equal_installments_amortize_full_principal() {
+            let terms = terms_with(PrincipalRepayment::EqualInstallments);
+            let principal = UsdCents::try_from_usd(dec!(100_000)).unwrap();
+            let start_date = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+            let maturity_date = FacilityDuration::Months(12).maturity_date(start_date);
+            let installments = terms.principal_installments(principal, start_date, maturity_date);
+
+            assert_eq!(installments.len(), 12);
+            let total = installments
+                .iter()
+                .fold(UsdCents::ZERO, |acc, i| acc + i.amount);
+            assert_eq!(total, principal);
+
+            assert!(installments[0].amount < installments[11].amount);
+        }
+
+        #[test]
+        fn short_facility_falls_back_to_bullet() {
+            let start_date = "2025-01-15T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+            let maturity_date = "2025-01-30T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+            let installments = terms_with(PrincipalRepayment::StraightLine).principal_installments(
+                UsdCents::from(1_000),
+                start_date,
+                maturity_date,
+            );
+            assert_eq!(installments.len(), 1);
+            assert_eq!(installments[0].due_at, maturity_date);
+        }
+    }
+
     fn default_balances(facility: UsdCents) -> CreditFacilityBalanceSummary {
         CreditFacilityBalanceSummary {
             facility,
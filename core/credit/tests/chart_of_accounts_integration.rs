@@ -21,10 +21,9 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage);
 
     let governance = governance::Governance::new(&pool, &authz, &outbox);
-    let customers = core_customer::Customers::new(&pool, &authz, &outbox, document_storage);
+    let customers = core_customer::Customers::new(&pool, &authz, &outbox, document_storage.clone());
     let custody =
         core_custody::CoreCustody::init(&pool, &authz, helpers::custody_config(), &outbox).await?;
-    let price = core_price::Price::new();
 
     let cala_config = CalaLedgerConfig::builder()
         .pool(pool.clone())
@@ -32,6 +31,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         .build()?;
     let cala = CalaLedger::init(cala_config).await?;
     let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let price = core_price::Price::init(&pool, &jobs, &outbox, Default::default()).await?;
 
     let journal_id = helpers::init_journal(&cala).await?;
 
@@ -44,6 +44,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &customers,
         &custody,
         &price,
+        document_storage,
         &outbox,
         &cala,
         journal_id,
@@ -103,11 +104,19 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_facility_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_collateral_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_in_liquidation_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_liquidator_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_liquidation_proceeds_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_credit_loss_expense_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_facility_parent_code("3".parse().unwrap())
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_in_liquidation_parent_code("3".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_unapplied_funds_parent_code("8".parse().unwrap())
+                .chart_of_account_penalty_income_parent_code("7".parse().unwrap())
+                .chart_of_account_commitment_fee_income_parent_code("7".parse().unwrap())
+                .chart_of_account_maintenance_fee_income_parent_code("7".parse().unwrap())
+                .chart_of_account_disbursal_fee_income_parent_code("7".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
@@ -238,11 +247,19 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_facility_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_collateral_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_in_liquidation_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_liquidator_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_liquidation_proceeds_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_credit_loss_expense_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_facility_parent_code("3".parse().unwrap())
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_in_liquidation_parent_code("3".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_unapplied_funds_parent_code("8".parse().unwrap())
+                .chart_of_account_penalty_income_parent_code("7".parse().unwrap())
+                .chart_of_account_commitment_fee_income_parent_code("7".parse().unwrap())
+                .chart_of_account_maintenance_fee_income_parent_code("7".parse().unwrap())
+                .chart_of_account_disbursal_fee_income_parent_code("7".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
@@ -146,6 +146,7 @@ pub mod event {
     use core_credit::CoreCreditEvent;
     use core_custody::CoreCustodyEvent;
     use core_customer::CoreCustomerEvent;
+    use core_price::CorePriceEvent;
     use governance::GovernanceEvent;
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -154,6 +155,7 @@ pub mod event {
         CoreCredit(CoreCreditEvent),
         CoreCustody(CoreCustodyEvent),
         CoreCustomer(CoreCustomerEvent),
+        CorePrice(CorePriceEvent),
         Governance(GovernanceEvent),
     }
 
@@ -179,4 +181,5 @@ pub mod event {
     impl_event_marker!(CoreCreditEvent, CoreCredit);
     impl_event_marker!(CoreCustodyEvent, CoreCustody);
     impl_event_marker!(CoreCustomerEvent, CoreCustomer);
+    impl_event_marker!(CorePriceEvent, CorePrice);
 }
@@ -4,6 +4,8 @@ use thiserror::Error;
 pub enum CustodianClientError {
     #[error("CustodianClientError - ClientError: {0}")]
     ClientError(Box<dyn std::error::Error + Send + Sync>),
+    #[error("CustodianClientError - UnsupportedOperation: {0}")]
+    UnsupportedOperation(&'static str),
 }
 
 impl CustodianClientError {
@@ -1,6 +1,7 @@
 pub mod error;
 
 use async_trait::async_trait;
+use core_money::Satoshis;
 use serde_json::Value;
 
 use error::CustodianClientError;
@@ -11,10 +12,23 @@ pub struct WalletResponse {
     pub full_response: serde_json::Value,
 }
 
+pub struct WithdrawalResponse {
+    pub external_id: String,
+    pub full_response: serde_json::Value,
+}
+
 #[async_trait]
 pub trait CustodianClient: Send {
     async fn initialize_wallet(&self, label: &str) -> Result<WalletResponse, CustodianClientError>;
 
+    async fn initiate_withdrawal(
+        &self,
+        wallet_external_id: &str,
+        reference: &str,
+        address: &str,
+        amount: Satoshis,
+    ) -> Result<WithdrawalResponse, CustodianClientError>;
+
     async fn process_webhook(&self, payload: Value) -> Result<(), CustodianClientError>;
 }
 
@@ -33,6 +47,24 @@ impl CustodianClient for bitgo::BitgoClient {
         })
     }
 
+    async fn initiate_withdrawal(
+        &self,
+        wallet_external_id: &str,
+        reference: &str,
+        address: &str,
+        amount: Satoshis,
+    ) -> Result<WithdrawalResponse, CustodianClientError> {
+        let (transfer, full_response) = self
+            .send_coins(wallet_external_id, reference, address, amount.into_inner())
+            .await
+            .map_err(CustodianClientError::client)?;
+
+        Ok(WithdrawalResponse {
+            external_id: transfer.txid,
+            full_response,
+        })
+    }
+
     async fn process_webhook(&self, _payload: Value) -> Result<(), CustodianClientError> {
         Ok(())
     }
@@ -47,6 +79,16 @@ impl CustodianClient for komainu::KomainuClient {
         todo!()
     }
 
+    async fn initiate_withdrawal(
+        &self,
+        _wallet_external_id: &str,
+        _reference: &str,
+        _address: &str,
+        _amount: Satoshis,
+    ) -> Result<WithdrawalResponse, CustodianClientError> {
+        Err(CustodianClientError::UnsupportedOperation("withdrawal"))
+    }
+
     async fn process_webhook(&self, _payload: Value) -> Result<(), CustodianClientError> {
         Ok(())
     }
@@ -73,6 +115,19 @@ pub mod mock {
             })
         }
 
+        async fn initiate_withdrawal(
+            &self,
+            _wallet_external_id: &str,
+            _reference: &str,
+            _address: &str,
+            _amount: Satoshis,
+        ) -> Result<WithdrawalResponse, CustodianClientError> {
+            Ok(WithdrawalResponse {
+                external_id: "456".to_string(),
+                full_response: serde_json::Value::Null,
+            })
+        }
+
         async fn process_webhook(&self, _payload: Value) -> Result<(), CustodianClientError> {
             Ok(())
         }
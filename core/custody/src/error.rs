@@ -16,6 +16,8 @@ pub enum CoreCustodyError {
     CustodianClient(#[from] crate::custodian::client::error::CustodianClientError),
     #[error("CoreCustodyError - WalletError: {0}")]
     Wallet(#[from] crate::wallet::error::WalletError),
+    #[error("CoreCustodyError - WalletNotAttached: {0}")]
+    WalletNotAttached(crate::primitives::WalletId),
 }
 
 es_entity::from_es_entity_error!(CoreCustodyError);
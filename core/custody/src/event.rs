@@ -1,9 +1,20 @@
 use serde::{Deserialize, Serialize};
 
+use core_money::Satoshis;
+
 use crate::primitives::WalletId;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum CoreCustodyEvent {
-    WalletAttached { id: WalletId, address: String },
+    WalletAttached {
+        id: WalletId,
+        address: String,
+    },
+    WithdrawalInitiated {
+        id: WalletId,
+        reference: String,
+        address: String,
+        amount: Satoshis,
+    },
 }
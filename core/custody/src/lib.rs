@@ -314,6 +314,66 @@ where
         Ok(wallet)
     }
 
+    /// Sends funds out of a custodial wallet. The `reference` identifies the
+    /// withdrawal on the caller's side and is forwarded to the custodian so
+    /// that retries do not send twice. The custodian is called outside of any
+    /// database transaction and the result is recorded afterwards.
+    #[instrument(name = "core_custody.initiate_withdrawal", skip(self), err)]
+    pub async fn initiate_withdrawal(
+        &self,
+        wallet_id: WalletId,
+        reference: String,
+        address: String,
+        amount: core_money::Satoshis,
+    ) -> Result<Wallet, CoreCustodyError> {
+        let wallet = self.wallets.find_by_id(wallet_id).await?;
+        if wallet.has_withdrawal(&reference) {
+            return Ok(wallet);
+        }
+
+        let external_wallet_id = wallet
+            .external_id()
+            .ok_or(CoreCustodyError::WalletNotAttached(wallet_id))?
+            .to_owned();
+
+        let custodian = self.custodians.find_by_id(&wallet.custodian_id).await?;
+
+        let withdrawal = custodian
+            .custodian_client(self.config.custodian_encryption.key)
+            .await?
+            .initiate_withdrawal(&external_wallet_id, &reference, &address, amount)
+            .await?;
+
+        let mut db = self.wallets.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCustodyObject::wallet(wallet_id),
+                CoreCustodyAction::WALLET_INITIATE_WITHDRAWAL,
+            )
+            .await?;
+
+        let mut wallet = self.wallets.find_by_id_in_tx(db.tx(), wallet_id).await?;
+        if wallet
+            .record_withdrawal(
+                reference,
+                withdrawal.external_id,
+                address,
+                amount,
+                withdrawal.full_response,
+                &audit_info,
+            )
+            .did_execute()
+        {
+            self.wallets.update_in_op(&mut db, &mut wallet).await?;
+        }
+        db.commit().await?;
+
+        Ok(wallet)
+    }
+
     pub async fn handle_webhook(
         &self,
         provider: String,
@@ -36,6 +36,8 @@ impl CoreCustodyAction {
 
     pub const WALLET_GENERATE_ADDRESS: Self =
         CoreCustodyAction::Wallet(WalletAction::GenerateAddress);
+    pub const WALLET_INITIATE_WITHDRAWAL: Self =
+        CoreCustodyAction::Wallet(WalletAction::InitiateWithdrawal);
 
     pub fn entities() -> Vec<(
         CoreCustodyActionDiscriminants,
@@ -127,6 +129,7 @@ impl From<CustodianAction> for CoreCustodyAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum WalletAction {
     GenerateAddress,
+    InitiateWithdrawal,
 }
 
 impl WalletAction {
@@ -135,7 +138,7 @@ impl WalletAction {
 
         for variant in <Self as strum::VariantArray>::VARIANTS {
             let action_description = match variant {
-                Self::GenerateAddress => {
+                Self::GenerateAddress | Self::InitiateWithdrawal => {
                     ActionDescription::new(variant, &[PERMISSION_SET_CUSTODY_WRITER])
                 }
             };
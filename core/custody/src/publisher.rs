@@ -36,6 +36,17 @@ where
                     id: entity.id,
                     address: address.to_owned(),
                 }),
+                WithdrawalInitiated {
+                    reference,
+                    address,
+                    amount,
+                    ..
+                } => Some(CoreCustodyEvent::WithdrawalInitiated {
+                    id: entity.id,
+                    reference: reference.to_owned(),
+                    address: address.to_owned(),
+                    amount: *amount,
+                }),
             })
             .collect::<Vec<_>>();
 
@@ -2,6 +2,7 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
+use core_money::Satoshis;
 use es_entity::*;
 
 use crate::primitives::{CustodianId, WalletId};
@@ -21,6 +22,14 @@ pub enum WalletEvent {
         custodian_response: serde_json::Value,
         audit_info: AuditInfo,
     },
+    WithdrawalInitiated {
+        reference: String,
+        external_id: String,
+        address: String,
+        amount: Satoshis,
+        custodian_response: serde_json::Value,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -61,6 +70,45 @@ impl Wallet {
             _ => None,
         })
     }
+
+    pub fn external_id(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|e| match e {
+            WalletEvent::ExternalWalletAttached { external_id, .. } => Some(external_id.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn has_withdrawal(&self, reference: &str) -> bool {
+        self.events.iter_all().any(|e| {
+            matches!(e, WalletEvent::WithdrawalInitiated { reference: existing, .. } if existing == reference)
+        })
+    }
+
+    pub fn record_withdrawal(
+        &mut self,
+        reference: String,
+        external_id: String,
+        address: String,
+        amount: Satoshis,
+        custodian_response: serde_json::Value,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            WalletEvent::WithdrawalInitiated { reference: existing, .. } if existing == &reference
+        );
+
+        self.events.push(WalletEvent::WithdrawalInitiated {
+            reference,
+            external_id,
+            address,
+            amount,
+            custodian_response,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<WalletEvent> for Wallet {
@@ -49,6 +49,18 @@ pub enum CustomerEvent {
         email: String,
         audit_info: AuditInfo,
     },
+    BtcWithdrawalAddressUpdated {
+        address: String,
+        audit_info: AuditInfo,
+    },
+    BeneficiaryAccountAdded {
+        beneficiary_account: BeneficiaryAccount,
+        audit_info: AuditInfo,
+    },
+    BeneficiaryAccountRemoved {
+        beneficiary_account_id: BeneficiaryAccountId,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -65,6 +77,10 @@ pub struct Customer {
     pub customer_type: CustomerType,
     #[builder(setter(strip_option, into), default)]
     pub applicant_id: Option<String>,
+    #[builder(setter(strip_option, into), default)]
+    pub btc_withdrawal_address: Option<String>,
+    #[builder(default)]
+    pub beneficiary_accounts: Vec<BeneficiaryAccount>,
     events: EntityEvents<CustomerEvent>,
 }
 
@@ -193,11 +209,85 @@ impl Customer {
         self.email = new_email;
         Idempotent::Executed(())
     }
+
+    pub fn update_btc_withdrawal_address(
+        &mut self,
+        new_address: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CustomerEvent::BtcWithdrawalAddressUpdated { address: existing, .. } if existing == &new_address,
+            => CustomerEvent::BtcWithdrawalAddressUpdated { .. }
+        );
+        self.events
+            .push(CustomerEvent::BtcWithdrawalAddressUpdated {
+                address: new_address.clone(),
+                audit_info,
+            });
+        self.btc_withdrawal_address = Some(new_address);
+        Idempotent::Executed(())
+    }
+
+    pub fn beneficiary_account(&self, id: BeneficiaryAccountId) -> Option<&BeneficiaryAccount> {
+        self.beneficiary_accounts
+            .iter()
+            .find(|account| account.id == id)
+    }
+
+    pub fn add_beneficiary_account(
+        &mut self,
+        holder_name: String,
+        bank_name: String,
+        details: BankAccountDetails,
+        audit_info: AuditInfo,
+    ) -> Idempotent<BeneficiaryAccountId> {
+        if self
+            .beneficiary_accounts
+            .iter()
+            .any(|account| account.details == details)
+        {
+            return Idempotent::Ignored;
+        }
+
+        let beneficiary_account = BeneficiaryAccount {
+            id: BeneficiaryAccountId::new(),
+            holder_name,
+            bank_name,
+            details,
+        };
+        let id = beneficiary_account.id;
+        self.events.push(CustomerEvent::BeneficiaryAccountAdded {
+            beneficiary_account: beneficiary_account.clone(),
+            audit_info,
+        });
+        self.beneficiary_accounts.push(beneficiary_account);
+        Idempotent::Executed(id)
+    }
+
+    pub fn remove_beneficiary_account(
+        &mut self,
+        beneficiary_account_id: BeneficiaryAccountId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.beneficiary_account(beneficiary_account_id).is_none() {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(CustomerEvent::BeneficiaryAccountRemoved {
+            beneficiary_account_id,
+            audit_info,
+        });
+        self.beneficiary_accounts
+            .retain(|account| account.id != beneficiary_account_id);
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<CustomerEvent> for Customer {
     fn try_from_events(events: EntityEvents<CustomerEvent>) -> Result<Self, EsEntityError> {
         let mut builder = CustomerBuilder::default();
+        let mut beneficiary_accounts = Vec::new();
 
         for event in events.iter_all() {
             match event {
@@ -238,10 +328,28 @@ impl TryFromEvents<CustomerEvent> for Customer {
                 CustomerEvent::EmailUpdated { email, .. } => {
                     builder = builder.email(email.clone());
                 }
+                CustomerEvent::BtcWithdrawalAddressUpdated { address, .. } => {
+                    builder = builder.btc_withdrawal_address(address.clone());
+                }
+                CustomerEvent::BeneficiaryAccountAdded {
+                    beneficiary_account,
+                    ..
+                } => {
+                    beneficiary_accounts.push(beneficiary_account.clone());
+                }
+                CustomerEvent::BeneficiaryAccountRemoved {
+                    beneficiary_account_id,
+                    ..
+                } => {
+                    beneficiary_accounts.retain(|account| account.id != *beneficiary_account_id);
+                }
             }
         }
 
-        builder.events(events).build()
+        builder
+            .beneficiary_accounts(beneficiary_accounts)
+            .events(events)
+            .build()
     }
 }
 
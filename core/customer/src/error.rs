@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::primitives::BeneficiaryAccountId;
+
 #[derive(Error, Debug)]
 pub enum CustomerError {
     #[error("CustomerError - Sqlx: {0}")]
@@ -16,6 +18,10 @@ pub enum CustomerError {
     AuditError(#[from] audit::error::AuditError),
     #[error("CustomerError - SubjectIsNotCustomer")]
     SubjectIsNotCustomer,
+    #[error("CustomerError - InvalidBankAccountDetails")]
+    InvalidBankAccountDetails,
+    #[error("CustomerError - BeneficiaryAccountNotFound: {0}")]
+    BeneficiaryAccountNotFound(BeneficiaryAccountId),
     #[error("CustomerError - DocumentStorageError: {0}")]
     DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
 }
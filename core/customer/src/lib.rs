@@ -396,6 +396,96 @@ where
         Ok(customer)
     }
 
+    #[instrument(name = "customer.update_btc_withdrawal_address", skip(self), err)]
+    pub async fn update_btc_withdrawal_address(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+        address: String,
+    ) -> Result<Customer, CustomerError> {
+        let customer_id = customer_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::customer(customer_id),
+                CoreCustomerAction::CUSTOMER_UPDATE,
+            )
+            .await?;
+
+        let mut customer = self.repo.find_by_id(customer_id).await?;
+        if customer
+            .update_btc_withdrawal_address(address, audit_info)
+            .did_execute()
+        {
+            self.repo.update(&mut customer).await?;
+        }
+
+        Ok(customer)
+    }
+
+    #[instrument(name = "customer.add_beneficiary_account", skip(self), err)]
+    pub async fn add_beneficiary_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+        holder_name: String,
+        bank_name: String,
+        details: BankAccountDetails,
+    ) -> Result<Customer, CustomerError> {
+        let customer_id = customer_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::customer(customer_id),
+                CoreCustomerAction::CUSTOMER_UPDATE,
+            )
+            .await?;
+
+        if !details.is_valid() {
+            return Err(CustomerError::InvalidBankAccountDetails);
+        }
+
+        let mut customer = self.repo.find_by_id(customer_id).await?;
+        if customer
+            .add_beneficiary_account(holder_name, bank_name, details, audit_info)
+            .did_execute()
+        {
+            self.repo.update(&mut customer).await?;
+        }
+
+        Ok(customer)
+    }
+
+    #[instrument(name = "customer.remove_beneficiary_account", skip(self), err)]
+    pub async fn remove_beneficiary_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug,
+        beneficiary_account_id: BeneficiaryAccountId,
+    ) -> Result<Customer, CustomerError> {
+        let customer_id = customer_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CustomerObject::customer(customer_id),
+                CoreCustomerAction::CUSTOMER_UPDATE,
+            )
+            .await?;
+
+        let mut customer = self.repo.find_by_id(customer_id).await?;
+        if customer
+            .remove_beneficiary_account(beneficiary_account_id, audit_info)
+            .did_execute()
+        {
+            self.repo.update(&mut customer).await?;
+        }
+
+        Ok(customer)
+    }
+
     // Document management methods
     #[instrument(name = "customer.create_document", skip(self, content), err)]
     pub async fn create_document(
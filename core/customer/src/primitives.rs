@@ -14,6 +14,8 @@ es_entity::entity_id! {
 
 es_entity::entity_id! { AuthenticationId }
 
+es_entity::entity_id! { BeneficiaryAccountId }
+
 #[derive(Debug, Deserialize, Clone, Copy, Serialize, Eq, PartialEq)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
@@ -91,6 +93,73 @@ impl From<CustomerType> for String {
     }
 }
 
+/// External bank account a customer can have disbursals paid out to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BeneficiaryAccount {
+    pub id: BeneficiaryAccountId,
+    pub holder_name: String,
+    pub bank_name: String,
+    pub details: BankAccountDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BankAccountDetails {
+    /// US domestic account reachable over ACH / Fedwire.
+    Aba {
+        routing_number: String,
+        account_number: String,
+    },
+    /// SEPA / SWIFT account.
+    Iban { iban: String, bic: String },
+}
+
+impl BankAccountDetails {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            BankAccountDetails::Aba {
+                routing_number,
+                account_number,
+            } => {
+                let digits: Vec<u32> = routing_number
+                    .chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect();
+                digits.len() == 9
+                    && routing_number.len() == 9
+                    && digits
+                        .iter()
+                        .zip([3, 7, 1, 3, 7, 1, 3, 7, 1])
+                        .map(|(d, w)| d * w)
+                        .sum::<u32>()
+                        % 10
+                        == 0
+                    && !account_number.is_empty()
+                    && account_number.len() <= 17
+                    && account_number.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            BankAccountDetails::Iban { iban, bic } => {
+                (15..=34).contains(&iban.len())
+                    && iban.chars().all(|c| c.is_ascii_alphanumeric())
+                    && (bic.len() == 8 || bic.len() == 11)
+                    && bic.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        }
+    }
+
+    /// Account identifier with everything but the last four characters masked.
+    pub fn masked_account_number(&self) -> String {
+        let number = match self {
+            BankAccountDetails::Aba { account_number, .. } => account_number,
+            BankAccountDetails::Iban { iban, .. } => iban,
+        };
+        let visible = number.len().saturating_sub(4);
+        format!("{}{}", "*".repeat(visible), &number[visible..])
+    }
+}
+
 pub type CustomerAllOrOne = AllOrOne<CustomerId>;
 pub type CustomerDocumentAllOrOne = AllOrOne<CustomerDocumentId>;
 
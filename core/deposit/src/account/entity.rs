@@ -1,4 +1,6 @@
+use chrono::NaiveDate;
 use derive_builder::Builder;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -7,7 +9,7 @@ use es_entity::*;
 
 use audit::AuditInfo;
 
-use crate::primitives::*;
+use crate::{interest::*, primitives::*};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -17,6 +19,7 @@ pub enum DepositAccountEvent {
     Initialized {
         id: DepositAccountId,
         account_holder_id: DepositAccountHolderId,
+        product_id: DepositProductId,
         ledger_account_id: CalaAccountId,
         reference: String,
         name: String,
@@ -28,6 +31,25 @@ pub enum DepositAccountEvent {
         status: AccountStatus,
         audit_info: AuditInfo,
     },
+    InterestTermsUpdated {
+        interest_terms: Option<DepositInterestTerms>,
+        effective: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    InterestAccrued {
+        ledger_tx_id: CalaTransactionId,
+        accrued_on: NaiveDate,
+        balance: UsdCents,
+        interest: Decimal,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    },
+    InterestCapitalized {
+        ledger_tx_id: CalaTransactionId,
+        capitalized_on: NaiveDate,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -35,10 +57,13 @@ pub enum DepositAccountEvent {
 pub struct DepositAccount {
     pub id: DepositAccountId,
     pub account_holder_id: DepositAccountHolderId,
+    pub product_id: DepositProductId,
     pub reference: String,
     pub name: String,
     pub description: String,
     pub status: AccountStatus,
+    #[builder(default)]
+    pub interest_terms: Option<DepositInterestTerms>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -64,6 +89,141 @@ impl DepositAccount {
         self.status = status;
         Idempotent::Executed(())
     }
+
+    pub fn update_interest_terms(
+        &mut self,
+        interest_terms: Option<DepositInterestTerms>,
+        effective: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.interest_terms == interest_terms {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositAccountEvent::InterestTermsUpdated {
+            interest_terms,
+            effective,
+            audit_info,
+        });
+        self.interest_terms = interest_terms;
+        Idempotent::Executed(())
+    }
+
+    /// Interest keeps accruing (at zero) after the terms are removed until the
+    /// interest already accrued in the current month has been capitalized.
+    pub fn next_interest_accrual_date(&self) -> Option<NaiveDate> {
+        let mut accruing_since = None;
+        let mut last_accrued_on = None;
+        let mut has_uncapitalized_interest = false;
+        for event in self.events.iter_all() {
+            match event {
+                DepositAccountEvent::InterestTermsUpdated {
+                    interest_terms,
+                    effective,
+                    ..
+                } => match (interest_terms, accruing_since) {
+                    (Some(_), None) => accruing_since = Some(*effective),
+                    (None, _) => accruing_since = None,
+                    _ => (),
+                },
+                DepositAccountEvent::InterestAccrued { accrued_on, .. } => {
+                    last_accrued_on = Some(*accrued_on);
+                    has_uncapitalized_interest = true;
+                }
+                DepositAccountEvent::InterestCapitalized { .. } => {
+                    has_uncapitalized_interest = false;
+                }
+                _ => (),
+            }
+        }
+
+        let next_day =
+            last_accrued_on.map(|date| date.succ_opt().expect("should return a valid date"));
+        match (accruing_since, next_day) {
+            (Some(since), Some(next_day)) => Some(since.max(next_day)),
+            (Some(since), None) => Some(since),
+            (None, Some(next_day)) if has_uncapitalized_interest => Some(next_day),
+            _ => None,
+        }
+    }
+
+    /// Accrues a day of interest on the settled `balance`. Whole cents are
+    /// posted as they build up over the month so that rounding never loses
+    /// more than a fraction of a cent per capitalization period.
+    pub fn accrue_interest(
+        &mut self,
+        date: NaiveDate,
+        balance: UsdCents,
+        audit_info: AuditInfo,
+    ) -> Idempotent<DepositInterestPosting> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            DepositAccountEvent::InterestAccrued { accrued_on, .. } if accrued_on >= &date
+        );
+
+        let mut period_interest = Decimal::ZERO;
+        let mut period_amount = UsdCents::ZERO;
+        for event in self.events.iter_all().rev() {
+            match event {
+                DepositAccountEvent::InterestCapitalized { .. } => break,
+                DepositAccountEvent::InterestAccrued {
+                    interest, amount, ..
+                } => {
+                    period_interest += interest;
+                    period_amount += *amount;
+                }
+                _ => (),
+            }
+        }
+
+        let interest = self
+            .interest_terms
+            .map(|terms| terms.daily_interest(balance, date))
+            .unwrap_or(Decimal::ZERO);
+        let amount = UsdCents::from(
+            (period_interest + interest)
+                .floor()
+                .to_u64()
+                .expect("should return a valid integer"),
+        ) - period_amount;
+
+        let accrual = InterestAccrualData {
+            tx_id: CalaTransactionId::new(),
+            amount,
+            accrued_on: date,
+        };
+        self.events.push(DepositAccountEvent::InterestAccrued {
+            ledger_tx_id: accrual.tx_id,
+            accrued_on: date,
+            balance,
+            interest,
+            amount,
+            audit_info: audit_info.clone(),
+        });
+
+        let capitalization = if is_capitalization_date(date) {
+            let capitalization = InterestCapitalizationData {
+                tx_id: CalaTransactionId::new(),
+                amount: period_amount + amount,
+                capitalized_on: date,
+            };
+            self.events.push(DepositAccountEvent::InterestCapitalized {
+                ledger_tx_id: capitalization.tx_id,
+                capitalized_on: date,
+                amount: capitalization.amount,
+                audit_info,
+            });
+            Some(capitalization)
+        } else {
+            None
+        };
+
+        Idempotent::Executed(DepositInterestPosting {
+            deposit_account_id: self.id,
+            accrual: Some(accrual).filter(|accrual| !accrual.amount.is_zero()),
+            capitalization: capitalization
+                .filter(|capitalization| !capitalization.amount.is_zero()),
+        })
+    }
 }
 
 impl TryFromEvents<DepositAccountEvent> for DepositAccount {
@@ -74,6 +234,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                 DepositAccountEvent::Initialized {
                     id,
                     account_holder_id,
+                    product_id,
                     reference,
                     name,
                     description,
@@ -83,6 +244,7 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                     builder = builder
                         .id(*id)
                         .account_holder_id(*account_holder_id)
+                        .product_id(*product_id)
                         .reference(reference.to_string())
                         .name(name.to_string())
                         .description(description.to_string())
@@ -91,6 +253,11 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                 DepositAccountEvent::AccountStatusUpdated { status, .. } => {
                     builder = builder.status(*status);
                 }
+                DepositAccountEvent::InterestTermsUpdated { interest_terms, .. } => {
+                    builder = builder.interest_terms(*interest_terms);
+                }
+                DepositAccountEvent::InterestAccrued { .. } => (),
+                DepositAccountEvent::InterestCapitalized { .. } => (),
             }
         }
         builder.events(events).build()
@@ -103,6 +270,8 @@ pub struct NewDepositAccount {
     pub(super) id: DepositAccountId,
     #[builder(setter(into))]
     pub(super) account_holder_id: DepositAccountHolderId,
+    #[builder(setter(into))]
+    pub(super) product_id: DepositProductId,
     pub(super) reference: String,
     pub(super) name: String,
     pub(super) description: String,
@@ -124,6 +293,7 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
             [DepositAccountEvent::Initialized {
                 id: self.id,
                 account_holder_id: self.account_holder_id,
+                product_id: self.product_id,
                 ledger_account_id: self.id.into(),
                 reference: self.reference,
                 name: self.name,
@@ -138,3 +308,139 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn interest_terms() -> DepositInterestTerms {
+        DepositInterestTerms {
+            annual_rate: DepositRatePct::from(dec!(3.65)),
+            day_count_convention: DepositDayCountConvention::Actual365,
+        }
+    }
+
+    fn account_with_interest_terms() -> DepositAccount {
+        let id = DepositAccountId::new();
+        let events = vec![
+            DepositAccountEvent::Initialized {
+                id,
+                account_holder_id: DepositAccountHolderId::new(),
+                product_id: DepositProductId::new(),
+                ledger_account_id: id.into(),
+                reference: "ref".to_string(),
+                name: "name".to_string(),
+                description: "description".to_string(),
+                status: AccountStatus::Active,
+                audit_info: dummy_audit_info(),
+            },
+            DepositAccountEvent::InterestTermsUpdated {
+                interest_terms: Some(interest_terms()),
+                effective: date(1, 30),
+                audit_info: dummy_audit_info(),
+            },
+        ];
+        DepositAccount::try_from_events(EntityEvents::init(id, events)).unwrap()
+    }
+
+    mod interest {
+        use super::*;
+
+        #[test]
+        fn accrual_starts_when_terms_become_effective() {
+            let account = account_with_interest_terms();
+            assert_eq!(account.next_interest_accrual_date(), Some(date(1, 30)));
+        }
+
+        #[test]
+        fn update_interest_terms_is_idempotent() {
+            let mut account = account_with_interest_terms();
+            assert!(
+                account
+                    .update_interest_terms(Some(interest_terms()), date(2, 1), dummy_audit_info())
+                    .was_ignored()
+            );
+        }
+
+        #[test]
+        fn accrue_interest_is_idempotent_per_day() {
+            let mut account = account_with_interest_terms();
+            let balance = UsdCents::from(1_000_000);
+            assert!(
+                account
+                    .accrue_interest(date(1, 30), balance, dummy_audit_info())
+                    .did_execute()
+            );
+            assert!(
+                account
+                    .accrue_interest(date(1, 30), balance, dummy_audit_info())
+                    .was_ignored()
+            );
+            assert_eq!(account.next_interest_accrual_date(), Some(date(1, 31)));
+        }
+
+        #[test]
+        fn fractional_cents_carry_over_to_next_day() {
+            let mut account = account_with_interest_terms();
+            // 50 cents per day earns half a cent
+            let balance = UsdCents::from(5_000);
+
+            let posting = account
+                .accrue_interest(date(1, 30), balance, dummy_audit_info())
+                .unwrap();
+            assert!(posting.accrual.is_none());
+
+            let posting = account
+                .accrue_interest(date(1, 31), balance, dummy_audit_info())
+                .unwrap();
+            assert_eq!(posting.accrual.unwrap().amount, UsdCents::ONE);
+        }
+
+        #[test]
+        fn capitalizes_accrued_interest_at_month_end() {
+            let mut account = account_with_interest_terms();
+            let balance = UsdCents::from(1_000_000);
+
+            let posting = account
+                .accrue_interest(date(1, 30), balance, dummy_audit_info())
+                .unwrap();
+            assert!(posting.capitalization.is_none());
+
+            let posting = account
+                .accrue_interest(date(1, 31), balance, dummy_audit_info())
+                .unwrap();
+            assert_eq!(posting.accrual.unwrap().amount, UsdCents::from(100));
+            assert_eq!(posting.capitalization.unwrap().amount, UsdCents::from(200));
+        }
+
+        #[test]
+        fn accrual_stops_after_capitalization_once_terms_removed() {
+            let mut account = account_with_interest_terms();
+            let balance = UsdCents::from(1_000_000);
+            let _ = account.accrue_interest(date(1, 30), balance, dummy_audit_info());
+            let _ = account.update_interest_terms(None, date(1, 31), dummy_audit_info());
+            assert_eq!(account.next_interest_accrual_date(), Some(date(1, 31)));
+
+            let posting = account
+                .accrue_interest(date(1, 31), balance, dummy_audit_info())
+                .unwrap();
+            assert!(posting.accrual.is_none());
+            assert_eq!(posting.capitalization.unwrap().amount, UsdCents::from(100));
+            assert_eq!(account.next_interest_accrual_date(), None);
+        }
+    }
+}
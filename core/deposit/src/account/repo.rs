@@ -5,7 +5,7 @@ use outbox::OutboxEventMarker;
 
 use crate::{
     event::CoreDepositEvent,
-    primitives::{DepositAccountHolderId, DepositAccountId},
+    primitives::{DepositAccountHolderId, DepositAccountId, DepositProductId},
     publisher::DepositPublisher,
 };
 
@@ -15,7 +15,10 @@ use super::{entity::*, error::*};
 #[es_repo(
     entity = "DepositAccount",
     err = "DepositAccountError",
-    columns(account_holder_id(ty = "DepositAccountHolderId", list_for, update(persist = false))),
+    columns(
+        account_holder_id(ty = "DepositAccountHolderId", list_for, update(persist = false)),
+        product_id(ty = "DepositProductId", list_for, update(persist = false))
+    ),
     tbl_prefix = "core",
     post_persist_hook = "publish"
 )]
@@ -51,6 +54,26 @@ where
         }
     }
 
+    /// Holds a row lock on the account until `op` ends, serializing debits
+    /// that are checked against the product's withdrawal frequency limit.
+    pub async fn lock_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: DepositAccountId,
+    ) -> Result<(), DepositAccountError> {
+        sqlx::query!(
+            r#"
+            SELECT id FROM core_deposit_accounts
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            id as DepositAccountId,
+        )
+        .fetch_one(&mut **op.tx())
+        .await?;
+        Ok(())
+    }
+
     async fn publish(
         &self,
         db: &mut es_entity::DbOp<'_>,
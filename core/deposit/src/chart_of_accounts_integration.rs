@@ -8,6 +8,7 @@ pub struct ChartOfAccountsIntegrationConfig {
     #[builder(setter(into))]
     pub chart_of_accounts_id: ChartId,
     pub chart_of_accounts_omnibus_parent_code: AccountCode,
+    pub chart_of_accounts_interest_expense_parent_code: AccountCode,
     pub chart_of_accounts_individual_deposit_accounts_parent_code: AccountCode,
     pub chart_of_accounts_government_entity_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_private_company_deposit_accounts_parent_code: AccountCode,
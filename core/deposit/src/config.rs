@@ -0,0 +1,35 @@
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::UsdCents;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositConfig {
+    /// Deposit reversals above this amount need governance approval before
+    /// the funds are taken back out of the account.
+    #[serde(default = "default_reversal_approval_threshold")]
+    pub reversal_approval_threshold: UsdCents,
+    /// Transfers between deposit accounts above this amount need governance
+    /// approval before they are booked.
+    #[serde(default = "default_transfer_approval_threshold")]
+    pub transfer_approval_threshold: UsdCents,
+}
+
+impl Default for DepositConfig {
+    fn default() -> Self {
+        DepositConfig {
+            reversal_approval_threshold: default_reversal_approval_threshold(),
+            transfer_approval_threshold: default_transfer_approval_threshold(),
+        }
+    }
+}
+
+fn default_reversal_approval_threshold() -> UsdCents {
+    UsdCents::from(1_000_000)
+}
+
+fn default_transfer_approval_threshold() -> UsdCents {
+    UsdCents::from(1_000_000)
+}
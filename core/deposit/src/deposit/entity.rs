@@ -7,7 +7,30 @@ use audit::AuditInfo;
 use core_money::UsdCents;
 use es_entity::*;
 
-use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId};
+use crate::primitives::{ApprovalProcessId, CalaTransactionId, DepositAccountId, DepositId};
+
+use super::error::DepositError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum DepositReversalReason {
+    Fraud,
+    Mistaken,
+    ReturnedByBank,
+    Other,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum DepositStatus {
+    Confirmed,
+    PendingReversalApproval,
+    ReversalDenied,
+    ReversalFailed,
+    Reverted,
+}
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -22,6 +45,25 @@ pub enum DepositEvent {
         reference: String,
         audit_info: AuditInfo,
     },
+    ReversalRequested {
+        reason: DepositReversalReason,
+        allow_overdraft: bool,
+        approval_process_id: Option<ApprovalProcessId>,
+        audit_info: AuditInfo,
+    },
+    ReversalApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Reverted {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+    ReversalFailed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -31,6 +73,10 @@ pub struct Deposit {
     pub deposit_account_id: DepositAccountId,
     pub amount: UsdCents,
     pub reference: String,
+    #[builder(setter(strip_option), default)]
+    pub reversal_reason: Option<DepositReversalReason>,
+    #[builder(setter(strip_option), default)]
+    pub reverted_tx_id: Option<CalaTransactionId>,
     events: EntityEvents<DepositEvent>,
 }
 
@@ -40,6 +86,156 @@ impl Deposit {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    /// Records the intent to reverse this deposit. Reversals that need
+    /// governance approval carry the id of the approval process that has
+    /// to conclude before the deposit can be reverted.
+    pub fn request_reversal(
+        &mut self,
+        reason: DepositReversalReason,
+        allow_overdraft: bool,
+        approval_process_id: Option<ApprovalProcessId>,
+        audit_info: AuditInfo,
+    ) -> Result<(), DepositError> {
+        if self.is_reverted() {
+            return Err(DepositError::AlreadyReverted(self.id));
+        }
+        if self.reversal_reason.is_some() {
+            return Err(DepositError::ReversalAlreadyRequested(self.id));
+        }
+
+        self.events.push(DepositEvent::ReversalRequested {
+            reason,
+            allow_overdraft,
+            approval_process_id,
+            audit_info,
+        });
+        self.reversal_reason = Some(reason);
+
+        Ok(())
+    }
+
+    pub fn reversal_approval_process_id(&self) -> Option<ApprovalProcessId> {
+        self.events.iter_all().find_map(|e| match e {
+            DepositEvent::ReversalRequested {
+                approval_process_id,
+                ..
+            } => *approval_process_id,
+            _ => None,
+        })
+    }
+
+    pub fn reversal_allows_overdraft(&self) -> bool {
+        self.events.iter_all().any(|e| {
+            matches!(
+                e,
+                DepositEvent::ReversalRequested {
+                    allow_overdraft: true,
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn is_reversal_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let DepositEvent::ReversalApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn reversal_approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DepositEvent::ReversalApprovalProcessConcluded { .. }
+        );
+        let Some(approval_process_id) = self.reversal_approval_process_id() else {
+            return Idempotent::Ignored;
+        };
+        self.events
+            .push(DepositEvent::ReversalApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    /// Records that an approved reversal could not be carried out, e.g.
+    /// because the funds have left the account in the meantime.
+    pub fn reversal_failed(&mut self, reason: String, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DepositEvent::ReversalFailed { .. } | DepositEvent::Reverted { .. }
+        );
+        self.events
+            .push(DepositEvent::ReversalFailed { reason, audit_info });
+        Idempotent::Executed(())
+    }
+
+    fn has_reversal_failed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, DepositEvent::ReversalFailed { .. }))
+    }
+
+    /// Returns the id of the ledger transaction that takes the funds back
+    /// out of the deposit account.
+    pub fn revert(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, DepositError> {
+        if self.is_reverted() {
+            return Err(DepositError::AlreadyReverted(self.id));
+        }
+        if self.reversal_reason.is_none() {
+            return Err(DepositError::ReversalNotRequested(self.id));
+        }
+        if self.has_reversal_failed() {
+            return Err(DepositError::ReversalFailed(self.id));
+        }
+        if self.reversal_approval_process_id().is_some()
+            && self.is_reversal_approved_or_denied() != Some(true)
+        {
+            return Err(DepositError::ReversalNotApproved(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(DepositEvent::Reverted {
+            ledger_tx_id,
+            audit_info,
+        });
+        self.reverted_tx_id = Some(ledger_tx_id);
+
+        Ok(ledger_tx_id)
+    }
+
+    pub fn is_reverted(&self) -> bool {
+        self.reverted_tx_id.is_some()
+    }
+
+    pub fn status(&self) -> DepositStatus {
+        if self.is_reverted() {
+            DepositStatus::Reverted
+        } else if self.has_reversal_failed() {
+            DepositStatus::ReversalFailed
+        } else if self.reversal_reason.is_none() {
+            DepositStatus::Confirmed
+        } else {
+            match (
+                self.reversal_approval_process_id(),
+                self.is_reversal_approved_or_denied(),
+            ) {
+                (Some(_), None) => DepositStatus::PendingReversalApproval,
+                (Some(_), Some(false)) => DepositStatus::ReversalDenied,
+                _ => DepositStatus::Confirmed,
+            }
+        }
+    }
 }
 
 impl TryFromEvents<DepositEvent> for Deposit {
@@ -60,6 +256,14 @@ impl TryFromEvents<DepositEvent> for Deposit {
                         .amount(*amount)
                         .reference(reference.clone());
                 }
+                DepositEvent::ReversalRequested { reason, .. } => {
+                    builder = builder.reversal_reason(*reason);
+                }
+                DepositEvent::Reverted { ledger_tx_id, .. } => {
+                    builder = builder.reverted_tx_id(*ledger_tx_id);
+                }
+                DepositEvent::ReversalApprovalProcessConcluded { .. } => (),
+                DepositEvent::ReversalFailed { .. } => (),
             }
         }
         builder.events(events).build()
@@ -180,4 +384,110 @@ mod test {
 
         assert!(deposit.is_ok());
     }
+
+    fn deposit() -> Deposit {
+        let id = DepositId::new();
+        let events = EntityEvents::init(
+            id,
+            [DepositEvent::Initialized {
+                id,
+                ledger_tx_id: CalaTransactionId::new(),
+                deposit_account_id: DepositAccountId::new(),
+                amount: UsdCents::ONE,
+                reference: id.to_string(),
+                audit_info: dummy_audit_info(),
+            }],
+        );
+        Deposit::try_from_events(events).unwrap()
+    }
+
+    #[test]
+    fn can_revert_without_approval_process() {
+        let mut deposit = deposit();
+        deposit
+            .request_reversal(
+                DepositReversalReason::Mistaken,
+                false,
+                None,
+                dummy_audit_info(),
+            )
+            .unwrap();
+
+        assert!(deposit.revert(dummy_audit_info()).is_ok());
+        assert_eq!(deposit.status(), DepositStatus::Reverted);
+        assert!(matches!(
+            deposit.revert(dummy_audit_info()),
+            Err(DepositError::AlreadyReverted(_))
+        ));
+    }
+
+    #[test]
+    fn cannot_revert_before_reversal_is_approved() {
+        let mut deposit = deposit();
+        deposit
+            .request_reversal(
+                DepositReversalReason::Fraud,
+                false,
+                Some(deposit.id.into()),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(deposit.status(), DepositStatus::PendingReversalApproval);
+        assert!(matches!(
+            deposit.revert(dummy_audit_info()),
+            Err(DepositError::ReversalNotApproved(_))
+        ));
+
+        assert!(
+            deposit
+                .reversal_approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(deposit.revert(dummy_audit_info()).is_ok());
+    }
+
+    #[test]
+    fn denied_reversal_cannot_be_reverted() {
+        let mut deposit = deposit();
+        deposit
+            .request_reversal(
+                DepositReversalReason::ReturnedByBank,
+                false,
+                Some(deposit.id.into()),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        let _ = deposit.reversal_approval_process_concluded(false, dummy_audit_info());
+
+        assert_eq!(deposit.status(), DepositStatus::ReversalDenied);
+        assert!(matches!(
+            deposit.revert(dummy_audit_info()),
+            Err(DepositError::ReversalNotApproved(_))
+        ));
+    }
+
+    #[test]
+    fn failed_reversal_cannot_be_reverted() {
+        let mut deposit = deposit();
+        deposit
+            .request_reversal(
+                DepositReversalReason::Fraud,
+                false,
+                Some(deposit.id.into()),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        let _ = deposit.reversal_approval_process_concluded(true, dummy_audit_info());
+
+        assert!(
+            deposit
+                .reversal_failed("insufficient funds".to_string(), dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(deposit.status(), DepositStatus::ReversalFailed);
+        assert!(matches!(
+            deposit.revert(dummy_audit_info()),
+            Err(DepositError::ReversalFailed(_))
+        ));
+    }
 }
@@ -2,9 +2,9 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::Deposit;
 #[cfg(feature = "json-schema")]
 pub use entity::DepositEvent;
 pub(crate) use entity::*;
+pub use entity::{Deposit, DepositReversalReason, DepositStatus};
 pub use repo::deposit_cursor::DepositsByCreatedAtCursor;
 pub(crate) use repo::*;
@@ -12,6 +12,12 @@ pub enum CoreDepositError {
     DepositAccountError(#[from] crate::account::error::DepositAccountError),
     #[error("CoreDepositError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::DepositError),
+    #[error("CoreDepositError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
+    #[error("CoreDepositError - TermDepositError: {0}")]
+    TermDepositError(#[from] crate::term_deposit::error::TermDepositError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
@@ -36,10 +42,14 @@ pub enum CoreDepositError {
     DepositConfigAlreadyExists,
     #[error("CoreDepositError - DepositAccountNotActive")]
     DepositAccountNotActive,
+    #[error("CoreDepositError - DepositAccountTypeNotFound: {0}")]
+    DepositAccountTypeNotFound(crate::primitives::DepositAccountId),
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
+    #[error("CoreDepositError - TransferBuilderError: {0}")]
+    TransferBuilderError(#[from] super::NewTransferBuilderError),
 }
 
 impl CoreDepositError {
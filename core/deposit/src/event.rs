@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use super::primitives::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+use super::primitives::{
+    DepositAccountHolderId, DepositAccountId, DepositId, TransferId, WithdrawalId,
+};
 use core_money::UsdCents;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -15,6 +17,23 @@ pub enum CoreDepositEvent {
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
     },
+    DepositReverted {
+        id: DepositId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
+    TransferSent {
+        id: TransferId,
+        deposit_account_id: DepositAccountId,
+        counterparty_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
+    TransferReceived {
+        id: TransferId,
+        deposit_account_id: DepositAccountId,
+        counterparty_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
     WithdrawalConfirmed {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
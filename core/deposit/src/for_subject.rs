@@ -173,6 +173,26 @@ where
         Ok(deposit)
     }
 
+    pub async fn find_deposit_by_reverted_tx_id(
+        &self,
+        reverted_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Deposit, CoreDepositError> {
+        let reverted_tx_id = reverted_tx_id.into();
+        let deposit = self
+            .deposits
+            .find_by_reverted_tx_id(Some(reverted_tx_id))
+            .await?;
+
+        self.ensure_account_access(
+            deposit.deposit_account_id,
+            CoreDepositObject::deposit(deposit.id),
+            CoreDepositAction::DEPOSIT_READ,
+        )
+        .await?;
+
+        Ok(deposit)
+    }
+
     pub async fn list_withdrawals_for_account(
         &self,
         account_id: impl Into<DepositAccountId> + std::fmt::Debug,
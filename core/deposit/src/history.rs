@@ -1,14 +1,19 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId};
+use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId, UsdCents};
 
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
+    RevertedDeposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(WithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    InterestAccrual(InterestEntry),
+    InterestCapitalization(InterestEntry),
+    TransferIn(TransferEntry),
+    TransferOut(TransferEntry),
     Unknown(UnknownEntry),
     Ignored,
 }
@@ -36,6 +41,20 @@ pub struct PaymentEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct InterestEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct UnknownEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -43,14 +62,20 @@ pub struct UnknownEntry {
 }
 
 const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
+const REVERT_DEPOSIT: &str = "REVERT_DEPOSIT_DR";
 const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_SETTLED_CR";
 const RECORD_PAYMENT_ALLOCATION: &str = "RECORD_PAYMENT_ALLOCATION_DR";
+const ACCRUE_DEPOSIT_INTEREST: &str = "ACCRUE_DEPOSIT_INTEREST_PENDING_CR";
+const CAPITALIZE_DEPOSIT_INTEREST: &str = "CAPITALIZE_DEPOSIT_INTEREST_SETTLED_CR";
+const INTERNAL_TRANSFER_IN: &str = "INTERNAL_TRANSFER_CR";
+const INTERNAL_TRANSFER_OUT: &str = "INTERNAL_TRANSFER_DR";
 
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_CAPITALIZE_DEPOSIT_INTEREST_PENDING: &str = "CAPITALIZE_DEPOSIT_INTEREST_PENDING_DR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -60,6 +85,11 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            REVERT_DEPOSIT => DepositAccountHistoryEntry::RevertedDeposit(DepositEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
             INITIATE_WITHDRAW => DepositAccountHistoryEntry::Withdrawal(WithdrawalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
@@ -80,10 +110,41 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            ACCRUE_DEPOSIT_INTEREST => DepositAccountHistoryEntry::InterestAccrual(InterestEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("interest amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            CAPITALIZE_DEPOSIT_INTEREST => {
+                DepositAccountHistoryEntry::InterestCapitalization(InterestEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("interest amount should be positive"),
+                    recorded_at: entry.created_at(),
+                })
+            }
+            INTERNAL_TRANSFER_IN => DepositAccountHistoryEntry::TransferIn(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("transfer amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            INTERNAL_TRANSFER_OUT => DepositAccountHistoryEntry::TransferOut(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("transfer amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
 
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CAPITALIZE_DEPOSIT_INTEREST_PENDING => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -107,6 +168,10 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::RevertedDeposit(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Withdrawal(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -123,6 +188,22 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::InterestAccrual(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::InterestCapitalization(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferIn(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferOut(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Unknown(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
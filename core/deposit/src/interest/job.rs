@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::DepositAccountRepo, event::CoreDepositEvent, ledger::DepositLedger, primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DepositInterestAccrualJobConfig<Perms, E> {
+    pub deposit_account_id: DepositAccountId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for DepositInterestAccrualJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DepositInterestAccrualInit<Perms, E>;
+}
+
+pub struct DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_INTEREST_ACCRUAL_JOB: JobType = JobType::new("deposit-interest-accrual");
+impl<Perms, E> JobInitializer for DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_INTEREST_ACCRUAL_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositInterestAccrualJobRunner::<Perms, E> {
+            config: job.config()?,
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+/// A day's accrual runs once that day has ended, against its closing balance.
+pub(crate) fn accrual_runs_at(date: NaiveDate) -> DateTime<Utc> {
+    date.succ_opt()
+        .expect("should return a valid date")
+        .and_hms_opt(0, 0, 0)
+        .expect("should return a valid time")
+        .and_utc()
+}
+
+pub struct DepositInterestAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: DepositInterestAccrualJobConfig<Perms, E>,
+    accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for DepositInterestAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut db = self.accounts.begin_op().await?;
+        let mut account = self
+            .accounts
+            .find_by_id_in_tx(db.tx(), self.config.deposit_account_id)
+            .await?;
+        let Some(date) = account.next_interest_accrual_date() else {
+            return Ok(JobCompletion::Complete);
+        };
+        let runs_at = accrual_runs_at(date);
+        if runs_at > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(runs_at));
+        }
+
+        // Catching up on past days must not apply today's balance to them.
+        let balance = self.ledger.balance_as_of(account.id, date).await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+            )
+            .await?;
+        let es_entity::Idempotent::Executed(posting) =
+            account.accrue_interest(date, balance.settled, audit_info)
+        else {
+            return Ok(JobCompletion::RescheduleNow);
+        };
+        self.accounts.update_in_op(&mut db, &mut account).await?;
+        self.ledger.record_interest(db, posting).await?;
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
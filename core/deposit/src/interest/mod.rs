@@ -0,0 +1,164 @@
+mod job;
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, UsdCents};
+
+pub(crate) use job::*;
+
+const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+const NUMBER_OF_DAYS_IN_LEAP_YEAR: u64 = 366;
+const NUMBER_OF_DAYS_IN_BANKING_YEAR: u64 = 360;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct DepositRatePct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(DepositRatePct);
+
+impl DepositRatePct {
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    /// Unrounded share of `amount` in cents.
+    pub(crate) fn of(&self, amount: UsdCents) -> Decimal {
+        amount.to_usd() * self.0
+    }
+}
+
+impl From<Decimal> for DepositRatePct {
+    fn from(value: Decimal) -> Self {
+        DepositRatePct(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DepositDayCountConvention {
+    #[default]
+    Actual365,
+    Actual360,
+    ActualActual,
+}
+
+impl DepositDayCountConvention {
+    fn days_in_year(&self, date: NaiveDate) -> u64 {
+        match self {
+            Self::Actual365 => NUMBER_OF_DAYS_IN_YEAR,
+            Self::Actual360 => NUMBER_OF_DAYS_IN_BANKING_YEAR,
+            Self::ActualActual if date.leap_year() => NUMBER_OF_DAYS_IN_LEAP_YEAR,
+            Self::ActualActual => NUMBER_OF_DAYS_IN_YEAR,
+        }
+    }
+}
+
+/// Yield paid on the settled balance of a deposit account. Interest accrues
+/// daily and is capitalized into the balance on the last day of each month.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositInterestTerms {
+    pub annual_rate: DepositRatePct,
+    pub day_count_convention: DepositDayCountConvention,
+}
+
+impl DepositInterestTerms {
+    /// Unrounded interest in cents earned by `balance` over the single day `date`.
+    pub fn daily_interest(&self, balance: UsdCents, date: NaiveDate) -> Decimal {
+        balance.to_usd() * self.annual_rate.0
+            / Decimal::from(self.day_count_convention.days_in_year(date))
+    }
+}
+
+pub(crate) fn is_capitalization_date(date: NaiveDate) -> bool {
+    date.succ_opt().expect("should return a valid date").month() != date.month()
+}
+
+#[derive(Debug, Clone)]
+pub struct InterestAccrualData {
+    pub tx_id: CalaTransactionId,
+    pub amount: UsdCents,
+    pub accrued_on: NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct InterestCapitalizationData {
+    pub tx_id: CalaTransactionId,
+    pub amount: UsdCents,
+    pub capitalized_on: NaiveDate,
+}
+
+/// Ledger postings resulting from a single day of interest accrual. Days that
+/// don't cross a whole cent carry no accrual posting.
+#[derive(Debug, Clone)]
+pub struct DepositInterestPosting {
+    pub deposit_account_id: DepositAccountId,
+    pub accrual: Option<InterestAccrualData>,
+    pub capitalization: Option<InterestCapitalizationData>,
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn terms(
+        annual_rate: Decimal,
+        day_count_convention: DepositDayCountConvention,
+    ) -> DepositInterestTerms {
+        DepositInterestTerms {
+            annual_rate: DepositRatePct::from(annual_rate),
+            day_count_convention,
+        }
+    }
+
+    #[test]
+    fn daily_interest_in_cents() {
+        let terms = terms(dec!(3.65), DepositDayCountConvention::Actual365);
+        let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+
+        assert_eq!(
+            terms.daily_interest(UsdCents::from(1_000_000), date),
+            dec!(100)
+        );
+    }
+
+    #[test]
+    fn actual_actual_uses_leap_years() {
+        let terms = terms(dec!(3.66), DepositDayCountConvention::ActualActual);
+
+        assert_eq!(
+            terms.daily_interest(
+                UsdCents::from(1_000_000),
+                NaiveDate::from_ymd_opt(2024, 3, 14).unwrap()
+            ),
+            dec!(100)
+        );
+        assert!(
+            terms.daily_interest(
+                UsdCents::from(1_000_000),
+                NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+            ) > dec!(100)
+        );
+    }
+
+    #[test]
+    fn capitalizes_on_last_day_of_month() {
+        assert!(is_capitalization_date(
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        ));
+        assert!(is_capitalization_date(
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+        ));
+        assert!(!is_capitalization_date(
+            NaiveDate::from_ymd_opt(2025, 2, 27).unwrap()
+        ));
+    }
+}
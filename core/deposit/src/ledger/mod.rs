@@ -18,7 +18,13 @@ use cala_ledger::{
 use crate::{
     DepositAccountBalance, LedgerOmnibusAccountIds,
     chart_of_accounts_integration::ChartOfAccountsIntegrationConfig,
-    primitives::{CalaAccountId, CalaAccountSetId, DepositAccountType, UsdCents},
+    interest::DepositInterestPosting,
+    primitives::{
+        CalaAccountId, CalaAccountSetId, CalaTransactionId, DepositAccountId, DepositAccountType,
+        DepositId, DepositProductId, TermDepositId, TransferId, UsdCents,
+    },
+    product::DepositProductLedgerAccountIds,
+    term_deposit::TermDepositSettlement,
 };
 
 use error::*;
@@ -45,8 +51,17 @@ pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_NAME: &str = "Deposit Omnibus Account Set"
 pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_REF: &str = "deposit-omnibus-account-set";
 pub const DEPOSIT_OMNIBUS_ACCOUNT_REF: &str = "deposit-omnibus-account";
 
+pub const DEPOSIT_PRODUCT_OMNIBUS_ACCOUNT_SET_NAME: &str = "Deposit Product Omnibus Account Set";
+pub const DEPOSIT_PRODUCT_OMNIBUS_ACCOUNT_SET_REF: &str = "deposit-product-omnibus-account-set";
+
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+
 pub const DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000001");
+pub const TERM_DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000003");
 
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
@@ -87,8 +102,11 @@ pub struct DepositLedger {
     journal_id: JournalId,
     deposits_account_set: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    product_omnibus_account_set_id: CalaAccountSetId,
+    interest_expense_account_ids: LedgerOmnibusAccountIds,
     usd: Currency,
     deposit_control_id: VelocityControlId,
+    term_deposit_control_id: VelocityControlId,
 }
 
 impl DepositLedger {
@@ -97,9 +115,16 @@ impl DepositLedger {
         journal_id: JournalId,
     ) -> Result<Self, DepositLedgerError> {
         templates::RecordDeposit::init(cala).await?;
+        templates::RevertDeposit::init(cala).await?;
+        templates::InternalTransfer::init(cala).await?;
         templates::InitiateWithdraw::init(cala).await?;
         templates::CancelWithdraw::init(cala).await?;
         templates::ConfirmWithdraw::init(cala).await?;
+        templates::AccrueDepositInterest::init(cala).await?;
+        templates::CapitalizeDepositInterest::init(cala).await?;
+        templates::OpenTermDeposit::init(cala).await?;
+        templates::ReleaseTermDeposit::init(cala).await?;
+        templates::PayTermDepositInterest::init(cala).await?;
 
         let deposits_normal_balance_type = DebitOrCredit::Credit;
 
@@ -167,6 +192,25 @@ impl DepositLedger {
         )
         .await?;
 
+        let product_omnibus_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_PRODUCT_OMNIBUS_ACCOUNT_SET_REF}"),
+            DEPOSIT_PRODUCT_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Debit,
+        )
+        .await?;
+
+        let interest_expense_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF}"),
+            DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Debit,
+        )
+        .await?;
+
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
 
         let deposit_control_id = Self::create_deposit_control(cala).await?;
@@ -181,6 +225,22 @@ impl DepositLedger {
             Err(e) => return Err(e.into()),
         }
 
+        let term_deposit_lock_id = velocity::TermDepositLock::init(cala).await?;
+
+        let term_deposit_control_id = Self::create_term_deposit_control(cala).await?;
+
+        for limit_id in [overdraft_prevention_id, term_deposit_lock_id] {
+            match cala
+                .velocities()
+                .add_limit_to_control(term_deposit_control_id, limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
         Ok(Self {
             cala: cala.clone(),
             journal_id,
@@ -211,7 +271,10 @@ impl DepositLedger {
                 },
             },
             deposit_omnibus_account_ids,
+            product_omnibus_account_set_id,
+            interest_expense_account_ids,
             deposit_control_id,
+            term_deposit_control_id,
             usd: Currency::USD,
         })
     }
@@ -369,6 +432,7 @@ impl DepositLedger {
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
+        deposit_omnibus_account_id: CalaAccountId,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
@@ -378,7 +442,7 @@ impl DepositLedger {
             journal_id: self.journal_id,
             currency: self.usd,
             amount: amount.to_usd(),
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_omnibus_account_id,
             credit_account_id: credit_account_id.into(),
         };
         self.cala
@@ -389,11 +453,71 @@ impl DepositLedger {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn revert_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        deposit_id: DepositId,
+        amount: UsdCents,
+        deposit_omnibus_account_id: CalaAccountId,
+        debit_account_id: impl Into<AccountId>,
+        allow_overdraft: bool,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::RevertDepositParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id,
+            debit_account_id: debit_account_id.into(),
+            correlation_id: deposit_id.to_string(),
+            allow_overdraft,
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::REVERT_DEPOSIT_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn transfer(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        transfer_id: TransferId,
+        amount: UsdCents,
+        from_account_id: impl Into<AccountId>,
+        to_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::InternalTransferParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            debit_account_id: from_account_id.into(),
+            credit_account_id: to_account_id.into(),
+            correlation_id: transfer_id.to_string(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::INTERNAL_TRANSFER_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn initiate_withdrawal(
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
+        deposit_omnibus_account_id: CalaAccountId,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
@@ -401,7 +525,7 @@ impl DepositLedger {
 
         let params = templates::InitiateWithdrawParams {
             journal_id: self.journal_id,
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_omnibus_account_id,
             credit_account_id: credit_account_id.into(),
             amount: amount.to_usd(),
             currency: self.usd,
@@ -421,6 +545,7 @@ impl DepositLedger {
         tx_id: impl Into<TransactionId>,
         correlation_id: String,
         amount: UsdCents,
+        deposit_omnibus_account_id: CalaAccountId,
         credit_account_id: impl Into<AccountId>,
         external_id: String,
     ) -> Result<(), DepositLedgerError> {
@@ -431,7 +556,7 @@ impl DepositLedger {
             journal_id: self.journal_id,
             currency: self.usd,
             amount: amount.to_usd(),
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_omnibus_account_id,
             credit_account_id: credit_account_id.into(),
             correlation_id,
             external_id,
@@ -449,6 +574,7 @@ impl DepositLedger {
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
+        deposit_omnibus_account_id: CalaAccountId,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
@@ -459,7 +585,7 @@ impl DepositLedger {
             currency: self.usd,
             amount: amount.to_usd(),
             credit_account_id: credit_account_id.into(),
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_omnibus_account_id,
         };
 
         self.cala
@@ -469,6 +595,193 @@ impl DepositLedger {
         Ok(())
     }
 
+    pub async fn record_interest(
+        &self,
+        op: es_entity::DbOp<'_>,
+        DepositInterestPosting {
+            deposit_account_id,
+            accrual,
+            capitalization,
+        }: DepositInterestPosting,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if let Some(accrual) = accrual {
+            let params = templates::AccrueDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: accrual.amount.to_usd(),
+                deposit_account_id: deposit_account_id.into(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                effective: accrual.accrued_on,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    accrual.tx_id,
+                    templates::ACCRUE_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        if let Some(capitalization) = capitalization {
+            let params = templates::CapitalizeDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: capitalization.amount.to_usd(),
+                deposit_account_id: deposit_account_id.into(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                effective: capitalization.capitalized_on,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    capitalization.tx_id,
+                    templates::CAPITALIZE_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn open_term_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        term_deposit_id: TermDepositId,
+        tx_id: CalaTransactionId,
+        deposit_account_id: DepositAccountId,
+        product_account_set_id: CalaAccountSetId,
+        principal: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let name = format!("Term Deposit {term_deposit_id}");
+        self.create_account_in_op(
+            &mut op,
+            term_deposit_id,
+            InternalAccountSetDetails {
+                id: product_account_set_id,
+                normal_balance_type: DebitOrCredit::Credit,
+            },
+            &format!("term-deposit:{term_deposit_id}"),
+            &name,
+            &name,
+        )
+        .await?;
+
+        let mut control_params = Params::default();
+        control_params.insert("term_deposit_id", term_deposit_id.to_string());
+        self.cala
+            .velocities()
+            .attach_control_to_account_in_op(
+                &mut op,
+                self.term_deposit_control_id,
+                CalaAccountId::from(term_deposit_id),
+                control_params,
+            )
+            .await?;
+
+        let params = templates::OpenTermDepositParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: principal.to_usd(),
+            deposit_account_id: deposit_account_id.into(),
+            term_deposit_account_id: term_deposit_id.into(),
+            correlation_id: term_deposit_id.to_string(),
+            effective,
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id, templates::OPEN_TERM_DEPOSIT_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn settle_term_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        TermDepositSettlement {
+            term_deposit_id,
+            deposit_account_id,
+            effective,
+            release,
+            interest,
+            penalty,
+        }: TermDepositSettlement,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if let Some(penalty) = penalty {
+            let params = templates::ReleaseTermDepositParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: penalty.amount.to_usd(),
+                term_deposit_account_id: term_deposit_id.into(),
+                credit_account_id: self.interest_expense_account_ids.account_id,
+                correlation_id: term_deposit_id.to_string(),
+                effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    penalty.tx_id,
+                    templates::RELEASE_TERM_DEPOSIT_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        if let Some(release) = release {
+            let params = templates::ReleaseTermDepositParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: release.amount.to_usd(),
+                term_deposit_account_id: term_deposit_id.into(),
+                credit_account_id: deposit_account_id.into(),
+                correlation_id: term_deposit_id.to_string(),
+                effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    release.tx_id,
+                    templates::RELEASE_TERM_DEPOSIT_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        if let Some(interest) = interest {
+            let params = templates::PayTermDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: interest.amount.to_usd(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                deposit_account_id: deposit_account_id.into(),
+                correlation_id: term_deposit_id.to_string(),
+                effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    interest.tx_id,
+                    templates::PAY_TERM_DEPOSIT_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn balance(
         &self,
         account_id: impl Into<AccountId>,
@@ -490,13 +803,51 @@ impl DepositLedger {
         }
     }
 
+    /// Closing balance of the account at the end of `date`, by effective date.
+    pub async fn balance_as_of(
+        &self,
+        account_id: impl Into<AccountId>,
+        date: chrono::NaiveDate,
+    ) -> Result<DepositAccountBalance, DepositLedgerError> {
+        let balance_id = (self.journal_id, account_id.into(), self.usd);
+        let mut ranges = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&[balance_id], date, Some(date))
+            .await?;
+        match ranges.remove(&balance_id) {
+            Some(range) => Ok(DepositAccountBalance {
+                settled: UsdCents::try_from_usd(range.close.settled())?,
+                pending: UsdCents::try_from_usd(range.close.pending())?,
+            }),
+            None => Ok(DepositAccountBalance::ZERO),
+        }
+    }
+
+    /// Account sets the deposit account is directly a member of.
+    pub async fn account_set_ids_for(
+        &self,
+        account_id: impl Into<AccountId>,
+    ) -> Result<Vec<CalaAccountSetId>, DepositLedgerError> {
+        Ok(self
+            .cala
+            .account_sets()
+            .find_where_member(account_id.into(), Default::default())
+            .await?
+            .entities
+            .into_iter()
+            .map(|account_set| account_set.id)
+            .collect())
+    }
+
     pub async fn create_deposit_account(
         &self,
         op: es_entity::DbOp<'_>,
         id: impl Into<CalaAccountId>,
         deposit_account_reference: String,
         deposit_account_name: String,
-        deposit_account_type: impl Into<DepositAccountType>,
+        product_account_set_id: CalaAccountSetId,
     ) -> Result<(), DepositLedgerError> {
         let id = id.into();
 
@@ -505,7 +856,10 @@ impl DepositLedger {
         self.create_account_in_op(
             &mut op,
             id,
-            self.deposit_internal_account_set_from_type(deposit_account_type.into()),
+            InternalAccountSetDetails {
+                id: product_account_set_id,
+                normal_balance_type: DebitOrCredit::Credit,
+            },
             &deposit_account_reference,
             &deposit_account_name,
             &deposit_account_name,
@@ -519,6 +873,88 @@ impl DepositLedger {
         Ok(())
     }
 
+    /// Creates the product's omnibus account and, below each of the module's
+    /// deposit account sets, the account set holding the product's accounts.
+    pub async fn create_deposit_product_accounts(
+        &self,
+        op: es_entity::DbOp<'_>,
+        product_id: DepositProductId,
+        product_name: &str,
+        ledger_account_ids: DepositProductLedgerAccountIds,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let omnibus_name = format!("{product_name} Omnibus Account");
+        let omnibus_account_set = NewAccountSet::builder()
+            .id(ledger_account_ids.omnibus_account_set_id)
+            .journal_id(self.journal_id)
+            .external_id(format!("deposit-product-omnibus-account-set:{product_id}"))
+            .name(omnibus_name.clone())
+            .description(omnibus_name.clone())
+            .normal_balance_type(DebitOrCredit::Debit)
+            .build()
+            .expect("Could not build new account set");
+        self.cala
+            .account_sets()
+            .create_in_op(&mut op, omnibus_account_set)
+            .await?;
+        self.cala
+            .account_sets()
+            .add_member_in_op(
+                &mut op,
+                self.product_omnibus_account_set_id,
+                ledger_account_ids.omnibus_account_set_id,
+            )
+            .await?;
+        self.create_account_in_op(
+            &mut op,
+            ledger_account_ids.omnibus_account_id,
+            InternalAccountSetDetails {
+                id: ledger_account_ids.omnibus_account_set_id,
+                normal_balance_type: DebitOrCredit::Debit,
+            },
+            &format!("deposit-product-omnibus-account:{product_id}"),
+            &omnibus_name,
+            &omnibus_name,
+        )
+        .await?;
+
+        for deposit_account_type in [
+            DepositAccountType::Individual,
+            DepositAccountType::GovernmentEntity,
+            DepositAccountType::PrivateCompany,
+            DepositAccountType::Bank,
+            DepositAccountType::FinancialInstitution,
+            DepositAccountType::NonDomiciledCompany,
+        ] {
+            let parent = self.deposit_internal_account_set_from_type(deposit_account_type);
+            let id = ledger_account_ids.account_set_id_for(deposit_account_type);
+            let name = format!("{product_name} Deposit Account Set ({deposit_account_type})");
+            let new_account_set = NewAccountSet::builder()
+                .id(id)
+                .journal_id(self.journal_id)
+                .external_id(format!(
+                    "deposit-product-account-set:{product_id}:{deposit_account_type}"
+                ))
+                .name(name.clone())
+                .description(name)
+                .normal_balance_type(parent.normal_balance_type)
+                .build()
+                .expect("Could not build new account set");
+            self.cala
+                .account_sets()
+                .create_in_op(&mut op, new_account_set)
+                .await?;
+            self.cala
+                .account_sets()
+                .add_member_in_op(&mut op, parent.id, id)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
     fn deposit_internal_account_set_from_type(
         &self,
         deposit_account_type: DepositAccountType,
@@ -589,6 +1025,25 @@ impl DepositLedger {
         }
     }
 
+    pub async fn create_term_deposit_control(
+        cala: &CalaLedger,
+    ) -> Result<VelocityControlId, DepositLedgerError> {
+        let control = NewVelocityControl::builder()
+            .id(TERM_DEPOSITS_VELOCITY_CONTROL_ID)
+            .name("Term Deposit Control")
+            .description("Velocity Control for Term Deposits")
+            .build()
+            .expect("build control");
+
+        match cala.velocities().create_control(control).await {
+            Err(cala_ledger::velocity::error::VelocityError::ControlIdAlreadyExists) => {
+                Ok(TERM_DEPOSITS_VELOCITY_CONTROL_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(control) => Ok(control.id()),
+        }
+    }
+
     pub async fn add_deposit_control_to_account(
         &self,
         op: &mut cala_ledger::LedgerOperation<'_>,
@@ -675,7 +1130,11 @@ impl DepositLedger {
     ) -> Result<(), DepositLedgerError> {
         let mut op = self.cala.begin_operation().await?;
 
-        let mut account_set_ids = vec![self.deposit_omnibus_account_ids.account_set_id];
+        let mut account_set_ids = vec![
+            self.deposit_omnibus_account_ids.account_set_id,
+            self.product_omnibus_account_set_id,
+            self.interest_expense_account_ids.account_set_id,
+        ];
         account_set_ids.extend(self.deposits_account_set.account_set_ids());
         let mut account_sets = self
             .cala
@@ -687,6 +1146,7 @@ impl DepositLedger {
             config: _,
             audit_info: _,
             omnibus_parent_account_set_id,
+            interest_expense_parent_account_set_id,
             individual_deposit_accounts_parent_account_set_id:
                 individual_deposit_parent_account_set_id,
             government_entity_deposit_accounts_parent_account_set_id:
@@ -710,6 +1170,26 @@ impl DepositLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.product_omnibus_account_set_id,
+            *omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.omnibus_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.interest_expense_account_ids.account_set_id,
+            *interest_expense_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.interest_expense_parent_account_set_id,
+        )
+        .await?;
+
         self.attach_charts_account_set(
             &mut op,
             &mut account_sets,
@@ -782,6 +1262,7 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub audit_info: AuditInfo,
 
     pub omnibus_parent_account_set_id: CalaAccountSetId,
+    pub interest_expense_parent_account_set_id: CalaAccountSetId,
 
     pub individual_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub government_entity_deposit_accounts_parent_account_set_id: CalaAccountSetId,
@@ -1,9 +1,23 @@
+mod accrue_deposit_interest;
 mod cancel_withdraw;
+mod capitalize_deposit_interest;
 mod confirm_withdraw;
 mod initiate_withdraw;
+mod internal_transfer;
+mod open_term_deposit;
+mod pay_term_deposit_interest;
 mod record_deposit;
+mod release_term_deposit;
+mod revert_deposit;
 
+pub use accrue_deposit_interest::*;
 pub use cancel_withdraw::*;
+pub use capitalize_deposit_interest::*;
 pub use confirm_withdraw::*;
 pub use initiate_withdraw::*;
+pub use internal_transfer::*;
+pub use open_term_deposit::*;
+pub use pay_term_deposit_interest::*;
 pub use record_deposit::*;
+pub use release_term_deposit::*;
+pub use revert_deposit::*;
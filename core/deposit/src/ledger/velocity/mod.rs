@@ -1,3 +1,5 @@
 mod overdraft_prevention;
+mod term_deposit_lock;
 
 pub use overdraft_prevention::*;
+pub use term_deposit_lock::*;
@@ -4,9 +4,14 @@ use cala_ledger::{velocity::*, *};
 
 use crate::ledger::error::*;
 
+/// Keeps a deposit account from going negative. Deposit reversals that were
+/// explicitly allowed to overdraw the account opt out via their transaction
+/// metadata.
 pub struct OverdraftPrevention;
 
-const OVERDRAFT_PREVENTION_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000001");
+// Supersedes the unconditional limit `00000000-0000-0000-0000-000000000001`,
+// which is detached from the deposit controls by migration.
+const OVERDRAFT_PREVENTION_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000004");
 
 impl OverdraftPrevention {
     #[instrument(name = "ledger.overdraft_prevention.init", skip_all)]
@@ -16,6 +21,9 @@ impl OverdraftPrevention {
             .name("Overdraft Prevention")
             .description("Prevent overdraft on withdrawals")
             .window(vec![])
+            .condition(
+                "context.vars.transaction.metadata == null || context.vars.transaction.metadata.allow_overdraft != true",
+            )
             .limit(
                 NewLimit::builder()
                     .balance(vec![
@@ -0,0 +1,54 @@
+use tracing::instrument;
+
+use cala_ledger::{tx_template::NewParamDefinition, velocity::*, *};
+
+use crate::ledger::error::*;
+
+/// Keeps the funds of a term deposit from being debited by any transaction
+/// that isn't correlated with the term deposit itself, i.e. anything but its
+/// maturity or early break.
+pub struct TermDepositLock;
+
+const TERM_DEPOSIT_LOCK_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000003");
+
+impl TermDepositLock {
+    #[instrument(name = "ledger.term_deposit_lock.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let limit = NewVelocityLimit::builder()
+            .id(TERM_DEPOSIT_LOCK_ID)
+            .name("Term Deposit Lock")
+            .description("Lock term deposit funds until maturity or early break")
+            .window(vec![])
+            .condition("context.vars.transaction.correlation_id != params.term_deposit_id")
+            .params(vec![
+                NewParamDefinition::builder()
+                    .name("term_deposit_id")
+                    .r#type(ParamDataType::String)
+                    .build()
+                    .expect("param definition"),
+            ])
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(TERM_DEPOSIT_LOCK_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
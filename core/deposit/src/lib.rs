@@ -3,19 +3,25 @@
 
 mod account;
 mod chart_of_accounts_integration;
+mod config;
 mod deposit;
 mod deposit_account_balance;
 pub mod error;
 mod event;
 mod for_subject;
 mod history;
+mod interest;
 mod ledger;
 mod primitives;
 mod processes;
+mod product;
 mod publisher;
+mod term_deposit;
 mod time;
+mod transfer;
 mod withdrawal;
 
+use chrono::{DateTime, Utc};
 use deposit_account_cursor::DepositAccountsByCreatedAtCursor;
 use tracing::instrument;
 
@@ -24,7 +30,7 @@ use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use core_accounting::Chart;
 use governance::{Governance, GovernanceEvent};
-use job::Jobs;
+use job::{JobId, Jobs};
 use outbox::{Outbox, OutboxEventMarker};
 
 pub use account::DepositAccount;
@@ -32,18 +38,38 @@ use account::*;
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, ChartOfAccountsIntegrationConfigBuilderError,
 };
+pub use config::*;
 use deposit::*;
-pub use deposit::{Deposit, DepositsByCreatedAtCursor};
+pub use deposit::{Deposit, DepositReversalReason, DepositStatus, DepositsByCreatedAtCursor};
 pub use deposit_account_balance::DepositAccountBalance;
 use error::*;
 pub use event::*;
 pub use for_subject::DepositsForSubject;
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+use interest::*;
+pub use interest::{DepositDayCountConvention, DepositInterestTerms, DepositRatePct};
 use ledger::*;
 pub use primitives::*;
 pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
 use processes::approval::{ApproveWithdrawal, WithdrawApprovalInit, WithdrawApprovalJobConfig};
+pub use processes::reversal_approval::APPROVE_DEPOSIT_REVERSAL_PROCESS;
+use processes::reversal_approval::{
+    ApproveDepositReversal, DepositReversalApprovalInit, DepositReversalApprovalJobConfig,
+};
+pub use processes::transfer_approval::APPROVE_TRANSFER_PROCESS;
+use processes::transfer_approval::{
+    ApproveTransfer, TransferApprovalInit, TransferApprovalJobConfig,
+};
+use product::*;
+pub use product::{
+    DepositOperation, DepositProduct, DepositProductCategory, DepositProductLedgerAccountIds,
+    DepositProductRules, TermDepositRules, WithdrawalFrequencyLimit, WithdrawalLimitPeriod,
+};
 use publisher::DepositPublisher;
+use term_deposit::*;
+pub use term_deposit::{TermDeposit, TermDepositMaturityInstruction, TermDepositStatus};
+use transfer::*;
+pub use transfer::{Transfer, TransferStatus};
 use withdrawal::*;
 pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
 
@@ -51,9 +77,14 @@ pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor}
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::product::DepositProductEvent;
+    pub use crate::term_deposit::TermDepositEvent;
+    pub use crate::transfer::TransferEvent;
     pub use crate::withdrawal::WithdrawalEvent;
 }
 
+pub const DEFAULT_DEPOSIT_PRODUCT_NAME: &str = "Checking";
+
 pub struct CoreDeposit<Perms, E>
 where
     Perms: PermissionCheck,
@@ -62,12 +93,20 @@ where
     accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    products: DepositProductRepo,
+    default_product_id: DepositProductId,
+    term_deposits: TermDepositRepo,
+    transfers: TransferRepo<E>,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_deposit_reversal: ApproveDepositReversal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
     ledger: DepositLedger,
     cala: CalaLedger,
     authz: Perms,
     governance: Governance<Perms, E>,
     outbox: Outbox<E>,
+    jobs: Jobs,
+    config: DepositConfig,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -80,12 +119,20 @@ where
             accounts: self.accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            products: self.products.clone(),
+            default_product_id: self.default_product_id,
+            term_deposits: self.term_deposits.clone(),
+            transfers: self.transfers.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_deposit_reversal: self.approve_deposit_reversal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
             outbox: self.outbox.clone(),
+            jobs: self.jobs.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -102,6 +149,7 @@ where
     #[allow(clippy::too_many_arguments)]
     pub async fn init(
         pool: &sqlx::PgPool,
+        config: DepositConfig,
         authz: &Perms,
         outbox: &Outbox<E>,
         governance: &Governance<Perms, E>,
@@ -113,15 +161,42 @@ where
         let accounts = DepositAccountRepo::new(pool, &publisher);
         let deposits = DepositRepo::new(pool, &publisher);
         let withdrawals = WithdrawalRepo::new(pool, &publisher);
+        let products = DepositProductRepo::new(pool);
+        let term_deposits = TermDepositRepo::new(pool);
+        let transfers = TransferRepo::new(pool, &publisher);
         let ledger = DepositLedger::init(cala, journal_id).await?;
 
         let approve_withdrawal = ApproveWithdrawal::new(&withdrawals, authz.audit(), governance);
+        let approve_deposit_reversal =
+            ApproveDepositReversal::new(&deposits, &accounts, &products, &ledger, authz.audit());
+        let approve_transfer =
+            ApproveTransfer::new(&transfers, &accounts, &products, &ledger, authz.audit());
 
         jobs.add_initializer_and_spawn_unique(
             WithdrawApprovalInit::new(outbox, &approve_withdrawal),
             WithdrawApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            DepositReversalApprovalInit::new(outbox, &approve_deposit_reversal),
+            DepositReversalApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+        jobs.add_initializer_and_spawn_unique(
+            TransferApprovalInit::new(outbox, &approve_transfer),
+            TransferApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+        jobs.add_initializer(DepositInterestAccrualInit::<Perms, E>::new(
+            &accounts,
+            &ledger,
+            authz.audit(),
+        ));
+        jobs.add_initializer(TermDepositMaturityInit::<Perms>::new(
+            &term_deposits,
+            &ledger,
+            authz.audit(),
+        ));
 
         match governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await {
             Err(governance::error::GovernanceError::PolicyError(
@@ -130,17 +205,69 @@ where
             Err(e) => return Err(e.into()),
             _ => (),
         }
+        match governance
+            .init_policy(APPROVE_DEPOSIT_REVERSAL_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+        match governance.init_policy(APPROVE_TRANSFER_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        let default_product_id = match products
+            .find_by_name(DEFAULT_DEPOSIT_PRODUCT_NAME.to_string())
+            .await
+        {
+            Ok(product) => product.id,
+            Err(e) if e.was_not_found() => {
+                let audit_info = authz
+                    .audit()
+                    .record_system_entry(
+                        CoreDepositObject::all_deposit_products(),
+                        CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+                    )
+                    .await?;
+                Self::create_product_with_audit(
+                    &products,
+                    &ledger,
+                    DEFAULT_DEPOSIT_PRODUCT_NAME,
+                    DepositProductCategory::Checking,
+                    DepositProductRules::default(),
+                    audit_info,
+                )
+                .await?
+                .id
+            }
+            Err(e) => return Err(e.into()),
+        };
 
         let res = Self {
             accounts,
             deposits,
             withdrawals,
+            products,
+            default_product_id,
+            term_deposits,
+            transfers,
             authz: authz.clone(),
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_deposit_reversal,
+            approve_transfer,
             ledger,
+            jobs: jobs.clone(),
+            config,
         };
         Ok(res)
     }
@@ -166,15 +293,23 @@ where
         ))
     }
 
+    /// Product new accounts are opened on when no other product is chosen,
+    /// e.g. when accounts are created automatically for new customers.
+    pub fn default_product_id(&self) -> DepositProductId {
+        self.default_product_id
+    }
+
     #[instrument(name = "deposit.create_account", skip(self, deposit_account_type), err)]
     pub async fn create_account(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
+        product_id: impl Into<DepositProductId> + std::fmt::Debug,
         active: bool,
         deposit_account_type: impl Into<DepositAccountType>,
     ) -> Result<DepositAccount, CoreDepositError> {
         let holder_id = holder_id.into();
+        let product = self.products.find_by_id(product_id.into()).await?;
 
         let name = &format!("Deposit Account {holder_id}");
         let reference = &format!("deposit-customer-account:{holder_id}");
@@ -191,6 +326,7 @@ where
         let new_account = NewDepositAccount::builder()
             .id(account_id)
             .account_holder_id(holder_id)
+            .product_id(product.id)
             .reference(reference.to_string())
             .name(name.to_string())
             .description(name.to_string())
@@ -200,7 +336,16 @@ where
             .expect("Could not build new account");
 
         let mut op = self.accounts.begin_op().await?;
-        let account = self.accounts.create_in_op(&mut op, new_account).await?;
+        let mut account = self.accounts.create_in_op(&mut op, new_account).await?;
+        if let Some(interest_terms) = product.rules.interest_terms {
+            self.update_interest_terms_in_op(
+                &mut op,
+                &mut account,
+                Some(interest_terms),
+                audit_info,
+            )
+            .await?;
+        }
 
         self.ledger
             .create_deposit_account(
@@ -208,7 +353,9 @@ where
                 account_id,
                 account.reference.to_string(),
                 account.name.to_string(),
-                deposit_account_type,
+                product
+                    .ledger_account_ids
+                    .account_set_id_for(deposit_account_type.into()),
             )
             .await?;
 
@@ -271,6 +418,239 @@ where
         Ok(())
     }
 
+    /// Passing `None` stops interest from accruing once the interest already
+    /// accrued this month has been capitalized.
+    #[instrument(name = "deposit.update_account_interest_terms", skip(self), err)]
+    pub async fn update_account_interest_terms(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        interest_terms: Option<DepositInterestTerms>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_INTEREST_TERMS,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        let mut op = self.accounts.begin_op().await?;
+        self.update_interest_terms_in_op(&mut op, &mut account, interest_terms, audit_info)
+            .await?;
+        op.commit().await?;
+
+        Ok(account)
+    }
+
+    async fn update_interest_terms_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        account: &mut DepositAccount,
+        interest_terms: Option<DepositInterestTerms>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(), CoreDepositError> {
+        let was_accruing = account.next_interest_accrual_date().is_some();
+        if account
+            .update_interest_terms(interest_terms, crate::time::now().date_naive(), audit_info)
+            .was_ignored()
+        {
+            return Ok(());
+        }
+
+        self.accounts.update_in_op(op, account).await?;
+        if let (false, Some(date)) = (was_accruing, account.next_interest_accrual_date()) {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    op,
+                    JobId::new(),
+                    DepositInterestAccrualJobConfig::<Perms, E> {
+                        deposit_account_id: account.id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    accrual_runs_at(date),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "deposit.create_product", skip(self), err)]
+    pub async fn create_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: impl Into<String> + std::fmt::Debug,
+        category: DepositProductCategory,
+        rules: DepositProductRules,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+            )
+            .await?;
+
+        Self::create_product_with_audit(
+            &self.products,
+            &self.ledger,
+            &name.into(),
+            category,
+            rules,
+            audit_info,
+        )
+        .await
+    }
+
+    async fn create_product_with_audit(
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+        name: &str,
+        category: DepositProductCategory,
+        rules: DepositProductRules,
+        audit_info: audit::AuditInfo,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let product_id = DepositProductId::new();
+        let ledger_account_ids = DepositProductLedgerAccountIds::new();
+        let new_product = NewDepositProduct::builder()
+            .id(product_id)
+            .name(name)
+            .category(category)
+            .rules(rules)
+            .ledger_account_ids(ledger_account_ids)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new product");
+
+        let mut op = products.begin_op().await?;
+        let product = products.create_in_op(&mut op, new_product).await?;
+        ledger
+            .create_deposit_product_accounts(op, product_id, name, ledger_account_ids)
+            .await?;
+
+        Ok(product)
+    }
+
+    /// Changed interest terms are applied to every account already opened on
+    /// the product, replacing any terms set on the account itself.
+    #[instrument(name = "deposit.update_product_rules", skip(self), err)]
+    pub async fn update_product_rules(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        product_id: impl Into<DepositProductId> + std::fmt::Debug,
+        rules: DepositProductRules,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let product_id = product_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(product_id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_RULES,
+            )
+            .await?;
+
+        let mut product = self.products.find_by_id(product_id).await?;
+        let interest_terms_changed = product.rules.interest_terms != rules.interest_terms;
+        if product
+            .update_rules(rules, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(product);
+        }
+
+        let mut op = self.products.begin_op().await?;
+        self.products.update_in_op(&mut op, &mut product).await?;
+        if interest_terms_changed {
+            let mut query = Default::default();
+            loop {
+                let mut res = self
+                    .accounts
+                    .list_for_product_id_by_created_at(
+                        product_id,
+                        query,
+                        es_entity::ListDirection::Ascending,
+                    )
+                    .await?;
+                for account in res.entities.iter_mut() {
+                    self.update_interest_terms_in_op(
+                        &mut op,
+                        account,
+                        product.rules.interest_terms,
+                        audit_info.clone(),
+                    )
+                    .await?;
+                }
+
+                if let Some(q) = res.into_next_query() {
+                    query = q;
+                } else {
+                    break;
+                };
+            }
+        }
+        op.commit().await?;
+
+        Ok(product)
+    }
+
+    #[instrument(name = "deposit.find_product_by_id", skip(self), err)]
+    pub async fn find_product_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<Option<DepositProduct>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
+            .await?;
+
+        match self.products.find_by_id(id).await {
+            Ok(product) => Ok(Some(product)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_products", skip(self), err)]
+    pub async fn list_products(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<DepositProduct>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+            )
+            .await?;
+
+        let mut products = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .products
+                .list_by_name(query, es_entity::ListDirection::Ascending)
+                .await?;
+            products.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+        Ok(products)
+    }
+
     #[instrument(name = "deposit.account_history", skip(self), err)]
     pub async fn account_history(
         &self,
@@ -316,7 +696,12 @@ where
                 CoreDepositAction::DEPOSIT_CREATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        let account = self.check_account_active(deposit_account_id).await?;
+        let product = self.products.find_by_id(account.product_id).await?;
+        product
+            .rules
+            .check_operation(DepositOperation::Deposit)
+            .map_err(CoreDepositError::from)?;
         let deposit_id = DepositId::new();
         let new_deposit = NewDeposit::builder()
             .id(deposit_id)
@@ -330,7 +715,71 @@ where
         let mut op = self.deposits.begin_op().await?;
         let deposit = self.deposits.create_in_op(&mut op, new_deposit).await?;
         self.ledger
-            .record_deposit(op, deposit_id, amount, deposit_account_id)
+            .record_deposit(
+                op,
+                deposit_id,
+                amount,
+                product.ledger_account_ids.omnibus_account_id,
+                deposit_account_id,
+            )
+            .await?;
+        Ok(deposit)
+    }
+
+    /// Takes the funds of a deposit back out of its account, e.g. when the
+    /// incoming wire turns out to be fraudulent or is returned by the bank.
+    /// Reversals above the configured threshold only go through once the
+    /// governance approval process concludes.
+    #[instrument(name = "deposit.revert_deposit", skip(self), err)]
+    pub async fn revert_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_id: impl Into<DepositId> + std::fmt::Debug,
+        reason: DepositReversalReason,
+        allow_overdraft: bool,
+    ) -> Result<Deposit, CoreDepositError> {
+        let id = deposit_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit(id),
+                CoreDepositAction::DEPOSIT_REVERT,
+            )
+            .await?;
+        let mut deposit = self.deposits.find_by_id(id).await?;
+
+        let mut op = self.deposits.begin_op().await?;
+        if deposit.amount > self.config.reversal_approval_threshold {
+            deposit.request_reversal(reason, allow_overdraft, Some(id.into()), audit_info)?;
+            self.governance
+                .start_process(
+                    &mut op,
+                    id,
+                    id.to_string(),
+                    APPROVE_DEPOSIT_REVERSAL_PROCESS,
+                )
+                .await?;
+            self.deposits.update_in_op(&mut op, &mut deposit).await?;
+            op.commit().await?;
+            return Ok(deposit);
+        }
+
+        let account = self.accounts.find_by_id(deposit.deposit_account_id).await?;
+        let product = self.products.find_by_id(account.product_id).await?;
+        deposit.request_reversal(reason, allow_overdraft, None, audit_info.clone())?;
+        let tx_id = deposit.revert(audit_info)?;
+        self.deposits.update_in_op(&mut op, &mut deposit).await?;
+        self.ledger
+            .revert_deposit(
+                op,
+                tx_id,
+                deposit.id,
+                deposit.amount,
+                product.ledger_account_ids.omnibus_account_id,
+                deposit.deposit_account_id,
+                allow_overdraft,
+            )
             .await?;
         Ok(deposit)
     }
@@ -352,7 +801,25 @@ where
                 CoreDepositAction::WITHDRAWAL_INITIATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        let account = self.check_account_active(deposit_account_id).await?;
+        let product = self.products.find_by_id(account.product_id).await?;
+        // Concurrent debits must not both pass the frequency limit.
+        let mut op = self.withdrawals.begin_op().await?;
+        self.accounts
+            .lock_in_op(&mut op, deposit_account_id)
+            .await?;
+        let balance = self.ledger.balance(deposit_account_id).await?;
+        let withdrawals_in_period = match product.rules.withdrawal_frequency_limit {
+            Some(limit) => {
+                self.count_debits_since(deposit_account_id, limit.period.start(crate::time::now()))
+                    .await?
+            }
+            None => 0,
+        };
+        product
+            .rules
+            .check_withdrawal(amount, balance.settled, withdrawals_in_period)
+            .map_err(CoreDepositError::from)?;
         let withdrawal_id = WithdrawalId::new();
         let new_withdrawal = NewWithdrawal::builder()
             .id(withdrawal_id)
@@ -363,7 +830,6 @@ where
             .audit_info(audit_info)
             .build()?;
 
-        let mut op = self.withdrawals.begin_op().await?;
         self.governance
             .start_process(
                 &mut op,
@@ -378,7 +844,13 @@ where
             .await?;
 
         self.ledger
-            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id)
+            .initiate_withdrawal(
+                op,
+                withdrawal_id,
+                amount,
+                product.ledger_account_ids.omnibus_account_id,
+                deposit_account_id,
+            )
             .await?;
         Ok(withdrawal)
     }
@@ -399,8 +871,10 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
+        let account = self
+            .check_account_active(withdrawal.deposit_account_id)
             .await?;
+        let product = self.products.find_by_id(account.product_id).await?;
         let mut op = self.withdrawals.begin_op().await?;
         let tx_id = withdrawal.confirm(audit_info)?;
         self.withdrawals
@@ -413,6 +887,7 @@ where
                 tx_id,
                 withdrawal.id.to_string(),
                 withdrawal.amount,
+                product.ledger_account_ids.omnibus_account_id,
                 withdrawal.deposit_account_id,
                 format!("lana:withdraw:{}:confirm", withdrawal.id),
             )
@@ -437,19 +912,280 @@ where
             )
             .await?;
         let mut withdrawal = self.withdrawals.find_by_id(id).await?;
-        self.check_account_active(withdrawal.deposit_account_id)
+        let account = self
+            .check_account_active(withdrawal.deposit_account_id)
             .await?;
+        let product = self.products.find_by_id(account.product_id).await?;
         let mut op = self.withdrawals.begin_op().await?;
         let tx_id = withdrawal.cancel(audit_info)?;
         self.withdrawals
             .update_in_op(&mut op, &mut withdrawal)
             .await?;
         self.ledger
-            .cancel_withdrawal(op, tx_id, withdrawal.amount, withdrawal.deposit_account_id)
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                product.ledger_account_ids.omnibus_account_id,
+                withdrawal.deposit_account_id,
+            )
             .await?;
         Ok(withdrawal)
     }
 
+    /// Moves `amount` between two deposit accounts in a single ledger
+    /// transaction. Transfers above the configured threshold are only posted
+    /// once the approval process concludes.
+    #[instrument(name = "deposit.transfer", skip(self), err)]
+    pub async fn transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        let from_account_id = from_account_id.into();
+        let to_account_id = to_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_CREATE,
+            )
+            .await?;
+        let from_account = self.check_account_active(from_account_id).await?;
+        let to_account = self.check_account_active(to_account_id).await?;
+        let from_product = self.products.find_by_id(from_account.product_id).await?;
+        let to_product = self.products.find_by_id(to_account.product_id).await?;
+        // Concurrent debits must not both pass the frequency limit.
+        let mut op = self.transfers.begin_op().await?;
+        self.accounts.lock_in_op(&mut op, from_account_id).await?;
+        let balance = self.ledger.balance(from_account_id).await?;
+        let withdrawals_in_period = match from_product.rules.withdrawal_frequency_limit {
+            Some(limit) => {
+                self.count_debits_since(from_account_id, limit.period.start(crate::time::now()))
+                    .await?
+            }
+            None => 0,
+        };
+        from_product
+            .rules
+            .check_withdrawal(amount, balance.settled, withdrawals_in_period)
+            .map_err(CoreDepositError::from)?;
+        to_product
+            .rules
+            .check_operation(DepositOperation::Deposit)
+            .map_err(CoreDepositError::from)?;
+
+        let transfer_id = TransferId::new();
+        let requires_approval = amount > self.config.transfer_approval_threshold;
+        let new_transfer = NewTransfer::builder()
+            .id(transfer_id)
+            .from_account_id(from_account_id)
+            .to_account_id(to_account_id)
+            .amount(amount)
+            .approval_process_id(requires_approval.then(|| transfer_id.into()))
+            .reference(reference)
+            .audit_info(audit_info.clone())
+            .build()?;
+
+        if requires_approval {
+            self.governance
+                .start_process(
+                    &mut op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                )
+                .await?;
+            let transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+            op.commit().await?;
+            return Ok(transfer);
+        }
+
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+        self.ledger
+            .transfer(
+                op,
+                tx_id,
+                transfer_id,
+                amount,
+                from_account_id,
+                to_account_id,
+            )
+            .await?;
+        Ok(transfer)
+    }
+
+    /// Locks `amount` from the deposit account for the term of the product,
+    /// which must be a term deposit product.
+    #[instrument(name = "deposit.open_term_deposit", skip(self), err)]
+    pub async fn open_term_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        product_id: impl Into<DepositProductId> + std::fmt::Debug,
+        amount: UsdCents,
+        maturity_instruction: TermDepositMaturityInstruction,
+    ) -> Result<TermDeposit, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_term_deposits(),
+                CoreDepositAction::TERM_DEPOSIT_OPEN,
+            )
+            .await?;
+        let account = self.check_account_active(deposit_account_id).await?;
+        // The principal leaves the source account, so it is held to its
+        // product's rules like any other debit.
+        let source_product = self.products.find_by_id(account.product_id).await?;
+        let balance = self.ledger.balance(deposit_account_id).await?;
+        source_product
+            .rules
+            .check_debit(amount, balance.settled)
+            .map_err(CoreDepositError::from)?;
+        let deposit_account_type = source_product
+            .ledger_account_ids
+            .account_type_in(&self.ledger.account_set_ids_for(deposit_account_id).await?)
+            .ok_or(CoreDepositError::DepositAccountTypeNotFound(
+                deposit_account_id,
+            ))?;
+
+        let product = self.products.find_by_id(product_id.into()).await?;
+        let rules = product
+            .rules
+            .term_deposit
+            .ok_or(term_deposit::error::TermDepositError::NotATermDepositProduct)?;
+
+        let ledger_tx_id = CalaTransactionId::new();
+        let new_term_deposit = NewTermDeposit::builder()
+            .id(TermDepositId::new())
+            .deposit_account_id(deposit_account_id)
+            .product_id(product.id)
+            .ledger_tx_id(ledger_tx_id)
+            .principal(amount)
+            .interest_terms(product.rules.interest_terms)
+            .rules(rules)
+            .maturity_instruction(maturity_instruction)
+            .starts_on(crate::time::now().date_naive())
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new term deposit");
+
+        let mut op = self.term_deposits.begin_op().await?;
+        let term_deposit = self
+            .term_deposits
+            .create_in_op(&mut op, new_term_deposit)
+            .await?;
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                JobId::new(),
+                TermDepositMaturityJobConfig::<Perms> {
+                    term_deposit_id: term_deposit.id,
+                    _phantom: std::marker::PhantomData,
+                },
+                maturity_runs_at(term_deposit.matures_on),
+            )
+            .await?;
+        self.ledger
+            .open_term_deposit(
+                op,
+                term_deposit.id,
+                ledger_tx_id,
+                deposit_account_id,
+                product
+                    .ledger_account_ids
+                    .account_set_id_for(deposit_account_type),
+                amount,
+                term_deposit.starts_on,
+            )
+            .await?;
+
+        Ok(term_deposit)
+    }
+
+    #[instrument(name = "deposit.break_term_deposit", skip(self), err)]
+    pub async fn break_term_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        term_deposit_id: impl Into<TermDepositId> + std::fmt::Debug,
+    ) -> Result<TermDeposit, CoreDepositError> {
+        let id = term_deposit_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::term_deposit(id),
+                CoreDepositAction::TERM_DEPOSIT_BREAK,
+            )
+            .await?;
+
+        let mut term_deposit = self.term_deposits.find_by_id(id).await?;
+        let settlement = term_deposit.break_early(crate::time::now().date_naive(), audit_info)?;
+
+        let mut op = self.term_deposits.begin_op().await?;
+        self.term_deposits
+            .update_in_op(&mut op, &mut term_deposit)
+            .await?;
+        self.ledger.settle_term_deposit(op, settlement).await?;
+
+        Ok(term_deposit)
+    }
+
+    #[instrument(name = "deposit.find_term_deposit_by_id", skip(self), err)]
+    pub async fn find_term_deposit_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TermDepositId> + std::fmt::Debug,
+    ) -> Result<Option<TermDeposit>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::term_deposit(id),
+                CoreDepositAction::TERM_DEPOSIT_READ,
+            )
+            .await?;
+
+        match self.term_deposits.find_by_id(id).await {
+            Ok(term_deposit) => Ok(Some(term_deposit)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_term_deposits_for_account", skip(self), err)]
+    pub async fn list_term_deposits_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<TermDeposit>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_term_deposits(),
+                CoreDepositAction::TERM_DEPOSIT_LIST,
+            )
+            .await?;
+        Ok(self
+            .term_deposits
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
     #[instrument(name = "deposit.account_balance", skip(self), err)]
     pub async fn account_balance(
         &self,
@@ -492,6 +1228,50 @@ where
         }
     }
 
+    #[instrument(name = "deposit.find_deposit_by_reverted_tx_id", skip(self), err)]
+    pub async fn find_deposit_by_reverted_tx_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reverted_tx_id: impl Into<CalaTransactionId> + std::fmt::Debug,
+    ) -> Result<Deposit, CoreDepositError> {
+        let reverted_tx_id = reverted_tx_id.into();
+        let deposit = self
+            .deposits
+            .find_by_reverted_tx_id(Some(reverted_tx_id))
+            .await?;
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit(deposit.id),
+                CoreDepositAction::DEPOSIT_READ,
+            )
+            .await?;
+
+        Ok(deposit)
+    }
+
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self), err)]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        match self.transfers.find_by_id(id).await {
+            Ok(transfer) => Ok(Some(transfer)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     #[instrument(name = "deposit.find_withdrawal_by_id", skip(self), err)]
     pub async fn find_withdrawal_by_id(
         &self,
@@ -552,6 +1332,22 @@ where
         Ok(self.deposits.find_all(ids).await?)
     }
 
+    #[instrument(name = "deposit.find_all_transfers", skip(self), err)]
+    pub async fn find_all_transfers<T: From<Transfer>>(
+        &self,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.find_all_deposit_products", skip(self), err)]
+    pub async fn find_all_deposit_products<T: From<DepositProduct>>(
+        &self,
+        ids: &[DepositProductId],
+    ) -> Result<std::collections::HashMap<DepositProductId, T>, CoreDepositError> {
+        Ok(self.products.find_all(ids).await?)
+    }
+
     #[instrument(name = "deposit.find_all_deposit_accounts", skip(self), err)]
     pub async fn find_all_deposit_accounts<T: From<DepositAccount>>(
         &self,
@@ -750,6 +1546,8 @@ where
 
         let omnibus_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_accounts_omnibus_parent_code)?;
+        let interest_expense_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_accounts_interest_expense_parent_code)?;
 
         let audit_info = self
             .authz
@@ -764,6 +1562,7 @@ where
             audit_info,
             config: config.clone(),
             omnibus_parent_account_set_id,
+            interest_expense_parent_account_set_id,
             individual_deposit_accounts_parent_account_set_id,
             government_entity_deposit_accounts_parent_account_set_id,
             private_company_deposit_accounts_parent_account_set_id,
@@ -782,11 +1581,83 @@ where
     async fn check_account_active(
         &self,
         deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account = self.accounts.find_by_id(deposit_account_id).await?;
         if account.status.is_inactive() {
             return Err(CoreDepositError::DepositAccountNotActive);
         }
-        Ok(())
+        Ok(account)
+    }
+
+    /// Counts the withdrawals and outgoing transfers made from the account
+    /// since `since`, for the product's withdrawal frequency limit.
+    async fn count_debits_since(
+        &self,
+        deposit_account_id: DepositAccountId,
+        since: DateTime<Utc>,
+    ) -> Result<usize, CoreDepositError> {
+        let mut count = 0;
+        let mut query = Default::default();
+        loop {
+            let res = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at(
+                    deposit_account_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+
+            let mut reached_start = false;
+            for withdrawal in res.entities.iter() {
+                if withdrawal.created_at() < since {
+                    reached_start = true;
+                    break;
+                }
+                if !matches!(
+                    withdrawal.status(),
+                    WithdrawalStatus::Cancelled | WithdrawalStatus::Denied
+                ) {
+                    count += 1;
+                }
+            }
+
+            match res.into_next_query() {
+                Some(q) if !reached_start => query = q,
+                _ => break,
+            }
+        }
+
+        let mut query = Default::default();
+        loop {
+            let res = self
+                .transfers
+                .list_for_from_account_id_by_created_at(
+                    deposit_account_id,
+                    query,
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+
+            let mut reached_start = false;
+            for transfer in res.entities.iter() {
+                if transfer.created_at() < since {
+                    reached_start = true;
+                    break;
+                }
+                if !matches!(
+                    transfer.status(),
+                    TransferStatus::Denied | TransferStatus::Failed
+                ) {
+                    count += 1;
+                }
+            }
+
+            match res.into_next_query() {
+                Some(q) if !reached_start => query = q,
+                _ => break,
+            }
+        }
+        Ok(count)
     }
 }
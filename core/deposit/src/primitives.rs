@@ -14,13 +14,20 @@ pub use cala_ledger::primitives::{
 es_entity::entity_id! {
     DepositAccountHolderId,
     DepositAccountId,
+    DepositProductId,
+    TermDepositId,
+    TransferId,
     WithdrawalId,
     ChartOfAccountsIntegrationConfigId,
     DepositId;
 
     DepositAccountHolderId => core_customer::CustomerId,
     DepositAccountId => CalaAccountId,
+    TermDepositId => CalaAccountId,
     DepositId => CalaTransactionId,
+    DepositId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    TransferId => ApprovalProcessId,
     WithdrawalId => CalaTransactionId,
     WithdrawalId => ApprovalProcessId
 }
@@ -31,6 +38,9 @@ pub use core_money::UsdCents;
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
+pub type DepositProductAllOrOne = AllOrOne<DepositProductId>;
+pub type TermDepositAllOrOne = AllOrOne<TermDepositId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
 
@@ -49,6 +59,9 @@ pub struct LedgerOmnibusAccountIds {
 pub enum CoreDepositObject {
     DepositAccount(DepositAccountAllOrOne),
     Deposit(DepositAllOrOne),
+    DepositProduct(DepositProductAllOrOne),
+    TermDeposit(TermDepositAllOrOne),
+    Transfer(TransferAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
 }
@@ -70,6 +83,30 @@ impl CoreDepositObject {
         CoreDepositObject::Deposit(AllOrOne::ById(id))
     }
 
+    pub fn all_deposit_products() -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::All)
+    }
+
+    pub fn deposit_product(id: DepositProductId) -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::ById(id))
+    }
+
+    pub fn all_term_deposits() -> Self {
+        CoreDepositObject::TermDeposit(AllOrOne::All)
+    }
+
+    pub fn term_deposit(id: TermDepositId) -> Self {
+        CoreDepositObject::TermDeposit(AllOrOne::ById(id))
+    }
+
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
     pub fn all_withdrawals() -> Self {
         CoreDepositObject::Withdrawal(AllOrOne::All)
     }
@@ -90,6 +127,9 @@ impl Display for CoreDepositObject {
         match self {
             DepositAccount(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Deposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositProduct(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            TermDeposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Transfer(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Withdrawal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
@@ -115,6 +155,24 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::Deposit(obj_ref)
             }
+            DepositProduct => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositProduct(obj_ref)
+            }
+            TermDeposit => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::TermDeposit(obj_ref)
+            }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
             Withdrawal => {
                 let obj_ref = id
                     .parse()
@@ -138,6 +196,9 @@ impl FromStr for CoreDepositObject {
 pub enum CoreDepositAction {
     DepositAccount(DepositAccountAction),
     Deposit(DepositAction),
+    DepositProduct(DepositProductAction),
+    TermDeposit(TermDepositAction),
+    Transfer(TransferAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
 }
@@ -153,10 +214,37 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Read);
     pub const DEPOSIT_ACCOUNT_LIST: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::List);
+    pub const DEPOSIT_ACCOUNT_UPDATE_INTEREST_TERMS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateInterestTerms);
+    pub const DEPOSIT_ACCOUNT_ACCRUE_INTEREST: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AccrueInterest);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
     pub const DEPOSIT_LIST: Self = CoreDepositAction::Deposit(DepositAction::List);
+    pub const DEPOSIT_REVERT: Self = CoreDepositAction::Deposit(DepositAction::Revert);
+    pub const DEPOSIT_CONCLUDE_REVERSAL_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Deposit(DepositAction::ConcludeReversalApprovalProcess);
+
+    pub const DEPOSIT_PRODUCT_CREATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Create);
+    pub const DEPOSIT_PRODUCT_UPDATE_RULES: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::UpdateRules);
+    pub const DEPOSIT_PRODUCT_READ: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Read);
+    pub const DEPOSIT_PRODUCT_LIST: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::List);
+
+    pub const TERM_DEPOSIT_OPEN: Self = CoreDepositAction::TermDeposit(TermDepositAction::Open);
+    pub const TERM_DEPOSIT_BREAK: Self = CoreDepositAction::TermDeposit(TermDepositAction::Break);
+    pub const TERM_DEPOSIT_MATURE: Self = CoreDepositAction::TermDeposit(TermDepositAction::Mature);
+    pub const TERM_DEPOSIT_READ: Self = CoreDepositAction::TermDeposit(TermDepositAction::Read);
+    pub const TERM_DEPOSIT_LIST: Self = CoreDepositAction::TermDeposit(TermDepositAction::List);
+    pub const TRANSFER_CREATE: Self = CoreDepositAction::Transfer(TransferAction::Create);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_UPDATE: Self =
         CoreDepositAction::ChartOfAccountsIntegrationConfig(
@@ -187,6 +275,9 @@ impl CoreDepositAction {
             let actions = match entity {
                 DepositAccount => DepositAccountAction::describe(),
                 Deposit => DepositAction::describe(),
+                DepositProduct => DepositProductAction::describe(),
+                TermDeposit => TermDepositAction::describe(),
+                Transfer => TransferAction::describe(),
                 ChartOfAccountsIntegrationConfig => {
                     ChartOfAccountsIntegrationConfigAction::describe()
                 }
@@ -206,6 +297,9 @@ impl Display for CoreDepositAction {
         match self {
             DepositAccount(action) => action.fmt(f),
             Deposit(action) => action.fmt(f),
+            DepositProduct(action) => action.fmt(f),
+            TermDeposit(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
         }
@@ -221,6 +315,9 @@ impl FromStr for CoreDepositAction {
         let res = match entity.parse()? {
             DepositAccount => CoreDepositAction::from(action.parse::<DepositAccountAction>()?),
             Deposit => CoreDepositAction::from(action.parse::<DepositAction>()?),
+            DepositProduct => CoreDepositAction::from(action.parse::<DepositProductAction>()?),
+            TermDeposit => CoreDepositAction::from(action.parse::<TermDepositAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
             ChartOfAccountsIntegrationConfig => {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
@@ -240,6 +337,8 @@ pub enum DepositAccountAction {
     ReadTxHistory,
     Read,
     List,
+    UpdateInterestTerms,
+    AccrueInterest,
 }
 
 impl DepositAccountAction {
@@ -268,6 +367,12 @@ impl DepositAccountAction {
                     variant,
                     &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
                 ),
+                Self::UpdateInterestTerms => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::AccrueInterest => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -288,6 +393,8 @@ pub enum DepositAction {
     Create,
     Read,
     List,
+    Revert,
+    ConcludeReversalApprovalProcess,
 }
 
 impl DepositAction {
@@ -305,6 +412,10 @@ impl DepositAction {
                     variant,
                     &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
                 ),
+                Self::Revert => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::ConcludeReversalApprovalProcess => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -319,6 +430,129 @@ impl From<DepositAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositProductAction {
+    Create,
+    UpdateRules,
+    Read,
+    List,
+}
+
+impl DepositProductAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::UpdateRules => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<DepositProductAction> for CoreDepositAction {
+    fn from(action: DepositProductAction) -> Self {
+        CoreDepositAction::DepositProduct(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TermDepositAction {
+    Open,
+    Break,
+    Mature,
+    Read,
+    List,
+}
+
+impl TermDepositAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Open => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Break => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Mature => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<TermDepositAction> for CoreDepositAction {
+    fn from(action: TermDepositAction) -> Self {
+        CoreDepositAction::TermDeposit(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Create,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl TransferAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::ConcludeApprovalProcess => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum WithdrawalAction {
@@ -396,6 +630,8 @@ impl From<ChartOfAccountsIntegrationConfigAction> for CoreDepositAction {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
 pub enum DepositAccountType {
     Individual,
     GovernmentEntity,
@@ -405,6 +641,17 @@ pub enum DepositAccountType {
     NonDomiciledCompany,
 }
 
+impl DepositAccountType {
+    pub const ALL: [DepositAccountType; 6] = [
+        DepositAccountType::Individual,
+        DepositAccountType::GovernmentEntity,
+        DepositAccountType::PrivateCompany,
+        DepositAccountType::Bank,
+        DepositAccountType::FinancialInstitution,
+        DepositAccountType::NonDomiciledCompany,
+    ];
+}
+
 impl From<CustomerType> for DepositAccountType {
     fn from(customer_type: CustomerType) -> Self {
         match customer_type {
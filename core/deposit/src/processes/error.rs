@@ -8,6 +8,16 @@ pub enum ProcessError {
     GovernanceError(#[from] governance::error::GovernanceError),
     #[error("ProcessError - Sqlx: {0}")]
     Sqlx(#[from] sqlx::Error),
+    #[error("ProcessError - DepositError: {0}")]
+    DepositError(#[from] crate::deposit::error::DepositError),
+    #[error("ProcessError - DepositAccountError: {0}")]
+    DepositAccountError(#[from] crate::account::error::DepositAccountError),
+    #[error("ProcessError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
+    #[error("ProcessError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
+    #[error("ProcessError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
     #[error("ProcessError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
     #[error("ProcessError - AuditError: {0}")]
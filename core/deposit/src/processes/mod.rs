@@ -1,2 +1,4 @@
 pub mod approval;
 pub mod error;
+pub mod reversal_approval;
+pub mod transfer_approval;
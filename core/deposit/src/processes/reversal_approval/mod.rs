@@ -0,0 +1,148 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+
+use audit::AuditSvc;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::DepositAccountRepo,
+    deposit::{Deposit, DepositRepo},
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::DepositId,
+    product::DepositProductRepo,
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_DEPOSIT_REVERSAL_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("deposit-reversal");
+
+pub struct ApproveDepositReversal<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    deposits: DepositRepo<E>,
+    accounts: DepositAccountRepo<E>,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+impl<Perms, E> Clone for ApproveDepositReversal<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            deposits: self.deposits.clone(),
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveDepositReversal<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        deposits: &DepositRepo<E>,
+        accounts: &DepositAccountRepo<E>,
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            deposits: deposits.clone(),
+            accounts: accounts.clone(),
+            products: products.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<DepositId>,
+        approved: bool,
+    ) -> Result<Deposit, ProcessError> {
+        let id = id.into();
+        let mut deposit = self.deposits.find_by_id(id).await?;
+        if deposit.is_reversal_approved_or_denied().is_some() {
+            return Ok(deposit);
+        }
+        let account = self.accounts.find_by_id(deposit.deposit_account_id).await?;
+        let product = self.products.find_by_id(account.product_id).await?;
+
+        let mut db = self.deposits.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::deposit(id),
+                CoreDepositAction::DEPOSIT_CONCLUDE_REVERSAL_APPROVAL_PROCESS,
+            )
+            .await?;
+        if deposit
+            .reversal_approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(deposit);
+        }
+
+        if !approved {
+            self.deposits.update_in_op(&mut db, &mut deposit).await?;
+            db.commit().await?;
+            return Ok(deposit);
+        }
+
+        // Nothing is held while the reversal awaits approval, so the funds
+        // may have left the account in the meantime. Record the failure
+        // rather than erroring, which would stall the approval listener.
+        if !deposit.reversal_allows_overdraft() {
+            let balance = self.ledger.balance(deposit.deposit_account_id).await?;
+            if balance.settled < deposit.amount {
+                let _ = deposit.reversal_failed(
+                    format!(
+                        "settled balance {} does not cover the deposit amount {}",
+                        balance.settled, deposit.amount
+                    ),
+                    audit_info,
+                );
+                self.deposits.update_in_op(&mut db, &mut deposit).await?;
+                db.commit().await?;
+                return Ok(deposit);
+            }
+        }
+
+        let tx_id = deposit.revert(audit_info)?;
+        self.deposits.update_in_op(&mut db, &mut deposit).await?;
+        self.ledger
+            .revert_deposit(
+                db,
+                tx_id,
+                deposit.id,
+                deposit.amount,
+                product.ledger_account_ids.omnibus_account_id,
+                deposit.deposit_account_id,
+                deposit.reversal_allows_overdraft(),
+            )
+            .await?;
+        Ok(deposit)
+    }
+}
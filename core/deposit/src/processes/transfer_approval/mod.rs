@@ -0,0 +1,156 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+
+use audit::AuditSvc;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::DepositAccountRepo,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::TransferId,
+    product::{DepositOperation, DepositProductRepo},
+    transfer::{Transfer, TransferRepo},
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType = ApprovalProcessType::new("transfer");
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    transfers: TransferRepo<E>,
+    accounts: DepositAccountRepo<E>,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            transfers: self.transfers.clone(),
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        transfers: &TransferRepo<E>,
+        accounts: &DepositAccountRepo<E>,
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            transfers: transfers.clone(),
+            accounts: accounts.clone(),
+            products: products.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<TransferId>,
+        approved: bool,
+    ) -> Result<Transfer, ProcessError> {
+        let id = id.into();
+        let mut transfer = self.transfers.find_by_id(id).await?;
+        if transfer.is_approved_or_denied().is_some() {
+            return Ok(transfer);
+        }
+        let mut db = self.transfers.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+        if transfer
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(transfer);
+        }
+
+        if !approved {
+            self.transfers.update_in_op(&mut db, &mut transfer).await?;
+            db.commit().await?;
+            return Ok(transfer);
+        }
+
+        // The checks made when the transfer was requested may no longer hold.
+        // Record the failure rather than erroring, which would stall the
+        // approval listener.
+        if let Some(reason) = self.rejection_reason(&transfer).await? {
+            let _ = transfer.fail(reason, audit_info);
+            self.transfers.update_in_op(&mut db, &mut transfer).await?;
+            db.commit().await?;
+            return Ok(transfer);
+        }
+
+        let tx_id = transfer.execute(audit_info)?;
+        self.transfers.update_in_op(&mut db, &mut transfer).await?;
+        self.ledger
+            .transfer(
+                db,
+                tx_id,
+                transfer.id,
+                transfer.amount,
+                transfer.from_account_id,
+                transfer.to_account_id,
+            )
+            .await?;
+        Ok(transfer)
+    }
+
+    async fn rejection_reason(&self, transfer: &Transfer) -> Result<Option<String>, ProcessError> {
+        let from_account = self.accounts.find_by_id(transfer.from_account_id).await?;
+        let to_account = self.accounts.find_by_id(transfer.to_account_id).await?;
+        for account in [&from_account, &to_account] {
+            if account.status.is_inactive() {
+                return Ok(Some(format!(
+                    "deposit account {} is not active",
+                    account.id
+                )));
+            }
+        }
+
+        let from_product = self.products.find_by_id(from_account.product_id).await?;
+        let to_product = self.products.find_by_id(to_account.product_id).await?;
+        let balance = self.ledger.balance(transfer.from_account_id).await?;
+        let checks = from_product
+            .rules
+            .check_debit(transfer.amount, balance.settled)
+            .and_then(|_| to_product.rules.check_operation(DepositOperation::Deposit));
+        Ok(checks.err().map(|e| e.to_string()))
+    }
+}
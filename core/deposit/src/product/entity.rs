@@ -0,0 +1,235 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::primitives::*;
+
+use super::rules::*;
+
+/// Ledger accounts a product gets on creation. Every product has its own
+/// omnibus account and, for each account holder type, its own account set
+/// that is nested below the module-wide deposit account set of that type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositProductLedgerAccountIds {
+    pub omnibus_account_set_id: CalaAccountSetId,
+    pub omnibus_account_id: CalaAccountId,
+    pub individual_account_set_id: CalaAccountSetId,
+    pub government_entity_account_set_id: CalaAccountSetId,
+    pub private_company_account_set_id: CalaAccountSetId,
+    pub bank_account_set_id: CalaAccountSetId,
+    pub financial_institution_account_set_id: CalaAccountSetId,
+    pub non_domiciled_company_account_set_id: CalaAccountSetId,
+}
+
+impl DepositProductLedgerAccountIds {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            omnibus_account_set_id: CalaAccountSetId::new(),
+            omnibus_account_id: CalaAccountId::new(),
+            individual_account_set_id: CalaAccountSetId::new(),
+            government_entity_account_set_id: CalaAccountSetId::new(),
+            private_company_account_set_id: CalaAccountSetId::new(),
+            bank_account_set_id: CalaAccountSetId::new(),
+            financial_institution_account_set_id: CalaAccountSetId::new(),
+            non_domiciled_company_account_set_id: CalaAccountSetId::new(),
+        }
+    }
+
+    /// The account type whose account set is among `account_set_ids`.
+    pub fn account_type_in(
+        &self,
+        account_set_ids: &[CalaAccountSetId],
+    ) -> Option<DepositAccountType> {
+        DepositAccountType::ALL
+            .into_iter()
+            .find(|account_type| account_set_ids.contains(&self.account_set_id_for(*account_type)))
+    }
+
+    pub fn account_set_id_for(&self, deposit_account_type: DepositAccountType) -> CalaAccountSetId {
+        match deposit_account_type {
+            DepositAccountType::Individual => self.individual_account_set_id,
+            DepositAccountType::GovernmentEntity => self.government_entity_account_set_id,
+            DepositAccountType::PrivateCompany => self.private_company_account_set_id,
+            DepositAccountType::Bank => self.bank_account_set_id,
+            DepositAccountType::FinancialInstitution => self.financial_institution_account_set_id,
+            DepositAccountType::NonDomiciledCompany => self.non_domiciled_company_account_set_id,
+        }
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositProductId")]
+pub enum DepositProductEvent {
+    Initialized {
+        id: DepositProductId,
+        name: String,
+        category: DepositProductCategory,
+        rules: DepositProductRules,
+        ledger_account_ids: DepositProductLedgerAccountIds,
+        audit_info: AuditInfo,
+    },
+    RulesUpdated {
+        rules: DepositProductRules,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositProduct {
+    pub id: DepositProductId,
+    pub name: String,
+    pub category: DepositProductCategory,
+    pub rules: DepositProductRules,
+    pub ledger_account_ids: DepositProductLedgerAccountIds,
+
+    events: EntityEvents<DepositProductEvent>,
+}
+
+impl DepositProduct {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("Deposit Product has never been persisted")
+    }
+
+    pub fn update_rules(
+        &mut self,
+        rules: DepositProductRules,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.rules == rules {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositProductEvent::RulesUpdated {
+            rules: rules.clone(),
+            audit_info,
+        });
+        self.rules = rules;
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<DepositProductEvent> for DepositProduct {
+    fn try_from_events(events: EntityEvents<DepositProductEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = DepositProductBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized {
+                    id,
+                    name,
+                    category,
+                    rules,
+                    ledger_account_ids,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .name(name.to_string())
+                        .category(*category)
+                        .rules(rules.clone())
+                        .ledger_account_ids(*ledger_account_ids)
+                }
+                DepositProductEvent::RulesUpdated { rules, .. } => {
+                    builder = builder.rules(rules.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositProduct {
+    #[builder(setter(into))]
+    pub(super) id: DepositProductId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    pub(super) category: DepositProductCategory,
+    pub(super) rules: DepositProductRules,
+    pub(super) ledger_account_ids: DepositProductLedgerAccountIds,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositProduct {
+    pub fn builder() -> NewDepositProductBuilder {
+        NewDepositProductBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositProductEvent> for NewDepositProduct {
+    fn into_events(self) -> EntityEvents<DepositProductEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositProductEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                category: self.category,
+                rules: self.rules,
+                ledger_account_ids: self.ledger_account_ids,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn product() -> DepositProduct {
+        let id = DepositProductId::new();
+        let events = EntityEvents::init(
+            id,
+            [DepositProductEvent::Initialized {
+                id,
+                name: "Savings".to_string(),
+                category: DepositProductCategory::Savings,
+                rules: DepositProductRules::default(),
+                ledger_account_ids: DepositProductLedgerAccountIds::new(),
+                audit_info: dummy_audit_info(),
+            }],
+        );
+        DepositProduct::try_from_events(events).unwrap()
+    }
+
+    #[test]
+    fn update_rules_is_idempotent() {
+        let mut product = product();
+        let rules = DepositProductRules {
+            minimum_balance: UsdCents::from(10_000),
+            ..Default::default()
+        };
+
+        assert!(
+            product
+                .update_rules(rules.clone(), dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(product.rules, rules);
+        assert!(
+            product
+                .update_rules(rules, dummy_audit_info())
+                .was_ignored()
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::primitives::UsdCents;
+
+use super::rules::{DepositOperation, WithdrawalLimitPeriod};
+
+#[derive(Error, Debug)]
+pub enum DepositProductError {
+    #[error("DepositProductError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositProductError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("DepositProductError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositProductError - OperationNotAllowed: {0}")]
+    OperationNotAllowed(DepositOperation),
+    #[error("DepositProductError - MinimumBalanceBreached: {0}")]
+    MinimumBalanceBreached(UsdCents),
+    #[error("DepositProductError - WithdrawalFrequencyLimitReached: {0} per {1}")]
+    WithdrawalFrequencyLimitReached(u32, WithdrawalLimitPeriod),
+}
+
+es_entity::from_es_entity_error!(DepositProductError);
@@ -0,0 +1,11 @@
+mod entity;
+pub mod error;
+mod repo;
+mod rules;
+
+#[cfg(feature = "json-schema")]
+pub use entity::DepositProductEvent;
+pub(crate) use entity::*;
+pub use entity::{DepositProduct, DepositProductLedgerAccountIds};
+pub(crate) use repo::*;
+pub use rules::*;
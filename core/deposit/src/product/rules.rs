@@ -0,0 +1,245 @@
+use chrono::{DateTime, Datelike, Days, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    interest::{DepositInterestTerms, DepositRatePct},
+    primitives::UsdCents,
+};
+
+use super::error::DepositProductError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DepositProductCategory {
+    Checking,
+    Savings,
+    TermDeposit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DepositOperation {
+    Deposit,
+    Withdrawal,
+}
+
+impl DepositOperation {
+    pub const ALL: [DepositOperation; 2] =
+        [DepositOperation::Deposit, DepositOperation::Withdrawal];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WithdrawalLimitPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl WithdrawalLimitPeriod {
+    /// Start of the calendar period containing `at`. Weeks start on Monday.
+    pub(crate) fn start(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let date = at.date_naive();
+        let start = match self {
+            Self::Daily => date,
+            Self::Weekly => date - Days::new(date.weekday().num_days_from_monday().into()),
+            Self::Monthly => date.with_day(1).expect("should return a valid date"),
+        };
+        start
+            .and_hms_opt(0, 0, 0)
+            .expect("should return a valid time")
+            .and_utc()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalFrequencyLimit {
+    pub max_withdrawals: u32,
+    pub period: WithdrawalLimitPeriod,
+}
+
+/// Settings of products that open fixed-term deposits. The penalty for
+/// breaking a deposit before maturity is a percentage of its principal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct TermDepositRules {
+    pub term_months: u32,
+    pub early_break_penalty: DepositRatePct,
+}
+
+/// Constraints every account opened on a product is held to. Interest terms
+/// are copied onto the accounts, the other rules are checked against the
+/// product whenever an operation is initiated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositProductRules {
+    pub allowed_operations: Vec<DepositOperation>,
+    pub minimum_balance: UsdCents,
+    pub withdrawal_frequency_limit: Option<WithdrawalFrequencyLimit>,
+    pub interest_terms: Option<DepositInterestTerms>,
+    #[serde(default)]
+    pub term_deposit: Option<TermDepositRules>,
+}
+
+impl Default for DepositProductRules {
+    fn default() -> Self {
+        Self {
+            allowed_operations: DepositOperation::ALL.to_vec(),
+            minimum_balance: UsdCents::ZERO,
+            withdrawal_frequency_limit: None,
+            interest_terms: None,
+            term_deposit: None,
+        }
+    }
+}
+
+impl DepositProductRules {
+    pub fn allows(&self, operation: DepositOperation) -> bool {
+        self.allowed_operations.contains(&operation)
+    }
+
+    pub(crate) fn check_operation(
+        &self,
+        operation: DepositOperation,
+    ) -> Result<(), DepositProductError> {
+        if !self.allows(operation) {
+            return Err(DepositProductError::OperationNotAllowed(operation));
+        }
+        Ok(())
+    }
+
+    /// Checks that apply to any operation taking funds out of an account,
+    /// be it a withdrawal or an outgoing transfer.
+    pub(crate) fn check_debit(
+        &self,
+        amount: UsdCents,
+        settled_balance: UsdCents,
+    ) -> Result<(), DepositProductError> {
+        self.check_operation(DepositOperation::Withdrawal)?;
+
+        if settled_balance < amount + self.minimum_balance {
+            return Err(DepositProductError::MinimumBalanceBreached(
+                self.minimum_balance,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// `withdrawals_in_period` counts the withdrawals and outgoing transfers
+    /// already initiated in the current limit period that have not been
+    /// cancelled, denied or failed.
+    pub(crate) fn check_withdrawal(
+        &self,
+        amount: UsdCents,
+        settled_balance: UsdCents,
+        withdrawals_in_period: usize,
+    ) -> Result<(), DepositProductError> {
+        self.check_debit(amount, settled_balance)?;
+
+        if let Some(limit) = self.withdrawal_frequency_limit {
+            if withdrawals_in_period >= limit.max_withdrawals as usize {
+                return Err(DepositProductError::WithdrawalFrequencyLimitReached(
+                    limit.max_withdrawals,
+                    limit.period,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn savings_rules() -> DepositProductRules {
+        DepositProductRules {
+            allowed_operations: DepositOperation::ALL.to_vec(),
+            minimum_balance: UsdCents::from(10_000),
+            withdrawal_frequency_limit: Some(WithdrawalFrequencyLimit {
+                max_withdrawals: 2,
+                period: WithdrawalLimitPeriod::Monthly,
+            }),
+            interest_terms: None,
+            term_deposit: None,
+        }
+    }
+
+    #[test]
+    fn withdrawal_must_be_an_allowed_operation() {
+        let rules = DepositProductRules {
+            allowed_operations: vec![DepositOperation::Deposit],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            rules.check_withdrawal(UsdCents::from(100), UsdCents::from(1_000), 0),
+            Err(DepositProductError::OperationNotAllowed(
+                DepositOperation::Withdrawal
+            ))
+        ));
+    }
+
+    #[test]
+    fn withdrawal_keeps_minimum_balance() {
+        let rules = savings_rules();
+
+        assert!(
+            rules
+                .check_withdrawal(UsdCents::from(90_000), UsdCents::from(100_000), 0)
+                .is_ok()
+        );
+        assert!(matches!(
+            rules.check_withdrawal(UsdCents::from(90_001), UsdCents::from(100_000), 0),
+            Err(DepositProductError::MinimumBalanceBreached(_))
+        ));
+    }
+
+    #[test]
+    fn withdrawal_frequency_is_limited_per_period() {
+        let rules = savings_rules();
+
+        assert!(
+            rules
+                .check_withdrawal(UsdCents::from(100), UsdCents::from(100_000), 1)
+                .is_ok()
+        );
+        assert!(matches!(
+            rules.check_withdrawal(UsdCents::from(100), UsdCents::from(100_000), 2),
+            Err(DepositProductError::WithdrawalFrequencyLimitReached(
+                2,
+                WithdrawalLimitPeriod::Monthly
+            ))
+        ));
+    }
+
+    #[test]
+    fn limit_periods_start_on_calendar_boundaries() {
+        let at = "2025-03-14T09:26:53Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            WithdrawalLimitPeriod::Daily.start(at),
+            "2025-03-14T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            WithdrawalLimitPeriod::Weekly.start(at),
+            "2025-03-10T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            WithdrawalLimitPeriod::Monthly.start(at),
+            "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+}
@@ -4,6 +4,7 @@ use super::event::CoreDepositEvent;
 use crate::{
     account::{DepositAccount, DepositAccountEvent, error::DepositAccountError},
     deposit::{Deposit, DepositEvent, error::DepositError},
+    transfer::{Transfer, TransferEvent, error::TransferError},
     withdrawal::{Withdrawal, WithdrawalEvent, error::WithdrawalError},
 };
 
@@ -88,12 +89,50 @@ where
     ) -> Result<(), DepositError> {
         use DepositEvent::*;
         let publish_events = new_events
-            .map(|event| match &event.event {
-                Initialized { .. } => CoreDepositEvent::DepositInitialized {
+            .filter_map(|event| match &event.event {
+                Initialized { .. } => Some(CoreDepositEvent::DepositInitialized {
+                    id: entity.id,
+                    deposit_account_id: entity.deposit_account_id,
+                    amount: entity.amount,
+                }),
+                Reverted { .. } => Some(CoreDepositEvent::DepositReverted {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
                     amount: entity.amount,
-                },
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_transfer(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .flat_map(|event| match &event.event {
+                Executed { .. } => vec![
+                    CoreDepositEvent::TransferSent {
+                        id: entity.id,
+                        deposit_account_id: entity.from_account_id,
+                        counterparty_account_id: entity.to_account_id,
+                        amount: entity.amount,
+                    },
+                    CoreDepositEvent::TransferReceived {
+                        id: entity.id,
+                        deposit_account_id: entity.to_account_id,
+                        counterparty_account_id: entity.from_account_id,
+                        amount: entity.amount,
+                    },
+                ],
+                _ => vec![],
             })
             .collect::<Vec<_>>();
         self.outbox
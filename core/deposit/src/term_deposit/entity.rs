@@ -0,0 +1,469 @@
+use chrono::{Months, NaiveDate};
+use derive_builder::Builder;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::{interest::DepositInterestTerms, primitives::*, product::TermDepositRules};
+
+use super::{TermDepositPostingData, TermDepositSettlement, error::TermDepositError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TermDepositMaturityInstruction {
+    PayOut,
+    RollOver,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TermDepositStatus {
+    Active,
+    Matured,
+    Broken,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TermDepositId")]
+pub enum TermDepositEvent {
+    Initialized {
+        id: TermDepositId,
+        deposit_account_id: DepositAccountId,
+        product_id: DepositProductId,
+        ledger_account_id: CalaAccountId,
+        ledger_tx_id: CalaTransactionId,
+        principal: UsdCents,
+        interest_terms: Option<DepositInterestTerms>,
+        rules: TermDepositRules,
+        maturity_instruction: TermDepositMaturityInstruction,
+        starts_on: NaiveDate,
+        matures_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    RolledOver {
+        ledger_tx_id: CalaTransactionId,
+        interest: UsdCents,
+        starts_on: NaiveDate,
+        matures_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Matured {
+        ledger_tx_id: CalaTransactionId,
+        interest_ledger_tx_id: CalaTransactionId,
+        interest: UsdCents,
+        matured_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Broken {
+        ledger_tx_id: CalaTransactionId,
+        penalty_ledger_tx_id: CalaTransactionId,
+        penalty: UsdCents,
+        broken_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct TermDeposit {
+    pub id: TermDepositId,
+    pub deposit_account_id: DepositAccountId,
+    pub product_id: DepositProductId,
+    pub principal: UsdCents,
+    pub interest_terms: Option<DepositInterestTerms>,
+    pub rules: TermDepositRules,
+    pub maturity_instruction: TermDepositMaturityInstruction,
+    pub starts_on: NaiveDate,
+    pub matures_on: NaiveDate,
+    pub status: TermDepositStatus,
+
+    events: EntityEvents<TermDepositEvent>,
+}
+
+pub(crate) fn maturity_date(starts_on: NaiveDate, term_months: u32) -> NaiveDate {
+    starts_on
+        .checked_add_months(Months::new(term_months))
+        .expect("should return a valid date")
+}
+
+impl TermDeposit {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("Term Deposit has never been persisted")
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == TermDepositStatus::Active
+    }
+
+    /// Interest earned over the current term, accrued daily on the principal
+    /// and rounded down to whole cents.
+    pub fn interest_for_term(&self) -> UsdCents {
+        let Some(terms) = self.interest_terms else {
+            return UsdCents::ZERO;
+        };
+        let interest: Decimal = self
+            .starts_on
+            .iter_days()
+            .take_while(|date| date < &self.matures_on)
+            .map(|date| terms.daily_interest(self.principal, date))
+            .sum();
+        UsdCents::from(
+            interest
+                .floor()
+                .to_u64()
+                .expect("should return a valid integer"),
+        )
+    }
+
+    pub fn early_break_penalty(&self) -> UsdCents {
+        let penalty = UsdCents::from(
+            self.rules
+                .early_break_penalty
+                .of(self.principal)
+                .floor()
+                .to_u64()
+                .expect("should return a valid integer"),
+        );
+        penalty.min(self.principal)
+    }
+
+    /// Pays out principal and interest to the linked deposit account or, when
+    /// rolling over, pays out the interest and starts a new term on the same
+    /// principal.
+    pub fn mature(
+        &mut self,
+        date: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<TermDepositSettlement> {
+        if !self.is_active() || date < self.matures_on {
+            return Idempotent::Ignored;
+        }
+
+        let matured_on = self.matures_on;
+        let interest = TermDepositPostingData {
+            tx_id: CalaTransactionId::new(),
+            amount: self.interest_for_term(),
+        };
+        let release = match self.maturity_instruction {
+            TermDepositMaturityInstruction::PayOut => {
+                let release = TermDepositPostingData {
+                    tx_id: CalaTransactionId::new(),
+                    amount: self.principal,
+                };
+                self.events.push(TermDepositEvent::Matured {
+                    ledger_tx_id: release.tx_id,
+                    interest_ledger_tx_id: interest.tx_id,
+                    interest: interest.amount,
+                    matured_on,
+                    audit_info,
+                });
+                self.status = TermDepositStatus::Matured;
+                Some(release)
+            }
+            TermDepositMaturityInstruction::RollOver => {
+                let starts_on = matured_on;
+                let matures_on = maturity_date(starts_on, self.rules.term_months);
+                self.events.push(TermDepositEvent::RolledOver {
+                    ledger_tx_id: interest.tx_id,
+                    interest: interest.amount,
+                    starts_on,
+                    matures_on,
+                    audit_info,
+                });
+                self.starts_on = starts_on;
+                self.matures_on = matures_on;
+                None
+            }
+        };
+
+        Idempotent::Executed(TermDepositSettlement {
+            term_deposit_id: self.id,
+            deposit_account_id: self.deposit_account_id,
+            effective: matured_on,
+            release,
+            interest: Some(interest).filter(|interest| !interest.amount.is_zero()),
+            penalty: None,
+        })
+    }
+
+    /// Releases the principal before maturity, less the early break penalty.
+    /// Interest of the current term is forfeited.
+    pub fn break_early(
+        &mut self,
+        date: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<TermDepositSettlement, TermDepositError> {
+        if !self.is_active() {
+            return Err(TermDepositError::NotActive);
+        }
+        if date >= self.matures_on {
+            return Err(TermDepositError::AlreadyMatured);
+        }
+
+        let penalty = TermDepositPostingData {
+            tx_id: CalaTransactionId::new(),
+            amount: self.early_break_penalty(),
+        };
+        let release = TermDepositPostingData {
+            tx_id: CalaTransactionId::new(),
+            amount: self.principal - penalty.amount,
+        };
+        self.events.push(TermDepositEvent::Broken {
+            ledger_tx_id: release.tx_id,
+            penalty_ledger_tx_id: penalty.tx_id,
+            penalty: penalty.amount,
+            broken_on: date,
+            audit_info,
+        });
+        self.status = TermDepositStatus::Broken;
+
+        Ok(TermDepositSettlement {
+            term_deposit_id: self.id,
+            deposit_account_id: self.deposit_account_id,
+            effective: date,
+            release: Some(release).filter(|release| !release.amount.is_zero()),
+            interest: None,
+            penalty: Some(penalty).filter(|penalty| !penalty.amount.is_zero()),
+        })
+    }
+}
+
+impl TryFromEvents<TermDepositEvent> for TermDeposit {
+    fn try_from_events(events: EntityEvents<TermDepositEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TermDepositBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                TermDepositEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    product_id,
+                    principal,
+                    interest_terms,
+                    rules,
+                    maturity_instruction,
+                    starts_on,
+                    matures_on,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .product_id(*product_id)
+                        .principal(*principal)
+                        .interest_terms(*interest_terms)
+                        .rules(*rules)
+                        .maturity_instruction(*maturity_instruction)
+                        .starts_on(*starts_on)
+                        .matures_on(*matures_on)
+                        .status(TermDepositStatus::Active)
+                }
+                TermDepositEvent::RolledOver {
+                    starts_on,
+                    matures_on,
+                    ..
+                } => {
+                    builder = builder.starts_on(*starts_on).matures_on(*matures_on);
+                }
+                TermDepositEvent::Matured { .. } => {
+                    builder = builder.status(TermDepositStatus::Matured);
+                }
+                TermDepositEvent::Broken { .. } => {
+                    builder = builder.status(TermDepositStatus::Broken);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewTermDeposit {
+    #[builder(setter(into))]
+    pub(super) id: TermDepositId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) product_id: DepositProductId,
+    #[builder(setter(into))]
+    pub(super) ledger_tx_id: CalaTransactionId,
+    pub(super) principal: UsdCents,
+    pub(super) interest_terms: Option<DepositInterestTerms>,
+    pub(super) rules: TermDepositRules,
+    pub(super) maturity_instruction: TermDepositMaturityInstruction,
+    pub(super) starts_on: NaiveDate,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTermDeposit {
+    pub fn builder() -> NewTermDepositBuilder {
+        NewTermDepositBuilder::default()
+    }
+
+    pub(crate) fn matures_on(&self) -> NaiveDate {
+        maturity_date(self.starts_on, self.rules.term_months)
+    }
+}
+
+impl IntoEvents<TermDepositEvent> for NewTermDeposit {
+    fn into_events(self) -> EntityEvents<TermDepositEvent> {
+        let matures_on = self.matures_on();
+        EntityEvents::init(
+            self.id,
+            [TermDepositEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                product_id: self.product_id,
+                ledger_account_id: self.id.into(),
+                ledger_tx_id: self.ledger_tx_id,
+                principal: self.principal,
+                interest_terms: self.interest_terms,
+                rules: self.rules,
+                maturity_instruction: self.maturity_instruction,
+                starts_on: self.starts_on,
+                matures_on,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use crate::interest::{DepositDayCountConvention, DepositRatePct};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn starts_on() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+    }
+
+    fn term_deposit(maturity_instruction: TermDepositMaturityInstruction) -> TermDeposit {
+        let new_term_deposit = NewTermDeposit::builder()
+            .id(TermDepositId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .product_id(DepositProductId::new())
+            .ledger_tx_id(CalaTransactionId::new())
+            .principal(UsdCents::from(1_000_000))
+            .interest_terms(Some(DepositInterestTerms {
+                annual_rate: DepositRatePct::from(dec!(3.65)),
+                day_count_convention: DepositDayCountConvention::Actual365,
+            }))
+            .rules(TermDepositRules {
+                term_months: 3,
+                early_break_penalty: DepositRatePct::from(dec!(1)),
+            })
+            .maturity_instruction(maturity_instruction)
+            .starts_on(starts_on())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        TermDeposit::try_from_events(new_term_deposit.into_events()).unwrap()
+    }
+
+    #[test]
+    fn interest_accrues_over_the_whole_term() {
+        let term_deposit = term_deposit(TermDepositMaturityInstruction::PayOut);
+
+        assert_eq!(
+            term_deposit.matures_on,
+            NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()
+        );
+        assert_eq!(term_deposit.interest_for_term(), UsdCents::from(9_000));
+    }
+
+    #[test]
+    fn does_not_mature_before_maturity_date() {
+        let mut term_deposit = term_deposit(TermDepositMaturityInstruction::PayOut);
+
+        assert!(
+            term_deposit
+                .mature(
+                    NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn pays_out_principal_and_interest_at_maturity() {
+        let mut term_deposit = term_deposit(TermDepositMaturityInstruction::PayOut);
+        let matures_on = term_deposit.matures_on;
+
+        let settlement = term_deposit.mature(matures_on, dummy_audit_info()).unwrap();
+
+        assert_eq!(term_deposit.status, TermDepositStatus::Matured);
+        assert_eq!(settlement.release.unwrap().amount, term_deposit.principal);
+        assert_eq!(settlement.interest.unwrap().amount, UsdCents::from(9_000));
+        assert!(
+            term_deposit
+                .mature(matures_on, dummy_audit_info())
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn rolls_over_into_a_new_term() {
+        let mut term_deposit = term_deposit(TermDepositMaturityInstruction::RollOver);
+        let matures_on = term_deposit.matures_on;
+
+        let settlement = term_deposit.mature(matures_on, dummy_audit_info()).unwrap();
+
+        assert!(settlement.release.is_none());
+        assert!(settlement.interest.is_some());
+        assert!(term_deposit.is_active());
+        assert_eq!(term_deposit.starts_on, matures_on);
+        assert_eq!(
+            term_deposit.matures_on,
+            NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn early_break_charges_penalty() {
+        let mut term_deposit = term_deposit(TermDepositMaturityInstruction::PayOut);
+
+        let settlement = term_deposit
+            .break_early(
+                NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+
+        assert_eq!(term_deposit.status, TermDepositStatus::Broken);
+        assert_eq!(settlement.penalty.unwrap().amount, UsdCents::from(10_000));
+        assert_eq!(settlement.release.unwrap().amount, UsdCents::from(990_000));
+        assert!(settlement.interest.is_none());
+        assert!(matches!(
+            term_deposit.break_early(
+                NaiveDate::from_ymd_opt(2025, 2, 2).unwrap(),
+                dummy_audit_info()
+            ),
+            Err(TermDepositError::NotActive)
+        ));
+    }
+}
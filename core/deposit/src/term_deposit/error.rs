@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TermDepositError {
+    #[error("TermDepositError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TermDepositError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TermDepositError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TermDepositError - NotATermDepositProduct")]
+    NotATermDepositProduct,
+    #[error("TermDepositError - NotActive")]
+    NotActive,
+    #[error("TermDepositError - AlreadyMatured")]
+    AlreadyMatured,
+}
+
+es_entity::from_es_entity_error!(TermDepositError);
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::{ledger::DepositLedger, primitives::*};
+
+use super::repo::TermDepositRepo;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TermDepositMaturityJobConfig<Perms> {
+    pub term_deposit_id: TermDepositId,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> JobConfig for TermDepositMaturityJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = TermDepositMaturityInit<Perms>;
+}
+
+pub struct TermDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+{
+    term_deposits: TermDepositRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> TermDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        term_deposits: &TermDepositRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            term_deposits: term_deposits.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const TERM_DEPOSIT_MATURITY_JOB: JobType = JobType::new("term-deposit-maturity");
+impl<Perms> JobInitializer for TermDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        TERM_DEPOSIT_MATURITY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(TermDepositMaturityJobRunner::<Perms> {
+            config: job.config()?,
+            term_deposits: self.term_deposits.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub(crate) fn maturity_runs_at(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .expect("should return a valid time")
+        .and_utc()
+}
+
+pub struct TermDepositMaturityJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: TermDepositMaturityJobConfig<Perms>,
+    term_deposits: TermDepositRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for TermDepositMaturityJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut db = self.term_deposits.begin_op().await?;
+        let mut term_deposit = self
+            .term_deposits
+            .find_by_id_in_tx(db.tx(), self.config.term_deposit_id)
+            .await?;
+        if !term_deposit.is_active() {
+            return Ok(JobCompletion::Complete);
+        }
+        let runs_at = maturity_runs_at(term_deposit.matures_on);
+        if runs_at > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(runs_at));
+        }
+
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::term_deposit(term_deposit.id),
+                CoreDepositAction::TERM_DEPOSIT_MATURE,
+            )
+            .await?;
+        let es_entity::Idempotent::Executed(settlement) =
+            term_deposit.mature(crate::time::now().date_naive(), audit_info)
+        else {
+            return Ok(JobCompletion::Complete);
+        };
+        self.term_deposits
+            .update_in_op(&mut db, &mut term_deposit)
+            .await?;
+        self.ledger.settle_term_deposit(db, settlement).await?;
+
+        if term_deposit.is_active() {
+            return Ok(JobCompletion::RescheduleAt(maturity_runs_at(
+                term_deposit.matures_on,
+            )));
+        }
+        Ok(JobCompletion::Complete)
+    }
+}
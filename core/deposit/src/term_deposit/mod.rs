@@ -0,0 +1,33 @@
+mod entity;
+pub mod error;
+mod job;
+mod repo;
+
+use chrono::NaiveDate;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, TermDepositId, UsdCents};
+
+#[cfg(feature = "json-schema")]
+pub use entity::TermDepositEvent;
+pub(crate) use entity::*;
+pub use entity::{TermDeposit, TermDepositMaturityInstruction, TermDepositStatus};
+pub(crate) use job::*;
+pub(crate) use repo::*;
+
+#[derive(Debug, Clone)]
+pub struct TermDepositPostingData {
+    pub tx_id: CalaTransactionId,
+    pub amount: UsdCents,
+}
+
+/// Ledger postings settling a term deposit at maturity, on rollover or on an
+/// early break. Zero amounts carry no posting.
+#[derive(Debug, Clone)]
+pub struct TermDepositSettlement {
+    pub term_deposit_id: TermDepositId,
+    pub deposit_account_id: DepositAccountId,
+    pub effective: NaiveDate,
+    pub release: Option<TermDepositPostingData>,
+    pub interest: Option<TermDepositPostingData>,
+    pub penalty: Option<TermDepositPostingData>,
+}
@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, TermDepositId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "TermDeposit",
+    err = "TermDepositError",
+    columns(deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct TermDepositRepo {
+    pool: PgPool,
+}
+
+impl TermDepositRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
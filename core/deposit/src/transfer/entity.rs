@@ -0,0 +1,387 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, TransferId, UsdCents,
+};
+use audit::AuditInfo;
+
+use super::error::TransferError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TransferStatus {
+    PendingApproval,
+    Denied,
+    Failed,
+    Completed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Executed {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+    Failed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub reference: String,
+    pub approval_process_id: Option<ApprovalProcessId>,
+
+    events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    /// Both legs of a transfer are posted in a single ledger transaction
+    /// that shares the id of the transfer.
+    pub fn ledger_tx_id(&self) -> CalaTransactionId {
+        self.id.into()
+    }
+
+    pub fn execute(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, TransferError> {
+        if self.is_executed() {
+            return Err(TransferError::AlreadyExecuted(self.id));
+        }
+
+        if self.has_failed() {
+            return Err(TransferError::Failed(self.id));
+        }
+
+        if self.approval_process_id.is_some() && self.is_approved_or_denied() != Some(true) {
+            return Err(TransferError::NotApproved(self.id));
+        }
+
+        let ledger_tx_id = self.ledger_tx_id();
+        self.events.push(TransferEvent::Executed {
+            ledger_tx_id,
+            audit_info,
+        });
+
+        Ok(ledger_tx_id)
+    }
+
+    pub fn is_executed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, TransferEvent::Executed { .. }))
+    }
+
+    /// Records that an approved transfer could not be posted because the
+    /// accounts or their products no longer allow it.
+    pub fn fail(&mut self, reason: String, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::Failed { .. } | TransferEvent::Executed { .. }
+        );
+        self.events
+            .push(TransferEvent::Failed { reason, audit_info });
+        Idempotent::Executed(())
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, TransferEvent::Failed { .. }))
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| {
+            if let TransferEvent::ApprovalProcessConcluded { approved, .. } = e {
+                Some(*approved)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        if self.is_executed() {
+            TransferStatus::Completed
+        } else if self.has_failed() {
+            TransferStatus::Failed
+        } else {
+            match self.is_approved_or_denied() {
+                Some(false) => TransferStatus::Denied,
+                _ => TransferStatus::PendingApproval,
+            }
+        }
+    }
+
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::ApprovalProcessConcluded { .. }
+        );
+        let Some(approval_process_id) = self.approval_process_id else {
+            return Idempotent::Ignored;
+        };
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id,
+            approved,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                TransferEvent::Initialized {
+                    id,
+                    from_account_id,
+                    to_account_id,
+                    amount,
+                    reference,
+                    approval_process_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .from_account_id(*from_account_id)
+                        .to_account_id(*to_account_id)
+                        .amount(*amount)
+                        .reference(reference.clone())
+                        .approval_process_id(*approval_process_id)
+                }
+                TransferEvent::ApprovalProcessConcluded { .. } => (),
+                TransferEvent::Executed { .. } => (),
+                TransferEvent::Failed { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) from_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) to_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewTransferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(amount) = self.amount {
+            if amount.is_zero() {
+                return Err("Transfer amount cannot be zero".to_string());
+            }
+        }
+        match (self.from_account_id, self.to_account_id) {
+            (Some(from), Some(to)) if from == to => {
+                Err("Cannot transfer to the same account".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                from_account_id: self.from_account_id,
+                to_account_id: self.to_account_id,
+                amount: self.amount,
+                approval_process_id: self.approval_process_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn transfer(approval_process_id: Option<ApprovalProcessId>) -> Transfer {
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .approval_process_id(approval_process_id)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Transfer::try_from_events(new_transfer.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_transferring_to_same_account() {
+        let account_id = DepositAccountId::new();
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(account_id)
+            .to_account_id(account_id)
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn errors_when_zero_amount_transfer_is_passed() {
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn executes_without_approval_process() {
+        let mut transfer = transfer(None);
+
+        assert_eq!(
+            transfer.execute(dummy_audit_info()).unwrap(),
+            transfer.ledger_tx_id()
+        );
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+        assert!(matches!(
+            transfer.execute(dummy_audit_info()),
+            Err(TransferError::AlreadyExecuted(_))
+        ));
+    }
+
+    #[test]
+    fn executes_only_once_approved() {
+        let mut transfer = transfer(Some(ApprovalProcessId::new()));
+        assert!(matches!(
+            transfer.execute(dummy_audit_info()),
+            Err(TransferError::NotApproved(_))
+        ));
+
+        assert!(
+            transfer
+                .approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(transfer.execute(dummy_audit_info()).is_ok());
+    }
+
+    #[test]
+    fn denied_transfer_is_not_executed() {
+        let mut transfer = transfer(Some(ApprovalProcessId::new()));
+        let _ = transfer.approval_process_concluded(false, dummy_audit_info());
+
+        assert_eq!(transfer.status(), TransferStatus::Denied);
+        assert!(matches!(
+            transfer.execute(dummy_audit_info()),
+            Err(TransferError::NotApproved(_))
+        ));
+    }
+
+    #[test]
+    fn failed_transfer_is_not_executed() {
+        let mut transfer = transfer(Some(ApprovalProcessId::new()));
+        let _ = transfer.approval_process_concluded(true, dummy_audit_info());
+
+        assert!(
+            transfer
+                .fail("account inactive".to_string(), dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(transfer.status(), TransferStatus::Failed);
+        assert!(matches!(
+            transfer.execute(dummy_audit_info()),
+            Err(TransferError::Failed(_))
+        ));
+    }
+}
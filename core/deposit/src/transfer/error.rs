@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::primitives::TransferId;
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("TransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TransferError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TransferError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TransferError - AlreadyExecuted: {0}")]
+    AlreadyExecuted(TransferId),
+    #[error("TransferError - NotApproved: {0}")]
+    NotApproved(TransferId),
+    #[error("TransferError - Failed: {0}")]
+    Failed(TransferId),
+}
+
+es_entity::from_es_entity_error!(TransferError);
@@ -0,0 +1,9 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::TransferEvent;
+pub(crate) use entity::*;
+pub use entity::{Transfer, TransferStatus};
+pub(crate) use repo::*;
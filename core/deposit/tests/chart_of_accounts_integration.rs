@@ -29,6 +29,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -54,6 +55,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Bank Deposit Accounts
         5,Financial Institution Deposit Accounts
         6,Non Domiciled Individual Deposit Accounts
+        8,Interest Expense
         "#
     .to_string();
     let chart_id = chart.id;
@@ -76,6 +78,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
             ChartOfAccountsIntegrationConfig::builder()
                 .chart_of_accounts_id(chart_id)
                 .chart_of_accounts_omnibus_parent_code("2".parse().unwrap())
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
                 .chart_of_accounts_individual_deposit_accounts_parent_code("1".parse().unwrap())
                 .chart_of_accounts_government_entity_deposit_accounts_parent_code(
                     "7".parse().unwrap(),
@@ -114,6 +117,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Other Bank Deposit Accounts
         5,Other Financial Institution Deposit Accounts
         6,Other Non Domiciled Individual Deposit Accounts
+        8,Other Interest Expense
         "#
     .to_string();
     let chart_id = chart.id;
@@ -129,6 +133,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
             ChartOfAccountsIntegrationConfig::builder()
                 .chart_of_accounts_id(chart_id)
                 .chart_of_accounts_omnibus_parent_code("2".parse().unwrap())
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
                 .chart_of_accounts_individual_deposit_accounts_parent_code("1".parse().unwrap())
                 .chart_of_accounts_government_entity_deposit_accounts_parent_code(
                     "7".parse().unwrap(),
@@ -26,6 +26,7 @@ async fn deposit() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -40,6 +41,7 @@ async fn deposit() -> anyhow::Result<()> {
         .create_account(
             &DummySubject,
             account_holder_id,
+            deposit.default_product_id(),
             true,
             DepositAccountType::Individual,
         )
@@ -63,3 +65,153 @@ async fn deposit() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn revert_deposit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            deposit.default_product_id(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    let recorded = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let reverted = deposit
+        .revert_deposit(
+            &DummySubject,
+            recorded.id,
+            DepositReversalReason::Mistaken,
+            false,
+        )
+        .await?;
+    assert_eq!(reverted.status(), DepositStatus::Reverted);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, UsdCents::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transfer() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+    )
+    .await?;
+
+    let from_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            deposit.default_product_id(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+    let to_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            deposit.default_product_id(),
+            true,
+            DepositAccountType::Individual,
+        )
+        .await?;
+
+    deposit
+        .record_deposit(
+            &DummySubject,
+            from_account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let transfer = deposit
+        .transfer(
+            &DummySubject,
+            from_account.id,
+            to_account.id,
+            UsdCents::try_from_usd(dec!(40)).unwrap(),
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::Completed);
+
+    let from_balance = deposit
+        .account_balance(&DummySubject, from_account.id)
+        .await?;
+    assert_eq!(
+        from_balance.settled,
+        UsdCents::try_from_usd(dec!(60)).unwrap()
+    );
+    let to_balance = deposit
+        .account_balance(&DummySubject, to_account.id)
+        .await?;
+    assert_eq!(
+        to_balance.settled,
+        UsdCents::try_from_usd(dec!(40)).unwrap()
+    );
+
+    Ok(())
+}
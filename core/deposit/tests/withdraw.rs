@@ -27,6 +27,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -41,6 +42,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         .create_account(
             &DummySubject,
             account_holder_id,
+            deposit.default_product_id(),
             true,
             DepositAccountType::Individual,
         )
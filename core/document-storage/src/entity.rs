@@ -86,6 +86,16 @@ impl Document {
             .expect("entity_first_persisted_at not found")
     }
 
+    pub fn document_type(&self) -> &DocumentType {
+        self.events
+            .iter_all()
+            .find_map(|event| match event {
+                DocumentEvent::Initialized { document_type, .. } => Some(document_type),
+                _ => None,
+            })
+            .expect("Entity was not Initialized")
+    }
+
     pub fn upload_file(&mut self) -> Idempotent<()> {
         idempotency_guard!(self.events.iter_all(), DocumentEvent::FileUploaded { .. });
 
@@ -78,6 +78,8 @@ pub enum ConversionError {
     DecimalError(#[from] rust_decimal::Error),
     #[error("ConversionError - UnexpectedNegativeNumber: {0}")]
     UnexpectedNegativeNumber(rust_decimal::Decimal),
+    #[error("ConversionError - NotSatoshis: {0}")]
+    NotSatoshis(CollateralAsset),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -307,3 +309,126 @@ impl std::ops::Mul<u64> for UsdCents {
         Self(self.0 * rhs)
     }
 }
+
+/// An asset that can be pledged as collateral. Amounts of an asset are held
+/// as integer counts of its smallest tracked unit, `10^-decimals` of one whole
+/// unit (satoshis for BTC).
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CollateralAsset {
+    #[default]
+    Btc,
+    Eth,
+    Usdt,
+    TokenizedTreasury,
+}
+
+impl CollateralAsset {
+    pub const ALL: [CollateralAsset; 4] = [
+        CollateralAsset::Btc,
+        CollateralAsset::Eth,
+        CollateralAsset::Usdt,
+        CollateralAsset::TokenizedTreasury,
+    ];
+
+    pub const fn decimals(&self) -> u32 {
+        match self {
+            CollateralAsset::Btc => 8,
+            CollateralAsset::Eth => 9,
+            CollateralAsset::Usdt => 6,
+            CollateralAsset::TokenizedTreasury => 6,
+        }
+    }
+
+    pub const fn currency_code(&self) -> &'static str {
+        match self {
+            CollateralAsset::Btc => "BTC",
+            CollateralAsset::Eth => "ETH",
+            CollateralAsset::Usdt => "USDT",
+            CollateralAsset::TokenizedTreasury => "USTB",
+        }
+    }
+
+    pub fn units_to_decimal(&self, units: u64) -> Decimal {
+        Decimal::from_i128_with_scale(units as i128, self.decimals())
+    }
+}
+
+impl fmt::Display for CollateralAsset {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.currency_code())
+    }
+}
+
+/// An amount of a collateral asset, counted in the smallest unit of that
+/// asset. Keeps units of different assets from being mixed up, as only BTC
+/// amounts are satoshis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct CollateralAmount {
+    asset: CollateralAsset,
+    units: u64,
+}
+
+impl CollateralAmount {
+    pub const fn new(asset: CollateralAsset, units: u64) -> Self {
+        Self { asset, units }
+    }
+
+    pub const fn zero(asset: CollateralAsset) -> Self {
+        Self::new(asset, 0)
+    }
+
+    pub const fn asset(&self) -> CollateralAsset {
+        self.asset
+    }
+
+    pub const fn units(&self) -> u64 {
+        self.units
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.units == 0
+    }
+
+    /// The amount in whole units of the asset.
+    pub fn to_decimal(&self) -> Decimal {
+        self.asset.units_to_decimal(self.units)
+    }
+
+    /// `None` if `other` is a different asset or larger than `self`.
+    pub fn checked_sub(self, other: CollateralAmount) -> Option<CollateralAmount> {
+        if self.asset != other.asset {
+            return None;
+        }
+        self.units
+            .checked_sub(other.units)
+            .map(|units| Self::new(self.asset, units))
+    }
+}
+
+impl From<Satoshis> for CollateralAmount {
+    fn from(sats: Satoshis) -> Self {
+        Self::new(CollateralAsset::Btc, sats.into_inner())
+    }
+}
+
+impl TryFrom<CollateralAmount> for Satoshis {
+    type Error = ConversionError;
+
+    fn try_from(amount: CollateralAmount) -> Result<Self, Self::Error> {
+        if amount.asset != CollateralAsset::Btc {
+            return Err(ConversionError::NotSatoshis(amount.asset));
+        }
+        Ok(Satoshis::from(amount.units))
+    }
+}
+
+impl fmt::Display for CollateralAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.asset)
+    }
+}
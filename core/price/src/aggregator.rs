@@ -0,0 +1,192 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use core_money::UsdCents;
+
+use crate::{
+    config::PriceConfig,
+    error::PriceError,
+    source::{PriceQuote, PriceSource},
+};
+
+/// Combines quotes from every configured source into a single price.
+///
+/// Quotes older than `max_age` are discarded, the median of the rest is
+/// taken, and any quote further than `max_deviation_pct` from it is rejected
+/// as an outlier. The final price is the median of the remaining quotes.
+pub(crate) struct PriceAggregator {
+    sources: Vec<Box<dyn PriceSource>>,
+    max_age: chrono::Duration,
+    max_deviation_pct: Decimal,
+    min_sources: usize,
+}
+
+impl PriceAggregator {
+    pub fn new(sources: Vec<Box<dyn PriceSource>>, config: &PriceConfig) -> Self {
+        Self {
+            sources,
+            max_age: chrono::Duration::from_std(config.max_age)
+                .expect("max_age should fit in chrono::Duration"),
+            max_deviation_pct: config.max_deviation_pct,
+            min_sources: config.min_sources.max(1),
+        }
+    }
+
+    pub async fn usd_cents_per_unit(&self) -> Result<UsdCents, PriceError> {
+        if self.sources.is_empty() {
+            return Err(PriceError::NoPriceSourcesConfigured);
+        }
+
+        let results =
+            futures::future::join_all(self.sources.iter().map(|source| source.fetch_usd_quote()))
+                .await;
+        let quotes = results
+            .into_iter()
+            .zip(self.sources.iter())
+            .filter_map(|(res, source)| match res {
+                Ok(quote) => Some(quote),
+                Err(e) => {
+                    tracing::warn!(source = source.name(), error = %e, "price source unavailable");
+                    None
+                }
+            })
+            .collect();
+
+        self.aggregate(quotes, Utc::now())
+    }
+
+    fn aggregate(
+        &self,
+        quotes: Vec<PriceQuote>,
+        now: DateTime<Utc>,
+    ) -> Result<UsdCents, PriceError> {
+        let mut fresh: Vec<Decimal> = quotes
+            .into_iter()
+            .filter(|quote| now - quote.observed_at <= self.max_age)
+            .map(|quote| quote.usd_per_unit)
+            .collect();
+        if fresh.len() < self.min_sources {
+            return Err(PriceError::StalePrice {
+                fresh: fresh.len(),
+                required: self.min_sources,
+            });
+        }
+
+        let median = median(&mut fresh);
+        let mut agreeing: Vec<Decimal> = fresh
+            .into_iter()
+            .filter(|price| {
+                (*price - median).abs() * Decimal::ONE_HUNDRED <= self.max_deviation_pct * median
+            })
+            .collect();
+        if agreeing.len() < self.min_sources {
+            return Err(PriceError::DisputedPrice {
+                agreeing: agreeing.len(),
+                required: self.min_sources,
+                median,
+            });
+        }
+
+        let price = median(&mut agreeing).round_dp(2);
+        Ok(UsdCents::try_from_usd(price)?)
+    }
+}
+
+fn median(prices: &mut [Decimal]) -> Decimal {
+    prices.sort();
+    let mid = prices.len() / 2;
+    if prices.len() % 2 == 0 {
+        (prices[mid - 1] + prices[mid]) / Decimal::TWO
+    } else {
+        prices[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn aggregator(min_sources: usize) -> PriceAggregator {
+        PriceAggregator::new(
+            vec![],
+            &PriceConfig {
+                min_sources,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn quote(usd_per_unit: Decimal, observed_at: DateTime<Utc>) -> PriceQuote {
+        PriceQuote {
+            source: "test".to_string(),
+            usd_per_unit,
+            observed_at,
+        }
+    }
+
+    #[test]
+    fn takes_median_of_fresh_quotes() {
+        let now = Utc::now();
+        let price = aggregator(1)
+            .aggregate(
+                vec![
+                    quote(dec!(100_000), now),
+                    quote(dec!(100_500), now),
+                    quote(dec!(101_000), now),
+                ],
+                now,
+            )
+            .unwrap();
+        assert_eq!(price, UsdCents::from(100_500_00));
+    }
+
+    #[test]
+    fn ignores_stale_quotes() {
+        let now = Utc::now();
+        let res = aggregator(2).aggregate(
+            vec![
+                quote(dec!(100_000), now),
+                quote(dec!(100_100), now - chrono::Duration::minutes(10)),
+            ],
+            now,
+        );
+        assert!(matches!(
+            res,
+            Err(PriceError::StalePrice {
+                fresh: 1,
+                required: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let now = Utc::now();
+        let price = aggregator(2)
+            .aggregate(
+                vec![
+                    quote(dec!(100_000), now),
+                    quote(dec!(100_200), now),
+                    quote(dec!(100_400), now),
+                    quote(dec!(50_000), now),
+                ],
+                now,
+            )
+            .unwrap();
+        assert_eq!(price, UsdCents::from(100_200_00));
+    }
+
+    #[test]
+    fn errors_when_sources_disagree() {
+        let now = Utc::now();
+        let res = aggregator(2).aggregate(
+            vec![quote(dec!(100_000), now), quote(dec!(120_000), now)],
+            now,
+        );
+        let err = res.unwrap_err();
+        assert!(matches!(err, PriceError::DisputedPrice { .. }));
+        assert!(err.is_unreliable_price());
+    }
+}
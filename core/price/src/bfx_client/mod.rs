@@ -1,6 +1,7 @@
 pub mod error;
 mod response;
 
+use chrono::{DateTime, Utc};
 use reqwest::Client as ReqwestClient;
 
 use error::BfxClientError;
@@ -23,7 +24,10 @@ impl BfxClient {
         }
     }
 
-    pub async fn btc_usd_tick(&self) -> Result<BtcUsdTick, BfxClientError> {
+    /// Returns the ticker along with the time Bitfinex served it, if reported.
+    pub async fn btc_usd_tick(
+        &self,
+    ) -> Result<(BtcUsdTick, Option<DateTime<Utc>>), BfxClientError> {
         let url = format!("{BASE_URL}ticker/tBTCUSD");
         let response = self
             .client
@@ -31,9 +35,10 @@ impl BfxClient {
             .header("accept", "application/json")
             .send()
             .await?;
+        let served_at = crate::source::response_date(response.headers());
         let tick = Self::extract_response_data::<BtcUsdTick>(response).await?;
 
-        Ok(tick)
+        Ok((tick, served_at))
     }
 
     async fn extract_response_data<T: serde::de::DeserializeOwned>(
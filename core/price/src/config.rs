@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use core_money::CollateralAsset;
+
+#[serde_with::serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PriceConfig {
+    #[serde(default = "default_sources")]
+    pub sources: Vec<PriceSourceConfig>,
+    /// Sources quoting the USD price of one unit of each non-BTC collateral
+    /// asset. Assets without an entry cannot be priced.
+    #[serde(default)]
+    pub asset_sources: HashMap<CollateralAsset, Vec<PriceSourceConfig>>,
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[serde(default = "default_max_age")]
+    pub max_age: Duration,
+    #[serde(default = "default_max_deviation_pct")]
+    pub max_deviation_pct: Decimal,
+    #[serde(default = "default_min_sources")]
+    pub min_sources: usize,
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[serde(default = "default_cache_duration")]
+    pub cache_duration: Duration,
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[serde(default = "default_record_interval")]
+    pub record_interval: Duration,
+    #[serde(default = "default_publish_threshold_pct")]
+    pub publish_threshold_pct: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PriceSourceConfig {
+    Bitfinex,
+    RestJson {
+        name: String,
+        url: String,
+        price_pointer: String,
+        #[serde(default)]
+        timestamp_pointer: Option<String>,
+    },
+    File {
+        path: PathBuf,
+    },
+}
+
+impl Default for PriceConfig {
+    fn default() -> Self {
+        Self {
+            sources: default_sources(),
+            asset_sources: HashMap::new(),
+            max_age: default_max_age(),
+            max_deviation_pct: default_max_deviation_pct(),
+            min_sources: default_min_sources(),
+            cache_duration: default_cache_duration(),
+            record_interval: default_record_interval(),
+            publish_threshold_pct: default_publish_threshold_pct(),
+        }
+    }
+}
+
+fn default_sources() -> Vec<PriceSourceConfig> {
+    vec![PriceSourceConfig::Bitfinex]
+}
+
+fn default_max_age() -> Duration {
+    Duration::from_secs(120)
+}
+
+fn default_max_deviation_pct() -> Decimal {
+    Decimal::from(2)
+}
+
+fn default_min_sources() -> usize {
+    1
+}
+
+fn default_cache_duration() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_record_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_publish_threshold_pct() -> Decimal {
+    Decimal::new(5, 1)
+}
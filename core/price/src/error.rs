@@ -1,9 +1,53 @@
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum PriceError {
-    #[error("PriceError - BfxClientError: {0}")]
-    BfxClientError(#[from] super::bfx_client::error::BfxClientError),
     #[error("PriceError - ConversionError: {0}")]
     ConversionError(#[from] core_money::ConversionError),
+    #[error("PriceError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("PriceError - JobError: {0}")]
+    Job(#[from] ::job::error::JobError),
+    #[error("PriceError - NoPriceSourcesConfigured")]
+    NoPriceSourcesConfigured,
+    #[error("PriceError - NoPriceSourcesConfiguredForAsset: {0}")]
+    NoPriceSourcesConfiguredForAsset(core_money::CollateralAsset),
+    #[error("PriceError - StalePrice: only {fresh} fresh quote(s), {required} required")]
+    StalePrice { fresh: usize, required: usize },
+    #[error(
+        "PriceError - DisputedPrice: only {agreeing} quote(s) within range of median {median}, {required} required"
+    )]
+    DisputedPrice {
+        agreeing: usize,
+        required: usize,
+        median: Decimal,
+    },
+}
+
+impl PriceError {
+    /// The sources could not agree on a current price; callers should hold
+    /// off rather than act on it.
+    pub fn is_unreliable_price(&self) -> bool {
+        matches!(
+            self,
+            PriceError::StalePrice { .. } | PriceError::DisputedPrice { .. }
+        )
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PriceSourceError {
+    #[error("PriceSourceError - BfxClientError: {0}")]
+    BfxClientError(#[from] super::bfx_client::error::BfxClientError),
+    #[error("PriceSourceError - Reqwest: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("PriceSourceError - SerdeJson: {0}")]
+    Deserialization(#[from] serde_json::Error),
+    #[error("PriceSourceError - Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("PriceSourceError - MissingField: {0}")]
+    MissingField(String),
+    #[error("PriceSourceError - MissingTimestamp")]
+    MissingTimestamp,
 }
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{AssetPrice, PriceOfOneBTC};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CorePriceEvent {
+    PriceUpdated {
+        price: PriceOfOneBTC,
+        timestamp: DateTime<Utc>,
+    },
+    AssetPriceUpdated {
+        price: AssetPrice,
+        timestamp: DateTime<Utc>,
+    },
+}
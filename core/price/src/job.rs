@@ -0,0 +1,255 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use std::{collections::HashMap, time::Duration};
+
+use core_money::{CollateralAsset, UsdCents};
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{
+    Price,
+    event::CorePriceEvent,
+    primitives::{AssetPrice, PriceOfOneBTC},
+    record::*,
+};
+
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct PriceRecordingJobConfig<E> {
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    pub record_interval: Duration,
+    pub publish_threshold_pct: Decimal,
+    pub _phantom: std::marker::PhantomData<E>,
+}
+impl<E> JobConfig for PriceRecordingJobConfig<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    type Initializer = PriceRecordingInit<E>;
+}
+
+pub struct PriceRecordingInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    price: Price,
+    records: PriceRecordRepo,
+    outbox: Outbox<E>,
+}
+
+impl<E> PriceRecordingInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    pub fn new(price: &Price, records: &PriceRecordRepo, outbox: &Outbox<E>) -> Self {
+        Self {
+            price: price.clone(),
+            records: records.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const PRICE_RECORDING_JOB: JobType = JobType::new("price-recording");
+impl<E> JobInitializer for PriceRecordingInit<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        PRICE_RECORDING_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(PriceRecordingJobRunner::<E> {
+            config: job.config()?,
+            price: self.price.clone(),
+            records: self.records.clone(),
+            outbox: self.outbox.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct PriceRecordingJobData {
+    last_published: Option<PriceOfOneBTC>,
+    #[serde(default)]
+    last_published_assets: HashMap<CollateralAsset, AssetPrice>,
+}
+
+pub struct PriceRecordingJobRunner<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    config: PriceRecordingJobConfig<E>,
+    price: Price,
+    records: PriceRecordRepo,
+    outbox: Outbox<E>,
+}
+
+#[async_trait]
+impl<E> JobRunner for PriceRecordingJobRunner<E>
+where
+    E: OutboxEventMarker<CorePriceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<PriceRecordingJobData>()?
+            .unwrap_or_default();
+
+        let price = match self.price.usd_cents_per_btc().await {
+            Err(e) if e.is_unreliable_price() => {
+                tracing::warn!(error = %e, "skipping price record on unreliable price");
+                return Ok(JobCompletion::RescheduleIn(self.config.record_interval));
+            }
+            res => res?,
+        };
+        let record = PriceRecord {
+            usd_cents_per_btc: price,
+            recorded_at: chrono::Utc::now(),
+        };
+
+        let mut asset_prices = Vec::new();
+        for asset in self.price.assets() {
+            match self.price.usd_cents_per_unit(asset).await {
+                Err(e) if e.is_unreliable_price() => {
+                    tracing::warn!(error = %e, %asset, "skipping asset price on unreliable price");
+                }
+                res => {
+                    let asset_price = res?;
+                    if moved_beyond_threshold(
+                        state
+                            .last_published_assets
+                            .get(&asset)
+                            .map(|last| last.into_inner()),
+                        asset_price.into_inner(),
+                        self.config.publish_threshold_pct,
+                    ) {
+                        asset_prices.push(asset_price);
+                    }
+                }
+            }
+        }
+
+        let mut tx = self.records.begin().await?;
+        self.records.persist_in_tx(&mut tx, record).await?;
+        let publish = moved_beyond_threshold(
+            state.last_published.map(PriceOfOneBTC::into_inner),
+            price.into_inner(),
+            self.config.publish_threshold_pct,
+        );
+        if publish {
+            self.outbox
+                .publish_persisted(
+                    &mut tx,
+                    CorePriceEvent::PriceUpdated {
+                        price,
+                        timestamp: record.recorded_at,
+                    },
+                )
+                .await?;
+        }
+        for asset_price in asset_prices.iter() {
+            self.outbox
+                .publish_persisted(
+                    &mut tx,
+                    CorePriceEvent::AssetPriceUpdated {
+                        price: *asset_price,
+                        timestamp: record.recorded_at,
+                    },
+                )
+                .await?;
+        }
+        tx.commit().await?;
+
+        if publish || !asset_prices.is_empty() {
+            state.last_published = Some(price);
+            state.last_published_assets.extend(
+                asset_prices
+                    .into_iter()
+                    .map(|asset_price| (asset_price.asset, asset_price)),
+            );
+            current_job.update_execution_state(state).await?;
+        }
+
+        Ok(JobCompletion::RescheduleIn(self.config.record_interval))
+    }
+}
+
+fn moved_beyond_threshold(
+    last_published: Option<UsdCents>,
+    current: UsdCents,
+    threshold_pct: Decimal,
+) -> bool {
+    let Some(last) = last_published else {
+        return true;
+    };
+    let last = last.to_usd();
+    if last.is_zero() {
+        return true;
+    }
+    let moved_pct = (current.to_usd() - last).abs() / last * Decimal::ONE_HUNDRED;
+    moved_pct >= threshold_pct
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn cents(usd: Decimal) -> UsdCents {
+        UsdCents::try_from_usd(usd).unwrap()
+    }
+
+    #[test]
+    fn publishes_first_price() {
+        assert!(moved_beyond_threshold(
+            None,
+            cents(dec!(100_000)),
+            dec!(0.5)
+        ));
+    }
+
+    #[test]
+    fn skips_moves_within_threshold() {
+        assert!(!moved_beyond_threshold(
+            Some(cents(dec!(100_000))),
+            cents(dec!(100_400)),
+            dec!(0.5)
+        ));
+        assert!(!moved_beyond_threshold(
+            Some(cents(dec!(100_000))),
+            cents(dec!(99_600)),
+            dec!(0.5)
+        ));
+    }
+
+    #[test]
+    fn publishes_moves_reaching_threshold() {
+        assert!(moved_beyond_threshold(
+            Some(cents(dec!(100_000))),
+            cents(dec!(100_500)),
+            dec!(0.5)
+        ));
+        assert!(moved_beyond_threshold(
+            Some(cents(dec!(100_000))),
+            cents(dec!(99_000)),
+            dec!(0.5)
+        ));
+    }
+}
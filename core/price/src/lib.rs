@@ -1,46 +1,164 @@
+mod aggregator;
 mod bfx_client;
+mod config;
 pub mod error;
+mod event;
+mod job;
 mod primitives;
+mod record;
+pub mod source;
 
-use cached::proc_macro::cached;
+use chrono::{DateTime, Utc};
+use core_money::CollateralAsset;
+use outbox::{Outbox, OutboxEventMarker};
+use sqlx::PgPool;
 
-use core_money::UsdCents;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
 
-use bfx_client::BfxClient;
+use aggregator::PriceAggregator;
 use error::PriceError;
+use job::*;
+use record::PriceRecordRepo;
+use source::{FixedPriceSource, PriceSource};
+
+pub use config::*;
+pub use event::*;
 pub use primitives::*;
+pub use record::{PriceRecord, PriceRecordCursor};
 
 #[derive(Clone)]
 pub struct Price {
-    bfx: BfxClient,
+    aggregator: Arc<PriceAggregator>,
+    asset_aggregators: Arc<HashMap<CollateralAsset, PriceAggregator>>,
+    cache: Arc<RwLock<Option<(Instant, PriceOfOneBTC)>>>,
+    cache_duration: Duration,
+    records: PriceRecordRepo,
 }
 
 impl Price {
-    pub fn new() -> Self {
-        Self {
-            bfx: BfxClient::new(),
-        }
+    pub async fn init<E>(
+        pool: &PgPool,
+        jobs: &::job::Jobs,
+        outbox: &Outbox<E>,
+        config: PriceConfig,
+    ) -> Result<Self, PriceError>
+    where
+        E: OutboxEventMarker<CorePriceEvent>,
+    {
+        let sources: Vec<Box<dyn PriceSource>> = if std::env::var("BFX_LOCAL_PRICE").is_ok() {
+            vec![Box::new(FixedPriceSource::new(rust_decimal_macros::dec!(
+                100_000
+            )))]
+        } else {
+            config.sources.iter().map(source::from_config).collect()
+        };
+        Self::init_with_sources(pool, jobs, outbox, sources, config).await
+    }
+
+    pub async fn init_with_sources<E>(
+        pool: &PgPool,
+        jobs: &::job::Jobs,
+        outbox: &Outbox<E>,
+        sources: Vec<Box<dyn PriceSource>>,
+        config: PriceConfig,
+    ) -> Result<Self, PriceError>
+    where
+        E: OutboxEventMarker<CorePriceEvent>,
+    {
+        let records = PriceRecordRepo::new(pool);
+        let asset_aggregators = config
+            .asset_sources
+            .iter()
+            .filter(|(asset, _)| **asset != CollateralAsset::Btc)
+            .map(|(asset, sources)| {
+                let sources = sources.iter().map(source::from_config).collect();
+                (*asset, PriceAggregator::new(sources, &config))
+            })
+            .collect();
+        let price = Self {
+            aggregator: Arc::new(PriceAggregator::new(sources, &config)),
+            asset_aggregators: Arc::new(asset_aggregators),
+            cache: Arc::new(RwLock::new(None)),
+            cache_duration: config.cache_duration,
+            records: records.clone(),
+        };
+        jobs.add_initializer_and_spawn_unique(
+            PriceRecordingInit::<E>::new(&price, &records, outbox),
+            PriceRecordingJobConfig::<E> {
+                record_interval: config.record_interval,
+                publish_threshold_pct: config.publish_threshold_pct,
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
+        Ok(price)
     }
 
     pub async fn usd_cents_per_btc(&self) -> Result<PriceOfOneBTC, PriceError> {
-        usd_cents_per_btc_cached(&self.bfx).await
+        let cached = self
+            .cache
+            .read()
+            .expect("price cache poisoned")
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.cache_duration);
+        if let Some((_, price)) = cached {
+            return Ok(price);
+        }
+
+        let price = PriceOfOneBTC::new(self.aggregator.usd_cents_per_unit().await?);
+        *self.cache.write().expect("price cache poisoned") = Some((Instant::now(), price));
+        Ok(price)
     }
-}
 
-impl Default for Price {
-    fn default() -> Self {
-        Self::new()
+    /// Current price of one unit of `asset`. Only BTC prices are cached and
+    /// recorded; other assets are fetched from their sources on every call.
+    pub async fn usd_cents_per_unit(
+        &self,
+        asset: CollateralAsset,
+    ) -> Result<AssetPrice, PriceError> {
+        if asset == CollateralAsset::Btc {
+            return Ok(self.usd_cents_per_btc().await?.into());
+        }
+
+        let aggregator = self
+            .asset_aggregators
+            .get(&asset)
+            .ok_or(PriceError::NoPriceSourcesConfiguredForAsset(asset))?;
+        Ok(AssetPrice::new(
+            asset,
+            aggregator.usd_cents_per_unit().await?,
+        ))
+    }
+
+    /// Non-BTC assets with configured price sources.
+    pub fn assets(&self) -> impl Iterator<Item = CollateralAsset> + '_ {
+        self.asset_aggregators.keys().copied()
     }
-}
 
-#[cached(time = 60, result = true, key = "()", convert = r#"{}"#)]
-async fn usd_cents_per_btc_cached(bfx: &BfxClient) -> Result<PriceOfOneBTC, PriceError> {
-    if std::env::var("BFX_LOCAL_PRICE").is_ok() {
-        return Ok(PriceOfOneBTC::new(UsdCents::try_from_usd(
-            rust_decimal_macros::dec!(100_000),
-        )?));
+    /// The last recorded price at or before `at`, for calculations that need
+    /// the price that was in effect at some point in the past.
+    pub async fn price_at(&self, at: DateTime<Utc>) -> Result<Option<PriceRecord>, PriceError> {
+        self.records.find_at_or_before(at).await
     }
 
-    let last_price = bfx.btc_usd_tick().await?.last_price;
-    Ok(PriceOfOneBTC::new(UsdCents::try_from_usd(last_price)?))
+    /// Batched form of [`Price::price_at`]. Timestamps without any earlier
+    /// record are absent from the result.
+    pub async fn prices_at(
+        &self,
+        ats: &[DateTime<Utc>],
+    ) -> Result<HashMap<DateTime<Utc>, PriceRecord>, PriceError> {
+        self.records.find_all_at_or_before(ats).await
+    }
+
+    pub async fn list_records(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        query: es_entity::PaginatedQueryArgs<PriceRecordCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<PriceRecord, PriceRecordCursor>, PriceError> {
+        self.records.list_between(from, until, query).await
+    }
 }
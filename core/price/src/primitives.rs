@@ -1,7 +1,7 @@
 use rust_decimal::RoundingStrategy;
 use serde::{Deserialize, Serialize};
 
-use core_money::{Satoshis, UsdCents};
+use core_money::{CollateralAsset, Satoshis, UsdCents};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
@@ -30,3 +30,67 @@ impl PriceOfOneBTC {
         self.0
     }
 }
+
+/// USD price of one whole unit of a collateral asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AssetPrice {
+    pub asset: CollateralAsset,
+    usd_cents_per_unit: UsdCents,
+}
+
+impl AssetPrice {
+    pub const fn new(asset: CollateralAsset, usd_cents_per_unit: UsdCents) -> Self {
+        Self {
+            asset,
+            usd_cents_per_unit,
+        }
+    }
+
+    pub fn units_to_cents_round_down(self, units: u64) -> UsdCents {
+        let usd = (self.asset.units_to_decimal(units) * self.usd_cents_per_unit.to_usd())
+            .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+        UsdCents::try_from_usd(usd).expect("Decimal should have no fractional component here")
+    }
+
+    pub fn into_inner(self) -> UsdCents {
+        self.usd_cents_per_unit
+    }
+}
+
+impl From<PriceOfOneBTC> for AssetPrice {
+    fn from(price: PriceOfOneBTC) -> Self {
+        Self::new(CollateralAsset::Btc, price.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn asset_price_values_units_by_asset_decimals() {
+        let eth = AssetPrice::new(CollateralAsset::Eth, UsdCents::from(3_000_00));
+        // 1.5 ETH in gwei
+        assert_eq!(
+            eth.units_to_cents_round_down(1_500_000_000),
+            UsdCents::from(4_500_00)
+        );
+
+        let usdt = AssetPrice::new(CollateralAsset::Usdt, UsdCents::from(1_00));
+        assert_eq!(
+            usdt.units_to_cents_round_down(12_345_678),
+            UsdCents::from(12_34)
+        );
+    }
+
+    #[test]
+    fn btc_asset_price_matches_price_of_one_btc() {
+        let btc = PriceOfOneBTC::new(UsdCents::from(100_000_00));
+        let sats = Satoshis::from(12_345_678);
+        assert_eq!(
+            AssetPrice::from(btc).units_to_cents_round_down(sats.into_inner()),
+            btc.sats_to_cents_round_down(sats)
+        );
+    }
+}
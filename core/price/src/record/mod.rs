@@ -0,0 +1,27 @@
+mod repo;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::PriceOfOneBTC;
+
+pub(crate) use repo::PriceRecordRepo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceRecord {
+    pub usd_cents_per_btc: PriceOfOneBTC,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PriceRecordCursor {
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<&PriceRecord> for PriceRecordCursor {
+    fn from(record: &PriceRecord) -> Self {
+        Self {
+            recorded_at: record.recorded_at,
+        }
+    }
+}
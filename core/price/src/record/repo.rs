@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use std::collections::HashMap;
+
+use core_money::UsdCents;
+
+use crate::{error::PriceError, primitives::PriceOfOneBTC};
+
+use super::{PriceRecord, PriceRecordCursor};
+
+#[derive(Clone)]
+pub struct PriceRecordRepo {
+    pool: PgPool,
+}
+
+impl PriceRecordRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn begin(&self) -> Result<sqlx::Transaction<'_, sqlx::Postgres>, PriceError> {
+        Ok(self.pool.begin().await?)
+    }
+
+    pub async fn persist_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        record: PriceRecord,
+    ) -> Result<(), PriceError> {
+        let usd_cents_per_btc = record.usd_cents_per_btc.into_inner().into_inner() as i64;
+        sqlx::query!(
+            r#"
+            INSERT INTO core_price_records (recorded_at, usd_cents_per_btc)
+            VALUES ($1, $2)
+            ON CONFLICT (recorded_at) DO NOTHING
+            "#,
+            record.recorded_at,
+            usd_cents_per_btc
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find_at_or_before(
+        &self,
+        at: DateTime<Utc>,
+    ) -> Result<Option<PriceRecord>, PriceError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT recorded_at, usd_cents_per_btc
+            FROM core_price_records
+            WHERE recorded_at <= $1
+            ORDER BY recorded_at DESC
+            LIMIT 1
+            "#,
+            at
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| PriceRecord {
+            usd_cents_per_btc: price_from_row(row.usd_cents_per_btc),
+            recorded_at: row.recorded_at,
+        }))
+    }
+
+    pub async fn find_all_at_or_before(
+        &self,
+        ats: &[DateTime<Utc>],
+    ) -> Result<HashMap<DateTime<Utc>, PriceRecord>, PriceError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT t.at AS "at!", r.recorded_at, r.usd_cents_per_btc
+            FROM UNNEST($1::timestamptz[]) AS t(at)
+            JOIN LATERAL (
+                SELECT recorded_at, usd_cents_per_btc
+                FROM core_price_records
+                WHERE recorded_at <= t.at
+                ORDER BY recorded_at DESC
+                LIMIT 1
+            ) r ON true
+            "#,
+            ats
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.at,
+                    PriceRecord {
+                        usd_cents_per_btc: price_from_row(row.usd_cents_per_btc),
+                        recorded_at: row.recorded_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    pub async fn list_between(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        query: es_entity::PaginatedQueryArgs<PriceRecordCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<PriceRecord, PriceRecordCursor>, PriceError> {
+        let after = query.after.map(|cursor| cursor.recorded_at);
+        let limit = query.first;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT recorded_at, usd_cents_per_btc
+            FROM core_price_records
+            WHERE recorded_at >= $1 AND recorded_at <= $2
+              AND COALESCE(recorded_at > $3, true)
+            ORDER BY recorded_at
+            LIMIT $4
+            "#,
+            from,
+            until,
+            after,
+            (limit + 1) as i64,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let has_next_page = rows.len() > limit;
+        let entities: Vec<PriceRecord> = rows
+            .into_iter()
+            .take(limit)
+            .map(|row| PriceRecord {
+                usd_cents_per_btc: price_from_row(row.usd_cents_per_btc),
+                recorded_at: row.recorded_at,
+            })
+            .collect();
+
+        let end_cursor = if has_next_page {
+            entities.last().map(PriceRecordCursor::from)
+        } else {
+            None
+        };
+
+        Ok(es_entity::PaginatedQueryRet {
+            entities,
+            has_next_page,
+            end_cursor,
+        })
+    }
+}
+
+fn price_from_row(usd_cents_per_btc: i64) -> PriceOfOneBTC {
+    PriceOfOneBTC::new(UsdCents::from(usd_cents_per_btc as u64))
+}
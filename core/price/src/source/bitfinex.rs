@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::{bfx_client::BfxClient, error::PriceSourceError};
+
+use super::{PriceQuote, PriceSource};
+
+#[derive(Clone, Default)]
+pub struct BitfinexPriceSource {
+    client: BfxClient,
+}
+
+impl BitfinexPriceSource {
+    pub fn new() -> Self {
+        Self {
+            client: BfxClient::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceSource for BitfinexPriceSource {
+    fn name(&self) -> &str {
+        "bitfinex"
+    }
+
+    async fn fetch_usd_quote(&self) -> Result<PriceQuote, PriceSourceError> {
+        let (tick, served_at) = self.client.btc_usd_tick().await?;
+        Ok(PriceQuote {
+            source: self.name().to_string(),
+            usd_per_unit: tick.last_price,
+            observed_at: served_at.ok_or(PriceSourceError::MissingTimestamp)?,
+        })
+    }
+}
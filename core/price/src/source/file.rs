@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+
+use std::path::PathBuf;
+
+use crate::error::PriceSourceError;
+
+use super::{PriceQuote, PriceSource, rest_json::quote_from_json};
+
+/// Local stand-in that reads `{"usd_per_btc": "...", "observed_at": "..."}`
+/// from disk on every fetch. `observed_at` is optional and defaults to the
+/// file's modification time.
+#[derive(Clone)]
+pub struct FilePriceSource {
+    path: PathBuf,
+}
+
+impl FilePriceSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl PriceSource for FilePriceSource {
+    fn name(&self) -> &str {
+        "file"
+    }
+
+    async fn fetch_usd_quote(&self) -> Result<PriceQuote, PriceSourceError> {
+        let contents = tokio::fs::read_to_string(&self.path).await?;
+        let modified_at = tokio::fs::metadata(&self.path).await?.modified()?;
+        let body: serde_json::Value = serde_json::from_str(&contents)?;
+        let timestamp_pointer = body.get("observed_at").map(|_| "/observed_at");
+        quote_from_json(
+            self.name(),
+            &body,
+            "/usd_per_btc",
+            timestamp_pointer,
+            Some(modified_at.into()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use std::io::Write;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_quote_from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"{{"usd_per_btc": "100000", "observed_at": "2024-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+
+        let quote = FilePriceSource::new(file.path())
+            .fetch_usd_quote()
+            .await
+            .unwrap();
+        assert_eq!(quote.usd_per_unit, dec!(100000));
+        assert_eq!(
+            quote.observed_at,
+            "2024-01-01T00:00:00Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn defaults_to_modification_time() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"usd_per_btc": "100000"}}"#).unwrap();
+
+        let quote = FilePriceSource::new(file.path())
+            .fetch_usd_quote()
+            .await
+            .unwrap();
+        let modified_at: chrono::DateTime<chrono::Utc> = file
+            .as_file()
+            .metadata()
+            .unwrap()
+            .modified()
+            .unwrap()
+            .into();
+        assert_eq!(quote.observed_at, modified_at);
+    }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::error::PriceSourceError;
+
+use super::{PriceQuote, PriceSource};
+
+/// Always reports the same price. Backs the `BFX_LOCAL_PRICE` switch used in
+/// local development.
+pub(crate) struct FixedPriceSource {
+    usd_per_unit: Decimal,
+}
+
+impl FixedPriceSource {
+    pub fn new(usd_per_unit: Decimal) -> Self {
+        Self { usd_per_unit }
+    }
+}
+
+#[async_trait]
+impl PriceSource for FixedPriceSource {
+    fn name(&self) -> &str {
+        "fixed"
+    }
+
+    async fn fetch_usd_quote(&self) -> Result<PriceQuote, PriceSourceError> {
+        Ok(PriceQuote {
+            source: self.name().to_string(),
+            usd_per_unit: self.usd_per_unit,
+            observed_at: chrono::Utc::now(),
+        })
+    }
+}
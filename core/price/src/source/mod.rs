@@ -0,0 +1,60 @@
+mod bitfinex;
+mod file;
+mod fixed;
+mod rest_json;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::{config::PriceSourceConfig, error::PriceSourceError};
+
+pub use bitfinex::BitfinexPriceSource;
+pub use file::FilePriceSource;
+pub(crate) use fixed::FixedPriceSource;
+pub use rest_json::RestJsonPriceSource;
+
+/// A single USD price observation for one unit of an asset, reported by a
+/// [`PriceSource`].
+#[derive(Debug, Clone)]
+pub struct PriceQuote {
+    pub source: String,
+    pub usd_per_unit: Decimal,
+    pub observed_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait PriceSource: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn fetch_usd_quote(&self) -> Result<PriceQuote, PriceSourceError>;
+}
+
+/// The provider's `Date` response header, used as the observation time when
+/// the quote itself carries no timestamp.
+pub(crate) fn response_date(headers: &reqwest::header::HeaderMap) -> Option<DateTime<Utc>> {
+    headers
+        .get(reqwest::header::DATE)?
+        .to_str()
+        .ok()
+        .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+        .map(|date| date.with_timezone(&Utc))
+}
+
+pub(crate) fn from_config(config: &PriceSourceConfig) -> Box<dyn PriceSource> {
+    match config {
+        PriceSourceConfig::Bitfinex => Box::new(BitfinexPriceSource::new()),
+        PriceSourceConfig::RestJson {
+            name,
+            url,
+            price_pointer,
+            timestamp_pointer,
+        } => Box::new(RestJsonPriceSource::new(
+            name.clone(),
+            url.clone(),
+            price_pointer.clone(),
+            timestamp_pointer.clone(),
+        )),
+        PriceSourceConfig::File { path } => Box::new(FilePriceSource::new(path.clone())),
+    }
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client as ReqwestClient;
+use rust_decimal::Decimal;
+
+use std::str::FromStr;
+
+use crate::error::PriceSourceError;
+
+use super::{PriceQuote, PriceSource, response_date};
+
+/// Reads the price out of an arbitrary JSON endpoint. Values are located with
+/// JSON pointers (RFC 6901), e.g. `/data/amount`. Without a timestamp pointer
+/// the response's `Date` header is used as the observation time.
+#[derive(Clone)]
+pub struct RestJsonPriceSource {
+    name: String,
+    url: String,
+    price_pointer: String,
+    timestamp_pointer: Option<String>,
+    client: ReqwestClient,
+}
+
+impl RestJsonPriceSource {
+    pub fn new(
+        name: String,
+        url: String,
+        price_pointer: String,
+        timestamp_pointer: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            url,
+            price_pointer,
+            timestamp_pointer,
+            client: ReqwestClient::builder()
+                .use_rustls_tls()
+                .build()
+                .expect("should always build RestJsonPriceSource client"),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceSource for RestJsonPriceSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn fetch_usd_quote(&self) -> Result<PriceQuote, PriceSourceError> {
+        let response = self
+            .client
+            .get(&self.url)
+            .header("accept", "application/json")
+            .send()
+            .await?
+            .error_for_status()?;
+        let served_at = response_date(response.headers());
+        let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+        quote_from_json(
+            &self.name,
+            &body,
+            &self.price_pointer,
+            self.timestamp_pointer.as_deref(),
+            served_at,
+        )
+    }
+}
+
+pub(super) fn quote_from_json(
+    source: &str,
+    body: &serde_json::Value,
+    price_pointer: &str,
+    timestamp_pointer: Option<&str>,
+    fallback_observed_at: Option<DateTime<Utc>>,
+) -> Result<PriceQuote, PriceSourceError> {
+    let usd_per_unit = match body.pointer(price_pointer) {
+        Some(serde_json::Value::String(s)) => Decimal::from_str(s).ok(),
+        Some(serde_json::Value::Number(n)) => Decimal::from_str(&n.to_string()).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| PriceSourceError::MissingField(price_pointer.to_string()))?;
+
+    let observed_at = match timestamp_pointer {
+        Some(pointer) => match body.pointer(pointer) {
+            Some(serde_json::Value::String(s)) => s.parse::<DateTime<Utc>>().ok(),
+            Some(serde_json::Value::Number(n)) => n
+                .as_i64()
+                .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0)),
+            _ => None,
+        }
+        .ok_or_else(|| PriceSourceError::MissingField(pointer.to_string()))?,
+        None => fallback_observed_at.ok_or(PriceSourceError::MissingTimestamp)?,
+    };
+
+    Ok(PriceQuote {
+        source: source.to_string(),
+        usd_per_unit,
+        observed_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn reads_price_and_timestamp_from_pointers() {
+        let body = serde_json::json!({
+            "data": { "amount": "64123.45", "time": 1_700_000_000 }
+        });
+        let quote =
+            quote_from_json("test", &body, "/data/amount", Some("/data/time"), None).unwrap();
+        assert_eq!(quote.usd_per_unit, dec!(64123.45));
+        assert_eq!(quote.observed_at.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn errors_on_missing_price() {
+        let body = serde_json::json!({ "data": {} });
+        let res = quote_from_json("test", &body, "/data/amount", None, Some(Utc::now()));
+        assert!(matches!(res, Err(PriceSourceError::MissingField(_))));
+    }
+
+    #[test]
+    fn errors_without_any_timestamp() {
+        let body = serde_json::json!({ "data": { "amount": "64123.45" } });
+        let res = quote_from_json("test", &body, "/data/amount", None, None);
+        assert!(matches!(res, Err(PriceSourceError::MissingTimestamp)));
+    }
+
+    #[test]
+    fn parses_response_date_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::DATE,
+            "Tue, 14 Nov 2023 22:13:20 GMT".parse().unwrap(),
+        );
+        assert_eq!(
+            response_date(&headers).map(|date| date.timestamp()),
+            Some(1_700_000_000)
+        );
+    }
+}
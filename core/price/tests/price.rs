@@ -1,10 +1,135 @@
-use core_price::Price;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use core_money::UsdCents;
+use core_price::{CorePriceEvent, Price, PriceOfOneBTC};
+
+pub async fn init_pool() -> anyhow::Result<sqlx::PgPool> {
+    let pg_con = std::env::var("PG_CON").unwrap();
+    let pool = sqlx::PgPool::connect(&pg_con).await?;
+    Ok(pool)
+}
 
 #[tokio::test]
 async fn get_price() -> anyhow::Result<()> {
-    let price = Price::new();
+    let pool = init_pool().await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let outbox = outbox::Outbox::<CorePriceEvent>::init(&pool).await?;
+    let price = Price::init(&pool, &jobs, &outbox, Default::default()).await?;
     let res = price.usd_cents_per_btc().await;
     assert!(res.is_ok());
 
     Ok(())
 }
+
+async fn insert_record(
+    pool: &sqlx::PgPool,
+    recorded_at: DateTime<Utc>,
+    usd_cents_per_btc: i64,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "INSERT INTO core_price_records (recorded_at, usd_cents_per_btc) VALUES ($1, $2) ON CONFLICT (recorded_at) DO NOTHING",
+    )
+    .bind(recorded_at)
+    .bind(usd_cents_per_btc)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn price_at_returns_latest_record_before_timestamp() -> anyhow::Result<()> {
+    let pool = init_pool().await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let outbox = outbox::Outbox::<CorePriceEvent>::init(&pool).await?;
+    let price = Price::init(&pool, &jobs, &outbox, Default::default()).await?;
+
+    let first_at = Utc.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap();
+    let second_at = Utc.with_ymd_and_hms(2001, 1, 2, 0, 0, 0).unwrap();
+    insert_record(&pool, first_at, 1_000_000).await?;
+    insert_record(&pool, second_at, 2_000_000).await?;
+
+    assert!(
+        price
+            .price_at(first_at - Duration::seconds(1))
+            .await?
+            .is_none()
+    );
+
+    let at_first = price.price_at(first_at).await?.expect("record exists");
+    assert_eq!(at_first.recorded_at, first_at);
+    assert_eq!(
+        at_first.usd_cents_per_btc,
+        PriceOfOneBTC::new(UsdCents::from(1_000_000))
+    );
+
+    let between = price
+        .price_at(second_at - Duration::hours(1))
+        .await?
+        .expect("record exists");
+    assert_eq!(between, at_first);
+
+    let after_second = price
+        .price_at(second_at + Duration::hours(1))
+        .await?
+        .expect("record exists");
+    assert_eq!(after_second.recorded_at, second_at);
+    assert_eq!(
+        after_second.usd_cents_per_btc,
+        PriceOfOneBTC::new(UsdCents::from(2_000_000))
+    );
+
+    let batched = price
+        .prices_at(&[
+            second_at - Duration::hours(1),
+            second_at + Duration::hours(1),
+        ])
+        .await?;
+    assert_eq!(batched[&(second_at - Duration::hours(1))], at_first);
+    assert_eq!(batched[&(second_at + Duration::hours(1))], after_second);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_records_is_paginated() -> anyhow::Result<()> {
+    let pool = init_pool().await?;
+    let jobs = job::Jobs::new(&pool, job::JobExecutorConfig::default());
+    let outbox = outbox::Outbox::<CorePriceEvent>::init(&pool).await?;
+    let price = Price::init(&pool, &jobs, &outbox, Default::default()).await?;
+
+    let from = Utc.with_ymd_and_hms(2001, 2, 1, 0, 0, 0).unwrap();
+    let until = from + Duration::hours(2);
+    for hour in 0..3 {
+        insert_record(&pool, from + Duration::hours(hour), 1_000_000 + hour).await?;
+    }
+
+    let first_page = price
+        .list_records(
+            from,
+            until,
+            es_entity::PaginatedQueryArgs {
+                first: 2,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(first_page.has_next_page);
+    assert_eq!(first_page.entities.len(), 2);
+    assert_eq!(first_page.entities[0].recorded_at, from);
+
+    let second_page = price
+        .list_records(
+            from,
+            until,
+            es_entity::PaginatedQueryArgs {
+                first: 2,
+                after: first_page.end_cursor,
+            },
+        )
+        .await?;
+    assert!(!second_page.has_next_page);
+    assert_eq!(second_page.entities.len(), 1);
+    assert_eq!(second_page.entities[0].recorded_at, until);
+
+    Ok(())
+}
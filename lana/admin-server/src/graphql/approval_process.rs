@@ -3,8 +3,8 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    access::User, approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*,
-    withdrawal::*,
+    access::User, approval_rules::*, credit_facility::*, deposit::Deposit, loader::LanaDataLoader,
+    policy::*, transfer::Transfer, withdrawal::*,
 };
 
 pub use lana_app::governance::{
@@ -153,6 +153,30 @@ impl ApprovalProcess {
                     .expect("credit facility not found");
                 Ok(ApprovalProcessTarget::CreditFacility(credit_facility))
             }
+            ApprovalProcessType::CreditFacilityRestructuringApproval => {
+                let credit_facility = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<CreditFacilityId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("credit facility not found");
+                Ok(ApprovalProcessTarget::CreditFacility(credit_facility))
+            }
+            ApprovalProcessType::CollateralWithdrawalApproval => {
+                let credit_facility = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<CreditFacilityId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("credit facility not found");
+                Ok(ApprovalProcessTarget::CreditFacility(credit_facility))
+            }
             ApprovalProcessType::DisbursalApproval => {
                 let disbursal = loader
                     .load_one(
@@ -165,6 +189,30 @@ impl ApprovalProcess {
                     .expect("disbursal not found");
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::DepositReversalApproval => {
+                let deposit = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<DepositId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("deposit not found");
+                Ok(ApprovalProcessTarget::Deposit(deposit))
+            }
+            ApprovalProcessType::TransferApproval => {
+                let transfer = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<TransferId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("transfer not found");
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
         }
     }
 }
@@ -174,7 +222,11 @@ impl ApprovalProcess {
 pub enum ApprovalProcessType {
     WithdrawalApproval,
     CreditFacilityApproval,
+    CreditFacilityRestructuringApproval,
+    CollateralWithdrawalApproval,
     DisbursalApproval,
+    DepositReversalApproval,
+    TransferApproval,
 }
 
 impl From<&DomainApprovalProcessType> for ApprovalProcessType {
@@ -183,8 +235,18 @@ impl From<&DomainApprovalProcessType> for ApprovalProcessType {
             Self::WithdrawalApproval
         } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_PROCESS {
             Self::CreditFacilityApproval
+        } else if process_type
+            == &lana_app::governance::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS
+        {
+            Self::CreditFacilityRestructuringApproval
+        } else if process_type == &lana_app::governance::APPROVE_COLLATERAL_WITHDRAWAL_PROCESS {
+            Self::CollateralWithdrawalApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
+        } else if process_type == &lana_app::governance::APPROVE_DEPOSIT_REVERSAL_PROCESS {
+            Self::DepositReversalApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
         } else {
             panic!("Unknown approval process type: {process_type:?}");
         }
@@ -221,6 +283,8 @@ pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    Deposit(Deposit),
+    Transfer(Transfer),
 }
 
 #[derive(InputObject)]
@@ -10,11 +10,19 @@ pub struct CreditModuleConfig {
     chart_of_account_facility_omnibus_parent_code: Option<String>,
     chart_of_account_collateral_omnibus_parent_code: Option<String>,
     chart_of_account_in_liquidation_omnibus_parent_code: Option<String>,
+    chart_of_account_liquidator_omnibus_parent_code: Option<String>,
+    chart_of_account_liquidation_proceeds_omnibus_parent_code: Option<String>,
+    chart_of_account_credit_loss_expense_omnibus_parent_code: Option<String>,
     chart_of_account_facility_parent_code: Option<String>,
     chart_of_account_collateral_parent_code: Option<String>,
     chart_of_account_in_liquidation_parent_code: Option<String>,
     chart_of_account_interest_income_parent_code: Option<String>,
     chart_of_account_fee_income_parent_code: Option<String>,
+    chart_of_account_unapplied_funds_parent_code: Option<String>,
+    chart_of_account_penalty_income_parent_code: Option<String>,
+    chart_of_account_commitment_fee_income_parent_code: Option<String>,
+    chart_of_account_maintenance_fee_income_parent_code: Option<String>,
+    chart_of_account_disbursal_fee_income_parent_code: Option<String>,
 
     chart_of_account_short_term_individual_disbursed_receivable_parent_code: Option<String>,
     chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: Option<String>,
@@ -92,6 +100,21 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
                     .chart_of_account_in_liquidation_omnibus_parent_code
                     .to_string(),
             ),
+            chart_of_account_liquidator_omnibus_parent_code: Some(
+                values
+                    .chart_of_account_liquidator_omnibus_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_liquidation_proceeds_omnibus_parent_code: Some(
+                values
+                    .chart_of_account_liquidation_proceeds_omnibus_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_credit_loss_expense_omnibus_parent_code: Some(
+                values
+                    .chart_of_account_credit_loss_expense_omnibus_parent_code
+                    .to_string(),
+            ),
             chart_of_account_facility_parent_code: Some(
                 values.chart_of_account_facility_parent_code.to_string(),
             ),
@@ -109,6 +132,31 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
             chart_of_account_fee_income_parent_code: Some(
                 values.chart_of_account_fee_income_parent_code.to_string(),
             ),
+            chart_of_account_unapplied_funds_parent_code: Some(
+                values
+                    .chart_of_account_unapplied_funds_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_penalty_income_parent_code: Some(
+                values
+                    .chart_of_account_penalty_income_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_commitment_fee_income_parent_code: Some(
+                values
+                    .chart_of_account_commitment_fee_income_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_maintenance_fee_income_parent_code: Some(
+                values
+                    .chart_of_account_maintenance_fee_income_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_disbursal_fee_income_parent_code: Some(
+                values
+                    .chart_of_account_disbursal_fee_income_parent_code
+                    .to_string(),
+            ),
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code: Some(
                 values
@@ -300,11 +348,19 @@ pub struct CreditModuleConfigureInput {
     pub chart_of_account_facility_omnibus_parent_code: String,
     pub chart_of_account_collateral_omnibus_parent_code: String,
     pub chart_of_account_in_liquidation_omnibus_parent_code: String,
+    pub chart_of_account_liquidator_omnibus_parent_code: String,
+    pub chart_of_account_liquidation_proceeds_omnibus_parent_code: String,
+    pub chart_of_account_credit_loss_expense_omnibus_parent_code: String,
     pub chart_of_account_facility_parent_code: String,
     pub chart_of_account_collateral_parent_code: String,
     pub chart_of_account_in_liquidation_parent_code: String,
     pub chart_of_account_interest_income_parent_code: String,
     pub chart_of_account_fee_income_parent_code: String,
+    pub chart_of_account_unapplied_funds_parent_code: String,
+    pub chart_of_account_penalty_income_parent_code: String,
+    pub chart_of_account_commitment_fee_income_parent_code: String,
+    pub chart_of_account_maintenance_fee_income_parent_code: String,
+    pub chart_of_account_disbursal_fee_income_parent_code: String,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: String,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: String,
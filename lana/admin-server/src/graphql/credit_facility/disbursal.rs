@@ -2,10 +2,10 @@ use async_graphql::*;
 
 use super::CreditFacility;
 use crate::{
-    graphql::{approval_process::*, loader::LanaDataLoader},
+    graphql::{approval_process::*, customer::BeneficiaryAccount, loader::LanaDataLoader},
     primitives::*,
 };
-pub use lana_app::credit::{Disbursal as DomainDisbursal, DisbursalsCursor};
+pub use lana_app::credit::{Disbursal as DomainDisbursal, DisbursalsCursor, PaymentOutcome};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -52,6 +52,17 @@ impl CreditFacilityDisbursal {
             .unwrap_or_else(|| self.entity.status()))
     }
 
+    async fn beneficiary_account(&self) -> Option<BeneficiaryAccount> {
+        self.entity
+            .beneficiary_account
+            .clone()
+            .map(BeneficiaryAccount::from)
+    }
+
+    async fn payment_external_id(&self) -> Option<&str> {
+        self.entity.payment_external_id.as_deref()
+    }
+
     async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
@@ -66,5 +77,19 @@ impl CreditFacilityDisbursal {
 pub struct CreditFacilityDisbursalInitiateInput {
     pub credit_facility_id: UUID,
     pub amount: UsdCents,
+    pub beneficiary_account_id: Option<UUID>,
 }
 crate::mutation_payload! { CreditFacilityDisbursalInitiatePayload, disbursal: CreditFacilityDisbursal }
+
+#[derive(InputObject)]
+pub struct CreditFacilityDisbursalPaymentSettleInput {
+    pub disbursal_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityDisbursalPaymentSettlePayload, disbursal: CreditFacilityDisbursal }
+
+#[derive(InputObject)]
+pub struct CreditFacilityDisbursalPaymentReturnInput {
+    pub disbursal_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { CreditFacilityDisbursalPaymentReturnPayload, disbursal: CreditFacilityDisbursal }
@@ -1,6 +1,13 @@
 use async_graphql::*;
 
-use crate::primitives::*;
+use crate::{
+    graphql::{
+        loader::LanaDataLoader,
+        price::PriceAt,
+        terms::{AppliedRateFixing, TermValues},
+    },
+    primitives::*,
+};
 pub use lana_app::primitives::CollateralAction;
 
 #[derive(async_graphql::Union)]
@@ -12,6 +19,7 @@ pub enum CreditFacilityHistoryEntry {
     Disbursal(CreditFacilityDisbursalExecuted),
     Interest(CreditFacilityInterestAccrued),
     ReservedForLiquidation(CreditFacilityLiquidationAmountReserved),
+    TermsAmended(CreditFacilityTermsAmended),
 }
 
 #[derive(SimpleObject)]
@@ -23,6 +31,7 @@ pub struct CreditFacilityIncrementalPayment {
 }
 
 #[derive(SimpleObject)]
+#[graphql(complex)]
 pub struct CreditFacilityCollateralUpdated {
     pub satoshis: Satoshis,
     pub recorded_at: Timestamp,
@@ -31,6 +40,23 @@ pub struct CreditFacilityCollateralUpdated {
     pub tx_id: UUID,
 }
 
+#[ComplexObject]
+impl CreditFacilityCollateralUpdated {
+    /// The recorded BTC price as of the end of the effective date, so that
+    /// back-dated updates are valued at the price in force at the time.
+    async fn effective_price(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<UsdCents>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let end_of_day = self
+            .effective
+            .into_inner()
+            .and_hms_opt(23, 59, 59)
+            .expect("valid time")
+            .and_utc();
+        let record = loader.load_one(PriceAt(end_of_day)).await?;
+        Ok(record.map(|r| r.usd_cents_per_btc))
+    }
+}
+
 #[derive(SimpleObject)]
 pub struct CreditFacilityApproved {
     pub cents: UsdCents,
@@ -65,6 +91,7 @@ pub struct CreditFacilityInterestAccrued {
     pub effective: Date,
     pub tx_id: UUID,
     pub days: u32,
+    pub rate_fixing: Option<AppliedRateFixing>,
 }
 
 #[derive(SimpleObject)]
@@ -75,6 +102,15 @@ pub struct CreditFacilityLiquidationAmountReserved {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityTermsAmended {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub matures_at: Timestamp,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
@@ -99,6 +135,9 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation) => {
                 CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::TermsAmended(amendment) => {
+                CreditFacilityHistoryEntry::TermsAmended(amendment.into())
+            }
         }
     }
 }
@@ -170,6 +209,7 @@ impl From<lana_app::credit::InterestAccrualsPosted> for CreditFacilityInterestAc
             effective: interest.effective.into(),
             tx_id: UUID::from(interest.tx_id),
             days: interest.days,
+            rate_fixing: interest.rate_fixing.map(AppliedRateFixing::from),
         }
     }
 }
@@ -186,3 +226,15 @@ impl From<lana_app::credit::ObligationMovedToLiquidation>
         }
     }
 }
+
+impl From<lana_app::credit::CreditFacilityTermsAmended> for CreditFacilityTermsAmended {
+    fn from(amendment: lana_app::credit::CreditFacilityTermsAmended) -> Self {
+        Self {
+            previous_terms: amendment.previous_terms.into(),
+            terms: amendment.terms.into(),
+            matures_at: amendment.matures_at.into(),
+            recorded_at: amendment.recorded_at.into(),
+            effective: amendment.effective.into(),
+        }
+    }
+}
@@ -4,6 +4,8 @@ pub(super) mod disbursal;
 mod error;
 mod history;
 pub(super) mod payment_allocation;
+mod payoff_quote;
+mod price_shock;
 mod repayment;
 
 use async_graphql::*;
@@ -29,6 +31,8 @@ pub use collateral::*;
 pub use disbursal::*;
 pub use error::*;
 pub use history::*;
+pub use payoff_quote::*;
+pub use price_shock::*;
 pub use repayment::*;
 
 #[derive(SimpleObject, Clone)]
@@ -78,6 +82,12 @@ impl CreditFacility {
         self.entity.terms.into()
     }
 
+    async fn pending_terms_amendment(&self) -> Option<TermValues> {
+        self.entity
+            .pending_terms_amendment()
+            .map(|(_, terms)| terms.into())
+    }
+
     async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityStatus> {
         let (app, _) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app
@@ -178,11 +188,34 @@ impl CreditFacility {
             .is_ok())
     }
 
+    async fn subject_can_reverse_payment(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .subject_can_reverse_payment(sub, false)
+            .await
+            .is_ok())
+    }
+
     async fn subject_can_complete(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app.credit().subject_can_complete(sub, false).await.is_ok())
     }
 
+    async fn subject_can_prepay(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app.credit().subject_can_prepay(sub, false).await.is_ok())
+    }
+
+    async fn subject_can_restructure(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .subject_can_restructure(sub, false)
+            .await
+            .is_ok())
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let customer = loader
@@ -202,6 +235,20 @@ impl CreditFacility {
         Ok(CreditFacilityBalance::from(balance))
     }
 
+    async fn payoff_quote(
+        &self,
+        ctx: &Context<'_>,
+        as_of: Timestamp,
+    ) -> async_graphql::Result<CreditFacilityPayoffQuote> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let quote = app
+            .credit()
+            .facilities()
+            .payoff_quote(sub, self.entity.id, as_of.into_inner())
+            .await?;
+        Ok(CreditFacilityPayoffQuote::from(quote))
+    }
+
     async fn wallet(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Wallet>> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let collateral = loader
@@ -243,12 +290,31 @@ pub struct CreditFacilityPartialPaymentInput {
 }
 crate::mutation_payload! { CreditFacilityPartialPaymentPayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityPaymentReverseInput {
+    pub payment_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityPaymentReversePayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityCompleteInput {
     pub credit_facility_id: UUID,
 }
 crate::mutation_payload! { CreditFacilityCompletePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityPrepayInput {
+    pub credit_facility_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityPrepayPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityRestructureInput {
+    pub credit_facility_id: UUID,
+    pub terms: TermsInput,
+}
+crate::mutation_payload! { CreditFacilityRestructurePayload, credit_facility: CreditFacility }
+
 #[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CreditFacilitiesSortBy {
     #[default]
@@ -9,6 +9,7 @@ pub use lana_app::credit::PaymentAllocation as DomainPaymentAllocation;
 pub struct CreditFacilityPaymentAllocation {
     id: ID,
     payment_allocation_id: UUID,
+    payment_id: UUID,
     amount: UsdCents,
     created_at: Timestamp,
 
@@ -21,6 +22,7 @@ impl From<DomainPaymentAllocation> for CreditFacilityPaymentAllocation {
         Self {
             id: payment_allocation.id.to_global_id(),
             payment_allocation_id: UUID::from(payment_allocation.id),
+            payment_id: UUID::from(payment_allocation.payment_id),
             amount: payment_allocation.amount,
             created_at: payment_allocation.created_at().into(),
             entity: Arc::new(payment_allocation),
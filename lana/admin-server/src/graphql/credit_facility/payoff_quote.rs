@@ -0,0 +1,32 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+#[derive(SimpleObject)]
+pub(super) struct CreditFacilityPayoffQuote {
+    as_of: Timestamp,
+    valid_until: Timestamp,
+    principal: UsdCents,
+    posted_interest: UsdCents,
+    fees: UsdCents,
+    penalties: UsdCents,
+    projected_interest: UsdCents,
+    prepayment_fee: UsdCents,
+    total: UsdCents,
+}
+
+impl From<lana_app::credit::PayoffQuote> for CreditFacilityPayoffQuote {
+    fn from(quote: lana_app::credit::PayoffQuote) -> Self {
+        Self {
+            as_of: quote.as_of.into(),
+            valid_until: quote.valid_until.into(),
+            principal: quote.principal,
+            posted_interest: quote.posted_interest,
+            fees: quote.fees,
+            penalties: quote.penalties,
+            projected_interest: quote.projected_interest,
+            prepayment_fee: quote.prepayment_fee,
+            total: quote.total(),
+        }
+    }
+}
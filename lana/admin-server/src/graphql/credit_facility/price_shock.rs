@@ -0,0 +1,161 @@
+use async_graphql::*;
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+use lana_app::{
+    credit::{
+        PriceShock as DomainPriceShock, PriceShockBucket as DomainPriceShockBucket,
+        PriceShockFacilityResult as DomainPriceShockFacilityResult,
+        PriceShockSimulation as DomainPriceShockSimulation,
+        price_shock_simulation_error::PriceShockSimulationError,
+    },
+    document::{Document as DomainDocument, DocumentStatus, GeneratedDocumentDownloadLink},
+    primitives::PriceOfOneBTC,
+    terms::CVLPct,
+};
+
+#[derive(OneofObject)]
+pub enum PriceShockInput {
+    BtcPrice(UsdCents),
+    /// Relative move from the current BTC price, e.g. -30 for a 30% drop.
+    BtcPriceChangePct(Decimal),
+}
+
+impl TryFrom<PriceShockInput> for DomainPriceShock {
+    type Error = PriceShockSimulationError;
+
+    fn try_from(input: PriceShockInput) -> Result<Self, Self::Error> {
+        match input {
+            PriceShockInput::BtcPrice(price) => {
+                Ok(DomainPriceShock::Price(PriceOfOneBTC::new(price)))
+            }
+            PriceShockInput::BtcPriceChangePct(pct) => DomainPriceShock::change_pct(pct.into()),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockSimulation {
+    current_price: UsdCents,
+    shocked_price: UsdCents,
+    simulated_at: Timestamp,
+    total_uncovered_exposure: UsdCents,
+    buckets: Vec<PriceShockBucket>,
+    facilities: Vec<PriceShockFacilityResult>,
+}
+
+impl From<DomainPriceShockSimulation> for PriceShockSimulation {
+    fn from(simulation: DomainPriceShockSimulation) -> Self {
+        Self {
+            current_price: simulation.current_price.into_inner(),
+            shocked_price: simulation.shocked_price.into_inner(),
+            simulated_at: simulation.simulated_at.into(),
+            total_uncovered_exposure: simulation.total_uncovered_exposure(),
+            buckets: simulation
+                .buckets()
+                .into_iter()
+                .map(PriceShockBucket::from)
+                .collect(),
+            facilities: simulation
+                .facilities
+                .into_iter()
+                .map(PriceShockFacilityResult::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockBucket {
+    collateralization_state: CollateralizationState,
+    facility_count: u32,
+    collateral_value: UsdCents,
+    outstanding: UsdCents,
+    uncovered_exposure: UsdCents,
+}
+
+impl From<DomainPriceShockBucket> for PriceShockBucket {
+    fn from(bucket: DomainPriceShockBucket) -> Self {
+        Self {
+            collateralization_state: bucket.state,
+            facility_count: bucket.facility_count as u32,
+            collateral_value: bucket.collateral_value,
+            outstanding: bucket.outstanding,
+            uncovered_exposure: bucket.uncovered_exposure,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockFacilityResult {
+    credit_facility_id: UUID,
+    customer_id: UUID,
+    current_state: CollateralizationState,
+    simulated_state: CollateralizationState,
+    simulated_cvl: CVLPct,
+    collateral: Satoshis,
+    collateral_value: UsdCents,
+    outstanding: UsdCents,
+    uncovered_exposure: UsdCents,
+}
+
+impl From<DomainPriceShockFacilityResult> for PriceShockFacilityResult {
+    fn from(result: DomainPriceShockFacilityResult) -> Self {
+        Self {
+            credit_facility_id: UUID::from(result.credit_facility_id),
+            customer_id: UUID::from(result.customer_id),
+            current_state: result.current_state,
+            simulated_state: result.simulated_state,
+            simulated_cvl: result.simulated_cvl,
+            collateral: result.collateral,
+            collateral_value: result.collateral_value,
+            outstanding: result.outstanding,
+            uncovered_exposure: result.uncovered_exposure,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockSimulationCsvDocument {
+    document_id: UUID,
+    filename: String,
+    status: DocumentStatus,
+    created_at: Timestamp,
+}
+
+impl From<DomainDocument> for PriceShockSimulationCsvDocument {
+    fn from(document: DomainDocument) -> Self {
+        Self {
+            document_id: UUID::from(document.id),
+            created_at: document.created_at().into(),
+            status: document.status,
+            filename: document.filename,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PriceShockSimulationCsvCreateInput {
+    pub shock: PriceShockInput,
+}
+crate::mutation_payload! { PriceShockSimulationCsvCreatePayload, document: PriceShockSimulationCsvDocument }
+
+#[derive(InputObject)]
+pub struct PriceShockSimulationCsvDownloadLinkGenerateInput {
+    pub document_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockSimulationCsvDownloadLinkGeneratePayload {
+    document_id: UUID,
+    link: String,
+}
+
+impl From<GeneratedDocumentDownloadLink> for PriceShockSimulationCsvDownloadLinkGeneratePayload {
+    fn from(value: GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            document_id: UUID::from(value.document_id),
+            link: value.link,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use async_graphql::*;
 
-use crate::primitives::*;
+use crate::{
+    graphql::{loader::LanaDataLoader, price::PriceAt},
+    primitives::*,
+};
 
 #[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
 pub enum CreditFacilityRepaymentType {
     Disbursal,
     Interest,
+    Fee,
 }
 
 #[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
@@ -36,6 +40,7 @@ impl From<lana_app::credit::RepaymentStatus> for CreditFacilityRepaymentStatus {
 }
 
 #[derive(SimpleObject)]
+#[graphql(complex)]
 pub struct CreditFacilityRepaymentPlanEntry {
     pub repayment_type: CreditFacilityRepaymentType,
     pub status: CreditFacilityRepaymentStatus,
@@ -45,6 +50,22 @@ pub struct CreditFacilityRepaymentPlanEntry {
     pub due_at: Timestamp,
 }
 
+#[ComplexObject]
+impl CreditFacilityRepaymentPlanEntry {
+    /// The recorded BTC price when the obligation accrued. Empty for
+    /// upcoming entries that have not been recorded yet.
+    async fn accrual_price(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<UsdCents>> {
+        if self.status == CreditFacilityRepaymentStatus::Upcoming {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let record = loader
+            .load_one(PriceAt(self.accrual_at.into_inner()))
+            .await?;
+        Ok(record.map(|r| r.usd_cents_per_btc))
+    }
+}
+
 impl From<lana_app::credit::CreditFacilityRepaymentPlanEntry> for CreditFacilityRepaymentPlanEntry {
     fn from(repayment: lana_app::credit::CreditFacilityRepaymentPlanEntry) -> Self {
         match repayment {
@@ -64,6 +85,14 @@ impl From<lana_app::credit::CreditFacilityRepaymentPlanEntry> for CreditFacility
                 accrual_at: repayment.recorded_at.into(),
                 due_at: repayment.due_at.into(),
             },
+            lana_app::credit::CreditFacilityRepaymentPlanEntry::Fee(repayment) => Self {
+                repayment_type: CreditFacilityRepaymentType::Fee,
+                status: repayment.status.into(),
+                initial: repayment.initial,
+                outstanding: repayment.outstanding,
+                accrual_at: repayment.recorded_at.into(),
+                due_at: repayment.due_at.into(),
+            },
         }
     }
 }
@@ -9,7 +9,8 @@ use super::{
 };
 
 pub use lana_app::customer::{
-    AccountStatus, Customer as DomainCustomer, CustomerType, CustomersCursor,
+    AccountStatus, BankAccountDetails, BeneficiaryAccount as DomainBeneficiaryAccount,
+    Customer as DomainCustomer, CustomerType, CustomersCursor,
     CustomersSortBy as DomainCustomersSortBy, FindManyCustomers, KycLevel, Sort,
 };
 
@@ -57,6 +58,19 @@ impl Customer {
         self.entity.applicant_id.as_deref()
     }
 
+    async fn btc_withdrawal_address(&self) -> Option<&str> {
+        self.entity.btc_withdrawal_address.as_deref()
+    }
+
+    async fn beneficiary_accounts(&self) -> Vec<BeneficiaryAccount> {
+        self.entity
+            .beneficiary_accounts
+            .iter()
+            .cloned()
+            .map(BeneficiaryAccount::from)
+            .collect()
+    }
+
     async fn deposit_account(
         &self,
         ctx: &Context<'_>,
@@ -145,6 +159,94 @@ pub struct CustomerEmailUpdateInput {
 }
 crate::mutation_payload! { CustomerEmailUpdatePayload, customer: Customer }
 
+#[derive(InputObject)]
+pub struct CustomerBtcWithdrawalAddressUpdateInput {
+    pub customer_id: UUID,
+    pub address: String,
+}
+crate::mutation_payload! { CustomerBtcWithdrawalAddressUpdatePayload, customer: Customer }
+
+#[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BankAccountType {
+    Aba,
+    Iban,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct BeneficiaryAccount {
+    beneficiary_account_id: UUID,
+    holder_name: String,
+    bank_name: String,
+    account_type: BankAccountType,
+    masked_account_number: String,
+}
+
+impl From<DomainBeneficiaryAccount> for BeneficiaryAccount {
+    fn from(account: DomainBeneficiaryAccount) -> Self {
+        Self {
+            beneficiary_account_id: UUID::from(account.id),
+            holder_name: account.holder_name,
+            bank_name: account.bank_name,
+            account_type: match account.details {
+                BankAccountDetails::Aba { .. } => BankAccountType::Aba,
+                BankAccountDetails::Iban { .. } => BankAccountType::Iban,
+            },
+            masked_account_number: account.details.masked_account_number(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AbaBankAccountInput {
+    pub routing_number: String,
+    pub account_number: String,
+}
+
+#[derive(InputObject)]
+pub struct IbanBankAccountInput {
+    pub iban: String,
+    pub bic: String,
+}
+
+#[derive(OneofObject)]
+pub enum BankAccountDetailsInput {
+    Aba(AbaBankAccountInput),
+    Iban(IbanBankAccountInput),
+}
+
+impl From<BankAccountDetailsInput> for BankAccountDetails {
+    fn from(input: BankAccountDetailsInput) -> Self {
+        match input {
+            BankAccountDetailsInput::Aba(AbaBankAccountInput {
+                routing_number,
+                account_number,
+            }) => BankAccountDetails::Aba {
+                routing_number,
+                account_number,
+            },
+            BankAccountDetailsInput::Iban(IbanBankAccountInput { iban, bic }) => {
+                BankAccountDetails::Iban { iban, bic }
+            }
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CustomerBeneficiaryAccountAddInput {
+    pub customer_id: UUID,
+    pub holder_name: String,
+    pub bank_name: String,
+    pub details: BankAccountDetailsInput,
+}
+crate::mutation_payload! { CustomerBeneficiaryAccountAddPayload, customer: Customer }
+
+#[derive(InputObject)]
+pub struct CustomerBeneficiaryAccountRemoveInput {
+    pub customer_id: UUID,
+    pub beneficiary_account_id: UUID,
+}
+crate::mutation_payload! { CustomerBeneficiaryAccountRemovePayload, customer: Customer }
+
 #[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CustomersSortBy {
     CreatedAt,
@@ -7,16 +7,22 @@ use super::{
         disbursal::CreditFacilityDisbursal, payment_allocation::CreditFacilityPaymentAllocation,
     },
     deposit::Deposit,
+    transfer::Transfer,
     withdrawal::Withdrawal,
 };
 
 #[derive(Union)]
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
+    RevertedDeposit(RevertedDepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    InterestAccrual(InterestAccrualEntry),
+    InterestCapitalization(InterestCapitalizationEntry),
+    TransferIn(TransferInEntry),
+    TransferOut(TransferOutEntry),
     Unknown(UnknownEntry),
 }
 
@@ -28,6 +34,14 @@ pub struct DepositEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RevertedDepositEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct WithdrawalEntry {
@@ -59,6 +73,38 @@ pub struct PaymentEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+pub struct InterestAccrualEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct InterestCapitalizationEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferInEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferOutEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -80,6 +126,20 @@ impl DepositEntry {
     }
 }
 
+#[ComplexObject]
+impl RevertedDepositEntry {
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Deposit> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let deposit = app
+            .deposits()
+            .find_deposit_by_reverted_tx_id(sub, self.tx_id)
+            .await?;
+
+        Ok(Deposit::from(deposit))
+    }
+}
+
 #[ComplexObject]
 impl WithdrawalEntry {
     async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Withdrawal> {
@@ -141,6 +201,36 @@ impl PaymentEntry {
     }
 }
 
+#[ComplexObject]
+impl TransferInEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl TransferOutEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
 impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHistoryEntry {
     fn from(entry: lana_app::deposit::DepositAccountHistoryEntry) -> Self {
         match entry {
@@ -150,6 +240,12 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::RevertedDeposit(entry) => {
+                Self::RevertedDeposit(RevertedDepositEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Withdrawal(entry) => {
                 Self::Withdrawal(WithdrawalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -174,6 +270,34 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestAccrual(entry) => {
+                Self::InterestAccrual(InterestAccrualEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestCapitalization(entry) => {
+                Self::InterestCapitalization(InterestCapitalizationEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -8,6 +8,7 @@ pub use lana_app::deposit::ChartOfAccountsIntegrationConfig as DomainChartOfAcco
 pub struct DepositModuleConfig {
     chart_of_accounts_id: Option<UUID>,
     chart_of_accounts_omnibus_parent_code: Option<String>,
+    chart_of_accounts_interest_expense_parent_code: Option<String>,
     chart_of_accounts_individual_deposit_accounts_parent_code: Option<String>,
     chart_of_accounts_government_entity_deposit_accounts_parent_code: Option<String>,
     chart_of_account_private_company_deposit_accounts_parent_code: Option<String>,
@@ -26,6 +27,11 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositModuleConfig {
             chart_of_accounts_omnibus_parent_code: Some(
                 values.chart_of_accounts_omnibus_parent_code.to_string(),
             ),
+            chart_of_accounts_interest_expense_parent_code: Some(
+                values
+                    .chart_of_accounts_interest_expense_parent_code
+                    .to_string(),
+            ),
             chart_of_accounts_individual_deposit_accounts_parent_code: Some(
                 values
                     .chart_of_accounts_individual_deposit_accounts_parent_code
@@ -65,6 +71,7 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositModuleConfig {
 #[derive(InputObject)]
 pub struct DepositModuleConfigureInput {
     pub chart_of_accounts_omnibus_parent_code: String,
+    pub chart_of_accounts_interest_expense_parent_code: String,
     pub chart_of_accounts_individual_deposit_accounts_parent_code: String,
     pub chart_of_accounts_government_entity_deposit_accounts_parent_code: String,
     pub chart_of_account_private_company_deposit_accounts_parent_code: String,
@@ -16,14 +16,15 @@ use lana_app::{
     customer::CustomerDocumentId,
     deposit::error::CoreDepositError,
     governance::error::GovernanceError,
+    price::error::PriceError,
 };
 
 use crate::primitives::*;
 
 use super::{
     access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, policy::*, terms_template::*,
-    withdrawal::*,
+    customer::*, deposit::*, deposit_account::*, document::*, policy::*, price::*,
+    reference_rate::*, terms_template::*, transfer::*, withdrawal::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -228,6 +229,22 @@ impl Loader<WithdrawalId> for LanaLoader {
     }
 }
 
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositId> for LanaLoader {
     type Value = Deposit;
     type Error = Arc<CoreDepositError>;
@@ -308,6 +325,23 @@ impl Loader<TermsTemplateId> for LanaLoader {
     }
 }
 
+impl Loader<ReferenceRateId> for LanaLoader {
+    type Value = ReferenceRate;
+    type Error = Arc<lana_app::credit::reference_rate_error::ReferenceRateError>;
+
+    async fn load(
+        &self,
+        keys: &[ReferenceRateId],
+    ) -> Result<HashMap<ReferenceRateId, ReferenceRate>, Self::Error> {
+        self.app
+            .credit()
+            .reference_rates()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<CreditFacilityId> for LanaLoader {
     type Value = CreditFacility;
     type Error = Arc<lana_app::credit::error::CoreCreditError>;
@@ -404,3 +438,17 @@ impl Loader<AccountingCsvDocumentId> for LanaLoader {
             .map_err(Arc::new)
     }
 }
+
+impl Loader<PriceAt> for LanaLoader {
+    type Value = PriceRecord;
+    type Error = Arc<PriceError>;
+
+    async fn load(&self, keys: &[PriceAt]) -> Result<HashMap<PriceAt, PriceRecord>, Self::Error> {
+        let ats: Vec<_> = keys.iter().map(|key| key.0).collect();
+        let records = self.app.price().prices_at(&ats).await.map_err(Arc::new)?;
+        Ok(records
+            .into_iter()
+            .map(|(at, record)| (PriceAt(at), PriceRecord::from(record)))
+            .collect())
+    }
+}
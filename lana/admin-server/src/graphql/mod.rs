@@ -19,10 +19,12 @@ mod loader;
 mod price;
 mod primitives;
 mod profit_and_loss_config;
+mod reference_rate;
 mod report;
 mod sumsub;
 mod terms;
 mod terms_template;
+mod transfer;
 mod withdrawal;
 #[macro_use]
 pub mod macros;
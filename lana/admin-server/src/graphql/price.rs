@@ -1,7 +1,10 @@
-use async_graphql::*;
+use async_graphql::{connection::CursorType, *};
+use serde::{Deserialize, Serialize};
 
 use lana_app::primitives::UsdCents;
 
+use crate::primitives::Timestamp;
+
 #[derive(SimpleObject)]
 pub struct RealtimePrice {
     usd_cents_per_btc: UsdCents,
@@ -14,3 +17,61 @@ impl From<lana_app::primitives::PriceOfOneBTC> for RealtimePrice {
         }
     }
 }
+
+#[derive(SimpleObject, Clone)]
+pub struct PriceRecord {
+    pub usd_cents_per_btc: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+/// Loader key for the last recorded price at or before a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PriceAt(pub chrono::DateTime<chrono::Utc>);
+
+impl From<lana_app::price::PriceRecord> for PriceRecord {
+    fn from(record: lana_app::price::PriceRecord) -> Self {
+        Self {
+            usd_cents_per_btc: record.usd_cents_per_btc.into_inner(),
+            recorded_at: record.recorded_at.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PriceRecordCursor {
+    recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&lana_app::price::PriceRecord> for PriceRecordCursor {
+    fn from(record: &lana_app::price::PriceRecord) -> Self {
+        Self {
+            recorded_at: record.recorded_at,
+        }
+    }
+}
+impl From<PriceRecordCursor> for lana_app::price::PriceRecordCursor {
+    fn from(cursor: PriceRecordCursor) -> Self {
+        Self {
+            recorded_at: cursor.recorded_at,
+        }
+    }
+}
+
+impl CursorType for PriceRecordCursor {
+    type Error = String;
+
+    fn encode_cursor(&self) -> String {
+        use base64::{Engine as _, engine::general_purpose};
+        let json = serde_json::to_string(&self).expect("could not serialize token");
+        general_purpose::STANDARD_NO_PAD.encode(json.as_bytes())
+    }
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        use base64::{Engine as _, engine::general_purpose};
+        let bytes = general_purpose::STANDARD_NO_PAD
+            .decode(s.as_bytes())
+            .map_err(|e| e.to_string())?;
+        let json = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+        serde_json::from_str(&json).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,82 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::terms::AnnualRatePct;
+
+pub use lana_app::credit::{
+    ReferenceRate as DomainReferenceRate, ReferenceRateFixing as DomainReferenceRateFixing,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ReferenceRate {
+    id: ID,
+    reference_rate_id: UUID,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainReferenceRate>,
+}
+
+impl From<DomainReferenceRate> for ReferenceRate {
+    fn from(reference_rate: DomainReferenceRate) -> Self {
+        Self {
+            id: reference_rate.id.to_global_id(),
+            reference_rate_id: reference_rate.id.into(),
+            created_at: reference_rate.created_at().into(),
+            entity: Arc::new(reference_rate),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ReferenceRate {
+    async fn name(&self) -> &str {
+        &self.entity.name
+    }
+
+    async fn fixings(&self) -> Vec<ReferenceRateFixing> {
+        self.entity
+            .fixings()
+            .into_iter()
+            .map(ReferenceRateFixing::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ReferenceRateFixing {
+    effective_date: Date,
+    rate: AnnualRatePct,
+}
+
+impl From<DomainReferenceRateFixing> for ReferenceRateFixing {
+    fn from(fixing: DomainReferenceRateFixing) -> Self {
+        Self {
+            effective_date: fixing.effective_date.into(),
+            rate: fixing.rate,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct ReferenceRateCreateInput {
+    pub name: String,
+}
+crate::mutation_payload! { ReferenceRateCreatePayload, reference_rate: ReferenceRate }
+
+#[derive(InputObject)]
+pub struct ReferenceRateFixingPublishInput {
+    pub reference_rate_id: UUID,
+    pub effective_date: Date,
+    pub rate: AnnualRatePct,
+}
+crate::mutation_payload! { ReferenceRateFixingPublishPayload, reference_rate: ReferenceRate }
+
+#[derive(InputObject)]
+pub struct ReferenceRateFixingsCsvImportInput {
+    pub reference_rate_id: UUID,
+    pub file: Upload,
+}
+crate::mutation_payload! { ReferenceRateFixingsCsvImportPayload, reference_rate: ReferenceRate }
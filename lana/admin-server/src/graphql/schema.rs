@@ -17,7 +17,8 @@ use super::{
     access::*, accounting::*, approval_process::*, audit::*, authenticated_subject::*,
     balance_sheet_config::*, committee::*, credit_config::*, credit_facility::*, custody::*,
     customer::*, dashboard::*, deposit::*, deposit_config::*, document::*, loader::*, policy::*,
-    price::*, profit_and_loss_config::*, report::*, sumsub::*, terms_template::*, withdrawal::*,
+    price::*, profit_and_loss_config::*, reference_rate::*, report::*, sumsub::*,
+    terms_template::*, transfer::*, withdrawal::*,
 };
 
 pub struct Query;
@@ -185,6 +186,15 @@ impl Query {
         )
     }
 
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -233,6 +243,31 @@ impl Query {
             .collect())
     }
 
+    async fn reference_rate(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ReferenceRate>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ReferenceRate,
+            ctx,
+            app.credit().reference_rates().find_by_id(sub, id)
+        )
+    }
+
+    async fn reference_rates(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<ReferenceRate>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let reference_rates = app.credit().reference_rates().list(sub).await?;
+        Ok(reference_rates
+            .into_iter()
+            .map(ReferenceRate::from)
+            .collect())
+    }
+
     async fn credit_facility(
         &self,
         ctx: &Context<'_>,
@@ -297,6 +332,20 @@ impl Query {
         )
     }
 
+    async fn credit_facility_price_shock_simulation(
+        &self,
+        ctx: &Context<'_>,
+        shock: PriceShockInput,
+    ) -> async_graphql::Result<PriceShockSimulation> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let simulation = app
+            .credit()
+            .price_shock_simulations()
+            .simulate(sub, shock.try_into()?)
+            .await?;
+        Ok(PriceShockSimulation::from(simulation))
+    }
+
     async fn disbursal(
         &self,
         ctx: &Context<'_>,
@@ -649,6 +698,58 @@ impl Query {
         Ok(usd_cents_per_btc.into())
     }
 
+    async fn price_at(
+        &self,
+        ctx: &Context<'_>,
+        timestamp: Timestamp,
+    ) -> async_graphql::Result<Option<PriceRecord>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let record = app.price_at(sub, timestamp.into_inner()).await?;
+        Ok(record.map(PriceRecord::from))
+    }
+
+    async fn price_history(
+        &self,
+        ctx: &Context<'_>,
+        from: Timestamp,
+        until: Timestamp,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<PriceRecordCursor, PriceRecord>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        query(
+            after,
+            None,
+            Some(first),
+            None,
+            |after, _, first, _| async move {
+                let first = first.expect("First always exists");
+                let res = app
+                    .list_price_records(
+                        sub,
+                        from.into_inner(),
+                        until.into_inner(),
+                        es_entity::PaginatedQueryArgs {
+                            first,
+                            after: after.map(lana_app::price::PriceRecordCursor::from),
+                        },
+                    )
+                    .await?;
+
+                let mut connection = Connection::new(false, res.has_next_page);
+                connection
+                    .edges
+                    .extend(res.entities.into_iter().map(|record| {
+                        let cursor = PriceRecordCursor::from(&record);
+                        Edge::new(cursor, PriceRecord::from(record))
+                    }));
+
+                Ok::<_, async_graphql::Error>(connection)
+            },
+        )
+        .await
+    }
+
     async fn report(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Report>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         let report = app.reports().find_by_id(sub, id).await?;
@@ -783,7 +884,7 @@ impl Query {
         ledger_account_id: UUID,
     ) -> async_graphql::Result<Option<AccountingCsvDocument>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-        let latest_doc= app
+        let latest_doc = app
             .accounting()
             .csvs()
             .latest_document_for_ledger_account_id(sub, ledger_account_id)
@@ -976,6 +1077,59 @@ impl Mutation {
         )
     }
 
+    async fn customer_btc_withdrawal_address_update(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerBtcWithdrawalAddressUpdateInput,
+    ) -> async_graphql::Result<CustomerBtcWithdrawalAddressUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CustomerBtcWithdrawalAddressUpdatePayload,
+            Customer,
+            ctx,
+            app.customers()
+                .update_btc_withdrawal_address(sub, input.customer_id, input.address)
+        )
+    }
+
+    async fn customer_beneficiary_account_add(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerBeneficiaryAccountAddInput,
+    ) -> async_graphql::Result<CustomerBeneficiaryAccountAddPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CustomerBeneficiaryAccountAddPayload,
+            Customer,
+            ctx,
+            app.customers().add_beneficiary_account(
+                sub,
+                input.customer_id,
+                input.holder_name,
+                input.bank_name,
+                input.details.into(),
+            )
+        )
+    }
+
+    async fn customer_beneficiary_account_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerBeneficiaryAccountRemoveInput,
+    ) -> async_graphql::Result<CustomerBeneficiaryAccountRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CustomerBeneficiaryAccountRemovePayload,
+            Customer,
+            ctx,
+            app.customers().remove_beneficiary_account(
+                sub,
+                input.customer_id,
+                input.beneficiary_account_id.into(),
+            )
+        )
+    }
+
     async fn deposit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -1024,6 +1178,11 @@ impl Mutation {
             .chart_of_accounts_omnibus_parent_code(
                 input.chart_of_accounts_omnibus_parent_code.parse()?,
             )
+            .chart_of_accounts_interest_expense_parent_code(
+                input
+                    .chart_of_accounts_interest_expense_parent_code
+                    .parse()?,
+            )
             .build()?;
         let config = app
             .deposits()
@@ -1081,6 +1240,26 @@ impl Mutation {
         )
     }
 
+    pub async fn transfer_create(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferCreateInput,
+    ) -> async_graphql::Result<TransferCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TransferCreatePayload,
+            Transfer,
+            ctx,
+            app.deposits().transfer(
+                sub,
+                input.from_account_id,
+                input.to_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
     pub async fn withdrawal_initiate(
         &self,
         ctx: &Context<'_>,
@@ -1149,6 +1328,18 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .principal_repayment(input.principal_repayment.unwrap_or_default())
+            .day_count_convention(input.day_count_convention.unwrap_or_default())
+            .prepayment_fee_rate(input.prepayment_fee_rate.unwrap_or_default())
+            .payment_allocation_strategy(input.payment_allocation_strategy.unwrap_or_default())
+            .floating_rate(input.floating_rate.map(lana_app::terms::FloatingRate::from))
+            .penalty_rate(input.penalty_rate)
+            .fee_schedule(
+                input
+                    .fee_schedule
+                    .map(lana_app::terms::FeeSchedule::from)
+                    .unwrap_or_default(),
+            )
             .build()?;
 
         exec_mutation!(
@@ -1182,6 +1373,18 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .principal_repayment(input.principal_repayment.unwrap_or_default())
+            .day_count_convention(input.day_count_convention.unwrap_or_default())
+            .prepayment_fee_rate(input.prepayment_fee_rate.unwrap_or_default())
+            .payment_allocation_strategy(input.payment_allocation_strategy.unwrap_or_default())
+            .floating_rate(input.floating_rate.map(lana_app::terms::FloatingRate::from))
+            .penalty_rate(input.penalty_rate)
+            .fee_schedule(
+                input
+                    .fee_schedule
+                    .map(lana_app::terms::FeeSchedule::from)
+                    .unwrap_or_default(),
+            )
             .build()?;
         exec_mutation!(
             TermsTemplateUpdatePayload,
@@ -1195,6 +1398,63 @@ impl Mutation {
         )
     }
 
+    async fn reference_rate_create(
+        &self,
+        ctx: &Context<'_>,
+        input: ReferenceRateCreateInput,
+    ) -> async_graphql::Result<ReferenceRateCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReferenceRateCreatePayload,
+            ReferenceRate,
+            ctx,
+            app.credit()
+                .reference_rates()
+                .create_reference_rate(sub, input.name)
+        )
+    }
+
+    async fn reference_rate_fixing_publish(
+        &self,
+        ctx: &Context<'_>,
+        input: ReferenceRateFixingPublishInput,
+    ) -> async_graphql::Result<ReferenceRateFixingPublishPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReferenceRateFixingPublishPayload,
+            ReferenceRate,
+            ctx,
+            app.credit().reference_rates().publish_fixing(
+                sub,
+                ReferenceRateId::from(input.reference_rate_id),
+                input.effective_date.into_inner(),
+                input.rate
+            )
+        )
+    }
+
+    async fn reference_rate_fixings_csv_import(
+        &self,
+        ctx: &Context<'_>,
+        input: ReferenceRateFixingsCsvImportInput,
+    ) -> async_graphql::Result<ReferenceRateFixingsCsvImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?.content;
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+        exec_mutation!(
+            ReferenceRateFixingsCsvImportPayload,
+            ReferenceRate,
+            ctx,
+            app.credit().reference_rates().import_fixings_from_csv(
+                sub,
+                ReferenceRateId::from(input.reference_rate_id),
+                data
+            )
+        )
+    }
+
     async fn credit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -1212,11 +1472,19 @@ impl Mutation {
             chart_of_account_facility_omnibus_parent_code,
             chart_of_account_collateral_omnibus_parent_code,
             chart_of_account_in_liquidation_omnibus_parent_code,
+            chart_of_account_liquidator_omnibus_parent_code,
+            chart_of_account_liquidation_proceeds_omnibus_parent_code,
+            chart_of_account_credit_loss_expense_omnibus_parent_code,
             chart_of_account_facility_parent_code,
             chart_of_account_collateral_parent_code,
             chart_of_account_in_liquidation_parent_code,
             chart_of_account_interest_income_parent_code,
             chart_of_account_fee_income_parent_code,
+            chart_of_account_unapplied_funds_parent_code,
+            chart_of_account_penalty_income_parent_code,
+            chart_of_account_commitment_fee_income_parent_code,
+            chart_of_account_maintenance_fee_income_parent_code,
+            chart_of_account_disbursal_fee_income_parent_code,
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code,
             chart_of_account_short_term_government_entity_disbursed_receivable_parent_code,
@@ -1273,6 +1541,18 @@ impl Mutation {
                 chart_of_account_in_liquidation_omnibus_parent_code
                     .parse()?,
             )
+            .chart_of_account_liquidator_omnibus_parent_code(
+                chart_of_account_liquidator_omnibus_parent_code
+                    .parse()?,
+            )
+            .chart_of_account_liquidation_proceeds_omnibus_parent_code(
+                chart_of_account_liquidation_proceeds_omnibus_parent_code
+                    .parse()?,
+            )
+            .chart_of_account_credit_loss_expense_omnibus_parent_code(
+                chart_of_account_credit_loss_expense_omnibus_parent_code
+                    .parse()?,
+            )
             .chart_of_account_facility_parent_code(
                 chart_of_account_facility_parent_code.parse()?,
             )
@@ -1288,6 +1568,21 @@ impl Mutation {
             .chart_of_account_fee_income_parent_code(
                 chart_of_account_fee_income_parent_code.parse()?,
             )
+            .chart_of_account_unapplied_funds_parent_code(
+                chart_of_account_unapplied_funds_parent_code.parse()?,
+            )
+            .chart_of_account_penalty_income_parent_code(
+                chart_of_account_penalty_income_parent_code.parse()?,
+            )
+            .chart_of_account_commitment_fee_income_parent_code(
+                chart_of_account_commitment_fee_income_parent_code.parse()?,
+            )
+            .chart_of_account_maintenance_fee_income_parent_code(
+                chart_of_account_maintenance_fee_income_parent_code.parse()?,
+            )
+            .chart_of_account_disbursal_fee_income_parent_code(
+                chart_of_account_disbursal_fee_income_parent_code.parse()?,
+            )
             .chart_of_account_short_term_individual_disbursed_receivable_parent_code(chart_of_account_short_term_individual_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(chart_of_account_short_term_government_entity_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_private_company_disbursed_receivable_parent_code(chart_of_account_short_term_private_company_disbursed_receivable_parent_code.parse()?)
@@ -1365,6 +1660,18 @@ impl Mutation {
             .liquidation_cvl(terms.liquidation_cvl)
             .margin_call_cvl(terms.margin_call_cvl)
             .initial_cvl(terms.initial_cvl)
+            .principal_repayment(terms.principal_repayment.unwrap_or_default())
+            .day_count_convention(terms.day_count_convention.unwrap_or_default())
+            .prepayment_fee_rate(terms.prepayment_fee_rate.unwrap_or_default())
+            .payment_allocation_strategy(terms.payment_allocation_strategy.unwrap_or_default())
+            .floating_rate(terms.floating_rate.map(lana_app::terms::FloatingRate::from))
+            .penalty_rate(terms.penalty_rate)
+            .fee_schedule(
+                terms
+                    .fee_schedule
+                    .map(lana_app::terms::FeeSchedule::from)
+                    .unwrap_or_default(),
+            )
             .build()?;
 
         exec_mutation!(
@@ -1421,21 +1728,118 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_payment_reverse(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityPaymentReverseInput,
+    ) -> async_graphql::Result<CreditFacilityPaymentReversePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityPaymentReversePayload,
+            CreditFacility,
+            ctx,
+            app.credit().reverse_payment(sub, input.payment_id)
+        )
+    }
+
     pub async fn credit_facility_disbursal_initiate(
         &self,
         ctx: &Context<'_>,
         input: CreditFacilityDisbursalInitiateInput,
     ) -> async_graphql::Result<CreditFacilityDisbursalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        match input.beneficiary_account_id {
+            Some(beneficiary_account_id) => exec_mutation!(
+                CreditFacilityDisbursalInitiatePayload,
+                CreditFacilityDisbursal,
+                ctx,
+                app.credit().initiate_disbursal_to_beneficiary(
+                    sub,
+                    input.credit_facility_id.into(),
+                    input.amount,
+                    beneficiary_account_id.into(),
+                )
+            ),
+            None => exec_mutation!(
+                CreditFacilityDisbursalInitiatePayload,
+                CreditFacilityDisbursal,
+                ctx,
+                app.credit()
+                    .initiate_disbursal(sub, input.credit_facility_id.into(), input.amount)
+            ),
+        }
+    }
+
+    pub async fn credit_facility_disbursal_payment_settle(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityDisbursalPaymentSettleInput,
+    ) -> async_graphql::Result<CreditFacilityDisbursalPaymentSettlePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         exec_mutation!(
-            CreditFacilityDisbursalInitiatePayload,
+            CreditFacilityDisbursalPaymentSettlePayload,
             CreditFacilityDisbursal,
             ctx,
-            app.credit()
-                .initiate_disbursal(sub, input.credit_facility_id.into(), input.amount)
+            app.credit().disbursal_payouts().record_outcome(
+                sub,
+                input.disbursal_id,
+                PaymentOutcome::Settled
+            )
         )
     }
 
+    pub async fn credit_facility_disbursal_payment_return(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityDisbursalPaymentReturnInput,
+    ) -> async_graphql::Result<CreditFacilityDisbursalPaymentReturnPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityDisbursalPaymentReturnPayload,
+            CreditFacilityDisbursal,
+            ctx,
+            app.credit().disbursal_payouts().record_outcome(
+                sub,
+                input.disbursal_id,
+                PaymentOutcome::Returned {
+                    reason: input.reason
+                }
+            )
+        )
+    }
+
+    async fn price_shock_simulation_csv_create(
+        &self,
+        ctx: &Context<'_>,
+        input: PriceShockSimulationCsvCreateInput,
+    ) -> async_graphql::Result<PriceShockSimulationCsvCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let document = app
+            .credit()
+            .price_shock_simulations()
+            .create_csv(sub, input.shock.try_into()?)
+            .await?;
+        Ok(PriceShockSimulationCsvCreatePayload::from(
+            PriceShockSimulationCsvDocument::from(document),
+        ))
+    }
+
+    async fn price_shock_simulation_csv_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: PriceShockSimulationCsvDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<PriceShockSimulationCsvDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .credit()
+            .price_shock_simulations()
+            .generate_download_link(sub, input.document_id)
+            .await?;
+        Ok(PriceShockSimulationCsvDownloadLinkGeneratePayload::from(
+            link,
+        ))
+    }
+
     async fn credit_facility_complete(
         &self,
         ctx: &Context<'_>,
@@ -1451,6 +1855,68 @@ impl Mutation {
         )
     }
 
+    async fn credit_facility_prepay(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityPrepayInput,
+    ) -> async_graphql::Result<CreditFacilityPrepayPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityPrepayPayload,
+            CreditFacility,
+            ctx,
+            app.credit().prepay_facility(sub, input.credit_facility_id)
+        )
+    }
+
+    async fn credit_facility_restructure(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityRestructureInput,
+    ) -> async_graphql::Result<CreditFacilityRestructurePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityRestructureInput {
+            credit_facility_id,
+            terms,
+        } = input;
+
+        let term_values = lana_app::terms::TermValues::builder()
+            .annual_rate(terms.annual_rate)
+            .accrual_interval(terms.accrual_interval)
+            .accrual_cycle_interval(terms.accrual_cycle_interval)
+            .one_time_fee_rate(terms.one_time_fee_rate)
+            .duration(terms.duration)
+            .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
+            .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
+            .obligation_liquidation_duration_from_due(
+                terms.obligation_liquidation_duration_from_due,
+            )
+            .liquidation_cvl(terms.liquidation_cvl)
+            .margin_call_cvl(terms.margin_call_cvl)
+            .initial_cvl(terms.initial_cvl)
+            .principal_repayment(terms.principal_repayment.unwrap_or_default())
+            .day_count_convention(terms.day_count_convention.unwrap_or_default())
+            .prepayment_fee_rate(terms.prepayment_fee_rate.unwrap_or_default())
+            .payment_allocation_strategy(terms.payment_allocation_strategy.unwrap_or_default())
+            .floating_rate(terms.floating_rate.map(lana_app::terms::FloatingRate::from))
+            .penalty_rate(terms.penalty_rate)
+            .fee_schedule(
+                terms
+                    .fee_schedule
+                    .map(lana_app::terms::FeeSchedule::from)
+                    .unwrap_or_default(),
+            )
+            .build()?;
+
+        exec_mutation!(
+            CreditFacilityRestructurePayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .restructure_facility(sub, credit_facility_id, term_values)
+        )
+    }
+
     async fn custodian_create(
         &self,
         ctx: &Context<'_>,
@@ -1,9 +1,13 @@
 use async_graphql::*;
 
+use crate::primitives::*;
+
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestInterval,
-    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct,
-    TermValues as DomainTermValues,
+    AnnualRatePct, AppliedRateFixing as DomainAppliedRateFixing, CVLPct, DayCountConvention,
+    FacilityDuration as DomainDuration, FeeSchedule as DomainFeeSchedule,
+    FloatingRate as DomainFloatingRate, InterestInterval,
+    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct, PaymentAllocationStrategy,
+    PrincipalRepayment, TermValues as DomainTermValues,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -16,6 +20,13 @@ pub struct TermValues {
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    principal_repayment: PrincipalRepayment,
+    day_count_convention: DayCountConvention,
+    prepayment_fee_rate: OneTimeFeeRatePct,
+    payment_allocation_strategy: PaymentAllocationStrategy,
+    floating_rate: Option<FloatingRate>,
+    penalty_rate: Option<AnnualRatePct>,
+    fee_schedule: FeeSchedule,
 }
 
 impl From<DomainTermValues> for TermValues {
@@ -29,6 +40,13 @@ impl From<DomainTermValues> for TermValues {
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
             initial_cvl: values.initial_cvl,
+            principal_repayment: values.principal_repayment,
+            day_count_convention: values.day_count_convention,
+            prepayment_fee_rate: values.prepayment_fee_rate,
+            payment_allocation_strategy: values.payment_allocation_strategy,
+            floating_rate: values.floating_rate.map(FloatingRate::from),
+            penalty_rate: values.penalty_rate,
+            fee_schedule: values.fee_schedule.into(),
         }
     }
 }
@@ -46,6 +64,98 @@ pub struct TermsInput {
     pub obligation_liquidation_duration_from_due: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub principal_repayment: Option<PrincipalRepayment>,
+    pub day_count_convention: Option<DayCountConvention>,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub payment_allocation_strategy: Option<PaymentAllocationStrategy>,
+    pub floating_rate: Option<FloatingRateInput>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub fee_schedule: Option<FeeScheduleInput>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct FloatingRate {
+    reference_rate_id: UUID,
+    spread: AnnualRatePct,
+}
+
+impl From<DomainFloatingRate> for FloatingRate {
+    fn from(floating_rate: DomainFloatingRate) -> Self {
+        Self {
+            reference_rate_id: floating_rate.reference_rate_id.into(),
+            spread: floating_rate.spread,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct FloatingRateInput {
+    pub reference_rate_id: UUID,
+    pub spread: AnnualRatePct,
+}
+
+impl From<FloatingRateInput> for DomainFloatingRate {
+    fn from(input: FloatingRateInput) -> Self {
+        Self {
+            reference_rate_id: input.reference_rate_id.into(),
+            spread: input.spread,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct FeeSchedule {
+    commitment_fee_rate: Option<AnnualRatePct>,
+    annual_maintenance_fee: Option<UsdCents>,
+    disbursal_fee_rate: Option<OneTimeFeeRatePct>,
+}
+
+impl From<DomainFeeSchedule> for FeeSchedule {
+    fn from(fee_schedule: DomainFeeSchedule) -> Self {
+        Self {
+            commitment_fee_rate: fee_schedule.commitment_fee_rate,
+            annual_maintenance_fee: fee_schedule.annual_maintenance_fee,
+            disbursal_fee_rate: fee_schedule.disbursal_fee_rate,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct FeeScheduleInput {
+    pub commitment_fee_rate: Option<AnnualRatePct>,
+    pub annual_maintenance_fee: Option<UsdCents>,
+    pub disbursal_fee_rate: Option<OneTimeFeeRatePct>,
+}
+
+impl From<FeeScheduleInput> for DomainFeeSchedule {
+    fn from(input: FeeScheduleInput) -> Self {
+        Self {
+            commitment_fee_rate: input.commitment_fee_rate,
+            annual_maintenance_fee: input.annual_maintenance_fee,
+            disbursal_fee_rate: input.disbursal_fee_rate,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct AppliedRateFixing {
+    reference_rate_id: UUID,
+    effective_date: Date,
+    reference_rate: AnnualRatePct,
+    spread: AnnualRatePct,
+    annual_rate: AnnualRatePct,
+}
+
+impl From<DomainAppliedRateFixing> for AppliedRateFixing {
+    fn from(fixing: DomainAppliedRateFixing) -> Self {
+        Self {
+            reference_rate_id: fixing.reference_rate_id.into(),
+            effective_date: fixing.effective_date.into(),
+            reference_rate: fixing.reference_rate,
+            spread: fixing.spread,
+            annual_rate: fixing.annual_rate(),
+        }
+    }
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
@@ -64,6 +64,13 @@ pub(super) struct TermsTemplateCreateInput {
     pub liquidation_cvl: CVLPct,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub principal_repayment: Option<PrincipalRepayment>,
+    pub day_count_convention: Option<DayCountConvention>,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub payment_allocation_strategy: Option<PaymentAllocationStrategy>,
+    pub floating_rate: Option<FloatingRateInput>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub fee_schedule: Option<FeeScheduleInput>,
 }
 crate::mutation_payload! { TermsTemplateCreatePayload, terms_template: TermsTemplate }
 
@@ -81,5 +88,12 @@ pub(super) struct TermsTemplateUpdateInput {
     pub obligation_liquidation_duration_from_due: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    pub principal_repayment: Option<PrincipalRepayment>,
+    pub day_count_convention: Option<DayCountConvention>,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub payment_allocation_strategy: Option<PaymentAllocationStrategy>,
+    pub floating_rate: Option<FloatingRateInput>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    pub fee_schedule: Option<FeeScheduleInput>,
 }
 crate::mutation_payload! { TermsTemplateUpdatePayload, terms_template: TermsTemplate }
@@ -0,0 +1,87 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+};
+
+pub use lana_app::deposit::{Transfer as DomainTransfer, TransferStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    amount: UsdCents,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<DomainTransfer> for Transfer {
+    fn from(transfer: DomainTransfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            transfer_id: UUID::from(transfer.id),
+            from_account_id: UUID::from(transfer.from_account_id),
+            to_account_id: UUID::from(transfer.to_account_id),
+            amount: transfer.amount,
+            created_at: transfer.created_at().into(),
+
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self) -> TransferStatus {
+        self.entity.status()
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(approval_process_id).await?)
+    }
+
+    async fn from_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.from_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+
+    async fn to_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.to_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct TransferCreateInput {
+    pub from_account_id: UUID,
+    pub to_account_id: UUID,
+    pub amount: UsdCents,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TransferCreatePayload, transfer: Transfer }
@@ -7,6 +7,7 @@ mod primitives;
 
 mod auth;
 mod custodian_webhooks;
+mod payment_rail_webhooks;
 mod sumsub;
 
 use async_graphql::*;
@@ -46,6 +47,7 @@ pub async fn run(config: AdminServerConfig, app: LanaApp) -> anyhow::Result<()>
         )
         .merge(auth::auth_routes())
         .merge(custodian_webhooks::webhook_routes())
+        .merge(payment_rail_webhooks::payment_rail_routes())
         .merge(sumsub::sumsub_routes())
         .with_state(JwtDecoderState {
             decoder: jwks_decoder,
@@ -0,0 +1,61 @@
+use axum::{
+    Extension, Router,
+    extract::Json,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
+
+use jwks_utils::JwtDecoderState;
+use lana_app::{
+    app::LanaApp,
+    credit::{disbursal_payout_error::DisbursalPayoutError, payment_rail_error::PaymentRailError},
+};
+
+const SECRET_HEADER: &str = "x-payment-rail-secret";
+
+pub async fn payment_rail_webhook(
+    Extension(app): Extension<LanaApp>,
+    headers: HeaderMap,
+    Json(payload): Json<serde_json::Value>,
+) -> impl IntoResponse {
+    let secret = headers
+        .get(SECRET_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match app
+        .credit()
+        .disbursal_payouts()
+        .handle_webhook(secret, payload)
+        .await
+    {
+        Ok(_) => axum::Json("{}").into_response(),
+        Err(
+            err @ DisbursalPayoutError::PaymentRailError(PaymentRailError::InvalidWebhookSecret),
+        ) => {
+            tracing::warn!(error = %err, "rejected payment rail webhook");
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+        Err(
+            err @ (DisbursalPayoutError::SerdeJson(_)
+            | DisbursalPayoutError::PaymentRailError(
+                PaymentRailError::SerdeJson(_)
+                | PaymentRailError::MissingField(_)
+                | PaymentRailError::UnsupportedBankAccount(..)
+                | PaymentRailError::InvalidRoutingNumber(_)
+                | PaymentRailError::AmountTooLarge(_),
+            )),
+        ) => {
+            tracing::warn!(error = %err, "malformed payment rail webhook");
+            StatusCode::BAD_REQUEST.into_response()
+        }
+        Err(err) => {
+            tracing::error!(error = %err, "failed to handle payment rail webhook");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+pub fn payment_rail_routes() -> Router<JwtDecoderState> {
+    Router::new().route("/payment-rail/webhook", post(payment_rail_webhook))
+}
@@ -8,9 +8,9 @@ pub use lana_app::{
         AccountSpec, ApprovalProcessId, ChartId, CollateralId, CommitteeId, CreditFacilityId,
         CustodianId, CustomerDocumentId, CustomerId, DepositAccountId, DepositId, DisbursalId,
         DisbursalStatus, DocumentId, LedgerTransactionId, ManualTransactionId, PaymentAllocationId,
-        PaymentId, PermissionSetId, PolicyId, ReportId, ReportProgress, RoleId, Satoshis,
-        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, UsdCents, UserId, WalletId,
-        WithdrawalId,
+        PaymentId, PermissionSetId, PolicyId, ReferenceRateId, ReportId, ReportProgress, RoleId,
+        Satoshis, SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, TransferId, UsdCents,
+        UserId, WalletId, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -113,6 +113,7 @@ impl_to_global_id! {
     CustomerId,
     ChartId,
     TermsTemplateId,
+    ReferenceRateId,
     CreditFacilityId,
     CollateralId,
     WalletId,
@@ -127,6 +128,7 @@ impl_to_global_id! {
     CommitteeId,
     WithdrawalId,
     DepositId,
+    TransferId,
     ManualTransactionId,
     ApprovalProcessId,
     DepositAccountId,
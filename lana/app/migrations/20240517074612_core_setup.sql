@@ -61,11 +61,28 @@ CREATE TABLE core_chart_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_deposit_products (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_product_events (
+  id UUID NOT NULL REFERENCES core_deposit_products(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_deposit_accounts (
   id UUID PRIMARY KEY,
   account_holder_id UUID NOT NULL,
+  product_id UUID NOT NULL REFERENCES core_deposit_products(id),
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_deposit_accounts_product_id ON core_deposit_accounts(product_id);
 
 CREATE TABLE core_deposit_account_events (
   id UUID NOT NULL REFERENCES core_deposit_accounts(id),
@@ -76,9 +93,26 @@ CREATE TABLE core_deposit_account_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_term_deposits (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_term_deposits_deposit_account_id ON core_term_deposits(deposit_account_id);
+
+CREATE TABLE core_term_deposit_events (
+  id UUID NOT NULL REFERENCES core_term_deposits(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_deposits (
   id UUID PRIMARY KEY,
   deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  reverted_tx_id UUID DEFAULT NULL,
   reference VARCHAR NOT NULL UNIQUE,
   created_at TIMESTAMPTZ NOT NULL
 );
@@ -110,6 +144,26 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  from_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  to_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  approval_process_id UUID REFERENCES core_approval_processes(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_transfers_from_account_id ON core_transfers(from_account_id);
+CREATE INDEX idx_core_transfers_to_account_id ON core_transfers(to_account_id);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_customers (
   id UUID PRIMARY KEY,
   authentication_id UUID UNIQUE DEFAULT NULL,
@@ -143,6 +197,21 @@ CREATE TABLE core_terms_template_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_reference_rates (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_reference_rate_events (
+  id UUID NOT NULL REFERENCES core_reference_rates(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_permission_sets (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
@@ -191,6 +260,7 @@ CREATE TABLE core_user_events (
 
 CREATE TABLE core_collaterals (
   id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL,
   created_at TIMESTAMPTZ NOT NULL
 );
 
@@ -209,9 +279,13 @@ CREATE TABLE core_credit_facilities (
   approval_process_id UUID NOT NULL REFERENCES core_approval_processes(id),
   collateralization_ratio NUMERIC,
   collateralization_state VARCHAR NOT NULL,
+  collateralization_price_floor NUMERIC,
+  collateralization_price_ceiling NUMERIC,
   status VARCHAR NOT NULL,
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_credit_facilities_collateralization_price_floor ON core_credit_facilities(collateralization_price_floor);
+CREATE INDEX idx_core_credit_facilities_collateralization_price_ceiling ON core_credit_facilities(collateralization_price_ceiling);
 
 CREATE TABLE core_credit_facility_events (
   id UUID NOT NULL REFERENCES core_credit_facilities(id),
@@ -292,12 +366,28 @@ CREATE TABLE core_liquidation_process_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_margin_calls (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_margin_call_events (
+  id UUID NOT NULL REFERENCES core_margin_calls(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_disbursals (
   id UUID PRIMARY KEY,
   credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
   approval_process_id UUID NOT NULL REFERENCES core_approval_processes(id),
   obligation_id UUID DEFAULT NULL REFERENCES core_obligations(id),
   concluded_tx_id UUID DEFAULT NULL,
+  payment_external_id VARCHAR DEFAULT NULL UNIQUE,
   created_at TIMESTAMPTZ NOT NULL
 );
 
@@ -470,6 +560,11 @@ CREATE TABLE core_credit_facility_repayment_plans (
   modified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
+CREATE TABLE core_price_records (
+  recorded_at TIMESTAMPTZ PRIMARY KEY,
+  usd_cents_per_btc BIGINT NOT NULL
+);
+
 CREATE TABLE dashboards (
   id UUID PRIMARY KEY,
   dashboard_json JSONB NOT NULL,
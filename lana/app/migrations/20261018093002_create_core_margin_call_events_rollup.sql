@@ -0,0 +1,164 @@
+-- Auto-generated rollup table for MarginCallEvent
+CREATE TABLE core_margin_call_events_rollup (
+  id UUID PRIMARY KEY,
+  last_sequence INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  collateral BIGINT,
+  credit_facility_id UUID,
+  cure_deadline TIMESTAMPTZ,
+  effective VARCHAR,
+  outstanding BIGINT,
+  price JSONB,
+  reason VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_cured BOOLEAN DEFAULT false,
+  is_escalated BOOLEAN DEFAULT false
+
+);
+
+-- Auto-generated trigger function for MarginCallEvent
+CREATE OR REPLACE FUNCTION core_margin_call_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_margin_call_events_rollup%ROWTYPE;
+  new_row core_margin_call_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the current rollup state
+  SELECT * INTO current_row
+  FROM core_margin_call_events_rollup
+  WHERE id = NEW.id;
+
+  -- Early return if event is older than current state
+  IF current_row.id IS NOT NULL AND NEW.sequence <= current_row.last_sequence THEN
+    RETURN NEW;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'cured', 'escalated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.last_sequence := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.cure_deadline := (NEW.event ->> 'cure_deadline')::TIMESTAMPTZ;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.is_cured := false;
+    new_row.is_escalated := false;
+    new_row.outstanding := (NEW.event ->> 'outstanding')::BIGINT;
+    new_row.price := (NEW.event -> 'price');
+    new_row.reason := (NEW.event ->> 'reason');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.collateral := current_row.collateral;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.cure_deadline := current_row.cure_deadline;
+    new_row.effective := current_row.effective;
+    new_row.is_cured := current_row.is_cured;
+    new_row.is_escalated := current_row.is_escalated;
+    new_row.outstanding := current_row.outstanding;
+    new_row.price := current_row.price;
+    new_row.reason := current_row.reason;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.cure_deadline := (NEW.event ->> 'cure_deadline')::TIMESTAMPTZ;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.outstanding := (NEW.event ->> 'outstanding')::BIGINT;
+      new_row.price := (NEW.event -> 'price');
+    WHEN 'cured' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.is_cured := true;
+      new_row.outstanding := (NEW.event ->> 'outstanding')::BIGINT;
+      new_row.reason := (NEW.event ->> 'reason');
+    WHEN 'escalated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.is_escalated := true;
+  END CASE;
+
+  INSERT INTO core_margin_call_events_rollup (
+    id,
+    last_sequence,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    collateral,
+    credit_facility_id,
+    cure_deadline,
+    effective,
+    is_cured,
+    is_escalated,
+    outstanding,
+    price,
+    reason
+  )
+  VALUES (
+    new_row.id,
+    new_row.last_sequence,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.collateral,
+    new_row.credit_facility_id,
+    new_row.cure_deadline,
+    new_row.effective,
+    new_row.is_cured,
+    new_row.is_escalated,
+    new_row.outstanding,
+    new_row.price,
+    new_row.reason
+  )
+  ON CONFLICT (id) DO UPDATE SET
+    last_sequence = EXCLUDED.last_sequence,
+    modified_at = EXCLUDED.modified_at,
+    audit_entry_ids = EXCLUDED.audit_entry_ids,
+    collateral = EXCLUDED.collateral,
+    credit_facility_id = EXCLUDED.credit_facility_id,
+    cure_deadline = EXCLUDED.cure_deadline,
+    effective = EXCLUDED.effective,
+    is_cured = EXCLUDED.is_cured,
+    is_escalated = EXCLUDED.is_escalated,
+    outstanding = EXCLUDED.outstanding,
+    price = EXCLUDED.price,
+    reason = EXCLUDED.reason;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for MarginCallEvent
+CREATE TRIGGER core_margin_call_events_rollup_trigger
+  AFTER INSERT ON core_margin_call_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_margin_call_events_rollup_trigger();
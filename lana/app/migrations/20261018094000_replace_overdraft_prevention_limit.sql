@@ -0,0 +1,36 @@
+-- The overdraft prevention limit 00000000-0000-0000-0000-000000000001 has no
+-- condition, so it also blocks deposit reversals that are allowed to overdraw
+-- the account. It is replaced by 00000000-0000-0000-0000-000000000004, which
+-- is created and added to the deposit controls on startup.
+DELETE FROM cala_velocity_control_limits
+WHERE velocity_limit_id = '00000000-0000-0000-0000-000000000001'
+  AND velocity_control_id IN (
+    '00000000-0000-0000-0000-000000000001',
+    '00000000-0000-0000-0000-000000000003'
+  );
+
+-- Controls already attached to accounts keep a copy of their limits, so the
+-- copies of the old limit are switched over to the new one.
+UPDATE cala_velocity_account_controls
+SET values = jsonb_set(
+  values,
+  '{velocity_limits}',
+  (
+    SELECT jsonb_agg(
+      CASE
+        WHEN velocity_limit->>'velocity_limit_id' = '00000000-0000-0000-0000-000000000001'
+        THEN velocity_limit || jsonb_build_object(
+          'velocity_limit_id', '00000000-0000-0000-0000-000000000004',
+          'condition', 'context.vars.transaction.metadata == null || context.vars.transaction.metadata.allow_overdraft != true'
+        )
+        ELSE velocity_limit
+      END
+    )
+    FROM jsonb_array_elements(values->'velocity_limits') AS velocity_limit
+  )
+)
+WHERE velocity_control_id IN (
+    '00000000-0000-0000-0000-000000000001',
+    '00000000-0000-0000-0000-000000000003'
+  )
+  AND values->'velocity_limits' @> '[{"velocity_limit_id": "00000000-0000-0000-0000-000000000001"}]';
@@ -0,0 +1,352 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for ObligationEvent
+CREATE TABLE core_obligation_events_rollup (
+  id UUID PRIMARY KEY,
+  last_sequence INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  accrued VARCHAR,
+  amount BIGINT,
+  credit_facility_id UUID,
+  defaulted_account_id UUID,
+  defaulted_amount BIGINT,
+  defaulted_date TIMESTAMPTZ,
+  due_accounts JSONB,
+  due_amount BIGINT,
+  due_date TIMESTAMPTZ,
+  effective VARCHAR,
+  in_liquidation_account_id UUID,
+  initial_amount BIGINT,
+  liquidation_date TIMESTAMPTZ,
+  liquidation_process_id UUID,
+  not_yet_due_accounts JSONB,
+  obligation_type VARCHAR,
+  overdue_accounts JSONB,
+  overdue_amount BIGINT,
+  overdue_date TIMESTAMPTZ,
+  payment_allocation_amount BIGINT,
+  penalty_obligation_id UUID,
+  period JSONB,
+  reference VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+  ledger_tx_ids UUID[],
+  payment_allocation_ids UUID[],
+  payment_ids UUID[],
+
+  -- Toggle fields
+  is_completed BOOLEAN DEFAULT false,
+  is_defaulted_recorded BOOLEAN DEFAULT false,
+  is_due_recorded BOOLEAN DEFAULT false,
+  is_overdue_recorded BOOLEAN DEFAULT false
+
+);
+*/
+
+-- Migration to update core_obligation_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_obligation_events_rollup ADD COLUMN IF NOT EXISTS accrued VARCHAR;
+ALTER TABLE core_obligation_events_rollup ADD COLUMN IF NOT EXISTS penalty_obligation_id UUID;
+ALTER TABLE core_obligation_events_rollup ADD COLUMN IF NOT EXISTS period JSONB;
+
+
+-- Auto-generated trigger function for ObligationEvent
+CREATE OR REPLACE FUNCTION core_obligation_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_obligation_events_rollup%ROWTYPE;
+  new_row core_obligation_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the current rollup state
+  SELECT * INTO current_row
+  FROM core_obligation_events_rollup
+  WHERE id = NEW.id;
+
+  -- Early return if event is older than current state
+  IF current_row.id IS NOT NULL AND NEW.sequence <= current_row.last_sequence THEN
+    RETURN NEW;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'due_recorded', 'overdue_recorded', 'defaulted_recorded', 'payment_allocated', 'payment_allocation_reversed', 'penalty_accrued', 'penalty_posted', 'liquidation_process_started', 'liquidation_process_concluded', 'completed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.last_sequence := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.accrued := (NEW.event ->> 'accrued');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.defaulted_account_id := (NEW.event ->> 'defaulted_account_id')::UUID;
+    new_row.defaulted_amount := (NEW.event ->> 'defaulted_amount')::BIGINT;
+    new_row.defaulted_date := (NEW.event ->> 'defaulted_date')::TIMESTAMPTZ;
+    new_row.due_accounts := (NEW.event -> 'due_accounts');
+    new_row.due_amount := (NEW.event ->> 'due_amount')::BIGINT;
+    new_row.due_date := (NEW.event ->> 'due_date')::TIMESTAMPTZ;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.in_liquidation_account_id := (NEW.event ->> 'in_liquidation_account_id')::UUID;
+    new_row.initial_amount := (NEW.event ->> 'initial_amount')::BIGINT;
+    new_row.is_completed := false;
+    new_row.is_defaulted_recorded := false;
+    new_row.is_due_recorded := false;
+    new_row.is_overdue_recorded := false;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.liquidation_date := (NEW.event ->> 'liquidation_date')::TIMESTAMPTZ;
+    new_row.liquidation_process_id := (NEW.event ->> 'liquidation_process_id')::UUID;
+    new_row.not_yet_due_accounts := (NEW.event -> 'not_yet_due_accounts');
+    new_row.obligation_type := (NEW.event ->> 'obligation_type');
+    new_row.overdue_accounts := (NEW.event -> 'overdue_accounts');
+    new_row.overdue_amount := (NEW.event ->> 'overdue_amount')::BIGINT;
+    new_row.overdue_date := (NEW.event ->> 'overdue_date')::TIMESTAMPTZ;
+    new_row.payment_allocation_amount := (NEW.event ->> 'payment_allocation_amount')::BIGINT;
+    new_row.payment_allocation_ids := CASE
+       WHEN NEW.event ? 'payment_allocation_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'payment_allocation_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.payment_ids := CASE
+       WHEN NEW.event ? 'payment_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'payment_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.penalty_obligation_id := (NEW.event ->> 'penalty_obligation_id')::UUID;
+    new_row.period := (NEW.event -> 'period');
+    new_row.reference := (NEW.event ->> 'reference');
+  ELSE
+    -- Default all fields to current values
+    new_row.accrued := current_row.accrued;
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.defaulted_account_id := current_row.defaulted_account_id;
+    new_row.defaulted_amount := current_row.defaulted_amount;
+    new_row.defaulted_date := current_row.defaulted_date;
+    new_row.due_accounts := current_row.due_accounts;
+    new_row.due_amount := current_row.due_amount;
+    new_row.due_date := current_row.due_date;
+    new_row.effective := current_row.effective;
+    new_row.in_liquidation_account_id := current_row.in_liquidation_account_id;
+    new_row.initial_amount := current_row.initial_amount;
+    new_row.is_completed := current_row.is_completed;
+    new_row.is_defaulted_recorded := current_row.is_defaulted_recorded;
+    new_row.is_due_recorded := current_row.is_due_recorded;
+    new_row.is_overdue_recorded := current_row.is_overdue_recorded;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.liquidation_date := current_row.liquidation_date;
+    new_row.liquidation_process_id := current_row.liquidation_process_id;
+    new_row.not_yet_due_accounts := current_row.not_yet_due_accounts;
+    new_row.obligation_type := current_row.obligation_type;
+    new_row.overdue_accounts := current_row.overdue_accounts;
+    new_row.overdue_amount := current_row.overdue_amount;
+    new_row.overdue_date := current_row.overdue_date;
+    new_row.payment_allocation_amount := current_row.payment_allocation_amount;
+    new_row.payment_allocation_ids := current_row.payment_allocation_ids;
+    new_row.payment_ids := current_row.payment_ids;
+    new_row.penalty_obligation_id := current_row.penalty_obligation_id;
+    new_row.period := current_row.period;
+    new_row.reference := current_row.reference;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.defaulted_account_id := (NEW.event ->> 'defaulted_account_id')::UUID;
+      new_row.defaulted_date := (NEW.event ->> 'defaulted_date')::TIMESTAMPTZ;
+      new_row.due_accounts := (NEW.event -> 'due_accounts');
+      new_row.due_date := (NEW.event ->> 'due_date')::TIMESTAMPTZ;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.in_liquidation_account_id := (NEW.event ->> 'in_liquidation_account_id')::UUID;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.liquidation_date := (NEW.event ->> 'liquidation_date')::TIMESTAMPTZ;
+      new_row.not_yet_due_accounts := (NEW.event -> 'not_yet_due_accounts');
+      new_row.obligation_type := (NEW.event ->> 'obligation_type');
+      new_row.overdue_accounts := (NEW.event -> 'overdue_accounts');
+      new_row.overdue_date := (NEW.event ->> 'overdue_date')::TIMESTAMPTZ;
+      new_row.reference := (NEW.event ->> 'reference');
+    WHEN 'due_recorded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.due_amount := (NEW.event ->> 'due_amount')::BIGINT;
+      new_row.is_due_recorded := true;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'overdue_recorded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_overdue_recorded := true;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.overdue_amount := (NEW.event ->> 'overdue_amount')::BIGINT;
+    WHEN 'defaulted_recorded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.defaulted_amount := (NEW.event ->> 'defaulted_amount')::BIGINT;
+      new_row.is_defaulted_recorded := true;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'payment_allocated' THEN
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.payment_allocation_amount := (NEW.event ->> 'payment_allocation_amount')::BIGINT;
+      new_row.payment_allocation_ids := array_append(COALESCE(current_row.payment_allocation_ids, ARRAY[]::UUID[]), (NEW.event ->> 'payment_allocation_id')::UUID);
+      new_row.payment_ids := array_append(COALESCE(current_row.payment_ids, ARRAY[]::UUID[]), (NEW.event ->> 'payment_id')::UUID);
+    WHEN 'payment_allocation_reversed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.payment_allocation_amount := (NEW.event ->> 'payment_allocation_amount')::BIGINT;
+    WHEN 'penalty_accrued' THEN
+      new_row.accrued := (NEW.event ->> 'accrued');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.period := (NEW.event -> 'period');
+    WHEN 'penalty_posted' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.penalty_obligation_id := (NEW.event ->> 'penalty_obligation_id')::UUID;
+    WHEN 'liquidation_process_started' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.initial_amount := (NEW.event ->> 'initial_amount')::BIGINT;
+      new_row.liquidation_process_id := (NEW.event ->> 'liquidation_process_id')::UUID;
+    WHEN 'liquidation_process_concluded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.liquidation_process_id := (NEW.event ->> 'liquidation_process_id')::UUID;
+    WHEN 'completed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.is_completed := true;
+  END CASE;
+
+  INSERT INTO core_obligation_events_rollup (
+    id,
+    last_sequence,
+    created_at,
+    modified_at,
+    accrued,
+    amount,
+    audit_entry_ids,
+    credit_facility_id,
+    defaulted_account_id,
+    defaulted_amount,
+    defaulted_date,
+    due_accounts,
+    due_amount,
+    due_date,
+    effective,
+    in_liquidation_account_id,
+    initial_amount,
+    is_completed,
+    is_defaulted_recorded,
+    is_due_recorded,
+    is_overdue_recorded,
+    ledger_tx_ids,
+    liquidation_date,
+    liquidation_process_id,
+    not_yet_due_accounts,
+    obligation_type,
+    overdue_accounts,
+    overdue_amount,
+    overdue_date,
+    payment_allocation_amount,
+    payment_allocation_ids,
+    payment_ids,
+    penalty_obligation_id,
+    period,
+    reference
+  )
+  VALUES (
+    new_row.id,
+    new_row.last_sequence,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.accrued,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.credit_facility_id,
+    new_row.defaulted_account_id,
+    new_row.defaulted_amount,
+    new_row.defaulted_date,
+    new_row.due_accounts,
+    new_row.due_amount,
+    new_row.due_date,
+    new_row.effective,
+    new_row.in_liquidation_account_id,
+    new_row.initial_amount,
+    new_row.is_completed,
+    new_row.is_defaulted_recorded,
+    new_row.is_due_recorded,
+    new_row.is_overdue_recorded,
+    new_row.ledger_tx_ids,
+    new_row.liquidation_date,
+    new_row.liquidation_process_id,
+    new_row.not_yet_due_accounts,
+    new_row.obligation_type,
+    new_row.overdue_accounts,
+    new_row.overdue_amount,
+    new_row.overdue_date,
+    new_row.payment_allocation_amount,
+    new_row.payment_allocation_ids,
+    new_row.payment_ids,
+    new_row.penalty_obligation_id,
+    new_row.period,
+    new_row.reference
+  )
+  ON CONFLICT (id) DO UPDATE SET
+    last_sequence = EXCLUDED.last_sequence,
+    modified_at = EXCLUDED.modified_at,
+    accrued = EXCLUDED.accrued,
+    amount = EXCLUDED.amount,
+    audit_entry_ids = EXCLUDED.audit_entry_ids,
+    credit_facility_id = EXCLUDED.credit_facility_id,
+    defaulted_account_id = EXCLUDED.defaulted_account_id,
+    defaulted_amount = EXCLUDED.defaulted_amount,
+    defaulted_date = EXCLUDED.defaulted_date,
+    due_accounts = EXCLUDED.due_accounts,
+    due_amount = EXCLUDED.due_amount,
+    due_date = EXCLUDED.due_date,
+    effective = EXCLUDED.effective,
+    in_liquidation_account_id = EXCLUDED.in_liquidation_account_id,
+    initial_amount = EXCLUDED.initial_amount,
+    is_completed = EXCLUDED.is_completed,
+    is_defaulted_recorded = EXCLUDED.is_defaulted_recorded,
+    is_due_recorded = EXCLUDED.is_due_recorded,
+    is_overdue_recorded = EXCLUDED.is_overdue_recorded,
+    ledger_tx_ids = EXCLUDED.ledger_tx_ids,
+    liquidation_date = EXCLUDED.liquidation_date,
+    liquidation_process_id = EXCLUDED.liquidation_process_id,
+    not_yet_due_accounts = EXCLUDED.not_yet_due_accounts,
+    obligation_type = EXCLUDED.obligation_type,
+    overdue_accounts = EXCLUDED.overdue_accounts,
+    overdue_amount = EXCLUDED.overdue_amount,
+    overdue_date = EXCLUDED.overdue_date,
+    payment_allocation_amount = EXCLUDED.payment_allocation_amount,
+    payment_allocation_ids = EXCLUDED.payment_allocation_ids,
+    payment_ids = EXCLUDED.payment_ids,
+    penalty_obligation_id = EXCLUDED.penalty_obligation_id,
+    period = EXCLUDED.period,
+    reference = EXCLUDED.reference;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,199 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for CollateralEvent
+CREATE TABLE core_collateral_events_rollup (
+  id UUID PRIMARY KEY,
+  last_sequence INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  abs_diff BIGINT,
+  account_id UUID,
+  action VARCHAR,
+  address VARCHAR,
+  amount BIGINT,
+  approval_process_id UUID,
+  approved BOOLEAN,
+  asset VARCHAR,
+  collateral_amount BIGINT,
+  credit_facility_id UUID,
+  reason VARCHAR,
+  wallet_id UUID,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+  ledger_tx_ids UUID[]
+
+);
+*/
+
+-- Migration to update core_collateral_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_collateral_events_rollup ADD COLUMN IF NOT EXISTS asset VARCHAR;
+
+
+-- Auto-generated trigger function for CollateralEvent
+CREATE OR REPLACE FUNCTION core_collateral_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_collateral_events_rollup%ROWTYPE;
+  new_row core_collateral_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the current rollup state
+  SELECT * INTO current_row
+  FROM core_collateral_events_rollup
+  WHERE id = NEW.id;
+
+  -- Early return if event is older than current state
+  IF current_row.id IS NOT NULL AND NEW.sequence <= current_row.last_sequence THEN
+    RETURN NEW;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'updated', 'withdrawal_requested', 'withdrawal_concluded', 'withdrawal_failed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.last_sequence := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+    new_row.account_id := (NEW.event ->> 'account_id')::UUID;
+    new_row.action := (NEW.event ->> 'action');
+    new_row.address := (NEW.event ->> 'address');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.asset := (NEW.event ->> 'asset');
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.wallet_id := (NEW.event ->> 'wallet_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.abs_diff := current_row.abs_diff;
+    new_row.account_id := current_row.account_id;
+    new_row.action := current_row.action;
+    new_row.address := current_row.address;
+    new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.asset := current_row.asset;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.collateral_amount := current_row.collateral_amount;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.reason := current_row.reason;
+    new_row.wallet_id := current_row.wallet_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_id := (NEW.event ->> 'account_id')::UUID;
+      new_row.asset := (NEW.event ->> 'asset');
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.wallet_id := (NEW.event ->> 'wallet_id')::UUID;
+    WHEN 'updated' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.action := (NEW.event ->> 'action');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'withdrawal_requested' THEN
+      new_row.address := (NEW.event ->> 'address');
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'withdrawal_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'withdrawal_failed' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.reason := (NEW.event ->> 'reason');
+  END CASE;
+
+  INSERT INTO core_collateral_events_rollup (
+    id,
+    last_sequence,
+    created_at,
+    modified_at,
+    abs_diff,
+    account_id,
+    action,
+    address,
+    amount,
+    approval_process_id,
+    approved,
+    asset,
+    audit_entry_ids,
+    collateral_amount,
+    credit_facility_id,
+    ledger_tx_ids,
+    reason,
+    wallet_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.last_sequence,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.abs_diff,
+    new_row.account_id,
+    new_row.action,
+    new_row.address,
+    new_row.amount,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.asset,
+    new_row.audit_entry_ids,
+    new_row.collateral_amount,
+    new_row.credit_facility_id,
+    new_row.ledger_tx_ids,
+    new_row.reason,
+    new_row.wallet_id
+  )
+  ON CONFLICT (id) DO UPDATE SET
+    last_sequence = EXCLUDED.last_sequence,
+    modified_at = EXCLUDED.modified_at,
+    abs_diff = EXCLUDED.abs_diff,
+    account_id = EXCLUDED.account_id,
+    action = EXCLUDED.action,
+    address = EXCLUDED.address,
+    amount = EXCLUDED.amount,
+    approval_process_id = EXCLUDED.approval_process_id,
+    approved = EXCLUDED.approved,
+    asset = EXCLUDED.asset,
+    audit_entry_ids = EXCLUDED.audit_entry_ids,
+    collateral_amount = EXCLUDED.collateral_amount,
+    credit_facility_id = EXCLUDED.credit_facility_id,
+    ledger_tx_ids = EXCLUDED.ledger_tx_ids,
+    reason = EXCLUDED.reason,
+    wallet_id = EXCLUDED.wallet_id;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
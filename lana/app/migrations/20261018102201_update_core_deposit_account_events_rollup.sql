@@ -0,0 +1,210 @@
+-- Current table structure after migration:
+/*
+-- Auto-generated rollup table for DepositAccountEvent
+CREATE TABLE core_deposit_account_events_rollup (
+  id UUID PRIMARY KEY,
+  last_sequence INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  account_holder_id UUID,
+  accrued_on VARCHAR,
+  amount BIGINT,
+  balance BIGINT,
+  capitalized_on VARCHAR,
+  description VARCHAR,
+  effective VARCHAR,
+  interest VARCHAR,
+  interest_terms JSONB,
+  ledger_account_id UUID,
+  ledger_tx_id UUID,
+  name VARCHAR,
+  product_id UUID,
+  reference VARCHAR,
+  status VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+
+);
+*/
+
+-- Migration to update core_deposit_account_events_rollup table schema
+
+-- Add new columns
+ALTER TABLE core_deposit_account_events_rollup ADD COLUMN IF NOT EXISTS product_id UUID;
+
+
+-- Auto-generated trigger function for DepositAccountEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_events_rollup%ROWTYPE;
+  new_row core_deposit_account_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the current rollup state
+  SELECT * INTO current_row
+  FROM core_deposit_account_events_rollup
+  WHERE id = NEW.id;
+
+  -- Early return if event is older than current state
+  IF current_row.id IS NOT NULL AND NEW.sequence <= current_row.last_sequence THEN
+    RETURN NEW;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'account_status_updated', 'interest_terms_updated', 'interest_accrued', 'interest_capitalized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.last_sequence := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.accrued_on := (NEW.event ->> 'accrued_on');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+    new_row.capitalized_on := (NEW.event ->> 'capitalized_on');
+    new_row.description := (NEW.event ->> 'description');
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.interest := (NEW.event ->> 'interest');
+    new_row.interest_terms := (NEW.event -> 'interest_terms');
+    new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.name := (NEW.event ->> 'name');
+    new_row.product_id := (NEW.event ->> 'product_id')::UUID;
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.accrued_on := current_row.accrued_on;
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.balance := current_row.balance;
+    new_row.capitalized_on := current_row.capitalized_on;
+    new_row.description := current_row.description;
+    new_row.effective := current_row.effective;
+    new_row.interest := current_row.interest;
+    new_row.interest_terms := current_row.interest_terms;
+    new_row.ledger_account_id := current_row.ledger_account_id;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.name := current_row.name;
+    new_row.product_id := current_row.product_id;
+    new_row.reference := current_row.reference;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.description := (NEW.event ->> 'description');
+      new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+      new_row.name := (NEW.event ->> 'name');
+      new_row.product_id := (NEW.event ->> 'product_id')::UUID;
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'account_status_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'interest_terms_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.interest_terms := (NEW.event -> 'interest_terms');
+    WHEN 'interest_accrued' THEN
+      new_row.accrued_on := (NEW.event ->> 'accrued_on');
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.balance := (NEW.event ->> 'balance')::BIGINT;
+      new_row.interest := (NEW.event ->> 'interest');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'interest_capitalized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.capitalized_on := (NEW.event ->> 'capitalized_on');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_deposit_account_events_rollup (
+    id,
+    last_sequence,
+    created_at,
+    modified_at,
+    account_holder_id,
+    accrued_on,
+    amount,
+    audit_entry_ids,
+    balance,
+    capitalized_on,
+    description,
+    effective,
+    interest,
+    interest_terms,
+    ledger_account_id,
+    ledger_tx_id,
+    name,
+    product_id,
+    reference,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.last_sequence,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.account_holder_id,
+    new_row.accrued_on,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.balance,
+    new_row.capitalized_on,
+    new_row.description,
+    new_row.effective,
+    new_row.interest,
+    new_row.interest_terms,
+    new_row.ledger_account_id,
+    new_row.ledger_tx_id,
+    new_row.name,
+    new_row.product_id,
+    new_row.reference,
+    new_row.status
+  )
+  ON CONFLICT (id) DO UPDATE SET
+    last_sequence = EXCLUDED.last_sequence,
+    modified_at = EXCLUDED.modified_at,
+    account_holder_id = EXCLUDED.account_holder_id,
+    accrued_on = EXCLUDED.accrued_on,
+    amount = EXCLUDED.amount,
+    audit_entry_ids = EXCLUDED.audit_entry_ids,
+    balance = EXCLUDED.balance,
+    capitalized_on = EXCLUDED.capitalized_on,
+    description = EXCLUDED.description,
+    effective = EXCLUDED.effective,
+    interest = EXCLUDED.interest,
+    interest_terms = EXCLUDED.interest_terms,
+    ledger_account_id = EXCLUDED.ledger_account_id,
+    ledger_tx_id = EXCLUDED.ledger_tx_id,
+    name = EXCLUDED.name,
+    product_id = EXCLUDED.product_id,
+    reference = EXCLUDED.reference,
+    status = EXCLUDED.status;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,215 @@
+-- Auto-generated rollup table for TermDepositEvent
+CREATE TABLE core_term_deposit_events_rollup (
+  id UUID PRIMARY KEY,
+  last_sequence INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  broken_on VARCHAR,
+  deposit_account_id UUID,
+  interest BIGINT,
+  interest_ledger_tx_id UUID,
+  interest_terms JSONB,
+  ledger_account_id UUID,
+  ledger_tx_id UUID,
+  matured_on VARCHAR,
+  matures_on VARCHAR,
+  maturity_instruction VARCHAR,
+  penalty BIGINT,
+  penalty_ledger_tx_id UUID,
+  principal BIGINT,
+  product_id UUID,
+  rules JSONB,
+  starts_on VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+
+);
+
+-- Auto-generated trigger function for TermDepositEvent
+CREATE OR REPLACE FUNCTION core_term_deposit_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_term_deposit_events_rollup%ROWTYPE;
+  new_row core_term_deposit_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the current rollup state
+  SELECT * INTO current_row
+  FROM core_term_deposit_events_rollup
+  WHERE id = NEW.id;
+
+  -- Early return if event is older than current state
+  IF current_row.id IS NOT NULL AND NEW.sequence <= current_row.last_sequence THEN
+    RETURN NEW;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'rolled_over', 'matured', 'broken') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.last_sequence := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.broken_on := (NEW.event ->> 'broken_on');
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+    new_row.interest_ledger_tx_id := (NEW.event ->> 'interest_ledger_tx_id')::UUID;
+    new_row.interest_terms := (NEW.event -> 'interest_terms');
+    new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.matured_on := (NEW.event ->> 'matured_on');
+    new_row.matures_on := (NEW.event ->> 'matures_on');
+    new_row.maturity_instruction := (NEW.event ->> 'maturity_instruction');
+    new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+    new_row.penalty_ledger_tx_id := (NEW.event ->> 'penalty_ledger_tx_id')::UUID;
+    new_row.principal := (NEW.event ->> 'principal')::BIGINT;
+    new_row.product_id := (NEW.event ->> 'product_id')::UUID;
+    new_row.rules := (NEW.event -> 'rules');
+    new_row.starts_on := (NEW.event ->> 'starts_on');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.broken_on := current_row.broken_on;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.interest := current_row.interest;
+    new_row.interest_ledger_tx_id := current_row.interest_ledger_tx_id;
+    new_row.interest_terms := current_row.interest_terms;
+    new_row.ledger_account_id := current_row.ledger_account_id;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.matured_on := current_row.matured_on;
+    new_row.matures_on := current_row.matures_on;
+    new_row.maturity_instruction := current_row.maturity_instruction;
+    new_row.penalty := current_row.penalty;
+    new_row.penalty_ledger_tx_id := current_row.penalty_ledger_tx_id;
+    new_row.principal := current_row.principal;
+    new_row.product_id := current_row.product_id;
+    new_row.rules := current_row.rules;
+    new_row.starts_on := current_row.starts_on;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.interest_terms := (NEW.event -> 'interest_terms');
+      new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.matures_on := (NEW.event ->> 'matures_on');
+      new_row.maturity_instruction := (NEW.event ->> 'maturity_instruction');
+      new_row.principal := (NEW.event ->> 'principal')::BIGINT;
+      new_row.product_id := (NEW.event ->> 'product_id')::UUID;
+      new_row.rules := (NEW.event -> 'rules');
+      new_row.starts_on := (NEW.event ->> 'starts_on');
+    WHEN 'rolled_over' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.matures_on := (NEW.event ->> 'matures_on');
+      new_row.starts_on := (NEW.event ->> 'starts_on');
+    WHEN 'matured' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+      new_row.interest_ledger_tx_id := (NEW.event ->> 'interest_ledger_tx_id')::UUID;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.matured_on := (NEW.event ->> 'matured_on');
+    WHEN 'broken' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.broken_on := (NEW.event ->> 'broken_on');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+      new_row.penalty_ledger_tx_id := (NEW.event ->> 'penalty_ledger_tx_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_term_deposit_events_rollup (
+    id,
+    last_sequence,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    broken_on,
+    deposit_account_id,
+    interest,
+    interest_ledger_tx_id,
+    interest_terms,
+    ledger_account_id,
+    ledger_tx_id,
+    matured_on,
+    matures_on,
+    maturity_instruction,
+    penalty,
+    penalty_ledger_tx_id,
+    principal,
+    product_id,
+    rules,
+    starts_on
+  )
+  VALUES (
+    new_row.id,
+    new_row.last_sequence,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.broken_on,
+    new_row.deposit_account_id,
+    new_row.interest,
+    new_row.interest_ledger_tx_id,
+    new_row.interest_terms,
+    new_row.ledger_account_id,
+    new_row.ledger_tx_id,
+    new_row.matured_on,
+    new_row.matures_on,
+    new_row.maturity_instruction,
+    new_row.penalty,
+    new_row.penalty_ledger_tx_id,
+    new_row.principal,
+    new_row.product_id,
+    new_row.rules,
+    new_row.starts_on
+  )
+  ON CONFLICT (id) DO UPDATE SET
+    last_sequence = EXCLUDED.last_sequence,
+    modified_at = EXCLUDED.modified_at,
+    audit_entry_ids = EXCLUDED.audit_entry_ids,
+    broken_on = EXCLUDED.broken_on,
+    deposit_account_id = EXCLUDED.deposit_account_id,
+    interest = EXCLUDED.interest,
+    interest_ledger_tx_id = EXCLUDED.interest_ledger_tx_id,
+    interest_terms = EXCLUDED.interest_terms,
+    ledger_account_id = EXCLUDED.ledger_account_id,
+    ledger_tx_id = EXCLUDED.ledger_tx_id,
+    matured_on = EXCLUDED.matured_on,
+    matures_on = EXCLUDED.matures_on,
+    maturity_instruction = EXCLUDED.maturity_instruction,
+    penalty = EXCLUDED.penalty,
+    penalty_ledger_tx_id = EXCLUDED.penalty_ledger_tx_id,
+    principal = EXCLUDED.principal,
+    product_id = EXCLUDED.product_id,
+    rules = EXCLUDED.rules,
+    starts_on = EXCLUDED.starts_on;
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for TermDepositEvent
+CREATE TRIGGER core_term_deposit_events_rollup_trigger
+  AFTER INSERT ON core_term_deposit_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_term_deposit_events_rollup_trigger();
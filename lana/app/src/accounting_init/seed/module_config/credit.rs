@@ -15,11 +15,19 @@ struct CreditConfigData {
     facility_omnibus_parent_code: String,
     collateral_omnibus_parent_code: String,
     in_liquidation_omnibus_parent_code: String,
+    liquidator_omnibus_parent_code: String,
+    liquidation_proceeds_omnibus_parent_code: String,
+    credit_loss_expense_omnibus_parent_code: String,
     facility_parent_code: String,
     collateral_parent_code: String,
     in_liquidation_parent_code: String,
     interest_income_parent_code: String,
     fee_income_parent_code: String,
+    unapplied_funds_parent_code: String,
+    penalty_income_parent_code: String,
+    commitment_fee_income_parent_code: String,
+    maintenance_fee_income_parent_code: String,
+    disbursal_fee_income_parent_code: String,
     short_term_individual_interest_receivable_parent_code: String,
     short_term_government_entity_interest_receivable_parent_code: String,
     short_term_private_company_interest_receivable_parent_code: String,
@@ -67,11 +75,19 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         facility_omnibus_parent_code,
         collateral_omnibus_parent_code,
         in_liquidation_omnibus_parent_code,
+        liquidator_omnibus_parent_code,
+        liquidation_proceeds_omnibus_parent_code,
+        credit_loss_expense_omnibus_parent_code,
         facility_parent_code,
         collateral_parent_code,
         in_liquidation_parent_code,
         interest_income_parent_code,
         fee_income_parent_code,
+        unapplied_funds_parent_code,
+        penalty_income_parent_code,
+        commitment_fee_income_parent_code,
+        maintenance_fee_income_parent_code,
+        disbursal_fee_income_parent_code,
         short_term_individual_interest_receivable_parent_code,
         short_term_government_entity_interest_receivable_parent_code,
         short_term_private_company_interest_receivable_parent_code,
@@ -116,11 +132,29 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         .chart_of_account_in_liquidation_omnibus_parent_code(
             in_liquidation_omnibus_parent_code.parse()?,
         )
+        .chart_of_account_liquidator_omnibus_parent_code(liquidator_omnibus_parent_code.parse()?)
+        .chart_of_account_liquidation_proceeds_omnibus_parent_code(
+            liquidation_proceeds_omnibus_parent_code.parse()?,
+        )
+        .chart_of_account_credit_loss_expense_omnibus_parent_code(
+            credit_loss_expense_omnibus_parent_code.parse()?,
+        )
         .chart_of_account_facility_parent_code(facility_parent_code.parse()?)
         .chart_of_account_collateral_parent_code(collateral_parent_code.parse()?)
         .chart_of_account_in_liquidation_parent_code(in_liquidation_parent_code.parse()?)
         .chart_of_account_interest_income_parent_code(interest_income_parent_code.parse()?)
         .chart_of_account_fee_income_parent_code(fee_income_parent_code.parse()?)
+        .chart_of_account_unapplied_funds_parent_code(unapplied_funds_parent_code.parse()?)
+        .chart_of_account_penalty_income_parent_code(penalty_income_parent_code.parse()?)
+        .chart_of_account_commitment_fee_income_parent_code(
+            commitment_fee_income_parent_code.parse()?,
+        )
+        .chart_of_account_maintenance_fee_income_parent_code(
+            maintenance_fee_income_parent_code.parse()?,
+        )
+        .chart_of_account_disbursal_fee_income_parent_code(
+            disbursal_fee_income_parent_code.parse()?,
+        )
         .chart_of_account_short_term_individual_interest_receivable_parent_code(
             short_term_individual_interest_receivable_parent_code.parse()?,
         )
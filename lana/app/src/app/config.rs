@@ -4,9 +4,10 @@ use std::path::PathBuf;
 
 use crate::{
     access::config::AccessConfig, applicant::SumsubConfig, credit::CreditConfig,
-    custody::CustodyConfig, customer_sync::CustomerSyncConfig, job::JobExecutorConfig,
-    notification::NotificationConfig, report::ReportConfig, service_account::ServiceAccountConfig,
-    storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
+    custody::CustodyConfig, customer_sync::CustomerSyncConfig, deposit::DepositConfig,
+    job::JobExecutorConfig, notification::NotificationConfig, price::PriceConfig,
+    report::ReportConfig, service_account::ServiceAccountConfig, storage::config::StorageConfig,
+    user_onboarding::UserOnboardingConfig,
 };
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -20,6 +21,8 @@ pub struct AppConfig {
     #[serde(default)]
     pub credit: CreditConfig,
     #[serde(default)]
+    pub deposit: DepositConfig,
+    #[serde(default)]
     pub service_account: ServiceAccountConfig,
     #[serde(default)]
     pub report: ReportConfig,
@@ -35,6 +38,8 @@ pub struct AppConfig {
     pub custody: CustodyConfig,
     #[serde(default)]
     pub notification: NotificationConfig,
+    #[serde(default)]
+    pub price: PriceConfig,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -12,7 +12,7 @@ use crate::{
     accounting_init::{ChartsInit, JournalInit, StatementsInit},
     applicant::Applicants,
     audit::{Audit, AuditCursor, AuditEntry},
-    authorization::{AppAction, AppObject, AuditAction, Authorization, seed},
+    authorization::{AppAction, AppObject, AuditAction, Authorization, PriceRecordAction, seed},
     credit::Credit,
     custody::Custody,
     customer::Customers,
@@ -24,7 +24,7 @@ use crate::{
     job::Jobs,
     notification::Notification,
     outbox::Outbox,
-    price::Price,
+    price::{Price, PriceRecord, PriceRecordCursor},
     primitives::Subject,
     report::Reports,
     storage::Storage,
@@ -78,7 +78,7 @@ impl LanaApp {
 
         let dashboard = Dashboard::init(&pool, &authz, &jobs, &outbox).await?;
         let governance = Governance::new(&pool, &authz, &outbox);
-        let price = Price::new();
+        let price = Price::init(&pool, &jobs, &outbox, config.price).await?;
         let storage = Storage::new(&config.storage);
         let documents = DocumentStorage::new(&pool, &storage);
         let report = Reports::init(&pool, &config.report, &authz, &jobs, &storage).await?;
@@ -107,6 +107,7 @@ impl LanaApp {
         let customers = Customers::new(&pool, &authz, &outbox, documents.clone());
         let deposits = Deposits::init(
             &pool,
+            config.deposit,
             &authz,
             &outbox,
             &governance,
@@ -132,6 +133,7 @@ impl LanaApp {
             &customers,
             &custody,
             &price,
+            documents.clone(),
             &outbox,
             &cala,
             journal_init.journal_id,
@@ -221,6 +223,43 @@ impl LanaApp {
         self.audit.list(query).await.map_err(ApplicationError::from)
     }
 
+    #[instrument(name = "lana.price.price_at", skip(self), err)]
+    pub async fn price_at(
+        &self,
+        sub: &Subject,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<PriceRecord>, ApplicationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                AppObject::all_price_records(),
+                AppAction::PriceRecord(PriceRecordAction::Read),
+            )
+            .await?;
+
+        Ok(self.price.price_at(at).await?)
+    }
+
+    #[instrument(name = "lana.price.list_price_records", skip(self), err)]
+    pub async fn list_price_records(
+        &self,
+        sub: &Subject,
+        from: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+        query: es_entity::PaginatedQueryArgs<PriceRecordCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<PriceRecord, PriceRecordCursor>, ApplicationError>
+    {
+        self.authz
+            .enforce_permission(
+                sub,
+                AppObject::all_price_records(),
+                AppAction::PriceRecord(PriceRecordAction::List),
+            )
+            .await?;
+
+        Ok(self.price.list_records(from, until, query).await?)
+    }
+
     pub fn accounting(&self) -> &Accounting {
         &self.accounting
     }
@@ -53,8 +53,9 @@ pub mod access {
 
 pub mod customer {
     pub use core_customer::{
-        AccountStatus, Customer, CustomerDocumentId, CustomerId, CustomerType, CustomersCursor,
-        CustomersSortBy, FindManyCustomers, KycLevel, Sort, error,
+        AccountStatus, BankAccountDetails, BeneficiaryAccount, BeneficiaryAccountId, Customer,
+        CustomerDocumentId, CustomerId, CustomerType, CustomersCursor, CustomersSortBy,
+        FindManyCustomers, KycLevel, Sort, error,
     };
     pub type Customers =
         core_customer::Customers<crate::authorization::Authorization, lana_events::LanaEvent>;
@@ -78,9 +79,13 @@ pub mod governance {
     use crate::authorization::Authorization;
     use lana_events::LanaEvent;
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
+    pub use crate::credit::APPROVE_COLLATERAL_WITHDRAWAL_PROCESS;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROCESS;
+    pub use crate::credit::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
-    pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
+    pub use core_deposit::{
+        APPROVE_DEPOSIT_REVERSAL_PROCESS, APPROVE_TRANSFER_PROCESS, APPROVE_WITHDRAWAL_PROCESS,
+    };
     pub use governance::{
         ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules, Committee,
         CommitteeId, Policy, approval_process_cursor, committee_cursor, error, policy_cursor,
@@ -101,8 +106,9 @@ pub mod audit {
 pub mod deposit {
     pub use core_deposit::{
         ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
-        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositId,
-        DepositsByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalStatus,
+        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry,
+        DepositConfig, DepositId, DepositReversalReason, DepositStatus, DepositsByCreatedAtCursor,
+        Transfer, TransferId, TransferStatus, Withdrawal, WithdrawalId, WithdrawalStatus,
         WithdrawalsByCreatedAtCursor, error,
     };
 
@@ -146,15 +152,20 @@ pub mod custody {
 
 pub mod credit {
     pub use core_credit::{
-        APPROVE_CREDIT_FACILITY_PROCESS, APPROVE_DISBURSAL_PROCESS,
+        APPROVE_COLLATERAL_WITHDRAWAL_PROCESS, APPROVE_CREDIT_FACILITY_PROCESS,
+        APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS, APPROVE_DISBURSAL_PROCESS,
         ChartOfAccountsIntegrationConfig, Collateral, CollateralUpdated, CollateralizationUpdated,
         CoreCreditEvent, CreditConfig, CreditFacilitiesCursor, CreditFacilitiesSortBy,
         CreditFacility, CreditFacilityApproved, CreditFacilityBalanceSummary,
         CreditFacilityHistoryEntry, CreditFacilityRepaymentPlanEntry, CreditFacilityStatus,
-        Disbursal, DisbursalExecuted, DisbursalStatus, DisbursalsCursor, DisbursalsSortBy,
-        FacilityCVL, FindManyCreditFacilities, FindManyDisbursals, IncrementalPayment,
-        InterestAccrualsPosted, ListDirection, ObligationMovedToLiquidation, Payment,
-        PaymentAllocation, RepaymentStatus, Sort, TermsTemplate, error, terms_template_error,
+        CreditFacilityTermsAmended, Disbursal, DisbursalExecuted, DisbursalStatus,
+        DisbursalsCursor, DisbursalsSortBy, FacilityCVL, FindManyCreditFacilities,
+        FindManyDisbursals, IncrementalPayment, InterestAccrualsPosted, ListDirection,
+        ObligationMovedToLiquidation, Payment, PaymentAllocation, PaymentOutcome, PayoffQuote,
+        PriceShock, PriceShockBucket, PriceShockFacilityResult, PriceShockSimulation,
+        ReferenceRate, ReferenceRateFixing, RepaymentStatus, Sort, TermsTemplate,
+        disbursal_payout_error, error, payment_rail_error, price_shock_simulation_error,
+        reference_rate_error, terms_template_error,
     };
 
     pub type Credit =
@@ -163,7 +174,8 @@ pub mod credit {
 
 pub mod terms {
     pub use core_credit::{
-        AnnualRatePct, CVLPct, CollateralizationState, FacilityDuration, InterestInterval,
-        ObligationDuration, OneTimeFeeRatePct, TermValues,
+        AnnualRatePct, AppliedRateFixing, CVLPct, CollateralizationState, DayCountConvention,
+        FacilityDuration, FeeSchedule, FloatingRate, InterestInterval, ObligationDuration,
+        OneTimeFeeRatePct, PaymentAllocationStrategy, PrincipalRepayment, TermValues,
     };
 }
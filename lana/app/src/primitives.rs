@@ -8,11 +8,13 @@ pub use core_accounting::{
 };
 pub use core_credit::{
     CollateralAction, CollateralId, CreditFacilityId, CreditFacilityStatus, DisbursalId,
-    DisbursalStatus, PaymentAllocationId, PaymentId, TermsTemplateId,
+    DisbursalStatus, PaymentAllocationId, PaymentId, ReferenceRateId, TermsTemplateId,
 };
 pub use core_custody::{CustodianId, WalletId};
 pub use core_customer::{CustomerDocumentId, CustomerId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    DepositAccountHolderId, DepositAccountId, DepositId, TransferId, WithdrawalId,
+};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use document_storage::{DocumentId, ReferenceId};
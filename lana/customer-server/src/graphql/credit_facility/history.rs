@@ -1,6 +1,9 @@
 use async_graphql::*;
 
-use crate::primitives::*;
+use crate::{
+    graphql::terms::{AppliedRateFixing, TermValues},
+    primitives::*,
+};
 pub use lana_app::primitives::CollateralAction;
 
 #[derive(async_graphql::Union)]
@@ -12,6 +15,7 @@ pub enum CreditFacilityHistoryEntry {
     Disbursal(CreditFacilityDisbursalExecuted),
     Interest(CreditFacilityInterestAccrued),
     ReservedForLiquidation(CreditFacilityLiquidationAmountReserved),
+    TermsAmended(CreditFacilityTermsAmended),
 }
 
 #[derive(SimpleObject)]
@@ -65,6 +69,7 @@ pub struct CreditFacilityInterestAccrued {
     pub effective: Date,
     pub tx_id: UUID,
     pub days: u32,
+    pub rate_fixing: Option<AppliedRateFixing>,
 }
 
 #[derive(SimpleObject)]
@@ -75,6 +80,15 @@ pub struct CreditFacilityLiquidationAmountReserved {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityTermsAmended {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub matures_at: Timestamp,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
@@ -99,6 +113,9 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation) => {
                 CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::TermsAmended(amendment) => {
+                CreditFacilityHistoryEntry::TermsAmended(amendment.into())
+            }
         }
     }
 }
@@ -170,6 +187,7 @@ impl From<lana_app::credit::InterestAccrualsPosted> for CreditFacilityInterestAc
             effective: interest.effective.into(),
             tx_id: UUID::from(interest.tx_id),
             days: interest.days,
+            rate_fixing: interest.rate_fixing.map(AppliedRateFixing::from),
         }
     }
 }
@@ -186,3 +204,15 @@ impl From<lana_app::credit::ObligationMovedToLiquidation>
         }
     }
 }
+
+impl From<lana_app::credit::CreditFacilityTermsAmended> for CreditFacilityTermsAmended {
+    fn from(amendment: lana_app::credit::CreditFacilityTermsAmended) -> Self {
+        Self {
+            previous_terms: amendment.previous_terms.into(),
+            terms: amendment.terms.into(),
+            matures_at: amendment.matures_at.into(),
+            recorded_at: amendment.recorded_at.into(),
+            effective: amendment.effective.into(),
+        }
+    }
+}
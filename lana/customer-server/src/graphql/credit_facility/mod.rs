@@ -2,6 +2,7 @@ mod balance;
 pub mod disbursal;
 mod history;
 pub(super) mod payment_allocation;
+mod payoff_quote;
 mod repayment;
 
 use async_graphql::*;
@@ -18,6 +19,7 @@ use super::terms::*;
 use balance::*;
 use disbursal::*;
 use history::*;
+use payoff_quote::*;
 use repayment::*;
 
 #[derive(SimpleObject, Clone)]
@@ -73,6 +75,21 @@ impl CreditFacility {
         Ok(CreditFacilityBalance::from(balance))
     }
 
+    async fn payoff_quote(
+        &self,
+        ctx: &Context<'_>,
+        as_of: Timestamp,
+    ) -> async_graphql::Result<CreditFacilityPayoffQuote> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let quote = app
+            .credit()
+            .for_subject(sub)?
+            .payoff_quote(self.entity.id, as_of.into_inner())
+            .await?;
+
+        Ok(CreditFacilityPayoffQuote::from(quote))
+    }
+
     async fn current_cvl(&self, ctx: &Context<'_>) -> async_graphql::Result<CVLPct> {
         let app = ctx.data_unchecked::<LanaApp>();
         Ok(app.credit().current_cvl(&self.entity).await?)
@@ -6,6 +6,7 @@ use crate::primitives::*;
 pub enum CreditFacilityRepaymentType {
     Disbursal,
     Interest,
+    Fee,
 }
 
 #[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
@@ -62,6 +63,14 @@ impl From<lana_app::credit::CreditFacilityRepaymentPlanEntry> for CreditFacility
                 accrual_at: repayment.recorded_at.into(),
                 due_at: repayment.due_at.into(),
             },
+            lana_app::credit::CreditFacilityRepaymentPlanEntry::Fee(repayment) => Self {
+                repayment_type: CreditFacilityRepaymentType::Fee,
+                status: repayment.status.into(),
+                initial: repayment.initial,
+                outstanding: repayment.outstanding,
+                accrual_at: repayment.recorded_at.into(),
+                due_at: repayment.due_at.into(),
+            },
         }
     }
 }
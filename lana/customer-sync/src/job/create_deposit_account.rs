@@ -215,6 +215,7 @@ where
                 .create_account(
                     &<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject as SystemSubject>::system(),
                     id,
+                    self.deposit.default_product_id(),
                     active,
                     customer_type,
                 )
@@ -42,6 +42,14 @@ impl DashboardValues {
                 self.total_disbursed -= *amount;
                 true
             }
+            LanaEvent::Credit(CoreCreditEvent::FacilityRepaymentReversed {
+                obligation_type: ObligationType::Disbursal,
+                amount,
+                ..
+            }) => {
+                self.total_disbursed += *amount;
+                true
+            }
             LanaEvent::Credit(CoreCreditEvent::FacilityCollateralUpdated {
                 abs_diff,
                 action: CollateralAction::Add,
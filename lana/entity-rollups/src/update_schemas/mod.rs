@@ -7,12 +7,15 @@ use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
 use core_accounting::event_schema::{ChartEvent, ManualTransactionEvent};
 use core_credit::event_schema::{
     CollateralEvent, CreditFacilityEvent, DisbursalEvent, InterestAccrualCycleEvent,
-    LiquidationProcessEvent, ObligationEvent, PaymentAllocationEvent, PaymentEvent,
-    TermsTemplateEvent,
+    LiquidationProcessEvent, MarginCallEvent, ObligationEvent, PaymentAllocationEvent,
+    PaymentEvent, ReferenceRateEvent, TermsTemplateEvent,
 };
 use core_custody::event_schema::CustodianEvent;
 use core_customer::event_schema::CustomerEvent;
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    DepositAccountEvent, DepositEvent, DepositProductEvent, TermDepositEvent, TransferEvent,
+    WithdrawalEvent,
+};
 use document_storage::event_schema::DocumentEvent;
 use governance::event_schema::{ApprovalProcessEvent, CommitteeEvent, PolicyEvent};
 use schemars::schema_for;
@@ -198,9 +201,29 @@ pub fn update_schemas(
         SchemaInfo {
             name: "DepositEvent",
             filename: "deposit_event_schema.json",
+            toggle_events: vec!["ReversalApprovalProcessConcluded", "Reverted"],
             generate_schema: || serde_json::to_value(schema_for!(DepositEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "DepositProductEvent",
+            filename: "deposit_product_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(DepositProductEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "TermDepositEvent",
+            filename: "term_deposit_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(TermDepositEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "TransferEvent",
+            filename: "transfer_event_schema.json",
+            toggle_events: vec!["ApprovalProcessConcluded", "Executed"],
+            generate_schema: || serde_json::to_value(schema_for!(TransferEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "WithdrawalEvent",
             filename: "withdrawal_event_schema.json",
@@ -339,6 +362,12 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(TermsTemplateEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "ReferenceRateEvent",
+            filename: "reference_rate_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(ReferenceRateEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "ChartEvent",
             filename: "chart_event_schema.json",
@@ -372,6 +401,13 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(LiquidationProcessEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "MarginCallEvent",
+            filename: "margin_call_event_schema.json",
+            toggle_events: vec!["Cured", "Escalated"],
+            generate_schema: || serde_json::to_value(schema_for!(MarginCallEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "DocumentEvent",
             filename: "document_event_schema.json",
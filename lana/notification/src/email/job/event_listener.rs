@@ -8,7 +8,7 @@ use job::{
 use lana_events::{CoreCreditEvent, LanaEvent};
 use outbox::Outbox;
 
-use crate::email::EmailNotification;
+use crate::email::{EmailNotification, templates::MarginCallStage};
 
 #[derive(Serialize, Deserialize)]
 pub struct EmailEventListenerConfig;
@@ -91,15 +91,59 @@ impl EmailEventListenerRunner {
         db: &mut es_entity::DbOp<'_>,
         event: &LanaEvent,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        if let LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
-            id,
-            credit_facility_id,
-            amount,
-        }) = event
-        {
-            self.email_notification
-                .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
-                .await?;
+        match event {
+            LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
+                id,
+                credit_facility_id,
+                amount,
+            }) => {
+                self.email_notification
+                    .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::MarginCallOpened {
+                id,
+                credit_facility_id,
+                ..
+            }) => {
+                self.email_notification
+                    .send_margin_call_notification(
+                        db,
+                        id,
+                        credit_facility_id,
+                        MarginCallStage::Opened,
+                    )
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::MarginCallCured {
+                id,
+                credit_facility_id,
+                ..
+            }) => {
+                self.email_notification
+                    .send_margin_call_notification(
+                        db,
+                        id,
+                        credit_facility_id,
+                        MarginCallStage::Cured,
+                    )
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::MarginCallEscalated {
+                id,
+                credit_facility_id,
+                ..
+            }) => {
+                self.email_notification
+                    .send_margin_call_notification(
+                        db,
+                        id,
+                        credit_facility_id,
+                        MarginCallStage::Escalated,
+                    )
+                    .await?;
+            }
+            _ => {}
         }
         Ok(())
     }
@@ -7,14 +7,16 @@ pub mod templates;
 
 use ::job::{JobId, Jobs};
 use core_access::user::Users;
-use core_credit::{CoreCredit, CreditFacilityId, ObligationId, ObligationType};
+use core_credit::{CoreCredit, CreditFacilityId, MarginCallId, ObligationId, ObligationType};
 use core_customer::Customers;
 use job::{EmailSenderConfig, EmailSenderInit};
 use lana_events::LanaEvent;
 
 use crate::{Authorization, LanaAudit};
 use smtp::SmtpClient;
-use templates::{EmailTemplate, EmailType, OverduePaymentEmailData};
+use templates::{
+    EmailTemplate, EmailType, MarginCallEmailData, MarginCallStage, OverduePaymentEmailData,
+};
 
 pub use config::EmailConfig;
 pub use error::EmailError;
@@ -75,6 +77,8 @@ impl EmailNotification {
             payment_type: match obligation.obligation_type {
                 ObligationType::Disbursal => "Principal Repayment".to_string(),
                 ObligationType::Interest => "Interest Payment".to_string(),
+                ObligationType::Penalty => "Penalty Interest Payment".to_string(),
+                ObligationType::Fee => "Fee Payment".to_string(),
             },
             original_amount: obligation.initial_amount,
             outstanding_amount: *amount,
@@ -82,6 +86,69 @@ impl EmailNotification {
             customer_email: customer.email,
         };
 
+        self.send_to_admins_in_op(db, EmailType::OverduePayment(email_data))
+            .await
+    }
+
+    pub async fn send_margin_call_notification(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        margin_call_id: &MarginCallId,
+        credit_facility_id: &CreditFacilityId,
+        stage: MarginCallStage,
+    ) -> Result<(), EmailError> {
+        let margin_call = self
+            .credit
+            .margin_calls()
+            .find_by_id_without_audit(*margin_call_id)
+            .await?;
+
+        let credit_facility = self
+            .credit
+            .facilities()
+            .find_by_id_without_audit(*credit_facility_id)
+            .await?;
+
+        let customer = self
+            .customers
+            .find_by_id_without_audit(credit_facility.customer_id)
+            .await?;
+
+        let email_data = MarginCallEmailData {
+            facility_id: credit_facility_id.to_string(),
+            stage,
+            outstanding_amount: margin_call.outstanding,
+            cure_deadline: margin_call.cure_deadline,
+            customer_email: customer.email.clone(),
+            for_admin: false,
+        };
+
+        self.jobs
+            .create_and_spawn_in_op(
+                db,
+                JobId::new(),
+                EmailSenderConfig {
+                    recipient: customer.email,
+                    email_type: EmailType::MarginCall(email_data.clone()),
+                },
+            )
+            .await?;
+
+        self.send_to_admins_in_op(
+            db,
+            EmailType::MarginCall(MarginCallEmailData {
+                for_admin: true,
+                ..email_data
+            }),
+        )
+        .await
+    }
+
+    async fn send_to_admins_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        email_type: EmailType,
+    ) -> Result<(), EmailError> {
         let mut query = es_entity::PaginatedQueryArgs::default();
         loop {
             let first = query.first;
@@ -96,7 +163,7 @@ impl EmailNotification {
             for user in entities {
                 let email_config = EmailSenderConfig {
                     recipient: user.email,
-                    email_type: EmailType::OverduePayment(email_data.clone()),
+                    email_type: email_type.clone(),
                 };
                 self.jobs
                     .create_and_spawn_in_op(db, JobId::new(), email_config)
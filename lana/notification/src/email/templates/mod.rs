@@ -6,9 +6,10 @@ use serde_json::json;
 
 use crate::email::error::EmailError;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum EmailType {
     OverduePayment(OverduePaymentEmailData),
+    MarginCall(MarginCallEmailData),
     General { subject: String, body: String },
 }
 
@@ -25,6 +26,8 @@ impl EmailTemplate {
         handlebars.register_template_string("styles", include_str!("partials/styles.hbs"))?;
         handlebars.register_template_string("general", include_str!("views/general.hbs"))?;
         handlebars.register_template_string("overdue", include_str!("views/overdue.hbs"))?;
+        handlebars
+            .register_template_string("margin_call", include_str!("views/margin_call.hbs"))?;
         Ok(Self {
             handlebars,
             admin_panel_url,
@@ -34,6 +37,7 @@ impl EmailTemplate {
     pub fn render_email(&self, email_type: &EmailType) -> Result<(String, String), EmailError> {
         match email_type {
             EmailType::OverduePayment(data) => self.render_overdue_payment_email(data),
+            EmailType::MarginCall(data) => self.render_margin_call_email(data),
             EmailType::General { subject, body } => self.generic_email_template(subject, body),
         }
     }
@@ -76,6 +80,53 @@ impl EmailTemplate {
         let html_body = self.handlebars.render("overdue", &data)?;
         Ok((subject, html_body))
     }
+
+    fn render_margin_call_email(
+        &self,
+        data: &MarginCallEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let cure_deadline = data.cure_deadline.format("%Y-%m-%d %H:%M UTC");
+        let (subject, message) = match data.stage {
+            MarginCallStage::Opened => (
+                format!("Lana Bank: Margin Call on Credit Facility {}", data.facility_id),
+                format!(
+                    "The collateral backing this credit facility has fallen below the margin call threshold. \
+                     Add collateral or make a repayment before {cure_deadline} to avoid liquidation."
+                ),
+            ),
+            MarginCallStage::Cured => (
+                format!("Lana Bank: Margin Call Resolved - {}", data.facility_id),
+                "The credit facility is fully collateralized again and the margin call has been closed."
+                    .to_string(),
+            ),
+            MarginCallStage::Escalated => (
+                format!(
+                    "Lana Bank: Margin Call Escalated to Liquidation - {}",
+                    data.facility_id
+                ),
+                format!(
+                    "The margin call was not cured before {cure_deadline}. \
+                     The outstanding obligations of this credit facility are being liquidated."
+                ),
+            ),
+        };
+        let facility_url = data.for_admin.then(|| {
+            format!(
+                "{}/credit-facilities/{}",
+                self.admin_panel_url, data.facility_id
+            )
+        });
+        let data = json!({
+            "subject": &subject,
+            "message": &message,
+            "outstanding_amount": data.outstanding_amount.formatted_usd(),
+            "cure_deadline": cure_deadline.to_string(),
+            "customer_email": &data.customer_email,
+            "facility_url": &facility_url,
+        });
+        let html_body = self.handlebars.render("margin_call", &data)?;
+        Ok((subject, html_body))
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -87,3 +138,20 @@ pub struct OverduePaymentEmailData {
     pub due_date: DateTime<Utc>,
     pub customer_email: String,
 }
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum MarginCallStage {
+    Opened,
+    Cured,
+    Escalated,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MarginCallEmailData {
+    pub facility_id: String,
+    pub stage: MarginCallStage,
+    pub outstanding_amount: UsdCents,
+    pub cure_deadline: DateTime<Utc>,
+    pub customer_email: String,
+    pub for_admin: bool,
+}
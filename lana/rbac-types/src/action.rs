@@ -177,6 +177,7 @@ macro_rules! impl_trivial_action {
 pub enum AppAction {
     Report(ReportAction),
     Audit(AuditAction),
+    PriceRecord(PriceRecordAction),
 }
 
 impl AppAction {
@@ -189,6 +190,7 @@ impl AppAction {
             let actions = match entity {
                 Report => ReportAction::describe(),
                 Audit => AuditAction::describe(),
+                PriceRecord => PriceRecordAction::describe(),
             };
 
             result.push((*entity, actions));
@@ -205,6 +207,7 @@ impl Display for AppAction {
         match self {
             Report(action) => action.fmt(f),
             Audit(action) => action.fmt(f),
+            PriceRecord(action) => action.fmt(f),
         }
     }
 }
@@ -220,6 +223,7 @@ impl FromStr for AppAction {
         let res = match entity.parse()? {
             Report => AppAction::from(action.parse::<ReportAction>()?),
             Audit => AppAction::from(action.parse::<AuditAction>()?),
+            PriceRecord => AppAction::from(action.parse::<PriceRecordAction>()?),
         };
         Ok(res)
     }
@@ -251,6 +255,37 @@ impl AuditAction {
 
 impl_trivial_action!(AuditAction, Audit);
 
+#[derive(Clone, PartialEq, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PriceRecordAction {
+    Read,
+    List,
+}
+
+impl PriceRecordAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_APP_VIEWER, PERMISSION_SET_APP_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_APP_VIEWER, PERMISSION_SET_APP_WRITER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl_trivial_action!(PriceRecordAction, PriceRecord);
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ReportAction {
@@ -116,11 +116,12 @@ impl FromStr for LanaObject {
     }
 }
 
-es_entity::entity_id!(ApplicantId, AuditId);
+es_entity::entity_id!(ApplicantId, AuditId, PriceRecordId);
 
 pub type ApplicantAllOrOne = AllOrOne<ApplicantId>;
 pub type ReportAllOrOne = AllOrOne<ReportId>;
 pub type AuditAllOrOne = AllOrOne<AuditId>;
+pub type PriceRecordAllOrOne = AllOrOne<PriceRecordId>;
 
 #[derive(Clone, Copy, Debug, PartialEq, strum::EnumDiscriminants)]
 #[strum_discriminants(derive(strum::Display, strum::EnumString))]
@@ -129,6 +130,7 @@ pub enum AppObject {
     Applicant(ApplicantAllOrOne),
     Report(ReportAllOrOne),
     Audit(AuditAllOrOne),
+    PriceRecord(PriceRecordAllOrOne),
 }
 
 impl AppObject {
@@ -141,6 +143,9 @@ impl AppObject {
     pub const fn all_audits() -> Self {
         Self::Audit(AllOrOne::All)
     }
+    pub const fn all_price_records() -> Self {
+        Self::PriceRecord(AllOrOne::All)
+    }
 }
 
 impl Display for AppObject {
@@ -150,6 +155,7 @@ impl Display for AppObject {
             Self::Applicant(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Self::Report(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Self::Audit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Self::PriceRecord(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
 }
@@ -173,6 +179,10 @@ impl FromStr for AppObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse AppObject")?;
                 Self::Audit(obj_ref)
             }
+            PriceRecord => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AppObject")?;
+                Self::PriceRecord(obj_ref)
+            }
         };
 
         Ok(res)
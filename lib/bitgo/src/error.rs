@@ -6,4 +6,6 @@ pub enum BitgoError {
     ReqwestError(#[from] reqwest::Error),
     #[error("KomainuError - InvalidEndpoint: {0}")]
     InvalidEndpoint(String),
+    #[error("BitgoError - Deserialization: {0}")]
+    Deserialization(#[from] serde_json::Error),
 }
@@ -79,4 +79,36 @@ impl BitgoClient {
 
         Ok((wallet, response))
     }
+
+    #[tracing::instrument(name = "bitgo.send_coins", skip(self), err)]
+    pub async fn send_coins(
+        &self,
+        wallet_id: &str,
+        sequence_id: &str,
+        address: &str,
+        amount_sats: u64,
+    ) -> Result<(Transfer, Value), BitgoError> {
+        let url = self
+            .endpoint
+            .join(&self.coin)
+            .expect("correct URL")
+            .join(&format!("wallet/{wallet_id}/sendcoins"))
+            .expect("correct URL");
+
+        let request = self
+            .http_client
+            .post(url)
+            .bearer_auth(&self.long_lived_token)
+            .json(&json!({
+                "address": address,
+                "amount": amount_sats.to_string(),
+                "sequenceId": sequence_id,
+                "walletPassphrase": &self.passphrase
+            }));
+
+        let response: Value = request.send().await?.error_for_status()?.json().await?;
+        let transfer = serde_json::from_value(response.clone())?;
+
+        Ok((transfer, response))
+    }
 }
@@ -12,3 +12,8 @@ pub struct Wallet {
 pub struct Address {
     pub address: String,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Transfer {
+    pub txid: String,
+}
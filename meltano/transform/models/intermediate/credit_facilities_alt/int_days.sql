@@ -1,7 +1,29 @@
-select
-    date(requested_at) as day,
-    any_value(last_price_usd having max requested_at) as close_price_usd_per_btc
+with recorded_prices as (
+    select
+        date(recorded_at) as day,
+        any_value(usd_per_btc having max recorded_at) as close_price_usd_per_btc
+
+    from {{ ref('stg_core_price_records') }}
+
+    group by day
+),
+
+ticker_prices as (
+    select
+        date(requested_at) as day,
+        any_value(last_price_usd having max requested_at) as close_price_usd_per_btc
 
-from {{ ref('stg_bitfinex_ticker_price') }}
+    from {{ ref('stg_bitfinex_ticker_price') }}
+
+    group by day
+)
+
+select
+    day,
+    coalesce(
+        recorded_prices.close_price_usd_per_btc,
+        ticker_prices.close_price_usd_per_btc
+    ) as close_price_usd_per_btc
 
-group by day
+from recorded_prices
+full outer join ticker_prices using (day)
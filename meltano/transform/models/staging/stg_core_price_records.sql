@@ -0,0 +1,16 @@
+{{ config(
+    materialized = 'incremental',
+    unique_key ='recorded_at',
+) }}
+
+select
+    recorded_at,
+    usd_cents_per_btc,
+    usd_cents_per_btc / 100.0 as usd_per_btc,
+    _sdc_batched_at
+
+from {{ source("lana", "public_core_price_records_view") }}
+
+{% if is_incremental() %}
+    where _sdc_batched_at >= (select coalesce(max(_sdc_batched_at), '1900-01-01') from {{ this }})
+{% endif %}